@@ -0,0 +1,35 @@
+import React from "react";
+import { BasicComponentWithPassthroughChildren } from "../types";
+
+type UnknownComponentData = {
+    message?: string;
+    code?: string;
+};
+
+/**
+ * An _UnknownComponent is used for elements that have no renderer.
+ *
+ * If core marked the element as an unknown component, e.g., a component from a newer version of DoenetML,
+ * a placeholder is rendered around its children so the rest of the document is unaffected.
+ * Other elements are omitted with a warning logged to the console.
+ */
+export const _UnknownComponent: BasicComponentWithPassthroughChildren<UnknownComponentData> =
+    ({ node, children, htmlId }) => {
+        if (node.data.code !== "UnknownComponentType") {
+            console.warn(`No component for element <${node.name}>`);
+            return null;
+        }
+        return (
+            <span
+                id={htmlId}
+                className="unknown-component"
+                title={node.data.message}
+                style={{ outline: "1px dashed currentColor" }}
+            >
+                <code className="unknown-component-name">
+                    {`<${node.name}>`}
+                </code>{" "}
+                {children}
+            </span>
+        );
+    };
@@ -0,0 +1,31 @@
+import React from "react";
+import { BasicComponentWithPassthroughChildren } from "../types";
+import { useAppDispatch } from "../../state/hooks";
+import { Action, AccordionPropsInText } from "@doenet/doenetml-worker";
+import { coreActions } from "../../state/redux-slices/core";
+
+export const Accordion: BasicComponentWithPassthroughChildren<{
+    props: AccordionPropsInText;
+}> = ({ node, children, htmlId }) => {
+    const id = node.data.id;
+    const { title, open } = node.data.props;
+    const dispatch = useAppDispatch();
+
+    const toggleOpen = React.useCallback(() => {
+        const action: Action = {
+            component: "accordion",
+            componentIdx: id,
+            actionName: "toggleOpen",
+        };
+        dispatch(coreActions.dispatchAction(action));
+    }, [dispatch, id]);
+
+    return (
+        <div className="accordion" id={htmlId}>
+            <button aria-expanded={open} onClick={toggleOpen}>
+                {title}
+            </button>
+            {open && <div>{children}</div>}
+        </div>
+    );
+};
@@ -1,19 +1,60 @@
 import React from "react";
-import { BasicComponent } from "../types";
-import { useAppSelector } from "../../state/hooks";
+import type { Action, AnswerPropsInText } from "@doenet/doenetml-worker";
+import { BasicComponentWithPassthroughChildren } from "../types";
+import { useAppDispatch, useAppSelector } from "../../state/hooks";
 import { renderingOnServerSelector } from "../../state/redux-slices/global";
+import { coreActions } from "../../state/redux-slices/core";
 
-export const Answer: BasicComponent = ({ node }) => {
+export const Answer: BasicComponentWithPassthroughChildren<{
+    props: AnswerPropsInText;
+}> = ({ node, children, htmlId }) => {
     const onServer = useAppSelector(renderingOnServerSelector);
+    const id = node.data.id;
+    const { creditAchieved, justSubmitted } = node.data.props;
+    const dispatch = useAppDispatch();
+
+    const submitAnswer = React.useCallback(() => {
+        const action: Action = {
+            component: "answer",
+            componentIdx: id,
+            actionName: "submitAnswer",
+        };
+        dispatch(coreActions.dispatchAction(action));
+    }, [dispatch, id]);
+
     if (onServer) {
-        return <span className="answer-input"></span>;
+        return (
+            <span className="answer-input" id={htmlId}>
+                {children}
+            </span>
+        );
     }
+
+    let status = null;
+    if (justSubmitted) {
+        status =
+            creditAchieved >= 1
+                ? "Correct"
+                : creditAchieved > 0
+                  ? `${Math.round(creditAchieved * 100)}% correct`
+                  : "Incorrect";
+    }
+
     return (
-        <span className="answer-input">
-            <input type="text" />
-            <button type="button" className="answer-submit">
+        <span className="answer-input" id={htmlId}>
+            {children}
+            <button
+                type="button"
+                className="answer-submit"
+                onClick={submitAnswer}
+            >
                 Submit
             </button>
+            {status ? (
+                <span className="answer-status" aria-live="polite">
+                    {status}
+                </span>
+            ) : null}
         </span>
     );
 };
@@ -0,0 +1,134 @@
+import React from "react";
+import { BasicComponent } from "../types";
+import { GraphContext, LAYER_OFFSETS } from "./graph";
+import * as JSG from "jsxgraph";
+import {
+    attachStandardGraphListeners,
+    GraphListenerActions,
+    GraphListeners,
+    removeStandardGraphListeners,
+} from "./jsxgraph/listeners";
+import type { Action } from "@doenet/doenetml-worker";
+import { useAppDispatch } from "../../state/hooks";
+import { coreActions } from "../../state/redux-slices/core";
+
+type CircleData = {
+    props: {
+        /** The numerical `[x, y]` coordinates of the center of the circle. */
+        numericalCenter: [number, number];
+        radius: number;
+    };
+};
+
+export const CircleInGraph: BasicComponent<CircleData> = ({ node }) => {
+    const board = React.useContext(GraphContext);
+    const circleRef = React.useRef<JSG.Circle | null>(null);
+    const circleListenersActions = React.useRef<GraphListenerActions>({});
+    const circleListenersAttached = React.useRef<GraphListeners>({});
+    const id = node.data.id;
+
+    const dispatch = useAppDispatch();
+
+    const { numericalCenter, radius } = node.data.props;
+
+    React.useEffect(() => {
+        if (!board) {
+            circleRef.current = null;
+            return;
+        }
+        if (circleRef.current) {
+            return;
+        }
+        const circle = createCircle(board, {
+            center: numericalCenter,
+            radius,
+            lineColor: "var(--mainBlue)",
+            hidden: false,
+            fixed: false,
+            layer: 0,
+            selectedStyle: { lineOpacity: 1, lineWidth: 2 },
+        });
+        circleRef.current = circle;
+
+        circleListenersActions.current.drag = function (e, interactionState) {
+            const center = circleRef.current!.center;
+            let action: Action = {
+                component: "circle",
+                actionName: "moveCircle",
+                componentIdx: id,
+                args: { center: [center.X(), center.Y()] },
+            };
+            dispatch(coreActions.dispatchAction(action));
+        };
+
+        circleListenersAttached.current = attachStandardGraphListeners(
+            circle,
+            circleListenersActions.current,
+        );
+
+        return () => {
+            removeStandardGraphListeners(
+                circle,
+                circleListenersAttached.current,
+            );
+            board.removeObject(circle);
+        };
+    }, [board, circleRef]);
+
+    if (!board || !circleRef.current) {
+        return null;
+    }
+
+    // We have a pre-existing circle. Update it so that it matches the values from the props.
+    const circle = circleRef.current;
+    circle.center.coords.setCoordinates(JXG.COORDS_BY_USER, [
+        1,
+        ...numericalCenter,
+    ]);
+    circle.setRadius(radius);
+    circle.needsUpdate = true;
+    circle.update();
+    board.updateRenderer();
+
+    return null;
+};
+
+function createCircle(
+    board: JSG.Board,
+    props: {
+        center: [number, number];
+        radius: number;
+        lineColor: string;
+        hidden: boolean;
+        fixed: boolean;
+        layer: number;
+        selectedStyle: {
+            lineOpacity: number;
+            lineWidth: number;
+        };
+    },
+) {
+    const lineColor = props.lineColor;
+
+    // Things to be passed to JSXGraph as attributes
+    const jsxCircleAttributes: JSG.CircleAttributes = {
+        visible: !props.hidden,
+        fixed: props.fixed,
+        layer: 10 * props.layer + LAYER_OFFSETS.line,
+        strokeColor: lineColor,
+        strokeOpacity: props.selectedStyle.lineOpacity,
+        highlightStrokeColor: lineColor,
+        highlightStrokeOpacity: props.selectedStyle.lineOpacity * 0.5,
+        strokeWidth: props.selectedStyle.lineWidth,
+        highlightStrokeWidth: props.selectedStyle.lineWidth,
+        center: { visible: false },
+    };
+
+    const circle: JSG.Circle = board.create(
+        "circle",
+        [[...props.center], props.radius],
+        jsxCircleAttributes,
+    );
+
+    return circle;
+}
@@ -4,14 +4,31 @@ import { GraphContext, LAYER_OFFSETS } from "./graph";
 import * as JSG from "jsxgraph";
 import {
     attachStandardGraphListeners,
+    GraphListenerActions,
     GraphListeners,
     removeStandardGraphListeners,
 } from "./jsxgraph/listeners";
+import type { Action } from "@doenet/doenetml-worker";
+import { useAppDispatch } from "../../state/hooks";
+import { coreActions } from "../../state/redux-slices/core";
 
-export const LineInGraph: BasicComponent = ({ node }) => {
+type LineData = {
+    props: {
+        /** The numerical `[x, y]` coordinates of the two points the line passes through. */
+        numericalPoints: [number, number][];
+    };
+};
+
+export const LineInGraph: BasicComponent<LineData> = ({ node }) => {
     const board = React.useContext(GraphContext);
     const lineRef = React.useRef<JSG.Line | null>(null);
+    const lineListenersActions = React.useRef<GraphListenerActions>({});
     const lineListenersAttached = React.useRef<GraphListeners>({});
+    const id = node.data.id;
+
+    const dispatch = useAppDispatch();
+
+    const numericalPoints = node.data.props.numericalPoints;
 
     React.useEffect(() => {
         if (!board) {
@@ -22,10 +39,7 @@ export const LineInGraph: BasicComponent = ({ node }) => {
             return;
         }
         const line = createLine(board, {
-            numericalPoints: [
-                [0, 0],
-                [1, 1],
-            ],
+            numericalPoints,
             labelForGraph: "test",
             lineColor: "var(--mainGreen)",
             hidden: false,
@@ -41,8 +55,24 @@ export const LineInGraph: BasicComponent = ({ node }) => {
             return;
         }
 
-        // TODO: actually create the line listeners and actions
-        lineListenersAttached.current = attachStandardGraphListeners(line, {});
+        lineListenersActions.current.drag = function (e, interactionState) {
+            const line = lineRef.current!;
+            let action: Action = {
+                component: "line",
+                actionName: "moveLine",
+                componentIdx: id,
+                args: {
+                    point1coords: [line.point1.X(), line.point1.Y()],
+                    point2coords: [line.point2.X(), line.point2.Y()],
+                },
+            };
+            dispatch(coreActions.dispatchAction(action));
+        };
+
+        lineListenersAttached.current = attachStandardGraphListeners(
+            line,
+            lineListenersActions.current,
+        );
 
         return () => {
             removeStandardGraphListeners(line, lineListenersAttached.current);
@@ -50,17 +80,33 @@ export const LineInGraph: BasicComponent = ({ node }) => {
         };
     }, [board, lineRef]);
 
-    if (!board) {
+    if (!board || !lineRef.current) {
         return null;
     }
 
+    // We have a pre-existing line. Move its points to match the values from the props.
+    const line = lineRef.current;
+    if (numericalPoints.length === 2) {
+        line.point1.coords.setCoordinates(JXG.COORDS_BY_USER, [
+            1,
+            ...numericalPoints[0],
+        ]);
+        line.point2.coords.setCoordinates(JXG.COORDS_BY_USER, [
+            1,
+            ...numericalPoints[1],
+        ]);
+        line.needsUpdate = true;
+        line.update();
+        board.updateRenderer();
+    }
+
     return null;
 };
 
 function createLine(
     board: JSG.Board,
     props: {
-        numericalPoints: [[number, number], [number, number]];
+        numericalPoints: [number, number][];
         labelForGraph: string;
         lineColor: string;
         hidden: boolean;
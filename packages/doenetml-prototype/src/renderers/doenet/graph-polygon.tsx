@@ -0,0 +1,179 @@
+import React from "react";
+import { BasicComponent } from "../types";
+import { GraphContext, LAYER_OFFSETS } from "./graph";
+import * as JSG from "jsxgraph";
+import {
+    attachStandardGraphListeners,
+    GraphListeners,
+    removeStandardGraphListeners,
+} from "./jsxgraph/listeners";
+import type { Action } from "@doenet/doenetml-worker";
+import { useAppDispatch } from "../../state/hooks";
+import { coreActions } from "../../state/redux-slices/core";
+
+type PolygonData = {
+    props: {
+        /** The numerical `[x, y]` coordinates of the vertices of the polygon. */
+        numericalVertices: [number, number][];
+    };
+};
+
+export const PolygonInGraph: BasicComponent<PolygonData> = ({ node }) => {
+    const board = React.useContext(GraphContext);
+    const polygonRef = React.useRef<JSG.Polygon | null>(null);
+    const polygonListenersAttached = React.useRef<GraphListeners>({});
+    const vertexListenersAttached = React.useRef<GraphListeners[]>([]);
+    const id = node.data.id;
+
+    const dispatch = useAppDispatch();
+
+    const numericalVertices = node.data.props.numericalVertices;
+
+    React.useEffect(() => {
+        if (!board) {
+            polygonRef.current = null;
+            return;
+        }
+        if (polygonRef.current) {
+            return;
+        }
+        const polygon = createPolygon(board, {
+            vertices: numericalVertices,
+            lineColor: "var(--mainBlue)",
+            hidden: false,
+            fixed: false,
+            layer: 0,
+            selectedStyle: { lineOpacity: 1, lineWidth: 2 },
+        });
+        polygonRef.current = polygon;
+        if (!polygon) {
+            return;
+        }
+
+        // JSXGraph repeats the first vertex at the end to close the polygon.
+        const vertices = polygon.vertices.slice(0, -1);
+
+        polygonListenersAttached.current = attachStandardGraphListeners(
+            polygon,
+            {
+                drag: function (e, interactionState) {
+                    let action: Action = {
+                        component: "polygon",
+                        actionName: "movePolygon",
+                        componentIdx: id,
+                        args: {
+                            vertexCoords: vertices.map((vertex) => [
+                                vertex.X(),
+                                vertex.Y(),
+                            ]),
+                        },
+                    };
+                    dispatch(coreActions.dispatchAction(action));
+                },
+            },
+        );
+        vertexListenersAttached.current = vertices.map((vertex, vertexIndex) =>
+            attachStandardGraphListeners(vertex, {
+                drag: function (e, interactionState) {
+                    let action: Action = {
+                        component: "polygon",
+                        actionName: "movePolygonVertex",
+                        componentIdx: id,
+                        args: {
+                            vertexIndex,
+                            coords: [vertex.X(), vertex.Y()],
+                        },
+                    };
+                    dispatch(coreActions.dispatchAction(action));
+                },
+            }),
+        );
+
+        return () => {
+            removeStandardGraphListeners(
+                polygon,
+                polygonListenersAttached.current,
+            );
+            vertices.forEach((vertex, vertexIndex) =>
+                removeStandardGraphListeners(
+                    vertex,
+                    vertexListenersAttached.current[vertexIndex],
+                ),
+            );
+            board.removeObject(polygon);
+        };
+    }, [board, polygonRef]);
+
+    if (!board || !polygonRef.current) {
+        return null;
+    }
+
+    // We have a pre-existing polygon. Move its vertices to match the values from the props.
+    // TODO: recreate the polygon if the number of vertices changes
+    const vertices = polygonRef.current.vertices.slice(0, -1);
+    if (vertices.length === numericalVertices.length) {
+        vertices.forEach((vertex, vertexIndex) => {
+            vertex.coords.setCoordinates(JXG.COORDS_BY_USER, [
+                1,
+                ...numericalVertices[vertexIndex],
+            ]);
+        });
+        polygonRef.current.needsUpdate = true;
+        polygonRef.current.update();
+        board.updateRenderer();
+    }
+
+    return null;
+};
+
+function createPolygon(
+    board: JSG.Board,
+    props: {
+        vertices: [number, number][];
+        lineColor: string;
+        hidden: boolean;
+        fixed: boolean;
+        layer: number;
+        selectedStyle: {
+            lineOpacity: number;
+            lineWidth: number;
+        };
+    },
+) {
+    if (props.vertices.length < 2) {
+        return null;
+    }
+
+    const lineColor = props.lineColor;
+
+    // Things to be passed to JSXGraph as attributes
+    const jsxPolygonAttributes: JSG.PolygonAttributes = {
+        visible: !props.hidden,
+        fixed: props.fixed,
+        layer: 10 * props.layer + LAYER_OFFSETS.line,
+        fillColor: lineColor,
+        fillOpacity: 0.3,
+        highlightFillColor: lineColor,
+        highlightFillOpacity: 0.15,
+        borders: {
+            strokeColor: lineColor,
+            strokeOpacity: props.selectedStyle.lineOpacity,
+            strokeWidth: props.selectedStyle.lineWidth,
+            highlightStrokeColor: lineColor,
+            highlightStrokeWidth: props.selectedStyle.lineWidth,
+        },
+        vertices: {
+            layer: 10 * props.layer + LAYER_OFFSETS.vertex,
+            fixed: props.fixed,
+            withLabel: false,
+        },
+    };
+
+    const polygon: JSG.Polygon = board.create(
+        "polygon",
+        props.vertices.map((vertex) => [...vertex]),
+        jsxPolygonAttributes,
+    );
+
+    return polygon;
+}
@@ -138,7 +138,7 @@ export const Graph: BasicComponent<GraphData> = ({ node }) => {
 
                 const action: Action = {
                     component: "graph",
-                    actionName: "changeBoundingBox",
+                    actionName: "changeAxisLimits",
                     componentIdx: node.data.id,
                     args: { xMin, xMax, yMin, yMax },
                 };
@@ -172,6 +172,22 @@ export const Graph: BasicComponent<GraphData> = ({ node }) => {
         previousBoundingBox.current = boundingBox;
     }
 
+    const grid = node.data.props.grid;
+    React.useEffect(() => {
+        if (!board || grid === "none") {
+            return;
+        }
+        const gridElement = board.create("grid", [], {
+            strokeColor: "var(--canvasText)",
+            strokeOpacity: 0.2,
+            // A dense grid adds minor grid lines between the major ones.
+            minorElements: grid === "dense" ? 4 : 0,
+        });
+        return () => {
+            board.removeObject(gridElement);
+        };
+    }, [board, grid]);
+
     const elementChildrenIds = React.useMemo(
         () =>
             node.children
@@ -181,7 +197,13 @@ export const Graph: BasicComponent<GraphData> = ({ node }) => {
     );
 
     return (
-        <div className="graph-container">
+        <div
+            className="graph-container"
+            style={{
+                width: `${node.data.props.width}px`,
+                aspectRatio: node.data.props.aspectRatio,
+            }}
+        >
             <div
                 className="jsxgraph-container"
                 id={boardId}
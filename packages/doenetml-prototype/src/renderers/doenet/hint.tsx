@@ -0,0 +1,31 @@
+import React from "react";
+import { BasicComponentWithPassthroughChildren } from "../types";
+import { useAppDispatch } from "../../state/hooks";
+import { Action, HintPropsInText } from "@doenet/doenetml-worker";
+import { coreActions } from "../../state/redux-slices/core";
+
+export const Hint: BasicComponentWithPassthroughChildren<{
+    props: HintPropsInText;
+}> = ({ node, children, htmlId }) => {
+    const id = node.data.id;
+    const { title, open } = node.data.props;
+    const dispatch = useAppDispatch();
+
+    const toggleOpen = React.useCallback(() => {
+        const action: Action = {
+            component: "hint",
+            componentIdx: id,
+            actionName: open ? "closeHint" : "revealHint",
+        };
+        dispatch(coreActions.dispatchAction(action));
+    }, [dispatch, id, open]);
+
+    return (
+        <div className="hint" id={htmlId}>
+            <button aria-expanded={open} onClick={toggleOpen}>
+                {title}
+            </button>
+            {open && <div>{children}</div>}
+        </div>
+    );
+};
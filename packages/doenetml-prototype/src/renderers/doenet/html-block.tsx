@@ -0,0 +1,12 @@
+import React from "react";
+import { BasicComponent } from "../types";
+import type { HtmlBlockPropsInText } from "@doenet/doenetml-worker";
+
+type HtmlBlockData = { props: HtmlBlockPropsInText };
+
+/**
+ * Render the HTML of an `<htmlBlock>`. The HTML has already been sanitized by core.
+ */
+export const HtmlBlock: BasicComponent<HtmlBlockData> = ({ node }) => {
+    return <div dangerouslySetInnerHTML={{ __html: node.data.props.html }} />;
+};
@@ -0,0 +1,21 @@
+import React from "react";
+import { BasicComponent } from "../types";
+import type { ImagePropsInText } from "@doenet/doenetml-worker";
+
+type ImageData = { props: ImagePropsInText };
+
+export const Image: BasicComponent<ImageData> = ({ node, htmlId }) => {
+    const { source, width, height, description } = node.data.props;
+    return (
+        <img
+            id={htmlId}
+            className="image"
+            src={source}
+            alt={description}
+            style={{
+                width: width || undefined,
+                height: height || undefined,
+            }}
+        />
+    );
+};
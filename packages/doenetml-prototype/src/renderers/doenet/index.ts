@@ -3,7 +3,9 @@ export * from "./_fragment";
 export * from "./_omit";
 export * from "./_pass-through-with-logging";
 export * from "./_pass-through-with-tag";
+export * from "./_unknown-component";
 export * from "./angle";
+export * from "./accordion";
 export * from "./answer";
 export * from "./as-list";
 export * from "./block-quote";
@@ -15,8 +17,13 @@ export * from "./division";
 export * from "./document";
 export * from "./em";
 export * from "./graph";
+export * from "./graph-circle";
 export * from "./graph-line";
 export * from "./graph-point";
+export * from "./graph-polygon";
+export * from "./hint";
+export * from "./html-block";
+export * from "./image";
 export * from "./li";
 export * from "./m";
 export * from "./number";
@@ -24,10 +31,16 @@ export * from "./ol";
 export * from "./orbital-diagram";
 export * from "./p";
 export * from "./problem";
+export * from "./progress-bar";
 export * from "./q";
 export * from "./row";
+export * from "./side-by-side";
+export * from "./slider";
+export * from "./solution";
+export * from "./spacer";
 export * from "./sq";
 export * from "./table";
+export * from "./tabs";
 export * from "./tabular";
 export * from "./tag";
 export * from "./tagc";
@@ -36,4 +49,5 @@ export * from "./text-input";
 export * from "./point";
 export * from "./title";
 export * from "./ul";
+export * from "./video";
 export * from "./xref";
@@ -0,0 +1,21 @@
+import React from "react";
+import { BasicComponent } from "../types";
+import type { ProgressBarPropsInText } from "@doenet/doenetml-worker";
+
+type ProgressBarData = { props: ProgressBarPropsInText };
+
+export const ProgressBar: BasicComponent<ProgressBarData> = ({
+    node,
+    htmlId,
+}) => {
+    const fraction = node.data.props.fraction;
+    return (
+        <progress
+            id={htmlId}
+            className="progress-bar"
+            value={fraction}
+            max={1}
+            aria-label={`${Math.round(fraction * 100)}% complete`}
+        />
+    );
+};
@@ -0,0 +1,39 @@
+import React from "react";
+import { BasicComponentWithPassthroughChildren } from "../types";
+import type { SideBySidePropsInText } from "@doenet/doenetml-worker";
+
+const ALIGN_ITEMS = {
+    top: "flex-start",
+    middle: "center",
+    bottom: "flex-end",
+} as const;
+
+export const SideBySide: BasicComponentWithPassthroughChildren<{
+    props: SideBySidePropsInText;
+}> = ({ node, children, htmlId }) => {
+    const { widths, stacked, valign } = node.data.props;
+    const childArray = Array.isArray(children) ? children : [children];
+
+    return (
+        <div
+            id={htmlId}
+            className="side-by-side"
+            style={{
+                display: "flex",
+                flexDirection: stacked ? "column" : "row",
+                alignItems:
+                    ALIGN_ITEMS[valign as keyof typeof ALIGN_ITEMS] ??
+                    "flex-start",
+            }}
+        >
+            {childArray.map((child, index) => (
+                <div
+                    key={index}
+                    style={{ width: `${widths[index] ?? 0}%` }}
+                >
+                    {child}
+                </div>
+            ))}
+        </div>
+    );
+};
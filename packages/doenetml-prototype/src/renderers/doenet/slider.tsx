@@ -0,0 +1,50 @@
+import React from "react";
+import type { Action, SliderPropsInText } from "@doenet/doenetml-worker";
+import { BasicComponent } from "../types";
+import { useAppDispatch } from "../../state/hooks";
+import { coreActions } from "../../state/redux-slices/core";
+
+type SliderData = { props: SliderPropsInText };
+
+export const Slider: BasicComponent<SliderData> = ({ node, htmlId }) => {
+    const id = node.data.id;
+    const { value, from, to, step, markers, label, disabled } =
+        node.data.props;
+    const markersId = `${htmlId}-markers`;
+
+    const dispatch = useAppDispatch();
+
+    return (
+        <span className="slider" id={htmlId}>
+            <label>
+                {label ? <span className="input-label">{label}</span> : null}
+                <input
+                    type="range"
+                    min={Math.min(from, to)}
+                    max={Math.max(from, to)}
+                    step={step > 0 ? step : "any"}
+                    value={value}
+                    disabled={disabled}
+                    list={markers.length > 0 ? markersId : undefined}
+                    onChange={(e) => {
+                        const action: Action = {
+                            component: "slider",
+                            componentIdx: id,
+                            actionName: "changeValue",
+                            args: { value: Number(e.target.value) },
+                        };
+                        dispatch(coreActions.dispatchAction(action));
+                    }}
+                />
+            </label>
+            {markers.length > 0 ? (
+                <datalist id={markersId}>
+                    {markers.map((marker, i) => (
+                        <option key={i} value={marker as number} />
+                    ))}
+                </datalist>
+            ) : null}
+            <span className="slider-value">{value}</span>
+        </span>
+    );
+};
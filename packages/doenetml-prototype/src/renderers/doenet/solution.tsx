@@ -0,0 +1,35 @@
+import React from "react";
+import { BasicComponentWithPassthroughChildren } from "../types";
+import { useAppDispatch } from "../../state/hooks";
+import { Action, SolutionPropsInText } from "@doenet/doenetml-worker";
+import { coreActions } from "../../state/redux-slices/core";
+
+export const Solution: BasicComponentWithPassthroughChildren<{
+    props: SolutionPropsInText;
+}> = ({ node, children, htmlId }) => {
+    const id = node.data.id;
+    const { title, revealed } = node.data.props;
+    const dispatch = useAppDispatch();
+
+    const reveal = React.useCallback(() => {
+        const action: Action = {
+            component: "solution",
+            componentIdx: id,
+            actionName: "revealSolution",
+        };
+        dispatch(coreActions.dispatchAction(action));
+    }, [dispatch, id]);
+
+    return (
+        <div className="solution" id={htmlId}>
+            <button
+                aria-expanded={revealed}
+                disabled={revealed}
+                onClick={reveal}
+            >
+                {title}
+            </button>
+            {revealed && <div>{children}</div>}
+        </div>
+    );
+};
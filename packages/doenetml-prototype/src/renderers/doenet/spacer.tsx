@@ -0,0 +1,6 @@
+import React from "react";
+import { BasicComponent } from "../types";
+
+export const Spacer: BasicComponent = ({ htmlId }) => {
+    return <div id={htmlId} className="spacer" />;
+};
@@ -4,6 +4,7 @@ import { XrefLabel } from "@doenet/doenetml-worker";
 import { Element } from "../element";
 import { Header } from "./_header";
 import classNames from "classnames";
+import { Tabular } from "./tabular";
 
 type TableData = {
     props: {
@@ -15,16 +16,20 @@ type TableData = {
         codeNumber: string;
         divisionDepth: number;
         xrefLabel: XrefLabel;
+        /**
+         * Set when the `<table>` holds its rows directly rather than being a titled figure.
+         */
+        numRows?: number;
     };
 };
 
-export const Table: BasicComponentWithPassthroughChildren<TableData> = ({
-    node,
-    children,
-    htmlId,
-    ancestors,
-    visibilityRef,
-}) => {
+export const Table: BasicComponentWithPassthroughChildren<TableData> = (
+    props,
+) => {
+    const { node, children, htmlId, ancestors, visibilityRef } = props;
+    if (node.data.props.numRows != null) {
+        return <Tabular {...props} />;
+    }
     const titleElmId = node.data.props.title;
     const displayName = node.data.props.titlePrefix;
 
@@ -0,0 +1,58 @@
+import React from "react";
+import { BasicComponentWithPassthroughChildren } from "../types";
+import { useAppDispatch } from "../../state/hooks";
+import { Action, TabsPropsInText } from "@doenet/doenetml-worker";
+import { coreActions } from "../../state/redux-slices/core";
+import { useDocumentKeyboardShortcuts } from "../keyboard-shortcuts";
+
+export const Tabs: BasicComponentWithPassthroughChildren<{
+    props: TabsPropsInText;
+}> = ({ node, children, htmlId }) => {
+    const id = node.data.id;
+    const { selectedIndex, tabTitles } = node.data.props;
+    const dispatch = useAppDispatch();
+
+    const selectTab = React.useCallback(
+        (index: number) => {
+            const action: Action = {
+                component: "tabs",
+                componentIdx: id,
+                actionName: "selectTab",
+                args: { index },
+            };
+            dispatch(coreActions.dispatchAction(action));
+        },
+        [dispatch, id],
+    );
+
+    const callAction = React.useCallback(
+        (actionName: string) => {
+            const action: Action = {
+                component: "tabs",
+                componentIdx: id,
+                actionName,
+            } as Action;
+            dispatch(coreActions.dispatchAction(action));
+        },
+        [dispatch, id],
+    );
+    useDocumentKeyboardShortcuts(node.data.keyboard_shortcuts, callAction);
+
+    return (
+        <div className="tabs" id={htmlId}>
+            <div role="tablist">
+                {(tabTitles as string[]).map((title, i) => (
+                    <button
+                        key={i}
+                        role="tab"
+                        aria-selected={selectedIndex === i + 1}
+                        onClick={() => selectTab(i + 1)}
+                    >
+                        {title || `Tab ${i + 1}`}
+                    </button>
+                ))}
+            </div>
+            <div role="tabpanel">{children}</div>
+        </div>
+    );
+};
@@ -6,6 +6,7 @@ import { renderingOnServerSelector } from "../../state/redux-slices/global";
 import "./text-input.css";
 import { coreActions } from "../../state/redux-slices/core";
 import { _ServerSafeMath } from "./_server-safe-math";
+import { actionForKeyboardEvent } from "../keyboard-shortcuts";
 
 /** Validation state for answer submissions */
 export type ValidationState =
@@ -101,7 +102,12 @@ export const TextInput: BasicComponent<TextInputData> = ({ node, htmlId }) => {
                     }}
                     onBlur={updateValue}
                     onKeyUp={(e) => {
-                        if (e.key === "Enter") {
+                        const actionName = actionForKeyboardEvent(
+                            e,
+                            node.data.keyboard_shortcuts,
+                            "focused",
+                        );
+                        if (actionName === "updateValue") {
                             updateValue();
                         }
                     }}
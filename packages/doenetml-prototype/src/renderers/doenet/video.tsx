@@ -0,0 +1,66 @@
+import React from "react";
+import { BasicComponent } from "../types";
+import { useAppDispatch } from "../../state/hooks";
+import { Action, VideoPropsInText } from "@doenet/doenetml-worker";
+import { coreActions } from "../../state/redux-slices/core";
+
+type VideoData = { props: VideoPropsInText };
+
+export const Video: BasicComponent<VideoData> = ({ node, htmlId }) => {
+    const id = node.data.id;
+    const { source } = node.data.props;
+    const dispatch = useAppDispatch();
+    // The time at which the video most recently started playing.
+    const beginTime = React.useRef<number | null>(null);
+
+    const onPlay = React.useCallback(
+        (e: React.SyntheticEvent<HTMLVideoElement>) => {
+            beginTime.current = e.currentTarget.currentTime;
+            const action: Action = {
+                component: "video",
+                componentIdx: id,
+                actionName: "recordVideoStarted",
+                args: { duration: e.currentTarget.duration },
+            };
+            dispatch(coreActions.dispatchAction(action));
+        },
+        [dispatch, id],
+    );
+
+    const onStop = React.useCallback(
+        (e: React.SyntheticEvent<HTMLVideoElement>) => {
+            if (beginTime.current !== null) {
+                const action: Action = {
+                    component: "video",
+                    componentIdx: id,
+                    actionName: "recordVideoWatched",
+                    args: {
+                        beginTime: beginTime.current,
+                        endTime: e.currentTarget.currentTime,
+                    },
+                };
+                dispatch(coreActions.dispatchAction(action));
+                beginTime.current = null;
+            }
+            const action: Action = {
+                component: "video",
+                componentIdx: id,
+                actionName: "recordVideoPaused",
+            };
+            dispatch(coreActions.dispatchAction(action));
+        },
+        [dispatch, id],
+    );
+
+    return (
+        <video
+            id={htmlId}
+            className="video"
+            src={source}
+            controls
+            onPlay={onPlay}
+            onPause={onStop}
+            onEnded={onStop}
+        />
+    );
+};
@@ -1,2 +1,3 @@
 export * from "./element";
 export * from "./error";
+export * from "./keyboard-shortcuts";
@@ -0,0 +1,76 @@
+import React from "react";
+import { KeyboardShortcut } from "@doenet/doenetml-worker";
+
+const MODIFIERS = {
+    Ctrl: "ctrlKey",
+    Alt: "altKey",
+    Shift: "shiftKey",
+    Meta: "metaKey",
+} as const;
+
+/**
+ * Whether the keyboard event `event` presses exactly the keys of `shortcut`.
+ * Core normalizes `shortcut.keys` to, e.g., `Ctrl+Shift+k`: modifiers first, then the key.
+ */
+export function eventMatchesShortcut(
+    event: Pick<
+        KeyboardEvent,
+        "key" | "ctrlKey" | "altKey" | "shiftKey" | "metaKey"
+    >,
+    shortcut: KeyboardShortcut,
+): boolean {
+    const parts = shortcut.keys.split("+");
+    const key = parts.pop() ?? "";
+    for (const [name, flag] of Object.entries(MODIFIERS)) {
+        if (parts.includes(name) !== event[flag]) {
+            return false;
+        }
+    }
+    return event.key.toLowerCase() === key.toLowerCase();
+}
+
+/**
+ * Find the action that the keyboard event `event` should call, among the shortcuts with scope `scope`.
+ */
+export function actionForKeyboardEvent(
+    event: Pick<
+        KeyboardEvent,
+        "key" | "ctrlKey" | "altKey" | "shiftKey" | "metaKey"
+    >,
+    shortcuts: KeyboardShortcut[] | undefined,
+    scope: KeyboardShortcut["scope"],
+): string | undefined {
+    return shortcuts?.find(
+        (shortcut) =>
+            shortcut.scope === scope && eventMatchesShortcut(event, shortcut),
+    )?.actionName;
+}
+
+/**
+ * Call `callAction` whenever a document-wide shortcut in `shortcuts` is pressed.
+ * Conflicts between components have already been resolved by core, so at most one component
+ * responds to any keys.
+ */
+export function useDocumentKeyboardShortcuts(
+    shortcuts: KeyboardShortcut[] | undefined,
+    callAction: (actionName: string) => void,
+) {
+    React.useEffect(() => {
+        if (!shortcuts?.some((shortcut) => shortcut.scope === "document")) {
+            return;
+        }
+        const handleKeyDown = (event: KeyboardEvent) => {
+            const actionName = actionForKeyboardEvent(
+                event,
+                shortcuts,
+                "document",
+            );
+            if (actionName) {
+                event.preventDefault();
+                callAction(actionName);
+            }
+        };
+        window.addEventListener("keydown", handleKeyDown);
+        return () => window.removeEventListener("keydown", handleKeyDown);
+    }, [shortcuts, callAction]);
+}
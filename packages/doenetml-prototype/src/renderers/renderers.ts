@@ -3,11 +3,13 @@ import { BasicComponent, BasicComponentWithPassthroughChildren } from "./types";
 import {
     Answer,
     Document,
+    CircleInGraph,
     Graph,
     LineInGraph,
     M,
     P,
     PointInGraph,
+    PolygonInGraph,
     PointInText,
     Problem,
     Division,
@@ -26,6 +28,7 @@ import {
     _Omit,
     _PassThroughWithTag,
     _PassThroughWithLogging,
+    _UnknownComponent,
     Angle,
     Q,
     Tag,
@@ -40,6 +43,17 @@ import {
     Tabular,
     Cell,
     Row,
+    HtmlBlock,
+    Image,
+    Video,
+    SideBySide,
+    Slider,
+    Spacer,
+    Tabs,
+    Accordion,
+    ProgressBar,
+    Hint,
+    Solution,
 } from "./doenet";
 export {
     PRETEXT_TEXT_MODE_COMPONENTS,
@@ -155,9 +169,10 @@ export const TEXT_MODE_COMPONENTS: RendererObject = {
     // cell: passThroughWithRenamedTag("td"),
     _fragment: { component: _Fragment, passthroughChildren: true },
     abs: { component: M },
+    accordion: { component: Accordion, passthroughChildren: true },
     alert: passThroughWithRenamedTag("strong"),
     angle: { component: Angle },
-    answer: { component: Answer },
+    answer: { component: Answer, passthroughChildren: true },
     aside: { component: Division, passthroughChildren: true },
     asList: { component: AsList, passthroughChildren: true },
     atom: { component: M },
@@ -168,10 +183,13 @@ export const TEXT_MODE_COMPONENTS: RendererObject = {
     br: { component: _PassThroughWithTag },
     c: passThroughWithRenamedTag("code"),
     callAction: { component: Button },
+    case: PassThroughWithoutTagConverter,
     ceil: { component: M },
     choice: PassThroughWithoutTagConverter,
     choiceInput: { component: ChoiceInput },
     clampNumber: { component: M },
+    collect: PassThroughWithoutTagConverter,
+    conditionalContent: PassThroughWithoutTagConverter,
     convertSetToList: { component: M },
     count: { component: M },
     division: {
@@ -181,15 +199,22 @@ export const TEXT_MODE_COMPONENTS: RendererObject = {
     },
     document: { component: Document, passthroughChildren: true },
     electronConfiguration: { component: M },
+    else: PassThroughWithoutTagConverter,
     ellipsis: renderAs("…", { wrapInSpanWithId: true }),
     em: { component: Em, passthroughChildren: true },
     evaluate: { component: M },
+    experiment: PassThroughWithoutTagConverter,
     extractMath: { component: M },
     floor: { component: M },
     function: { component: M },
     gcd: { component: M },
     graph: { component: Graph },
+    hint: { component: Hint, passthroughChildren: true },
     hr: { component: _PassThroughWithTag },
+    htmlBlock: { component: HtmlBlock },
+    image: { component: Image },
+    interactiveOnly: PassThroughWithoutTagConverter,
+    forInstructor: PassThroughWithoutTagConverter,
     interval: { component: M },
     ion: { component: M },
     ionicCompound: { component: M },
@@ -199,6 +224,7 @@ export const TEXT_MODE_COMPONENTS: RendererObject = {
     lsq: renderAs("‘"),
     m: { component: M, passthroughChildren: true },
     math: { component: M },
+    mathList: { component: AsList, passthroughChildren: true },
     matrix: { component: M },
     max: { component: M },
     md: { component: M },
@@ -212,37 +238,52 @@ export const TEXT_MODE_COMPONENTS: RendererObject = {
     nbsp: renderAs(" "),
     ndash: renderAs("–"),
     number: { component: Number },
+    numberList: { component: AsList, passthroughChildren: true },
     odeSystem: { component: M },
     ol: { component: Ol, passthroughChildren: true },
     orbitalDiagram: { component: OrbitalDiagram },
     p: { component: P, passthroughChildren: true },
     point: { component: PointInText },
+    printFallback: PassThroughWithoutTagConverter,
     problem: {
         component: Problem,
         passthroughChildren: true,
         monitorVisibility: true,
     },
     product: { component: M },
+    progressBar: { component: ProgressBar },
     q: { component: Q, passthroughChildren: true },
     rightHandSide: { component: M },
     round: { component: M },
     rq: renderAs("”"),
     rsq: renderAs("’"),
+    select: PassThroughWithoutTagConverter,
+    selectFromSequence: { component: Number },
+    sequence: { component: AsList, passthroughChildren: true },
     setSmallToZero: { component: M },
+    sideBySide: { component: SideBySide, passthroughChildren: true },
     sign: { component: M },
+    slider: { component: Slider },
+    solution: { component: Solution, passthroughChildren: true },
+    spacer: { component: Spacer },
     sq: { component: Sq, passthroughChildren: true },
+    stack: passThroughWithRenamedTag("div"),
     standardDeviation: { component: M },
     subsetOfReals: { component: M },
     sum: { component: M },
+    tab: passThroughWithRenamedTag("div"),
+    tabs: { component: Tabs, passthroughChildren: true },
     tag: { component: Tag, passthroughChildren: true },
     tagc: { component: TagC, passthroughChildren: true },
     text: { component: Text },
     textInput: { component: TextInput },
+    textList: { component: AsList, passthroughChildren: true },
     title: { component: Title, passthroughChildren: true },
     triggerSet: { component: Button },
     ul: { component: Ul, passthroughChildren: true },
     updateValue: { component: Button },
     variance: { component: M },
+    video: { component: Video },
     wrapNumberPeriodic: { component: M },
 
     // For PreTeXt compatibility
@@ -250,9 +291,18 @@ export const TEXT_MODE_COMPONENTS: RendererObject = {
     article: { component: _Fragment, passthroughChildren: true },
     book: { component: _Fragment, passthroughChildren: true },
     xref: { component: Xref, passthroughChildren: true },
+
+    // Elements without a renderer, e.g., components from a newer version of DoenetML,
+    // are rendered as a placeholder if core marked them as unknown components.
+    [FALLBACK_RENDERER_KEY]: {
+        component: _UnknownComponent,
+        passthroughChildren: true,
+    },
 };
 
 export const GRAPH_MODE_COMPONENTS: RendererObject = {
+    circle: { component: CircleInGraph },
     line: { component: LineInGraph },
     point: { component: PointInGraph },
+    polygon: { component: PolygonInGraph },
 };
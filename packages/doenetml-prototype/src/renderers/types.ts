@@ -1,4 +1,8 @@
-import { ElementRefAnnotation, FlatDastElement } from "@doenet/doenetml-worker";
+import {
+    ElementRefAnnotation,
+    FlatDastElement,
+    KeyboardShortcut,
+} from "@doenet/doenetml-worker";
 import { AncestorChain } from "./utils";
 
 export type BasicComponentProps<Data = {}> = {
@@ -6,7 +10,11 @@ export type BasicComponentProps<Data = {}> = {
     // We want to use strict types only, so we omit it here. When flatDastFromJS.ts is no longer needed, this type narrowing
     // can be removed.
     node: Omit<FlatDastElement, "data"> & {
-        data: { id: number; action_names?: string[] } & Data;
+        data: {
+            id: number;
+            action_names?: string[];
+            keyboard_shortcuts?: KeyboardShortcut[];
+        } & Data;
     };
     /**
      * If you want to track the visibility of this component. Set it to a ref object which
@@ -23,6 +23,31 @@ export function createWrappedCoreWorker() {
     return Comlink.wrap(worker) as Comlink.Remote<CoreWorker>;
 }
 
+const FOCUSABLE = "button, input, textarea, select, [tabindex]";
+
+/**
+ * Move keyboard focus to the element that Core marked with `focus` in `updates`,
+ * once the updates have been rendered. If the element itself cannot be focused,
+ * focus its selected control (e.g., the selected tab) or its first focusable descendant.
+ */
+function focusRequestedElement(updates: ActionResponse["payload"]) {
+    const requested = Object.entries(updates).find(
+        ([, update]) => update.focus,
+    );
+    if (requested == null || typeof document === "undefined") {
+        return;
+    }
+    requestAnimationFrame(() => {
+        const element = document.getElementById(`doenet-id-${requested[0]}`);
+        const target = element?.matches(FOCUSABLE)
+            ? element
+            : element?.querySelector<HTMLElement>(
+                  `[aria-selected="true"], ${FOCUSABLE}`,
+              );
+        target?.focus();
+    });
+}
+
 export const workerCache: {
     worker: Comlink.Remote<CoreWorker>;
     coreType: CoreType;
@@ -158,6 +183,7 @@ export const coreThunks = {
                             updates.payload,
                         ),
                     );
+                    focusRequestedElement(updates.payload);
                 }
             } catch (e) {
                 dispatch(_coreReducerActions._setInErrorState(true));
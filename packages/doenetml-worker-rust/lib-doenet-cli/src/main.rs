@@ -0,0 +1,249 @@
+//! The `doenet` command, for working on DoenetML documents without a browser:
+//! - `doenet --help` describes the commands and how DoenetML source is parsed,
+//! - `doenet validate file.doenet` lists the errors, warnings, and unused content of the document,
+//!   exiting with a failure if there are any errors,
+//! - `doenet render file.doenet` prints the rendered document as canonical JSON,
+//!   or as a static HTML page with `--html`,
+//! - `doenet score file.doenet state.json` prints the score of the document with the state
+//!   saved by `Core::export_state`, e.g., a student's responses.
+//!
+//! See `source` for how documents are read.
+
+mod source;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use anyhow::{Context, Result};
+use doenetml_core::{
+    Core,
+    dast::{DastWarning, flat_dast::ErrorType},
+};
+
+const USAGE: &str = "\
+Usage:
+  doenet --help
+  doenet validate <file>
+  doenet render <file> [--html]
+  doenet score <file> <state.json>
+
+<file> is DoenetML source, or DAST if its name ends in `.json`.
+
+DoenetML source is parsed by running the script `parse-dast.js` with node.
+The script is looked for next to the doenet executable,
+or its path can be given by the environment variable DOENET_PARSE_DAST.
+Build it with `npm run test:rust:before` in `packages/doenetml-worker-rust`,
+which writes it to `lib-doenetml-core/tests/dist/parse-dast.js`.";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Help,
+    Validate { file: PathBuf },
+    Render { file: PathBuf, html: bool },
+    Score { file: PathBuf, state: PathBuf },
+}
+
+impl Command {
+    /// Parse the command line arguments, excluding the name of the program.
+    fn parse(args: &[String]) -> Option<Command> {
+        let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+        match args.as_slice() {
+            ["--help" | "-h" | "help"] => Some(Command::Help),
+            ["validate", file] => Some(Command::Validate { file: file.into() }),
+            ["render", "--html", file] | ["render", file, "--html"] => Some(Command::Render {
+                file: file.into(),
+                html: true,
+            }),
+            ["render", file] => Some(Command::Render {
+                file: file.into(),
+                html: false,
+            }),
+            ["score", file, state] => Some(Command::Score {
+                file: file.into(),
+                state: state.into(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let Some(command) = Command::parse(&args) else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+
+    match run(command) {
+        Ok(exit_code) => exit_code,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<ExitCode> {
+    match command {
+        Command::Help => {
+            println!("{USAGE}");
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Validate { file } => {
+            let mut core = load_document(&file)?;
+            let diagnostics = core.validate();
+            for diagnostic in diagnostics.iter() {
+                println!("{}", format_diagnostic(&file, diagnostic));
+            }
+
+            let has_errors = diagnostics
+                .iter()
+                .any(|diagnostic| diagnostic.severity == ErrorType::Error);
+            Ok(if has_errors {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            })
+        }
+        Command::Render { file, html } => {
+            let mut core = load_document(&file)?;
+            if html {
+                println!("{}", html_page(&core.to_html()));
+            } else {
+                println!("{}", core.render_tree_canonical());
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Score { file, state } => {
+            let mut core = load_document(&file)?;
+            let state_blob =
+                fs::read(&state).with_context(|| format!("could not read {}", state.display()))?;
+            core.load_state(&state_blob)
+                .with_context(|| format!("could not load the state in {}", state.display()))?;
+
+            let score = core.get_score();
+            for (i, credit) in score.credits.iter().enumerate() {
+                println!("answer {}: {credit}", i + 1);
+            }
+            for objective in core.get_objective_report().objectives {
+                println!(
+                    "objective {}: {} / {}",
+                    objective.objective,
+                    objective.credits.iter().sum::<f64>(),
+                    objective.credits.len()
+                );
+            }
+            println!("score: {} / {}", score.score, score.total_points);
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn load_document(file: &Path) -> Result<Core> {
+    let dast_root = source::read_dast(file)?;
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    Ok(core)
+}
+
+/// Format `diagnostic` as `file:line:column: severity[code]: message`, with its suggestion on the next line.
+fn format_diagnostic(file: &Path, diagnostic: &DastWarning) -> String {
+    let location = match &diagnostic.position {
+        Some(position) => format!(
+            "{}:{}:{}",
+            file.display(),
+            position.start.line,
+            position.start.column
+        ),
+        None => file.display().to_string(),
+    };
+    let severity = match diagnostic.severity {
+        ErrorType::Error => "error",
+        ErrorType::Warning => "warning",
+        ErrorType::Info => "info",
+    };
+    let code = diagnostic
+        .code
+        .map(|code| format!("[{}]", code.as_str()))
+        .unwrap_or_default();
+
+    let mut formatted = format!("{location}: {severity}{code}: {}", diagnostic.message);
+    if diagnostic.count > 1 {
+        formatted.push_str(&format!(" ({} times)", diagnostic.count));
+    }
+    if let Some(suggestion) = &diagnostic.suggestion {
+        formatted.push_str(&format!("\n  {suggestion}"));
+    }
+    formatted
+}
+
+fn html_page(body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n</head>\n<body>\n{body}\n</body>\n</html>"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use doenetml_core::dast::{DiagnosticCode, Point, Position};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            Command::parse(&args(&["validate", "a.doenet"])),
+            Some(Command::Validate {
+                file: "a.doenet".into()
+            })
+        );
+        assert_eq!(
+            Command::parse(&args(&["render", "--html", "a.doenet"])),
+            Some(Command::Render {
+                file: "a.doenet".into(),
+                html: true
+            })
+        );
+        assert_eq!(
+            Command::parse(&args(&["score", "a.doenet", "state.json"])),
+            Some(Command::Score {
+                file: "a.doenet".into(),
+                state: "state.json".into()
+            })
+        );
+        assert_eq!(Command::parse(&args(&["--help"])), Some(Command::Help));
+        assert_eq!(Command::parse(&args(&["score", "a.doenet"])), None);
+        assert_eq!(Command::parse(&args(&[])), None);
+    }
+
+    #[test]
+    fn test_format_diagnostic() {
+        let point = |line, column| Point {
+            line,
+            column,
+            offset: None,
+        };
+        let diagnostic = DastWarning {
+            message: "No referent found for reference: $t".to_string(),
+            severity: ErrorType::Warning,
+            code: Some(DiagnosticCode::NoReferent),
+            suggestion: Some("Did you mean `$s`?".to_string()),
+            count: 2,
+            position: Some(Position {
+                start: point(3, 5),
+                end: point(3, 7),
+            }),
+            source_doc: None,
+        };
+        assert_eq!(
+            format_diagnostic(Path::new("a.doenet"), &diagnostic),
+            "a.doenet:3:5: warning[DML2001]: No referent found for reference: $t (2 times)\n  Did you mean `$s`?"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Reading a document as DAST.
+//!
+//! A file whose name ends in `.json` is read as DAST that has already been parsed.
+//! Any other file is DoenetML source, which is parsed with node by the script `parse-dast.js`
+//! that `npm run test:rust:before` builds into `lib-doenetml-core/tests/dist`.
+//! The script is looked for in the directory of the `doenet` executable,
+//! unless its path is given by the environment variable `DOENET_PARSE_DAST`.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use anyhow::{Context, Result, bail};
+use doenetml_core::dast::DastRoot;
+
+/// The environment variable giving the path of the script that parses DoenetML into DAST.
+const PARSE_DAST_SCRIPT_VAR: &str = "DOENET_PARSE_DAST";
+
+/// The file name of the script that parses DoenetML into DAST.
+const PARSE_DAST_SCRIPT_NAME: &str = "parse-dast.js";
+
+/// Read the document in the file `path` as DAST.
+pub fn read_dast(path: &Path) -> Result<DastRoot> {
+    let json = if path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        fs::read_to_string(path).with_context(|| format!("could not read {}", path.display()))?
+    } else {
+        parse_via_node(path)?
+    };
+    serde_json::from_str(&json).with_context(|| format!("{} is not valid DAST", path.display()))
+}
+
+/// The path of the script that parses DoenetML into DAST:
+/// the value of `DOENET_PARSE_DAST` if it is set, otherwise `parse-dast.js` in the directory of the executable.
+fn parse_dast_script() -> Result<PathBuf> {
+    if let Some(script) = env::var_os(PARSE_DAST_SCRIPT_VAR) {
+        return Ok(PathBuf::from(script));
+    }
+    let executable = env::current_exe().context("could not find the doenet executable")?;
+    Ok(executable.with_file_name(PARSE_DAST_SCRIPT_NAME))
+}
+
+/// Parse the DoenetML source in the file `path`, returning the DAST as JSON.
+fn parse_via_node(path: &Path) -> Result<String> {
+    if !path.is_file() {
+        bail!("could not read {}", path.display());
+    }
+    let script = parse_dast_script()?;
+    if !script.is_file() {
+        bail!(
+            "the DoenetML parser {} was not found. Build it with `npm run test:rust:before` \
+             in `packages/doenetml-worker-rust` and set `{PARSE_DAST_SCRIPT_VAR}` to the path of \
+             `lib-doenetml-core/tests/dist/{PARSE_DAST_SCRIPT_NAME}` or copy it next to the doenet executable, \
+             or give the document as DAST in a `.json` file",
+            script.display()
+        );
+    }
+
+    let output = Command::new("node")
+        .arg(&script)
+        .arg("-f")
+        .arg(path)
+        .output()
+        .context("could not run node, which is needed to parse DoenetML")?;
+    if !output.status.success() {
+        bail!(
+            "could not parse {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    String::from_utf8(output.stdout).context("the parser did not give UTF-8 output")
+}
@@ -1,12 +1,12 @@
 //! A DoenetML component. A component is a collection of props combined with render information.
 //! All information in the DAST that is sent to the UI comes from components.
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 use crate::core::props::{PropDefinition, PropDefinitionMeta, PropProfile};
-use crate::dast::Position as DastPosition;
-use crate::props::RenderContext;
+use crate::dast::{ComponentCapabilities, KeyboardShortcut, Position as DastPosition};
+use crate::props::{RenderContext, RenderMode};
 
 use super::_error::_Error;
 use super::_external::_External;
@@ -42,7 +42,7 @@ pub struct ComponentCommonData {
 
     /// Any remaining attributes that appeared in the DoenetML
     /// but where not recognized component
-    pub unrecognized_attributes: HashMap<String, FlatAttribute>,
+    pub unrecognized_attributes: BTreeMap<String, FlatAttribute>,
 }
 
 pub trait ComponentCommon {
@@ -63,7 +63,7 @@ pub trait ComponentCommon {
     }
 
     /// Get the hash map of all attributes that have not been recognized by its parent component.
-    fn get_unrecognized_attributes(&self) -> &HashMap<String, FlatAttribute> {
+    fn get_unrecognized_attributes(&self) -> &BTreeMap<String, FlatAttribute> {
         &self.get_common_data().unrecognized_attributes
     }
 }
@@ -126,18 +126,13 @@ impl ComponentProps for Component {
     fn get_for_render_local_prop_indices(
         &self,
         render_context: RenderContext,
+        render_mode: RenderMode,
     ) -> impl Iterator<Item = LocalPropIdx> {
         (0..self.variant.get_num_props()).filter_map(move |i| {
-            let for_render = self
-                .variant
-                .get_prop_for_render_outputs(LocalPropIdx::new(i));
-
-            let rendered_in_either = match render_context {
-                RenderContext::InGraph => for_render.in_graph,
-                RenderContext::InText => for_render.in_text,
-            };
-
-            rendered_in_either.then_some(LocalPropIdx::new(i))
+            self.variant
+                .get_prop_for_render_outputs(LocalPropIdx::new(i))
+                .is_rendered(render_context, render_mode)
+                .then_some(LocalPropIdx::new(i))
         })
     }
 }
@@ -152,6 +147,15 @@ impl ComponentNode for Component {
     fn extend_via_default_prop(&self) -> bool {
         self.variant.extend_via_default_prop()
     }
+    fn string_children_become(&self) -> Option<&'static str> {
+        self.variant.string_children_become()
+    }
+    fn capabilities(&self) -> ComponentCapabilities {
+        self.variant.capabilities()
+    }
+    fn is_experimental(&self) -> bool {
+        self.variant.is_experimental()
+    }
     fn provided_profiles(&self) -> Vec<(PropProfile, LocalPropIdx)> {
         (0..self.variant.get_num_props())
             .flat_map(|local_prop_idx| {
@@ -200,15 +204,15 @@ impl Component {
     }
 
     /// Create a new `_Error` component instance.
-    pub fn new_error(message: String, common: ComponentCommonData) -> Self {
-        let variant = ComponentEnum::_Error(_Error { message });
+    pub fn new_error(error: _Error, common: ComponentCommonData) -> Self {
+        let variant = ComponentEnum::_Error(error);
         Self { common, variant }
     }
 
     /// Set `self.common.unrecognized_attributes`.
     pub fn set_unrecognized_attributes(
         &mut self,
-        unused_attributes: HashMap<String, FlatAttribute>,
+        unused_attributes: BTreeMap<String, FlatAttribute>,
     ) {
         self.common.unrecognized_attributes = unused_attributes;
     }
@@ -235,4 +239,8 @@ impl ComponentOnAction for Component {
     ) -> Result<Vec<UpdateFromAction>, String> {
         self.variant.on_action(action, query_prop)
     }
+
+    fn get_keyboard_shortcuts(&self) -> Vec<KeyboardShortcut> {
+        self.variant.get_keyboard_shortcuts()
+    }
 }
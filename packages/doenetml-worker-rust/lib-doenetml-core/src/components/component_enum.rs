@@ -1,24 +1,67 @@
 use enum_dispatch::enum_dispatch;
-use strum_macros::EnumString;
+use strum::VariantNames;
+use strum_macros::{EnumString, VariantNames};
+
+use crate::{components::ComponentNode, dast::ComponentCapabilities};
 
 pub use super::_error::_Error;
 pub use super::_external::_External;
 pub use super::_ref::_Ref;
 pub use super::doenet::_fragment::_Fragment;
+pub use super::doenet::accordion::Accordion;
+pub use super::doenet::answer::Answer;
+pub use super::doenet::award::Award;
 pub use super::doenet::boolean::Boolean;
+pub use super::doenet::case::Case;
+pub use super::doenet::cell::Cell;
+pub use super::doenet::choice::Choice;
+pub use super::doenet::choice_input::ChoiceInput;
+pub use super::doenet::circle::Circle;
+pub use super::doenet::collect::Collect;
+pub use super::doenet::conditional_content::ConditionalContent;
 pub use super::doenet::division::Division;
 pub use super::doenet::document::Document;
+pub use super::doenet::else_case::Else;
+pub use super::doenet::embedded_applet::EmbeddedApplet;
+pub use super::doenet::experiment::Experiment;
+pub use super::doenet::for_instructor::ForInstructor;
+pub use super::doenet::function::Function;
 pub use super::doenet::graph::Graph;
+pub use super::doenet::hint::Hint;
+pub use super::doenet::html_block::HtmlBlock;
+pub use super::doenet::image::Image;
+pub use super::doenet::interactive_only::InteractiveOnly;
 pub use super::doenet::li::Li;
+pub use super::doenet::line::Line;
 pub use super::doenet::math::Math;
+pub use super::doenet::math_list::MathList;
+pub use super::doenet::matrix::Matrix;
 pub use super::doenet::number::Number;
+pub use super::doenet::number_list::NumberList;
 pub use super::doenet::ol::Ol;
 pub use super::doenet::p::P;
 pub use super::doenet::point::Point;
+pub use super::doenet::polygon::Polygon;
+pub use super::doenet::print_fallback::PrintFallback;
+pub use super::doenet::progress_bar::ProgressBar;
+pub use super::doenet::row::Row;
+pub use super::doenet::select::Select;
+pub use super::doenet::select_from_sequence::SelectFromSequence;
+pub use super::doenet::sequence::Sequence;
+pub use super::doenet::side_by_side::SideBySide;
+pub use super::doenet::slider::Slider;
+pub use super::doenet::solution::Solution;
+pub use super::doenet::spacer::Spacer;
+pub use super::doenet::stack::Stack;
+pub use super::doenet::tab::Tab;
+pub use super::doenet::table::Table;
+pub use super::doenet::tabs::Tabs;
 pub use super::doenet::text::Text;
 pub use super::doenet::text_input::TextInput;
+pub use super::doenet::text_list::TextList;
 pub use super::doenet::title::Title;
 pub use super::doenet::ul::Ul;
+pub use super::doenet::video::Video;
 pub use super::doenet::xref::Xref;
 
 /// A enum that can contain a component of any possible component type.
@@ -27,7 +70,7 @@ pub use super::doenet::xref::Xref;
 /// to allow easy access to the methods.
 ///
 /// Each component type added to `ComponentEnum` must implement the component node traits.
-#[derive(Debug, EnumString, Clone)]
+#[derive(Debug, EnumString, VariantNames, Clone)]
 #[enum_dispatch(
     ComponentNode,
     ComponentVariantProps,
@@ -39,8 +82,16 @@ pub use super::doenet::xref::Xref;
 pub enum ComponentEnum {
     Text(Text),
     TextInput(TextInput),
+    ChoiceInput(ChoiceInput),
+    Choice(Choice),
+    Answer(Answer),
+    Award(Award),
+    Slider(Slider),
     Math(Math),
+    Function(Function),
     Number(Number),
+    NumberList(NumberList),
+    Sequence(Sequence),
     Boolean(Boolean),
     Division(Division),
     Title(Title),
@@ -52,8 +103,74 @@ pub enum ComponentEnum {
     Li(Li),
     Graph(Graph),
     Point(Point),
+    Line(Line),
+    Circle(Circle),
+    Polygon(Polygon),
+    PrintFallback(PrintFallback),
+    InteractiveOnly(InteractiveOnly),
+    ForInstructor(ForInstructor),
+    Select(Select),
+    SelectFromSequence(SelectFromSequence),
+    ConditionalContent(ConditionalContent),
+    Case(Case),
+    Else(Else),
+    Collect(Collect),
+    Experiment(Experiment),
+    EmbeddedApplet(EmbeddedApplet),
+    HtmlBlock(HtmlBlock),
+    SideBySide(SideBySide),
+    Stack(Stack),
+    Spacer(Spacer),
+    Tabs(Tabs),
+    Tab(Tab),
+    Accordion(Accordion),
+    ProgressBar(ProgressBar),
+    Hint(Hint),
+    Solution(Solution),
+    Table(Table),
+    Row(Row),
+    Cell(Cell),
+    Matrix(Matrix),
+    MathList(MathList),
+    TextList(TextList),
+    Image(Image),
+    Video(Video),
     _Error(_Error),
     _External(_External),
     _Fragment(_Fragment),
     _Ref(_Ref),
 }
+
+impl ComponentEnum {
+    /// The tag names of all component types that may appear in DoenetML, e.g. `textInput`.
+    /// Internal component types (whose names begin with `_`) are excluded.
+    pub fn authored_component_types() -> Vec<String> {
+        Self::VARIANTS
+            .iter()
+            .filter(|name| !name.starts_with('_'))
+            .map(|name| {
+                let mut chars = name.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// The capabilities of each component type that may appear in DoenetML, keyed by tag name,
+    /// so that hosts can decide up front which renderers need fallbacks.
+    pub fn authored_component_capabilities() -> Vec<(String, ComponentCapabilities)> {
+        Self::VARIANTS
+            .iter()
+            .filter(|name| !name.starts_with('_'))
+            .filter_map(|name| name.parse::<ComponentEnum>().ok())
+            .map(|variant| {
+                (
+                    variant.get_component_type().to_string(),
+                    variant.capabilities(),
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,155 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::general_prop::not_hidden_filter;
+use crate::props::UpdaterObject;
+
+/// The `<accordion>` component shows a title that can be clicked to open or close its content.
+/// The content is rendered only while the `<accordion>` is open, so it is not calculated until it is opened.
+#[component(name = Accordion)]
+mod component {
+
+    use crate::general_prop::{BooleanProp, StringProp};
+
+    enum Props {
+        /// Whether the `<accordion>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        /// The title that is shown whether or not the `<accordion>` is open.
+        #[prop(value_type = PropValueType::String, is_public, for_render)]
+        Title,
+        /// Whether the content of the `<accordion>` is shown.
+        #[prop(value_type = PropValueType::Boolean, is_public, for_render)]
+        Open,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// Whether the `<accordion>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The title that is shown whether or not the `<accordion>` is open.
+        #[attribute(prop = StringProp, default = String::new())]
+        Title,
+        /// Whether the `<accordion>` is open initially.
+        #[attribute(prop = BooleanProp, default = false)]
+        Open,
+    }
+
+    enum Actions {
+        ToggleOpen,
+    }
+}
+
+pub use component::Accordion;
+pub use component::AccordionActions;
+pub use component::AccordionAttributes;
+pub use component::AccordionProps;
+
+impl PropGetUpdater for AccordionProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            AccordionProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            AccordionProps::Title => as_updater_object::<_, component::props::types::Title>(
+                component::attrs::Title::get_prop_updater(),
+            ),
+            AccordionProps::Open => as_updater_object::<_, component::props::types::Open>(
+                component::attrs::Open::get_prop_updater(),
+            ),
+            AccordionProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(custom_props::RenderedChildren::new()),
+        }
+    }
+}
+
+impl ComponentOnAction for Accordion {
+    fn on_action(
+        &self,
+        action: ActionsEnum,
+        query_prop: ActionQueryProp,
+    ) -> Result<Vec<UpdateFromAction>, String> {
+        // The type of `action` should have already been verified, so an
+        // error here is a programming logic error, not an API error.
+        let action: AccordionActions = action.try_into()?;
+
+        match action {
+            AccordionActions::ToggleOpen => {
+                let open: bool = query_prop
+                    .get_local_prop(AccordionProps::Open.local_idx())
+                    .value
+                    .try_into()
+                    .unwrap();
+
+                Ok(vec![UpdateFromAction {
+                    local_prop_idx: AccordionProps::Open.local_idx(),
+                    requested_value: (!open).into(),
+                }])
+            }
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use rendered_children::*;
+    mod rendered_children {
+        use super::*;
+
+        /// The children that this component renders: all of them while it is open, and none otherwise.
+        #[derive(Debug, Default)]
+        pub struct RenderedChildren {}
+
+        impl RenderedChildren {
+            pub fn new() -> Self {
+                RenderedChildren {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Accordion)]
+        struct RequiredData {
+            open: PropView<component::props::types::Open>,
+            children: PropView<prop_type::AnnotatedContentRefs>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn open_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: AccordionProps::Open.local_idx().into(),
+                }
+            }
+            fn children_query() -> DataQuery {
+                DataQuery::AnnotatedContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(not_hidden_filter()),
+                }
+            }
+        }
+
+        impl PropUpdater for RenderedChildren {
+            type PropType = component::props::types::RenderedChildren;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                if required_data.open.value {
+                    PropCalcResult::Calculated(required_data.children.value)
+                } else {
+                    PropCalcResult::Calculated(Rc::new(Vec::new().into()))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,422 @@
+use std::rc::Rc;
+
+use crate::components::doenet::award::Award;
+use crate::components::prelude::*;
+use crate::general_prop::{BooleanProp, IndependentProp};
+use crate::props::UpdaterObject;
+
+/// The `<answer>` component collects the responses of its input descendants, e.g., a `<textInput>`,
+/// and scores them with its `<award>` children when a user submits the answer.
+///
+/// The credit is the largest credit given by any `<award>`. It is recorded in `credit_achieved`
+/// only when the `submitAnswer` action is called, so that changing a response does not change
+/// the credit until the response is submitted.
+#[component(name = Answer)]
+mod component {
+
+    use super::*;
+
+    enum Props {
+        /// The credit, from 0 to 1, achieved by the most recently submitted responses.
+        #[prop(
+            value_type = PropValueType::Number,
+            profile = PropProfile::Number,
+            is_public,
+            for_render,
+            default
+        )]
+        CreditAchieved,
+        /// Whether a user has submitted a response.
+        #[prop(value_type = PropValueType::Boolean, is_public, for_render)]
+        ResponseHasBeenSubmitted,
+        /// The responses that were most recently submitted.
+        #[prop(value_type = PropValueType::PropVec, is_public)]
+        SubmittedResponses,
+        /// The current responses of the input descendants of the `<answer>`, in document order.
+        #[prop(value_type = PropValueType::PropVec, is_public)]
+        CurrentResponses,
+        /// The response that the `<award>` children compare against: the first of `current_responses`,
+        /// or an empty string if there are no inputs.
+        #[prop(value_type = PropValueType::String, profile = PropProfile::Response)]
+        CurrentResponse,
+        /// The credit that would be achieved if the current responses were submitted.
+        #[prop(value_type = PropValueType::Number)]
+        CurrentCreditAchieved,
+        /// Whether the current responses are the ones that were most recently submitted,
+        /// i.e., whether `credit_achieved` reflects the current responses.
+        #[prop(value_type = PropValueType::Boolean, for_render)]
+        JustSubmitted,
+        /// Whether the `<answer>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        /// The children of the `<answer>` other than its `<award>` children, which are not rendered.
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// Whether the `<answer>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+    }
+
+    enum Actions {
+        /// Submit the current responses, recording the credit they achieve.
+        SubmitAnswer,
+    }
+}
+
+pub use component::Answer;
+pub use component::AnswerActions;
+pub use component::AnswerAttributes;
+pub use component::AnswerProps;
+
+impl PropGetUpdater for AnswerProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            AnswerProps::CreditAchieved => as_updater_object::<
+                _,
+                component::props::types::CreditAchieved,
+            >(IndependentProp::new(0.0)),
+            AnswerProps::ResponseHasBeenSubmitted => as_updater_object::<
+                _,
+                component::props::types::ResponseHasBeenSubmitted,
+            >(IndependentProp::new(false)),
+            AnswerProps::SubmittedResponses => as_updater_object::<
+                _,
+                component::props::types::SubmittedResponses,
+            >(IndependentProp::new(Rc::default())),
+            AnswerProps::CurrentResponses => as_updater_object::<
+                _,
+                component::props::types::CurrentResponses,
+            >(custom_props::CurrentResponses::new()),
+            AnswerProps::CurrentResponse => as_updater_object::<
+                _,
+                component::props::types::CurrentResponse,
+            >(custom_props::CurrentResponse::new()),
+            AnswerProps::CurrentCreditAchieved => {
+                as_updater_object::<_, component::props::types::CurrentCreditAchieved>(
+                    custom_props::CurrentCreditAchieved::new(),
+                )
+            }
+            AnswerProps::JustSubmitted => as_updater_object::<
+                _,
+                component::props::types::JustSubmitted,
+            >(custom_props::JustSubmitted::new()),
+            AnswerProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            AnswerProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(custom_props::RenderedChildren::new()),
+        }
+    }
+}
+
+impl ComponentOnAction for Answer {
+    fn on_action(
+        &self,
+        action: ActionsEnum,
+        query_prop: ActionQueryProp,
+    ) -> Result<Vec<UpdateFromAction>, String> {
+        // The type of `action` should have already been verified, so an
+        // error here is a programming logic error, not an API error.
+        let action: AnswerActions = action.try_into()?;
+
+        match action {
+            AnswerActions::SubmitAnswer => {
+                let current_responses = query_prop
+                    .get_local_prop(AnswerProps::CurrentResponses.local_idx())
+                    .value;
+                let current_credit_achieved = query_prop
+                    .get_local_prop(AnswerProps::CurrentCreditAchieved.local_idx())
+                    .value;
+
+                Ok(vec![
+                    UpdateFromAction {
+                        local_prop_idx: AnswerProps::CreditAchieved.local_idx(),
+                        requested_value: current_credit_achieved,
+                    },
+                    UpdateFromAction {
+                        local_prop_idx: AnswerProps::ResponseHasBeenSubmitted.local_idx(),
+                        requested_value: true.into(),
+                    },
+                    UpdateFromAction {
+                        local_prop_idx: AnswerProps::SubmittedResponses.local_idx(),
+                        requested_value: current_responses,
+                    },
+                ])
+            }
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use current_responses::*;
+    mod current_responses {
+        use super::*;
+
+        /// The responses of the input descendants of the `<answer>`, in document order.
+        #[derive(Debug, Default)]
+        pub struct CurrentResponses {}
+
+        impl CurrentResponses {
+            pub fn new() -> Self {
+                CurrentResponses {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Answer)]
+        struct RequiredData {
+            responses: Vec<PropView<prop_type::String>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn responses_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::Descendants,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::Response]),
+                }
+            }
+        }
+
+        impl PropUpdater for CurrentResponses {
+            type PropType = prop_type::PropVec;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(Rc::new(
+                    required_data
+                        .responses
+                        .into_iter()
+                        .map(|response| PropValue::String(response.value))
+                        .collect(),
+                ))
+            }
+        }
+    }
+
+    pub use current_response::*;
+    mod current_response {
+        use super::*;
+
+        /// The first of the current responses, which the `<award>` children compare against.
+        #[derive(Debug, Default)]
+        pub struct CurrentResponse {}
+
+        impl CurrentResponse {
+            pub fn new() -> Self {
+                CurrentResponse {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Answer)]
+        struct RequiredData {
+            current_responses: PropView<prop_type::PropVec>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn current_responses_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: AnswerProps::CurrentResponses.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for CurrentResponse {
+            type PropType = prop_type::String;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let response = match required_data.current_responses.value.first() {
+                    Some(PropValue::String(response)) => response.clone(),
+                    _ => Rc::default(),
+                };
+                PropCalcResult::Calculated(response)
+            }
+        }
+    }
+
+    pub use current_credit_achieved::*;
+    mod current_credit_achieved {
+        use super::*;
+
+        /// The largest credit given by any `<award>` child for the current response, from 0 to 1.
+        #[derive(Debug, Default)]
+        pub struct CurrentCreditAchieved {}
+
+        impl CurrentCreditAchieved {
+            pub fn new() -> Self {
+                CurrentCreditAchieved {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Answer)]
+        struct RequiredData {
+            award_credits: Vec<PropView<prop_type::Number>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn award_credits_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::Children,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::CreditAchieved]),
+                }
+            }
+        }
+
+        impl PropUpdater for CurrentCreditAchieved {
+            type PropType = prop_type::Number;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let credit = required_data
+                    .award_credits
+                    .iter()
+                    .map(|credit| credit.value)
+                    .filter(|credit| !credit.is_nan())
+                    .fold(0.0, f64::max);
+                PropCalcResult::Calculated(credit.min(1.0))
+            }
+        }
+    }
+
+    pub use just_submitted::*;
+    mod just_submitted {
+        use super::*;
+
+        /// Whether the current responses are the ones that were most recently submitted.
+        #[derive(Debug, Default)]
+        pub struct JustSubmitted {}
+
+        impl JustSubmitted {
+            pub fn new() -> Self {
+                JustSubmitted {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Answer)]
+        struct RequiredData {
+            response_has_been_submitted: PropView<prop_type::Boolean>,
+            submitted_responses: PropView<prop_type::PropVec>,
+            current_responses: PropView<prop_type::PropVec>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn response_has_been_submitted_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: AnswerProps::ResponseHasBeenSubmitted.local_idx().into(),
+                }
+            }
+            fn submitted_responses_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: AnswerProps::SubmittedResponses.local_idx().into(),
+                }
+            }
+            fn current_responses_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: AnswerProps::CurrentResponses.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for JustSubmitted {
+            type PropType = prop_type::Boolean;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(
+                    required_data.response_has_been_submitted.value
+                        && required_data.submitted_responses.value
+                            == required_data.current_responses.value,
+                )
+            }
+        }
+    }
+
+    pub use rendered_children::*;
+    mod rendered_children {
+        use crate::general_prop::not_hidden_filter;
+        use crate::props::{ContentFilter, Op, OpNot};
+
+        use super::*;
+
+        /// The children that this component renders: all children that are not hidden, except `<award>`s.
+        #[derive(Debug, Default)]
+        pub struct RenderedChildren {}
+
+        impl RenderedChildren {
+            pub fn new() -> Self {
+                RenderedChildren {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Answer)]
+        struct RequiredData {
+            filtered_children: PropView<prop_type::AnnotatedContentRefs>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn filtered_children_query() -> DataQuery {
+                DataQuery::AnnotatedContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(Op::And(
+                        not_hidden_filter(),
+                        OpNot(ContentFilter::IsType(Award::NAME)),
+                    )),
+                }
+            }
+        }
+
+        impl PropUpdater for RenderedChildren {
+            type PropType = component::props::types::RenderedChildren;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(required_data.filtered_children.value)
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::components::prelude::*;
+use crate::general_prop::{NumberProp, StringProp};
+use crate::props::UpdaterObject;
+
+/// The `<award>` component, placed inside an `<answer>`, gives credit when the response of the answer
+/// matches the content of the `<award>`, e.g., `<award credit="0.5">hello</award>`.
+#[component(name = Award)]
+mod component {
+
+    use super::*;
+
+    enum Props {
+        /// The credit, from 0 to 1, given when the response matches.
+        #[prop(value_type = PropValueType::Number, is_public)]
+        Credit,
+        /// The response that earns the credit: the content of the `<award>`.
+        #[prop(value_type = PropValueType::String, is_public)]
+        CorrectResponse,
+        /// The credit given for the current response of the enclosing `<answer>`:
+        /// `credit` if the response matches `correct_response`, otherwise 0.
+        #[prop(
+            value_type = PropValueType::Number,
+            is_public,
+            profile = PropProfile::CreditAchieved
+        )]
+        CreditAchieved,
+    }
+
+    enum Attributes {
+        /// The credit, from 0 to 1, given when the response matches.
+        #[attribute(prop = NumberProp, default = 1.0)]
+        Credit,
+    }
+}
+
+pub use component::Award;
+pub use component::AwardActions;
+pub use component::AwardAttributes;
+pub use component::AwardProps;
+
+impl PropGetUpdater for AwardProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            AwardProps::Credit => as_updater_object::<_, component::props::types::Credit>(
+                component::attrs::Credit::get_prop_updater(),
+            ),
+            AwardProps::CorrectResponse => as_updater_object::<
+                _,
+                component::props::types::CorrectResponse,
+            >(StringProp::new_from_children("")),
+            AwardProps::CreditAchieved => as_updater_object::<
+                _,
+                component::props::types::CreditAchieved,
+            >(custom_props::CreditAchieved::new()),
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use credit_achieved::*;
+    mod credit_achieved {
+        use super::*;
+
+        /// The credit given for the current response of the enclosing `<answer>`.
+        #[derive(Debug, Default)]
+        pub struct CreditAchieved {}
+
+        impl CreditAchieved {
+            pub fn new() -> Self {
+                CreditAchieved {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Award)]
+        struct RequiredData {
+            /// The current response of the enclosing `<answer>`, if there is one.
+            response: Option<PropView<prop_type::String>>,
+            correct_response: PropView<prop_type::String>,
+            credit: PropView<prop_type::Number>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn response_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::NearestMatchingAncestor,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::Response]),
+                }
+            }
+            fn correct_response_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: AwardProps::CorrectResponse.local_idx().into(),
+                }
+            }
+            fn credit_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: AwardProps::Credit.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for CreditAchieved {
+            type PropType = prop_type::Number;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+
+                // Surrounding whitespace is not significant, and a blank response never matches.
+                let matches = required_data.response.is_some_and(|response| {
+                    let response = response.value.trim();
+                    !response.is_empty() && response == required_data.correct_response.value.trim()
+                });
+                PropCalcResult::Calculated(if matches {
+                    required_data.credit.value
+                } else {
+                    0.0
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::components::prelude::*;
+use crate::general_prop::RenderedChildrenPassthroughProp;
+use crate::props::UpdaterObject;
+
+/// A `<case>` is one of the blocks of a `<conditionalContent>`. Its content is shown when its `condition` is true
+/// and no earlier `<case>` of the `<conditionalContent>` is shown.
+#[component(name = Case)]
+mod component {
+
+    use crate::general_prop::BooleanProp;
+
+    enum Props {
+        /// Whether the content of the `<case>` should be shown.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Boolean, is_public)]
+        Condition,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// Whether the content of the `<case>` should be shown.
+        #[attribute(prop = BooleanProp, default = false)]
+        Condition,
+    }
+}
+
+pub use component::Case;
+pub use component::CaseActions;
+pub use component::CaseAttributes;
+pub use component::CaseProps;
+
+impl PropGetUpdater for CaseProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            CaseProps::Condition => as_updater_object::<_, component::props::types::Condition>(
+                component::attrs::Condition::get_prop_updater(),
+            ),
+            CaseProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(RenderedChildrenPassthroughProp::new()),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::rc::Rc;
+
+use crate::components::doenet::table::Halign;
+use crate::components::prelude::*;
+use crate::general_prop::{EnumAttributeValue, RenderedChildrenPassthroughProp};
+use crate::props::{ContentFilter, UpdaterObject};
+use crate::utils::usize_to_integer;
+
+/// The `<cell>` component is a cell of a `<row>` in a `<table>`. It can contain any content.
+///
+/// Its horizontal alignment is given by its own `halign` attribute, or else by the `halign` of its `<row>`,
+/// or else by the alignment of its column given by the `halign` of its `<table>`. Otherwise, it is `left`.
+#[component(name = Cell)]
+mod component {
+
+    use crate::general_prop::{BooleanProp, StringProp};
+
+    enum Props {
+        /// Whether the `<cell>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+        /// The position of the `<cell>` among the cells of its `<row>`, starting from 0.
+        #[prop(value_type = PropValueType::Integer)]
+        ColumnIndex,
+        /// The `halign` given by the attribute.
+        #[prop(value_type = PropValueType::String)]
+        SpecifiedHalign,
+        /// How the content is aligned horizontally: `left`, `center`, `right`, or `justify`.
+        #[prop(value_type = PropValueType::String, for_render, is_public)]
+        Halign,
+    }
+
+    enum Attributes {
+        /// Whether the `<cell>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// How the content is aligned horizontally: `left`, `center`, `right`, or `justify`.
+        #[attribute(prop = StringProp, default = String::new())]
+        Halign,
+    }
+}
+
+pub use component::Cell;
+pub use component::CellActions;
+pub use component::CellAttributes;
+pub use component::CellProps;
+
+impl PropGetUpdater for CellProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            CellProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            CellProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(RenderedChildrenPassthroughProp::new()),
+            CellProps::ColumnIndex => as_updater_object::<_, component::props::types::ColumnIndex>(
+                custom_props::ColumnIndex::new(),
+            ),
+            CellProps::SpecifiedHalign => {
+                as_updater_object::<_, component::props::types::SpecifiedHalign>(
+                    component::attrs::Halign::get_prop_updater(),
+                )
+            }
+            CellProps::Halign => as_updater_object::<_, component::props::types::Halign>(
+                custom_props::HalignProp::new(),
+            ),
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use column_index::*;
+    mod column_index {
+        use super::*;
+
+        /// The position of the `<cell>` among the `<cell>` children of its parent.
+        #[derive(Debug, Default)]
+        pub struct ColumnIndex {}
+
+        impl ColumnIndex {
+            pub fn new() -> Self {
+                ColumnIndex {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Cell)]
+        struct RequiredData {
+            siblings: PropView<prop_type::ContentRefs>,
+            self_ref: PropView<prop_type::ComponentRef>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn self_ref_query() -> DataQuery {
+                DataQuery::SelfRef
+            }
+            fn siblings_query() -> DataQuery {
+                DataQuery::ContentRefs {
+                    container: PropSource::Parent,
+                    filter: Rc::new(ContentFilter::IsType(Cell::NAME)),
+                }
+            }
+        }
+
+        impl PropUpdater for ColumnIndex {
+            type PropType = component::props::types::ColumnIndex;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let self_ref = required_data
+                    .self_ref
+                    .value
+                    .expect("SelfRef must always be valid")
+                    .as_content_ref();
+                let column_index = required_data
+                    .siblings
+                    .value
+                    .as_slice()
+                    .iter()
+                    .position(|sibling| sibling == &self_ref)
+                    .expect("Self must be in the list of siblings");
+                PropCalcResult::Calculated(
+                    usize_to_integer(column_index).expect("Number of cells must fit in an integer"),
+                )
+            }
+        }
+    }
+
+    pub use halign::*;
+    mod halign {
+        use super::*;
+
+        /// The horizontal alignment of the `<cell>`, taken from the first of the cell, its row,
+        /// and its table's column that gives a valid `halign`.
+        #[derive(Debug, Default)]
+        pub struct HalignProp {}
+
+        impl HalignProp {
+            pub fn new() -> Self {
+                HalignProp {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Cell)]
+        struct RequiredData {
+            specified_halign: PropView<component::props::types::SpecifiedHalign>,
+            row_halign: Option<PropView<prop_type::String>>,
+            table_halign: Option<PropView<prop_type::String>>,
+            column_index: PropView<component::props::types::ColumnIndex>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn specified_halign_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: CellProps::SpecifiedHalign.local_idx().into(),
+                }
+            }
+            fn row_halign_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::NearestMatchingAncestor,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::RowHalign]),
+                }
+            }
+            fn table_halign_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::NearestMatchingAncestor,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::TableHalign]),
+                }
+            }
+            fn column_index_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: CellProps::ColumnIndex.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for HalignProp {
+            type PropType = prop_type::String;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let column_halign = required_data.table_halign.and_then(|table_halign| {
+                    let column_index = usize::try_from(required_data.column_index.value).ok()?;
+                    table_halign
+                        .value
+                        .split_whitespace()
+                        .nth(column_index)
+                        .and_then(Halign::parse_attribute_value)
+                });
+                let halign = Halign::parse_attribute_value(&required_data.specified_halign.value)
+                    .or_else(|| {
+                        required_data
+                            .row_halign
+                            .and_then(|row_halign| Halign::parse_attribute_value(&row_halign.value))
+                    })
+                    .or(column_halign)
+                    .unwrap_or_default();
+                PropCalcResult::Calculated(Rc::new(halign.to_string()))
+            }
+        }
+    }
+}
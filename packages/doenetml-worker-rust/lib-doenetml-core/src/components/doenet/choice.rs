@@ -0,0 +1,52 @@
+use crate::components::prelude::*;
+use crate::general_prop::RenderedChildrenPassthroughProp;
+use crate::props::UpdaterObject;
+
+/// A `<choice>` is one of the options of a `<choiceInput>`. Its content is shown next to the button that selects it.
+#[component(name = Choice)]
+mod component {
+
+    use crate::general_prop::BooleanProp;
+
+    enum Props {
+        /// Whether the `<choice>` should be hidden, i.e., not offered by its `<choiceInput>`.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        /// Whether the `<choice>` is shown but cannot be selected.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Disabled, is_public)]
+        Disabled,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// Whether the `<choice>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// Whether the `<choice>` cannot be selected.
+        #[attribute(prop = BooleanProp, default = false)]
+        Disabled,
+    }
+}
+
+pub use component::Choice;
+pub use component::ChoiceActions;
+pub use component::ChoiceAttributes;
+pub use component::ChoiceProps;
+
+impl PropGetUpdater for ChoiceProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            ChoiceProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            ChoiceProps::Disabled => as_updater_object::<_, component::props::types::Disabled>(
+                component::attrs::Disabled::get_prop_updater(),
+            ),
+            ChoiceProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(RenderedChildrenPassthroughProp::new()),
+        }
+    }
+}
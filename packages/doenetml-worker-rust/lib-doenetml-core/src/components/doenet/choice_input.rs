@@ -0,0 +1,339 @@
+use std::rc::Rc;
+
+use crate::components::doenet::choice::Choice;
+use crate::components::prelude::*;
+use crate::general_prop::IndependentProp;
+use crate::props::{ContentFilter, UpdaterObject};
+
+/// The `<choiceInput>` component lets a user select among its `<choice>` children,
+/// shown as radio buttons or, if `selectMultiple` is set, as checkboxes.
+#[component(name = ChoiceInput, needs_pointer_events)]
+mod component {
+
+    use crate::general_prop::{BooleanProp, StringProp};
+
+    enum Props {
+        /// The indices of the selected `<choice>` children, starting from 1, in increasing order.
+        /// It is empty until a user selects a choice.
+        #[prop(value_type = PropValueType::PropVec, is_public, for_render)]
+        SelectedIndices,
+        /// Whether more than one `<choice>` may be selected.
+        #[prop(value_type = PropValueType::Boolean, is_public, for_render)]
+        SelectMultiple,
+        /// Whether the choices are displayed inline with the surrounding text rather than as a block.
+        #[prop(value_type = PropValueType::Boolean, for_render)]
+        Inline,
+        /// The indices of the `<choice>` children, starting from 1, in the order they are displayed.
+        /// Choices are displayed in the order they are authored.
+        #[prop(value_type = PropValueType::PropVec, for_render)]
+        ChoiceOrder,
+        /// Whether each `<choice>` child, in display order, is hidden.
+        #[prop(value_type = PropValueType::PropVec, for_render)]
+        ChoicesHidden,
+        /// Whether each `<choice>` child, in display order, cannot be selected.
+        #[prop(value_type = PropValueType::PropVec, for_render)]
+        ChoicesDisabled,
+        /// The label shown with the choices.
+        #[prop(value_type = PropValueType::String, for_render)]
+        Label,
+        /// A description of the `<choiceInput>` for assistive technology, used when there is no label.
+        #[prop(value_type = PropValueType::String, for_render)]
+        ShortDescription,
+        /// Whether the `<choiceInput>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// Whether the `<choiceInput>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// Whether more than one `<choice>` may be selected.
+        #[attribute(prop = BooleanProp, default = false)]
+        SelectMultiple,
+        /// Whether the choices are displayed inline with the surrounding text.
+        #[attribute(prop = BooleanProp, default = false)]
+        Inline,
+        /// The label shown with the choices.
+        #[attribute(prop = StringProp, default = String::new())]
+        Label,
+        /// A description of the `<choiceInput>` for assistive technology.
+        #[attribute(prop = StringProp, default = String::new())]
+        ShortDescription,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    #[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+    #[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+    #[serde(rename_all = "camelCase")]
+    #[serde(expecting = "`selectedIndices` must be an array of integers")]
+    pub struct ChoiceInputActionArgs {
+        pub selected_indices: Vec<prop_type::Integer>,
+    }
+
+    enum Actions {
+        /// Record the choices selected by a user.
+        UpdateSelectedIndices(ActionBody<ChoiceInputActionArgs>),
+    }
+}
+
+pub use component::ChoiceInput;
+pub use component::ChoiceInputActionArgs;
+pub use component::ChoiceInputActions;
+pub use component::ChoiceInputAttributes;
+pub use component::ChoiceInputProps;
+
+impl PropGetUpdater for ChoiceInputProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            ChoiceInputProps::SelectedIndices => as_updater_object::<
+                _,
+                component::props::types::SelectedIndices,
+            >(IndependentProp::new(Rc::default())),
+            ChoiceInputProps::SelectMultiple => {
+                as_updater_object::<_, component::props::types::SelectMultiple>(
+                    component::attrs::SelectMultiple::get_prop_updater(),
+                )
+            }
+            ChoiceInputProps::Inline => as_updater_object::<_, component::props::types::Inline>(
+                component::attrs::Inline::get_prop_updater(),
+            ),
+            ChoiceInputProps::ChoiceOrder => as_updater_object::<
+                _,
+                component::props::types::ChoiceOrder,
+            >(custom_props::ChoiceOrder::new()),
+            ChoiceInputProps::ChoicesHidden => {
+                as_updater_object::<_, component::props::types::ChoicesHidden>(
+                    custom_props::ChoiceFlags::new(PropProfile::Hidden),
+                )
+            }
+            ChoiceInputProps::ChoicesDisabled => {
+                as_updater_object::<_, component::props::types::ChoicesDisabled>(
+                    custom_props::ChoiceFlags::new(PropProfile::Disabled),
+                )
+            }
+            ChoiceInputProps::Label => as_updater_object::<_, component::props::types::Label>(
+                component::attrs::Label::get_prop_updater(),
+            ),
+            ChoiceInputProps::ShortDescription => {
+                as_updater_object::<_, component::props::types::ShortDescription>(
+                    component::attrs::ShortDescription::get_prop_updater(),
+                )
+            }
+            ChoiceInputProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            ChoiceInputProps::RenderedChildren => {
+                as_updater_object::<_, component::props::types::RenderedChildren>(
+                    custom_props::RenderedChildren::new(),
+                )
+            }
+        }
+    }
+}
+
+impl ComponentOnAction for ChoiceInput {
+    fn on_action(
+        &self,
+        action: ActionsEnum,
+        query_prop: ActionQueryProp,
+    ) -> Result<Vec<UpdateFromAction>, String> {
+        // The type of `action` should have already been verified, so an
+        // error here is a programming logic error, not an API error.
+        let action: ChoiceInputActions = action.try_into()?;
+
+        match action {
+            ChoiceInputActions::UpdateSelectedIndices(ActionBody { args }) => {
+                let choices_disabled: prop_type::PropVec = query_prop
+                    .get_local_prop(ChoiceInputProps::ChoicesDisabled.local_idx())
+                    .value
+                    .try_into()
+                    .unwrap();
+                let select_multiple: prop_type::Boolean = query_prop
+                    .get_local_prop(ChoiceInputProps::SelectMultiple.local_idx())
+                    .value
+                    .try_into()
+                    .unwrap();
+
+                // Ignore indices that do not refer to a choice that can be selected.
+                // `selected_indices` start from 1.
+                let mut selected_indices = args
+                    .selected_indices
+                    .into_iter()
+                    .filter(|&index| {
+                        usize::try_from(index - 1).is_ok_and(|idx| {
+                            choices_disabled.get(idx) == Some(&PropValue::Boolean(false))
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                selected_indices.sort_unstable();
+                selected_indices.dedup();
+                if !select_multiple {
+                    selected_indices.truncate(1);
+                }
+
+                Ok(vec![UpdateFromAction {
+                    local_prop_idx: ChoiceInputProps::SelectedIndices.local_idx(),
+                    requested_value: selected_indices
+                        .into_iter()
+                        .map(PropValue::Integer)
+                        .collect::<Vec<_>>()
+                        .into(),
+                }])
+            }
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    /// The query for the `<choice>` children of the `<choiceInput>`.
+    fn choices_query() -> DataQuery {
+        DataQuery::AnnotatedContentRefs {
+            container: PropSource::Me,
+            filter: Rc::new(ContentFilter::IsType(Choice::NAME)),
+        }
+    }
+
+    pub use choice_order::*;
+    mod choice_order {
+        use super::*;
+
+        /// The display order of the `<choice>` children, which is the order they are authored.
+        #[derive(Debug, Default)]
+        pub struct ChoiceOrder {}
+
+        impl ChoiceOrder {
+            pub fn new() -> Self {
+                ChoiceOrder {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(ChoiceInput)]
+        struct RequiredData {
+            choices: PropView<prop_type::AnnotatedContentRefs>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn choices_query() -> DataQuery {
+                super::choices_query()
+            }
+        }
+
+        impl PropUpdater for ChoiceOrder {
+            type PropType = prop_type::PropVec;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let num_choices = required_data.choices.value.0.len() as prop_type::Integer;
+                PropCalcResult::Calculated(Rc::new(
+                    (1..=num_choices).map(PropValue::Integer).collect(),
+                ))
+            }
+        }
+    }
+
+    pub use choice_flags::*;
+    mod choice_flags {
+        use super::*;
+
+        /// The boolean prop matching `profile` of each `<choice>` child, e.g., whether it is hidden.
+        #[derive(Debug)]
+        pub struct ChoiceFlags {
+            profile: PropProfile,
+        }
+
+        impl ChoiceFlags {
+            pub fn new(profile: PropProfile) -> Self {
+                ChoiceFlags { profile }
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries, pass_data = PropProfile)]
+        struct RequiredData {
+            flags: Vec<PropView<prop_type::Boolean>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn flags_query(profile: PropProfile) -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::Children,
+                    prop_specifier: PropSpecifier::Matching(vec![profile]),
+                }
+            }
+        }
+
+        impl PropUpdater for ChoiceFlags {
+            type PropType = prop_type::PropVec;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::data_queries_vec(self.profile)
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(Rc::new(
+                    required_data
+                        .flags
+                        .into_iter()
+                        .map(|flag| PropValue::Boolean(flag.value))
+                        .collect(),
+                ))
+            }
+        }
+    }
+
+    pub use rendered_children::*;
+    mod rendered_children {
+        use super::*;
+
+        /// The children that this component renders: all of its `<choice>` children, including hidden ones,
+        /// so that they line up with `choice_order`. The renderer skips the hidden choices.
+        #[derive(Debug, Default)]
+        pub struct RenderedChildren {}
+
+        impl RenderedChildren {
+            pub fn new() -> Self {
+                RenderedChildren {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(ChoiceInput)]
+        struct RequiredData {
+            choices: PropView<prop_type::AnnotatedContentRefs>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn choices_query() -> DataQuery {
+                super::choices_query()
+            }
+        }
+
+        impl PropUpdater for RenderedChildren {
+            type PropType = component::props::types::RenderedChildren;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(required_data.choices.value)
+            }
+        }
+    }
+}
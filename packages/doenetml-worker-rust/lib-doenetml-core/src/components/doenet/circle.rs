@@ -0,0 +1,194 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::general_prop::{BooleanProp, MathProp, NumberProp};
+use crate::props::UpdaterObject;
+use crate::state::types::math_expr::MathExpr;
+
+/// The `<circle>` component, given by its center and radius,
+/// e.g., `<circle center="$P" radius="2"/>` where `P` is a `<point>`.
+#[component(name = Circle, needs_pointer_events)]
+mod component {
+
+    use super::*;
+
+    enum Props {
+        /// Whether the `<circle>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        /// The coordinates of the center of the circle, as a math vector.
+        #[prop(value_type = PropValueType::Math, is_public)]
+        Center,
+        /// The numerical `[x, y]` coordinates of the center of the circle.
+        /// Coordinates that are not numerical are `NaN`.
+        #[prop(value_type = PropValueType::PropVec, for_render(in_graph))]
+        NumericalCenter,
+        /// The radius of the circle.
+        #[prop(value_type = PropValueType::Number, is_public, for_render(in_graph))]
+        Radius,
+    }
+
+    enum Attributes {
+        /// Whether the `<circle>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The center of the circle, either a reference to a `<point>` or its coordinates.
+        #[attribute(prop = MathProp, default = MathExpr::new_vector(&[0.0.into(), 0.0.into()]))]
+        Center,
+        /// The radius of the circle.
+        #[attribute(prop = NumberProp, default = 1.0)]
+        Radius,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    #[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+    #[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+    #[serde(expecting = "`center` must be an array of two numbers")]
+    pub struct CircleMoveActionArgs {
+        pub center: [prop_type::Number; 2],
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    #[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+    #[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+    #[serde(expecting = "`radius` must be a number")]
+    pub struct CircleResizeActionArgs {
+        pub radius: prop_type::Number,
+    }
+
+    enum Actions {
+        /// Move the center of the circle to `center`.
+        MoveCircle(ActionBody<CircleMoveActionArgs>),
+        /// Change the radius of the circle to `radius`.
+        ResizeCircle(ActionBody<CircleResizeActionArgs>),
+    }
+}
+
+pub use component::Circle;
+pub use component::CircleActions;
+pub use component::CircleAttributes;
+pub use component::CircleMoveActionArgs;
+pub use component::CircleProps;
+pub use component::CircleResizeActionArgs;
+
+impl PropGetUpdater for CircleProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            CircleProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            CircleProps::Center => as_updater_object::<_, component::props::types::Center>(
+                component::attrs::Center::get_prop_updater(),
+            ),
+            CircleProps::NumericalCenter => as_updater_object::<
+                _,
+                component::props::types::NumericalCenter,
+            >(custom_props::NumericalCenter::new()),
+            CircleProps::Radius => as_updater_object::<_, component::props::types::Radius>(
+                component::attrs::Radius::get_prop_updater(),
+            ),
+        }
+    }
+}
+
+impl ComponentOnAction for Circle {
+    fn on_action(
+        &self,
+        action: ActionsEnum,
+        _query_prop: ActionQueryProp,
+    ) -> Result<Vec<UpdateFromAction>, String> {
+        // The type of `action` should have already been verified, so an
+        // error here is a programming logic error, not an API error.
+        let action: CircleActions = action.try_into()?;
+
+        match action {
+            CircleActions::MoveCircle(ActionBody { args }) => {
+                let [x, y] = args.center;
+                Ok(vec![UpdateFromAction {
+                    local_prop_idx: CircleProps::Center.local_idx(),
+                    requested_value: PropValue::Math(Rc::new(MathExpr::new_vector(&[
+                        x.into(),
+                        y.into(),
+                    ]))),
+                }])
+            }
+            CircleActions::ResizeCircle(ActionBody { args }) => {
+                if !(args.radius.is_finite() && args.radius >= 0.0) {
+                    return Err(format!(
+                        "`radius` must be a non-negative number, found {}",
+                        args.radius
+                    ));
+                }
+                Ok(vec![UpdateFromAction {
+                    local_prop_idx: CircleProps::Radius.local_idx(),
+                    requested_value: PropValue::Number(args.radius),
+                }])
+            }
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use numerical_center::*;
+    mod numerical_center {
+        use super::*;
+
+        /// The numerical `[x, y]` coordinates of the center of the `<circle>`.
+        #[derive(Debug, Default)]
+        pub struct NumericalCenter {}
+
+        impl NumericalCenter {
+            pub fn new() -> Self {
+                NumericalCenter {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Circle)]
+        struct RequiredData {
+            center: PropView<prop_type::Math>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn center_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: CircleProps::Center.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for NumericalCenter {
+            type PropType = prop_type::PropVec;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+
+                let components = required_data
+                    .center
+                    .value
+                    .to_vector_components()
+                    .unwrap_or_default();
+                let coord = |i: usize| {
+                    components
+                        .get(i)
+                        .map(|component| component.to_number())
+                        .unwrap_or(prop_type::Number::NAN)
+                };
+
+                PropCalcResult::Calculated(Rc::new(vec![
+                    PropValue::Number(coord(0)),
+                    PropValue::Number(coord(1)),
+                ]))
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::dast::ElementRefAnnotation;
+use crate::props::{ContentFilter, UpdaterObject};
+
+/// The `<collect>` component renders the components of the types listed in `componentTypes`
+/// that are rendered inside of its `source`, e.g., `<collect source="$g" componentTypes="point"/>`
+/// renders every point of the graph `g`.
+///
+/// Components that are not rendered, e.g., inside an unselected `<case>` of a `<conditionalContent>`,
+/// are not collected, and the collected components change when the rendered content of `source` changes.
+#[component(name = Collect)]
+mod component {
+
+    use super::*;
+    use crate::general_prop::{BooleanProp, ComponentRefProp, StringProp};
+
+    enum Props {
+        /// Whether the `<collect>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+
+        /// The component whose descendants are collected.
+        #[prop(value_type = PropValueType::ComponentRef)]
+        Source,
+
+        /// The types of the components to collect, separated by spaces.
+        #[prop(value_type = PropValueType::String, is_public)]
+        ComponentTypes,
+
+        #[prop(
+            value_type = PropValueType::AnnotatedContentRefs,
+            profile = PropProfile::RenderedChildren
+        )]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// Whether the `<collect>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The component whose descendants are collected.
+        #[attribute(prop = ComponentRefProp, default = None, preserve_refs)]
+        Source,
+        /// The types of the components to collect, separated by spaces, e.g., `"point line"`.
+        #[attribute(prop = StringProp, default = String::new())]
+        ComponentTypes,
+    }
+}
+
+pub use component::Collect;
+pub use component::CollectActions;
+pub use component::CollectAttributes;
+pub use component::CollectProps;
+
+impl PropGetUpdater for CollectProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            CollectProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            CollectProps::Source => as_updater_object::<_, component::props::types::Source>(
+                component::attrs::Source::get_prop_updater(),
+            ),
+            CollectProps::ComponentTypes => {
+                as_updater_object::<_, component::props::types::ComponentTypes>(
+                    component::attrs::ComponentTypes::get_prop_updater(),
+                )
+            }
+            CollectProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(custom_props::RenderedChildren::new()),
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use rendered_children::*;
+    mod rendered_children {
+        use super::*;
+
+        /// The children that this component renders: the collected components.
+        /// They are rendered as duplicates, since they are also rendered inside of `source`.
+        #[derive(Debug, Default)]
+        pub struct RenderedChildren {}
+
+        impl RenderedChildren {
+            pub fn new() -> Self {
+                RenderedChildren {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Collect)]
+        struct RequiredData {
+            collected: PropView<prop_type::ContentRefs>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn collected_query() -> DataQuery {
+                DataQuery::DescendantContentRefs {
+                    container: PropSource::StaticComponentRef(CollectProps::Source.local_idx()),
+                    filter: Rc::new(ContentFilter::IsTypeListedIn(
+                        CollectProps::ComponentTypes.local_idx(),
+                    )),
+                }
+            }
+        }
+
+        impl PropUpdater for RenderedChildren {
+            type PropType = component::props::types::RenderedChildren;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let collected = required_data
+                    .collected
+                    .value
+                    .as_slice()
+                    .iter()
+                    .map(|content_ref| (content_ref.clone(), ElementRefAnnotation::Duplicate))
+                    .collect::<Vec<_>>();
+                PropCalcResult::Calculated(Rc::new(collected.into()))
+            }
+        }
+    }
+}
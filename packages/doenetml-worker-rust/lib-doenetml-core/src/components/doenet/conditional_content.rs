@@ -0,0 +1,158 @@
+use std::rc::Rc;
+
+use crate::components::doenet::case::Case;
+use crate::components::doenet::else_case::Else;
+use crate::components::prelude::*;
+use crate::general_prop::not_hidden_filter;
+use crate::props::{Cond, ContentFilter, Op, UpdaterObject};
+
+/// The `<conditionalContent>` component shows the content of its first `<case>` whose `condition` is true,
+/// or the content of its `<else>` if no `<case>` is shown, e.g.,
+/// `<conditionalContent><case condition="$b"><p>Yes</p></case><else><p>No</p></else></conditionalContent>`.
+///
+/// A `<conditionalContent>` without `<case>` or `<else>` children shows its children when its own `condition` is true.
+/// The content shown is recomputed whenever a condition changes.
+#[component(name = ConditionalContent)]
+mod component {
+
+    use crate::general_prop::BooleanProp;
+
+    enum Props {
+        /// Whether the children are shown, for a `<conditionalContent>` without `<case>` or `<else>` children.
+        #[prop(value_type = PropValueType::Boolean)]
+        Condition,
+
+        #[prop(
+            value_type = PropValueType::AnnotatedContentRefs,
+            profile = PropProfile::RenderedChildren
+        )]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// Whether the children are shown, for a `<conditionalContent>` without `<case>` or `<else>` children.
+        #[attribute(prop = BooleanProp, default = false)]
+        Condition,
+    }
+}
+
+pub use component::ConditionalContent;
+pub use component::ConditionalContentActions;
+pub use component::ConditionalContentAttributes;
+pub use component::ConditionalContentProps;
+
+impl PropGetUpdater for ConditionalContentProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            ConditionalContentProps::Condition => {
+                as_updater_object::<_, component::props::types::Condition>(
+                    component::attrs::Condition::get_prop_updater(),
+                )
+            }
+            ConditionalContentProps::RenderedChildren => {
+                as_updater_object::<_, component::props::types::RenderedChildren>(
+                    custom_props::RenderedChildren::new(),
+                )
+            }
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use rendered_children::*;
+    mod rendered_children {
+        use super::*;
+
+        /// The children that this component renders: the first `<case>` whose condition is true, otherwise the `<else>`.
+        #[derive(Debug, Default)]
+        pub struct RenderedChildren {}
+
+        impl RenderedChildren {
+            pub fn new() -> Self {
+                RenderedChildren {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(ConditionalContent)]
+        struct RequiredData {
+            cases: PropView<prop_type::ContentRefs>,
+            true_cases: PropView<prop_type::AnnotatedContentRefs>,
+            elses: PropView<prop_type::AnnotatedContentRefs>,
+            condition: PropView<prop_type::Boolean>,
+            children: PropView<prop_type::AnnotatedContentRefs>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn cases_query() -> DataQuery {
+                DataQuery::ContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(Op::Or(
+                        ContentFilter::IsType(Case::NAME),
+                        ContentFilter::IsType(Else::NAME),
+                    )),
+                }
+            }
+            fn true_cases_query() -> DataQuery {
+                DataQuery::AnnotatedContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(Op::And(
+                        ContentFilter::IsType(Case::NAME),
+                        ContentFilter::HasPropMatchingProfileAndCondition(
+                            PropProfile::Boolean,
+                            Cond::Eq(PropValue::Boolean(true)),
+                        ),
+                    )),
+                }
+            }
+            fn elses_query() -> DataQuery {
+                DataQuery::AnnotatedContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(ContentFilter::IsType(Else::NAME)),
+                }
+            }
+            fn condition_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: ConditionalContentProps::Condition.local_idx().into(),
+                }
+            }
+            fn children_query() -> DataQuery {
+                DataQuery::AnnotatedContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(not_hidden_filter()),
+                }
+            }
+        }
+
+        impl PropUpdater for RenderedChildren {
+            type PropType = component::props::types::RenderedChildren;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+
+                if required_data.cases.value.as_slice().is_empty() && required_data.condition.value
+                {
+                    return PropCalcResult::Calculated(required_data.children.value);
+                }
+
+                let shown = required_data
+                    .true_cases
+                    .value
+                    .0
+                    .first()
+                    .or_else(|| required_data.elses.value.0.first())
+                    .cloned();
+                PropCalcResult::Calculated(Rc::new(shown.into_iter().collect::<Vec<_>>().into()))
+            }
+        }
+    }
+}
@@ -3,12 +3,14 @@ use std::rc::Rc;
 use super::title::Title;
 use crate::components::prelude::*;
 use crate::general_prop::ComponentRefProp;
-use crate::general_prop::{BooleanProp, EnumProp};
+use crate::general_prop::PropAlias;
+use crate::general_prop::{BooleanProp, EnumProp, StringProp};
 use crate::props::DataQueryResults;
 use crate::props::PropView;
 use crate::props::UpdaterObject;
 use crate::props::as_updater_object;
 use crate::state::types::division_type::DivisionType;
+use crate::utils::usize_to_integer;
 
 /// The `<division>` component renders its children along with a title
 #[component(name = Division)]
@@ -50,6 +52,11 @@ mod component {
            )]
         CodeNumber,
 
+        /// The number of this `<division>`, e.g., `"1.2"` for the second subsection of the first section.
+        /// This is the public name of `CodeNumber`.
+        #[prop(value_type = PropValueType::String, is_public)]
+        SectionNumber,
+
         /// How many levels deep this `<division /> is nested.
         #[prop(
                value_type = PropValueType::Integer,
@@ -58,6 +65,11 @@ mod component {
            )]
         DivisionDepth,
 
+        /// The level of this `<division>` in the hierarchy of divisions, starting from `1`
+        /// for a `<division>` that is not inside of another.
+        #[prop(value_type = PropValueType::Integer, is_public)]
+        Level,
+
         /// The type of this `<division />`. E.g. "section", "chapter", "subsection", etc.
         /// Use this prop instead of `DivisionTypeAttr`; it will correctly handle the case
         /// where the user omits the `<division type="...">` attribute.
@@ -85,6 +97,16 @@ mod component {
            )]
         XrefLabel,
 
+        /// Whether the contents of the `<division>` have been released to the student,
+        /// based on the condition in its `availableAfterCredit` attribute.
+        /// A division that is not available renders its title but none of its other children.
+        #[prop(
+            value_type = PropValueType::Boolean,
+            is_public,
+            for_render
+        )]
+        Available,
+
         #[prop(
             value_type = PropValueType::AnnotatedContentRefs,
             profile = PropProfile::RenderedChildren
@@ -107,6 +129,15 @@ mod component {
             default = DivisionType::Section
         )]
         Type,
+
+        /// A condition on the credit of other components that must hold for the `<division>`
+        /// to be available, e.g., `availableAfterCredit="$problem1 >= 0.8"`.
+        /// Conditions may be combined with `and`.
+        #[attribute(
+            prop = StringProp,
+            default = String::new()
+        )]
+        AvailableAfterCredit,
     }
 }
 
@@ -126,6 +157,9 @@ impl PropGetUpdater for DivisionProps {
             DivisionProps::Hidden => {
                 as_updater_object::<_, props::types::Hidden>(attrs::Hide::get_prop_updater())
             }
+            DivisionProps::Available => {
+                as_updater_object::<_, props::types::Available>(custom_props::AvailableProp::new())
+            }
             DivisionProps::RenderedChildren => {
                 as_updater_object::<_, props::types::RenderedChildren>(
                     custom_props::RenderedChildren::new(),
@@ -137,9 +171,15 @@ impl PropGetUpdater for DivisionProps {
             DivisionProps::CodeNumber => {
                 as_updater_object::<_, props::types::CodeNumber>(custom_props::CodeNumberProp::new())
             }
+            DivisionProps::SectionNumber => as_updater_object::<_, props::types::SectionNumber>(
+                PropAlias::new(DivisionProps::CodeNumber.local_idx()),
+            ),
             DivisionProps::DivisionDepth => as_updater_object::<_, props::types::DivisionDepth>(
                 custom_props::DivisionDepth::new(),
             ),
+            DivisionProps::Level => {
+                as_updater_object::<_, props::types::Level>(custom_props::Level::new())
+            }
             DivisionProps::XrefLabel => {
                 as_updater_object::<_, props::types::XrefLabel>(custom_props::XrefLabel::new())
             }
@@ -276,6 +316,51 @@ mod custom_props {
         }
     }
 
+    pub use level::*;
+    mod level {
+        use super::*;
+
+        /// The level of this component in the hierarchy of divisions, one more than its depth.
+        #[derive(Debug, Default)]
+        pub struct Level {}
+
+        impl Level {
+            pub fn new() -> Self {
+                Level {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Division)]
+        struct RequiredData {
+            division_depth: PropView<prop_type::Integer>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn division_depth_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: DivisionProps::DivisionDepth.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for Level {
+            type PropType = prop_type::Integer;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(required_data.division_depth.value + 1)
+            }
+        }
+    }
+
     pub use code_number::*;
     mod code_number {
         use super::*;
@@ -402,7 +487,10 @@ mod custom_props {
                     .iter()
                     .position(|sibling| sibling == &self_ref)
                     .expect("Self must be in the list of siblings");
-                PropCalcResult::Calculated(serial_number as i64)
+                PropCalcResult::Calculated(
+                    usize_to_integer(serial_number)
+                        .expect("Number of siblings must fit in an integer"),
+                )
             }
         }
     }
@@ -429,10 +517,17 @@ mod custom_props {
         #[derive(TestDataQueryTypes)]
         #[owning_component(Division)]
         struct RequiredData {
+            available: PropView<props::types::Available>,
             filtered_children: PropView<prop_type::AnnotatedContentRefs>,
         }
 
         impl DataQueries for RequiredData {
+            fn available_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: DivisionProps::Available.local_idx().into(),
+                }
+            }
             fn filtered_children_query() -> DataQuery {
                 DataQuery::AnnotatedContentRefs {
                     container: PropSource::Me,
@@ -462,6 +557,10 @@ mod custom_props {
             }
             fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
                 let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                if !required_data.available.value {
+                    // The title is rendered separately, so it is still shown.
+                    return PropCalcResult::Calculated(Rc::default());
+                }
                 PropCalcResult::Calculated(Rc::new(
                     required_data.filtered_children.value.as_ref().clone(),
                 ))
@@ -469,6 +568,91 @@ mod custom_props {
         }
     }
 
+    pub use available::*;
+    mod available {
+        use super::*;
+
+        /// Whether the contents of this division are available, based on the condition
+        /// in its `availableAfterCredit` attribute. A division without a condition is always available.
+        #[derive(Debug, Default)]
+        pub struct AvailableProp {}
+
+        impl AvailableProp {
+            pub fn new() -> Self {
+                AvailableProp {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        struct RequiredData {
+            condition: Vec<PropView<PropValue>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn condition_query() -> DataQuery {
+                DataQuery::Attribute {
+                    attribute_name: "availableAfterCredit",
+                    match_profiles: vec![PropProfile::Number, PropProfile::String],
+                }
+            }
+        }
+
+        impl PropUpdater for AvailableProp {
+            type PropType = props::types::Available;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                if required_data.condition.is_empty() {
+                    return PropCalcResult::FromDefault(true);
+                }
+
+                // Referenced credits are substituted into the text of the condition.
+                let condition = required_data
+                    .condition
+                    .iter()
+                    .map(|part| match &part.value {
+                        PropValue::Number(credit) => credit.to_string(),
+                        PropValue::String(text) => text.to_string(),
+                        value => panic!("Unexpected value in credit condition: {value:?}"),
+                    })
+                    .collect::<String>();
+
+                PropCalcResult::Calculated(credit_condition_is_met(&condition))
+            }
+        }
+
+        /// Whether every comparison in `condition`, e.g., `0.9 >= 0.8 and 1 = 1`, holds.
+        /// A condition that cannot be parsed is never met.
+        fn credit_condition_is_met(condition: &str) -> bool {
+            condition
+                .split(" and ")
+                .all(|comparison| comparison_holds(comparison).unwrap_or(false))
+        }
+
+        fn comparison_holds(comparison: &str) -> Option<bool> {
+            // Two-character operators are listed first so that `>=` is not read as `>`.
+            for operator in [">=", "<=", ">", "<", "="] {
+                if let Some((lhs, rhs)) = comparison.split_once(operator) {
+                    let lhs: f64 = lhs.trim().parse().ok()?;
+                    let rhs: f64 = rhs.trim().parse().ok()?;
+                    return Some(match operator {
+                        ">=" => lhs >= rhs,
+                        "<=" => lhs <= rhs,
+                        ">" => lhs > rhs,
+                        "<" => lhs < rhs,
+                        _ => lhs == rhs,
+                    });
+                }
+            }
+            None
+        }
+    }
+
     pub use division_type::*;
     mod division_type {
         use super::*;
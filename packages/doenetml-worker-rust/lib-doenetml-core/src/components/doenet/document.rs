@@ -1,6 +1,7 @@
 use crate::components::prelude::*;
 use crate::general_prop::RenderedChildrenPassthroughProp;
 use crate::props::UpdaterObject;
+use crate::utils::usize_to_integer;
 
 /// The `Document` component is the root of every _DoenetML_ document.
 #[component(name = Document)]
@@ -12,6 +13,18 @@ pub mod component {
             profile = PropProfile::RenderedChildren
         )]
         RenderedChildren,
+        /// The fraction of the interactive components of the document that have been answered, from 0 to 1.
+        /// A document without interactive components is complete.
+        #[prop(
+            value_type = PropValueType::Number,
+            profile = PropProfile::CompletionFraction,
+            is_public
+        )]
+        CompletionFraction,
+        /// The number of `<solution>`s in the document that have been revealed,
+        /// e.g., so that a scoring policy can reduce the credit given.
+        #[prop(value_type = PropValueType::Integer, is_public)]
+        NumSolutionsRevealed,
     }
 }
 
@@ -28,6 +41,129 @@ impl PropGetUpdater for DocumentProps {
                 _,
                 component::props::types::RenderedChildren,
             >(RenderedChildrenPassthroughProp::new()),
+            DocumentProps::CompletionFraction => {
+                as_updater_object::<_, component::props::types::CompletionFraction>(
+                    custom_props::CompletionFraction::new(),
+                )
+            }
+            DocumentProps::NumSolutionsRevealed => {
+                as_updater_object::<_, component::props::types::NumSolutionsRevealed>(
+                    custom_props::NumSolutionsRevealed::new(),
+                )
+            }
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use completion_fraction::*;
+    mod completion_fraction {
+        use super::*;
+
+        /// The fraction of the interactive components of the document that have been answered.
+        #[derive(Debug, Default)]
+        pub struct CompletionFraction {}
+
+        impl CompletionFraction {
+            pub fn new() -> Self {
+                CompletionFraction {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Document)]
+        struct RequiredData {
+            /// Whether each interactive component of the document has been answered.
+            answered: Vec<PropView<prop_type::Boolean>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn answered_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::Descendants,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::Answered]),
+                }
+            }
+        }
+
+        impl PropUpdater for CompletionFraction {
+            type PropType = prop_type::Number;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let total = required_data.answered.len();
+                if total == 0 {
+                    return PropCalcResult::Calculated(1.0);
+                }
+                let answered = required_data
+                    .answered
+                    .iter()
+                    .filter(|answered| answered.value)
+                    .count();
+                PropCalcResult::Calculated(answered as f64 / total as f64)
+            }
+        }
+    }
+
+    pub use num_solutions_revealed::*;
+    mod num_solutions_revealed {
+        use super::*;
+
+        /// The number of `<solution>`s of the document that have been revealed.
+        #[derive(Debug, Default)]
+        pub struct NumSolutionsRevealed {}
+
+        impl NumSolutionsRevealed {
+            pub fn new() -> Self {
+                NumSolutionsRevealed {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Document)]
+        struct RequiredData {
+            /// Whether each `<solution>` of the document has been revealed.
+            revealed: Vec<PropView<prop_type::Boolean>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn revealed_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::Descendants,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::SolutionRevealed]),
+                }
+            }
+        }
+
+        impl PropUpdater for NumSolutionsRevealed {
+            type PropType = prop_type::Integer;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let num_revealed = required_data
+                    .revealed
+                    .iter()
+                    .filter(|revealed| revealed.value)
+                    .count();
+                PropCalcResult::Calculated(
+                    usize_to_integer(num_revealed)
+                        .expect("Number of solutions must fit in an integer"),
+                )
+            }
         }
     }
 }
@@ -0,0 +1,31 @@
+use crate::components::prelude::*;
+use crate::general_prop::RenderedChildrenPassthroughProp;
+use crate::props::UpdaterObject;
+
+/// An `<else>` is the last block of a `<conditionalContent>`. Its content is shown when no `<case>` is shown.
+///
+/// The module is not named `else`, as that is a keyword.
+#[component(name = Else)]
+mod component {
+
+    enum Props {
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+}
+
+pub use component::Else;
+pub use component::ElseActions;
+pub use component::ElseAttributes;
+pub use component::ElseProps;
+
+impl PropGetUpdater for ElseProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            ElseProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(RenderedChildrenPassthroughProp::new()),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::components::prelude::*;
+use crate::general_prop::{BooleanProp, IndependentProp, NumberProp, StringProp};
+use crate::props::UpdaterObject;
+
+/// The `<embeddedApplet>` component embeds a third-party applet (e.g., GeoGebra or Desmos)
+/// and bridges values between the applet and the document.
+///
+/// - The `input` attribute is sent to the applet: whenever it changes, the renderer receives
+///   a new `setApplet` value and passes it on to the applet.
+/// - The applet reports changes with the `appletValueChanged` action, which updates `value`.
+///   Since `value` is a prop, other components can depend on it like any other value, e.g., `$applet.value`.
+#[component(
+    name = EmbeddedApplet,
+    extend_via_default_prop,
+    ref_transmutes_to = "number",
+    needs_pointer_events,
+    needs_keyboard,
+    print_unsupported,
+    ssr_unsupported
+)]
+mod component {
+
+    use super::*;
+
+    enum Props {
+        /// The most recent value reported by the applet, or `NaN` if the applet has not reported a value.
+        #[prop(
+            value_type = PropValueType::Number,
+            is_public,
+            profile = PropProfile::Number,
+            default,
+        )]
+        Value,
+
+        /// The value that the applet should be set to.
+        ///
+        /// The renderer passes this value to the applet whenever it changes.
+        #[prop(value_type = PropValueType::Number, is_public, for_render)]
+        SetApplet,
+
+        /// The URL of the applet.
+        #[prop(value_type = PropValueType::String, for_render)]
+        Src,
+
+        /// The kind of applet, e.g., `geogebra` or `desmos`, which determines how the renderer
+        /// communicates with it.
+        #[prop(value_type = PropValueType::String, for_render)]
+        AppletType,
+
+        /// Whether the `<embeddedApplet>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+    }
+
+    enum Attributes {
+        /// Whether the `<embeddedApplet>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The URL of the applet.
+        #[attribute(prop = StringProp, default = String::new())]
+        Src,
+        /// The kind of applet, e.g., `geogebra` or `desmos`.
+        #[attribute(prop = StringProp, default = String::new())]
+        AppletType,
+        /// The value sent to the applet.
+        #[attribute(prop = NumberProp, default = prop_type::Number::NAN)]
+        Input,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    #[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+    #[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+    #[serde(expecting = "`value` must be a number")]
+    pub struct AppletValueChangedActionArgs {
+        pub value: prop_type::Number,
+    }
+
+    enum Actions {
+        AppletValueChanged(ActionBody<AppletValueChangedActionArgs>),
+    }
+}
+
+pub use component::AppletValueChangedActionArgs;
+pub use component::EmbeddedApplet;
+pub use component::EmbeddedAppletActions;
+pub use component::EmbeddedAppletAttributes;
+pub use component::EmbeddedAppletProps;
+
+impl PropGetUpdater for EmbeddedAppletProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            EmbeddedAppletProps::Value => as_updater_object::<_, component::props::types::Value>(
+                IndependentProp::new(prop_type::Number::NAN),
+            ),
+            EmbeddedAppletProps::SetApplet => {
+                as_updater_object::<_, component::props::types::SetApplet>(
+                    component::attrs::Input::get_prop_updater(),
+                )
+            }
+            EmbeddedAppletProps::Src => as_updater_object::<_, component::props::types::Src>(
+                component::attrs::Src::get_prop_updater(),
+            ),
+            EmbeddedAppletProps::AppletType => {
+                as_updater_object::<_, component::props::types::AppletType>(
+                    component::attrs::AppletType::get_prop_updater(),
+                )
+            }
+            EmbeddedAppletProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+        }
+    }
+}
+
+impl ComponentOnAction for EmbeddedApplet {
+    fn on_action(
+        &self,
+        action: ActionsEnum,
+        _query_prop: ActionQueryProp,
+    ) -> Result<Vec<UpdateFromAction>, String> {
+        // The type of `action` should have already been verified, so an
+        // error here is a programming logic error, not an API error.
+        let action: EmbeddedAppletActions = action.try_into()?;
+
+        match action {
+            EmbeddedAppletActions::AppletValueChanged(ActionBody { args }) => {
+                Ok(vec![UpdateFromAction {
+                    local_prop_idx: EmbeddedAppletProps::Value.local_idx(),
+                    requested_value: args.value.into(),
+                }])
+            }
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::props::{ContentFilter, UpdaterObject};
+use crate::utils::usize_to_integer;
+
+use super::select::select_option;
+
+/// The `<experiment>` component renders one of its child components, its arms, chosen from the seed
+/// of the student viewing the document, e.g., to compare versions of content in a learning-engineering study.
+/// The assigned arm is saved with the state of the document, so a student stays in the same arm.
+#[component(name = Experiment)]
+mod component {
+
+    enum Props {
+        /// The index of the assigned arm among the child components of the `<experiment>`,
+        /// or `-1` if it has no child components.
+        #[prop(value_type = PropValueType::Integer, is_public)]
+        ArmIndex,
+
+        #[prop(
+            value_type = PropValueType::AnnotatedContentRefs,
+            profile = PropProfile::RenderedChildren
+        )]
+        RenderedChildren,
+    }
+}
+
+pub use component::Experiment;
+pub use component::ExperimentActions;
+pub use component::ExperimentAttributes;
+pub use component::ExperimentProps;
+
+impl PropGetUpdater for ExperimentProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            ExperimentProps::ArmIndex => as_updater_object::<_, component::props::types::ArmIndex>(
+                custom_props::ArmIndex::new(),
+            ),
+            ExperimentProps::RenderedChildren => {
+                as_updater_object::<_, component::props::types::RenderedChildren>(
+                    custom_props::RenderedChildren::new(),
+                )
+            }
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use arm_index::*;
+    mod arm_index {
+        use super::*;
+
+        /// The index of the arm that the `<experiment>` renders.
+        #[derive(Debug, Default)]
+        pub struct ArmIndex {}
+
+        impl ArmIndex {
+            pub fn new() -> Self {
+                ArmIndex {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Experiment)]
+        struct RequiredData {
+            /// The arm restored from saved state, if any.
+            assigned_arm: PropView<prop_type::Integer>,
+            self_ref: PropView<prop_type::ComponentRef>,
+            arms: PropView<prop_type::ContentRefs>,
+            student_seed: PropView<prop_type::Integer>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn assigned_arm_query() -> DataQuery {
+                DataQuery::State
+            }
+            fn self_ref_query() -> DataQuery {
+                DataQuery::SelfRef
+            }
+            fn arms_query() -> DataQuery {
+                DataQuery::ContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(ContentFilter::IsComponent),
+                }
+            }
+            fn student_seed_query() -> DataQuery {
+                DataQuery::StudentSeed
+            }
+        }
+
+        impl PropUpdater for ArmIndex {
+            type PropType = prop_type::Integer;
+
+            fn default(&self) -> Self::PropType {
+                -1
+            }
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let num_arms = required_data.arms.value.as_slice().len();
+                if num_arms == 0 {
+                    return PropCalcResult::Calculated(-1);
+                }
+
+                // Keep the arm from saved state as long as it is still an arm of the experiment.
+                let assigned_arm = &required_data.assigned_arm;
+                if !assigned_arm.came_from_default
+                    && usize::try_from(assigned_arm.value).is_ok_and(|arm| arm < num_arms)
+                {
+                    return PropCalcResult::Calculated(assigned_arm.value);
+                }
+
+                let component_idx = required_data
+                    .self_ref
+                    .value
+                    .expect("SelfRef must always be valid")
+                    .0;
+                let arm = select_option(
+                    // The seed was wrapped into a signed integer; wrap it back.
+                    required_data.student_seed.value as u64,
+                    component_idx,
+                    num_arms,
+                    &[],
+                );
+                PropCalcResult::Calculated(
+                    usize_to_integer(arm).expect("Number of arms must fit in an integer"),
+                )
+            }
+        }
+    }
+
+    pub use rendered_children::*;
+    mod rendered_children {
+        use super::*;
+
+        /// The children that this component renders: only the assigned arm.
+        #[derive(Debug, Default)]
+        pub struct RenderedChildren {}
+
+        impl RenderedChildren {
+            pub fn new() -> Self {
+                RenderedChildren {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Experiment)]
+        struct RequiredData {
+            arms: PropView<prop_type::AnnotatedContentRefs>,
+            arm_index: PropView<component::props::types::ArmIndex>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn arms_query() -> DataQuery {
+                DataQuery::AnnotatedContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(ContentFilter::IsComponent),
+                }
+            }
+            fn arm_index_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: ExperimentProps::ArmIndex.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for RenderedChildren {
+            type PropType = component::props::types::RenderedChildren;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let assigned = usize::try_from(required_data.arm_index.value)
+                    .ok()
+                    .and_then(|idx| required_data.arms.value.0.get(idx).cloned());
+                PropCalcResult::Calculated(Rc::new(assigned.into_iter().collect::<Vec<_>>().into()))
+            }
+        }
+    }
+}
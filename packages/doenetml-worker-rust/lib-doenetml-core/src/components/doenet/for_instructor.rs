@@ -0,0 +1,42 @@
+use crate::components::prelude::*;
+use crate::general_prop::{RenderedChildrenPassthroughProp, RoleHiddenProp};
+use crate::props::{Role, UpdaterObject};
+
+/// The `<forInstructor>` component renders its children only for instructors and graders,
+/// e.g., for notes on how to assess a problem. Its contents are never rendered for students,
+/// and actions from components inside it are rejected when the document is viewed by a student.
+#[component(name = ForInstructor)]
+mod component {
+
+    enum Props {
+        /// Whether the `<forInstructor>` should be hidden,
+        /// which is the case unless the document is viewed by an instructor or grader.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+}
+
+pub use component::ForInstructor;
+pub use component::ForInstructorActions;
+pub use component::ForInstructorAttributes;
+pub use component::ForInstructorProps;
+
+/// The roles for which the contents of a `<forInstructor>` are shown.
+pub const FOR_INSTRUCTOR_ROLES: &[Role] = &[Role::Instructor, Role::Grader];
+
+impl PropGetUpdater for ForInstructorProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            ForInstructorProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                RoleHiddenProp::new(FOR_INSTRUCTOR_ROLES),
+            ),
+            ForInstructorProps::RenderedChildren => {
+                as_updater_object::<_, component::props::types::RenderedChildren>(
+                    RenderedChildrenPassthroughProp::new(),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::components::prelude::*;
+use crate::general_prop::{BooleanProp, LatexProp, MathProp, StringProp};
+use crate::props::UpdaterObject;
+use crate::state::types::math_expr::{MathExpr, MathParser};
+
+/// The `<function>` component is a mathematical function of a single variable,
+/// given by a formula in its `formula` attribute or its children,
+/// e.g., `<function variable="t">t^2+1</function>`.
+#[component(name = Function)]
+mod component {
+
+    use super::*;
+
+    enum Props {
+        /// The formula of the function as a math-expression in the function's variable.
+        #[prop(
+            value_type = PropValueType::Math,
+            profile = PropProfile::Math,
+            is_public,
+            default,
+        )]
+        Formula,
+
+        /// The value of the `formula` attribute.
+        #[prop(value_type = PropValueType::Math)]
+        FormulaAttr,
+
+        /// The formula given by the children of the `<function>`.
+        #[prop(value_type = PropValueType::Math)]
+        FormulaFromChildren,
+
+        /// The variable of the function, e.g., `x`.
+        #[prop(value_type = PropValueType::String, is_public)]
+        Variable,
+
+        /// A representation of `formula` as a Latex string
+        #[prop(
+            value_type = PropValueType::String,
+            profile = PropProfile::String,
+            is_public,
+            for_render,
+        )]
+        Latex,
+
+        /// Whether the `<function>` should be hidden.
+        #[prop(
+            value_type = PropValueType::Boolean,
+            profile = PropProfile::Hidden,
+            is_public,
+        )]
+        Hidden,
+    }
+
+    enum Attributes {
+        /// The formula of the function, which takes precedence over any children.
+        #[attribute(prop = MathProp, default = MathExpr::default())]
+        Formula,
+
+        /// The variable of the function.
+        #[attribute(prop = StringProp, default = "x".to_string())]
+        Variable,
+
+        /// Whether the `<function>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+    }
+}
+
+pub use component::Function;
+pub use component::FunctionActions;
+pub use component::FunctionAttributes;
+pub use component::FunctionProps;
+use component::attrs;
+use component::props;
+
+impl PropGetUpdater for FunctionProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            FunctionProps::Formula => {
+                as_updater_object::<_, props::types::Formula>(custom_props::Formula::new())
+            }
+            FunctionProps::FormulaAttr => {
+                as_updater_object::<_, props::types::FormulaAttr>(attrs::Formula::get_prop_updater())
+            }
+            FunctionProps::FormulaFromChildren => {
+                as_updater_object::<_, props::types::FormulaFromChildren>(
+                    MathProp::new_from_children(
+                        MathExpr::default(),
+                        // TODO: specify parser via attribute once we implement enum attributes and props
+                        MathParser::Text,
+                        // TODO: specify function_symbols via attribute once we implement array attributes and props
+                        vec!["f".to_string(), "g".to_string()],
+                    ),
+                )
+            }
+            FunctionProps::Variable => {
+                as_updater_object::<_, props::types::Variable>(attrs::Variable::get_prop_updater())
+            }
+            FunctionProps::Latex => as_updater_object::<_, props::types::Latex>(LatexProp::new(
+                FunctionProps::Formula.local_idx(),
+            )),
+            FunctionProps::Hidden => {
+                as_updater_object::<_, props::types::Hidden>(attrs::Hide::get_prop_updater())
+            }
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use formula::*;
+    mod formula {
+        use super::*;
+
+        /// The formula of the `<function>`, taken from the `formula` attribute if it was specified
+        /// and otherwise from the children.
+        #[derive(Debug, Default)]
+        pub struct Formula {}
+
+        impl Formula {
+            pub fn new() -> Self {
+                Formula {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, IntoDataQueryResults)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Function)]
+        struct RequiredData {
+            formula_attr: PropView<prop_type::Math>,
+            formula_from_children: PropView<prop_type::Math>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn formula_attr_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: FunctionProps::FormulaAttr.local_idx().into(),
+                }
+            }
+            fn formula_from_children_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: FunctionProps::FormulaFromChildren.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for Formula {
+            type PropType = prop_type::Math;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+
+                // A `formula` attribute takes precedence over any children.
+                if !required_data.formula_attr.came_from_default {
+                    PropCalcResult::Calculated(required_data.formula_attr.value)
+                } else if !required_data.formula_from_children.came_from_default {
+                    PropCalcResult::Calculated(required_data.formula_from_children.value)
+                } else {
+                    PropCalcResult::FromDefault(required_data.formula_from_children.value)
+                }
+            }
+
+            fn invert(
+                &self,
+                data: DataQueryResults,
+                requested_value: Self::PropType,
+                _is_direct_change_from_action: bool,
+            ) -> Result<DataQueryResults, InvertError> {
+                let mut desired = RequiredData::try_new_desired(&data).unwrap();
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+
+                if !required_data.formula_attr.came_from_default {
+                    desired.formula_attr.change_to(requested_value);
+                } else {
+                    desired.formula_from_children.change_to(requested_value);
+                }
+
+                Ok(desired.into_data_query_results())
+            }
+        }
+    }
+}
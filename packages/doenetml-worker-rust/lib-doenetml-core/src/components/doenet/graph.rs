@@ -2,10 +2,12 @@ use crate::components::prelude::*;
 use crate::general_prop::RenderedChildrenPassthroughProp;
 use crate::props::UpdaterObject;
 
-#[component(name = Graph)]
+/// The `<graph>` component draws its graphical children, e.g., `<point>`s,
+/// in a shared coordinate system with axes from `xMin` to `xMax` and `yMin` to `yMax`.
+#[component(name = Graph, needs_pointer_events, ssr_unsupported)]
 mod component {
 
-    use crate::general_prop::{BooleanProp, NumberProp};
+    use crate::general_prop::{BooleanProp, NumberProp, StringProp};
 
     enum Props {
         /// Whether the `<graph>` should be hidden.
@@ -21,6 +23,22 @@ mod component {
         YMin,
         #[prop(value_type = PropValueType::Number, for_render, is_public)]
         YMax,
+        /// The width of the `<graph>` in pixels specified by the `width` attribute.
+        #[prop(value_type = PropValueType::Number)]
+        SpecifiedWidth,
+        /// The width of the `<graph>` in pixels: the specified width,
+        /// reduced if necessary so that the `<graph>` fits in the viewport.
+        #[prop(value_type = PropValueType::Number, for_render, is_public)]
+        Width,
+        /// The ratio of the width to the height of the `<graph>`.
+        #[prop(value_type = PropValueType::Number, for_render, is_public)]
+        AspectRatio,
+        /// The grid specified by the `grid` attribute.
+        #[prop(value_type = PropValueType::String)]
+        SpecifiedGrid,
+        /// The grid drawn behind the axes: `"none"`, `"medium"`, or `"dense"`.
+        #[prop(value_type = PropValueType::String, for_render, is_public)]
+        Grid,
     }
 
     enum Attributes {
@@ -35,6 +53,16 @@ mod component {
         YMin,
         #[attribute(prop = NumberProp, default = 10.0)]
         YMax,
+        /// The width of the `<graph>` in pixels.
+        #[attribute(prop = NumberProp, default = 288.0)]
+        Width,
+        /// The ratio of the width to the height of the `<graph>`.
+        #[attribute(prop = NumberProp, default = 1.0)]
+        AspectRatio,
+        /// The grid drawn behind the axes: `none`, `medium`, or `dense`.
+        /// A blank `grid` attribute, or `grid="true"`, gives a medium grid.
+        #[attribute(prop = StringProp, default = "none".to_string())]
+        Grid,
     }
 
     #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
@@ -42,7 +70,7 @@ mod component {
     #[cfg_attr(feature = "web", tsify(from_wasm_abi))]
     #[cfg_attr(feature = "web", serde(rename_all = "camelCase"))]
     #[serde(expecting = "`x_min`, `x_max`, `y_min`, and `y_max` must be numbers")]
-    pub struct GraphChangeBoundingBoxActionArgs {
+    pub struct GraphChangeAxisLimitsActionArgs {
         pub x_min: prop_type::Number,
         pub x_max: prop_type::Number,
         pub y_min: prop_type::Number,
@@ -50,13 +78,15 @@ mod component {
     }
 
     enum Actions {
-        ChangeBoundingBox(ActionBody<GraphChangeBoundingBoxActionArgs>),
+        /// Change the axis limits, e.g., when a user pans or zooms the `<graph>`.
+        ChangeAxisLimits(ActionBody<GraphChangeAxisLimitsActionArgs>),
     }
 }
 
 pub use component::Graph;
 pub use component::GraphActions;
 pub use component::GraphAttributes;
+pub use component::GraphChangeAxisLimitsActionArgs;
 pub use component::GraphProps;
 
 impl PropGetUpdater for GraphProps {
@@ -81,6 +111,25 @@ impl PropGetUpdater for GraphProps {
             GraphProps::YMax => as_updater_object::<_, component::props::types::YMax>(
                 component::attrs::YMax::get_prop_updater(),
             ),
+            GraphProps::SpecifiedWidth => as_updater_object::<
+                _,
+                component::props::types::SpecifiedWidth,
+            >(component::attrs::Width::get_prop_updater()),
+            GraphProps::Width => {
+                as_updater_object::<_, component::props::types::Width>(custom_props::Width::new())
+            }
+            GraphProps::AspectRatio => {
+                as_updater_object::<_, component::props::types::AspectRatio>(
+                    component::attrs::AspectRatio::get_prop_updater(),
+                )
+            }
+            GraphProps::SpecifiedGrid => as_updater_object::<
+                _,
+                component::props::types::SpecifiedGrid,
+            >(component::attrs::Grid::get_prop_updater()),
+            GraphProps::Grid => {
+                as_updater_object::<_, component::props::types::Grid>(custom_props::Grid::new())
+            }
         }
     }
 }
@@ -96,7 +145,7 @@ impl ComponentOnAction for Graph {
         let action: GraphActions = action.try_into()?;
 
         match action {
-            GraphActions::ChangeBoundingBox(ActionBody { args }) => Ok(vec![
+            GraphActions::ChangeAxisLimits(ActionBody { args }) => Ok(vec![
                 UpdateFromAction {
                     local_prop_idx: GraphProps::XMin.local_idx(),
                     requested_value: PropValue::Number(args.x_min),
@@ -117,3 +166,122 @@ impl ComponentOnAction for Graph {
         }
     }
 }
+
+mod custom_props {
+    use super::*;
+    use crate::core::EnvironmentProperty;
+
+    pub use width::*;
+    mod width {
+        use super::*;
+
+        /// The width of the graph, limited to the width of the viewport when it is known.
+        #[derive(Debug, Default)]
+        pub struct Width {}
+
+        impl Width {
+            pub fn new() -> Self {
+                Width {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Graph)]
+        struct RequiredData {
+            specified_width: PropView<prop_type::Number>,
+            viewport_width: Option<PropView<prop_type::Number>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn specified_width_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: GraphProps::SpecifiedWidth.local_idx().into(),
+                }
+            }
+            fn viewport_width_query() -> DataQuery {
+                DataQuery::Environment(EnvironmentProperty::ViewportWidth)
+            }
+        }
+
+        impl PropUpdater for Width {
+            type PropType = prop_type::Number;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let specified_width = required_data.specified_width.value;
+                PropCalcResult::Calculated(match required_data.viewport_width {
+                    Some(viewport_width) => specified_width.min(viewport_width.value),
+                    None => specified_width,
+                })
+            }
+        }
+    }
+
+    pub use grid::*;
+    mod grid {
+        use super::*;
+
+        /// The grid drawn behind the axes, normalized from the `grid` attribute.
+        #[derive(Debug, Default)]
+        pub struct Grid {}
+
+        impl Grid {
+            pub fn new() -> Self {
+                Grid {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Graph)]
+        struct RequiredData {
+            specified_grid: PropView<prop_type::String>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn specified_grid_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: GraphProps::SpecifiedGrid.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for Grid {
+            type PropType = prop_type::String;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let grid = match required_data
+                    .specified_grid
+                    .value
+                    .trim()
+                    .to_lowercase()
+                    .as_str()
+                {
+                    // A blank attribute, as in `<graph grid>`, turns the grid on.
+                    "" | "true" | "medium" => "medium",
+                    "dense" => "dense",
+                    _ => "none",
+                };
+                if required_data.specified_grid.came_from_default {
+                    PropCalcResult::FromDefault(grid.to_string().into())
+                } else {
+                    PropCalcResult::Calculated(grid.to_string().into())
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::general_prop::not_hidden_filter;
+use crate::props::UpdaterObject;
+
+/// The `<hint>` component shows a title that can be clicked to reveal its content, e.g.,
+/// `<hint><p>Try factoring.</p></hint>`. Unlike a `<solution>`, a hint can be closed again.
+/// The content is rendered only while the `<hint>` is open.
+#[component(name = Hint)]
+mod component {
+
+    use crate::general_prop::{BooleanProp, StringProp};
+
+    enum Props {
+        /// Whether the `<hint>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        /// The title that is shown whether or not the `<hint>` is open.
+        #[prop(value_type = PropValueType::String, is_public, for_render)]
+        Title,
+        /// Whether the content of the `<hint>` is shown.
+        #[prop(value_type = PropValueType::Boolean, is_public, for_render)]
+        Open,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// Whether the `<hint>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The title that is shown whether or not the `<hint>` is open.
+        #[attribute(prop = StringProp, default = "Hint".to_string())]
+        Title,
+        /// Whether the `<hint>` is open initially.
+        #[attribute(prop = BooleanProp, default = false)]
+        Open,
+    }
+
+    enum Actions {
+        RevealHint,
+        CloseHint,
+    }
+}
+
+pub use component::Hint;
+pub use component::HintActions;
+pub use component::HintAttributes;
+pub use component::HintProps;
+
+impl PropGetUpdater for HintProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            HintProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            HintProps::Title => as_updater_object::<_, component::props::types::Title>(
+                component::attrs::Title::get_prop_updater(),
+            ),
+            HintProps::Open => as_updater_object::<_, component::props::types::Open>(
+                component::attrs::Open::get_prop_updater(),
+            ),
+            HintProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(custom_props::RenderedChildren::new()),
+        }
+    }
+}
+
+impl ComponentOnAction for Hint {
+    fn on_action(
+        &self,
+        action: ActionsEnum,
+        _query_prop: ActionQueryProp,
+    ) -> Result<Vec<UpdateFromAction>, String> {
+        // The type of `action` should have already been verified, so an
+        // error here is a programming logic error, not an API error.
+        let action: HintActions = action.try_into()?;
+
+        let open = match action {
+            HintActions::RevealHint => true,
+            HintActions::CloseHint => false,
+        };
+        Ok(vec![UpdateFromAction {
+            local_prop_idx: HintProps::Open.local_idx(),
+            requested_value: open.into(),
+        }])
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use rendered_children::*;
+    mod rendered_children {
+        use super::*;
+
+        /// The children that this component renders: all of them while it is open, and none otherwise.
+        #[derive(Debug, Default)]
+        pub struct RenderedChildren {}
+
+        impl RenderedChildren {
+            pub fn new() -> Self {
+                RenderedChildren {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Hint)]
+        struct RequiredData {
+            open: PropView<component::props::types::Open>,
+            children: PropView<prop_type::AnnotatedContentRefs>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn open_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: HintProps::Open.local_idx().into(),
+                }
+            }
+            fn children_query() -> DataQuery {
+                DataQuery::AnnotatedContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(not_hidden_filter()),
+                }
+            }
+        }
+
+        impl PropUpdater for RenderedChildren {
+            type PropType = component::props::types::RenderedChildren;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                if required_data.open.value {
+                    PropCalcResult::Calculated(required_data.children.value)
+                } else {
+                    PropCalcResult::Calculated(Rc::new(Vec::new().into()))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::components::prelude::*;
+use crate::general_prop::{BooleanProp, StringProp};
+use crate::props::UpdaterObject;
+
+/// The `<htmlBlock>` component displays raw HTML, e.g., from legacy content.
+///
+/// The HTML is given as the text of the `<htmlBlock>`, typically in a CDATA section
+/// so that it is not parsed as DoenetML, as in `<htmlBlock><![CDATA[<b>Hi</b>]]></htmlBlock>`.
+/// It is sanitized by core, keeping only an allow-list of formatting tags and attributes,
+/// and rendered as an opaque string. A warning lists any tags and attributes that were removed.
+#[component(name = HtmlBlock)]
+mod component {
+
+    use super::*;
+
+    enum Props {
+        /// The sanitized HTML that is displayed.
+        #[prop(value_type = PropValueType::String, is_public, for_render)]
+        Html,
+
+        /// The HTML as written by the author, before sanitization.
+        #[prop(value_type = PropValueType::String)]
+        RawHtml,
+
+        /// What sanitization removed from `raw_html`, in the order it first appears:
+        /// the tags, written as `<script>`, and the names of the attributes, e.g., `onclick`.
+        #[prop(value_type = PropValueType::PropVec, is_public)]
+        RemovedHtml,
+
+        /// Whether the `<htmlBlock>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+    }
+
+    enum Attributes {
+        /// Whether the `<htmlBlock>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+    }
+}
+
+pub use component::HtmlBlock;
+pub use component::HtmlBlockActions;
+pub use component::HtmlBlockAttributes;
+pub use component::HtmlBlockProps;
+
+impl PropGetUpdater for HtmlBlockProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            HtmlBlockProps::Html => as_updater_object::<_, component::props::types::Html>(
+                custom_props::SanitizedHtml::new(),
+            ),
+            HtmlBlockProps::RawHtml => as_updater_object::<_, component::props::types::RawHtml>(
+                StringProp::new_from_children(String::new()),
+            ),
+            HtmlBlockProps::RemovedHtml => as_updater_object::<
+                _,
+                component::props::types::RemovedHtml,
+            >(custom_props::RemovedHtml::new()),
+            HtmlBlockProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use sanitized_html::*;
+
+    mod sanitized_html {
+
+        use std::rc::Rc;
+
+        use crate::utils::sanitize_html;
+
+        use super::*;
+
+        /// The `raw_html` of the `<htmlBlock>` with disallowed tags and attributes removed.
+        #[derive(Debug, Default)]
+        pub struct SanitizedHtml {}
+
+        impl SanitizedHtml {
+            pub fn new() -> Self {
+                SanitizedHtml {}
+            }
+        }
+
+        /// The tags and attributes that sanitization removed from the `raw_html` of the `<htmlBlock>`.
+        #[derive(Debug, Default)]
+        pub struct RemovedHtml {}
+
+        impl RemovedHtml {
+            pub fn new() -> Self {
+                RemovedHtml {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, IntoDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        struct RequiredData {
+            raw_html: PropView<prop_type::String>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn raw_html_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: HtmlBlockProps::RawHtml.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for SanitizedHtml {
+            type PropType = prop_type::String;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+
+                PropCalcResult::Calculated(sanitize_html(&required_data.raw_html.value).html.into())
+            }
+        }
+
+        impl PropUpdater for RemovedHtml {
+            type PropType = prop_type::PropVec;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let sanitized = sanitize_html(&required_data.raw_html.value);
+
+                PropCalcResult::Calculated(Rc::new(
+                    sanitized
+                        .removed_tags
+                        .iter()
+                        .map(|tag| PropValue::from(format!("<{tag}>")))
+                        .chain(
+                            sanitized
+                                .removed_attributes
+                                .into_iter()
+                                .map(PropValue::from),
+                        )
+                        .collect(),
+                ))
+            }
+        }
+    }
+}
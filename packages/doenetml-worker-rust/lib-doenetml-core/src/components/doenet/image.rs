@@ -0,0 +1,171 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::general_prop::{BooleanProp, StringProp};
+use crate::props::UpdaterObject;
+
+/// The `<image>` component displays the image at the URL given by its `source` attribute.
+/// The `description` attribute gives the text that describes the image to assistive technology,
+/// e.g., `<image source="cat.png" width="50%" description="A cat asleep on a keyboard" />`.
+#[component(name = Image)]
+mod component {
+
+    use super::*;
+
+    enum Props {
+        /// Whether the `<image>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+
+        /// The URL of the image.
+        #[prop(value_type = PropValueType::String, is_public, for_render)]
+        Source,
+
+        /// The `width` given by the attribute.
+        #[prop(value_type = PropValueType::String)]
+        SpecifiedWidth,
+
+        /// The width of the image as a CSS length, e.g., `300px` or `50%`,
+        /// or the empty string if the width is determined by the image itself.
+        #[prop(value_type = PropValueType::String, is_public, for_render)]
+        Width,
+
+        /// The `height` given by the attribute.
+        #[prop(value_type = PropValueType::String)]
+        SpecifiedHeight,
+
+        /// The height of the image as a CSS length, e.g., `200px` or `50%`,
+        /// or the empty string if the height is determined by the image itself.
+        #[prop(value_type = PropValueType::String, is_public, for_render)]
+        Height,
+
+        /// The text that describes the image to assistive technology.
+        #[prop(value_type = PropValueType::String, is_public, for_render)]
+        Description,
+    }
+
+    enum Attributes {
+        /// Whether the `<image>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The URL of the image.
+        #[attribute(prop = StringProp, default = String::new())]
+        Source,
+        /// The width of the image in pixels, e.g., `300` or `300px`, or as a percentage of the available width, e.g., `50%`.
+        #[attribute(prop = StringProp, default = String::new())]
+        Width,
+        /// The height of the image in pixels, e.g., `200` or `200px`, or as a percentage, e.g., `50%`.
+        #[attribute(prop = StringProp, default = String::new())]
+        Height,
+        /// The text that describes the image to assistive technology.
+        #[attribute(prop = StringProp, default = String::new())]
+        Description,
+    }
+}
+
+pub use component::Image;
+pub use component::ImageActions;
+pub use component::ImageAttributes;
+pub use component::ImageProps;
+
+impl PropGetUpdater for ImageProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            ImageProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            ImageProps::Source => as_updater_object::<_, component::props::types::Source>(
+                component::attrs::Source::get_prop_updater(),
+            ),
+            ImageProps::SpecifiedWidth => as_updater_object::<
+                _,
+                component::props::types::SpecifiedWidth,
+            >(component::attrs::Width::get_prop_updater()),
+            ImageProps::Width => as_updater_object::<_, component::props::types::Width>(
+                custom_props::Size::new(ImageProps::SpecifiedWidth.local_idx()),
+            ),
+            ImageProps::SpecifiedHeight => {
+                as_updater_object::<_, component::props::types::SpecifiedHeight>(
+                    component::attrs::Height::get_prop_updater(),
+                )
+            }
+            ImageProps::Height => as_updater_object::<_, component::props::types::Height>(
+                custom_props::Size::new(ImageProps::SpecifiedHeight.local_idx()),
+            ),
+            ImageProps::Description => {
+                as_updater_object::<_, component::props::types::Description>(
+                    component::attrs::Description::get_prop_updater(),
+                )
+            }
+        }
+    }
+}
+
+/// Parse a size attribute into a CSS length. A number of pixels, e.g., `300` or `300px`, gives `300px`,
+/// and a percentage, e.g., `50%`, gives `50%`. Any other value, e.g., `auto`, gives `None`.
+pub fn parse_size(size: &str) -> Option<String> {
+    let size = size.trim();
+    let (number, unit) = match size.strip_suffix('%') {
+        Some(number) => (number, "%"),
+        None => (size.strip_suffix("px").unwrap_or(size), "px"),
+    };
+    let number = number
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite() && *number >= 0.0)?;
+    Some(format!("{number}{unit}"))
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use size::*;
+    mod size {
+        use super::*;
+
+        /// The width or height of the image, from the size given by the attribute.
+        #[derive(Debug)]
+        pub struct Size {
+            specified_local_prop_idx: LocalPropIdx,
+        }
+
+        impl Size {
+            pub fn new(specified_local_prop_idx: LocalPropIdx) -> Self {
+                Size {
+                    specified_local_prop_idx,
+                }
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries, pass_data = LocalPropIdx)]
+        struct RequiredData {
+            specified: PropView<prop_type::String>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn specified_query(specified_local_prop_idx: LocalPropIdx) -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: specified_local_prop_idx.into(),
+                }
+            }
+        }
+
+        impl PropUpdater for Size {
+            type PropType = prop_type::String;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::data_queries_vec(self.specified_local_prop_idx)
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(Rc::new(
+                    parse_size(&required_data.specified.value).unwrap_or_default(),
+                ))
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::components::prelude::*;
+use crate::general_prop::{RenderModeHiddenProp, RenderedChildrenPassthroughProp};
+use crate::props::{RenderMode, UpdaterObject};
+
+/// The `<interactiveOnly>` component renders its children only when the document is rendered interactively,
+/// i.e., not for print. Authors can use it for content that makes sense only when readers can interact with it,
+/// providing a static alternative in a `<printFallback>`.
+#[component(name = InteractiveOnly)]
+mod component {
+
+    enum Props {
+        /// Whether the `<interactiveOnly>` should be hidden,
+        /// which is the case when the document is rendered for print.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+}
+
+pub use component::InteractiveOnly;
+pub use component::InteractiveOnlyActions;
+pub use component::InteractiveOnlyAttributes;
+pub use component::InteractiveOnlyProps;
+
+impl PropGetUpdater for InteractiveOnlyProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            InteractiveOnlyProps::Hidden => {
+                as_updater_object::<_, component::props::types::Hidden>(RenderModeHiddenProp::new(
+                    RenderMode::Interactive,
+                ))
+            }
+            InteractiveOnlyProps::RenderedChildren => {
+                as_updater_object::<_, component::props::types::RenderedChildren>(
+                    RenderedChildrenPassthroughProp::new(),
+                )
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::components::prelude::*;
 use crate::general_prop::PropAlias;
 use crate::general_prop::RenderedChildrenPassthroughProp;
 use crate::props::UpdaterObject;
+use crate::utils::usize_to_integer;
 
 /// The `<li>` component is for marking items in a list (ordered or unordered).
 #[component(name = Li)]
@@ -295,7 +296,10 @@ mod custom_props {
                     .iter()
                     .position(|sibling| sibling == &self_ref)
                     .expect("Self must be in the list of siblings");
-                PropCalcResult::Calculated(serial_number as i64)
+                PropCalcResult::Calculated(
+                    usize_to_integer(serial_number)
+                        .expect("Number of siblings must fit in an integer"),
+                )
             }
         }
     }
@@ -0,0 +1,330 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::general_prop::{BooleanProp, NumericalPointsProp, PointListProp};
+use crate::props::UpdaterObject;
+use crate::state::types::math_expr::{JsMathExpr, MathExpr};
+
+/// The `<line>` component is the line through two points,
+/// e.g., `<line through="$P $Q"/>` where `P` and `Q` are `<point>`s.
+///
+/// Note: a line cannot yet be defined by an equation, as parsing the equation requires `math-expressions`.
+#[component(name = Line, needs_pointer_events)]
+mod component {
+
+    use super::*;
+
+    enum Props {
+        /// Whether the `<line>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        /// The coordinates of the points the line passes through, as math vectors.
+        #[prop(value_type = PropValueType::PropVec, is_public)]
+        Points,
+        /// The numerical coordinates `[x, y]` of the points the line passes through.
+        /// Coordinates that are not numerical are `NaN`.
+        #[prop(value_type = PropValueType::PropVec, for_render(in_graph))]
+        NumericalPoints,
+        /// The slope of the line, which is infinite for a vertical line
+        /// and `NaN` if the line is not determined by its points.
+        #[prop(value_type = PropValueType::Number, is_public)]
+        Slope,
+        /// The y-coordinate where the line crosses the y-axis,
+        /// which is `NaN` for a vertical line or if the line is not determined by its points.
+        #[prop(value_type = PropValueType::Number, is_public)]
+        Intercept,
+        /// The equation of the line, e.g., `y = 2x + 1` or, for a vertical line, `x = 3`.
+        #[prop(value_type = PropValueType::Math, is_public)]
+        Equation,
+    }
+
+    enum Attributes {
+        /// Whether the `<line>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The points the line passes through, e.g., `through="$P $Q"`.
+        #[attribute(prop = PointListProp, default = Rc::default())]
+        Through,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    #[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+    #[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+    #[serde(expecting = "`point1coords` and `point2coords` must be arrays of two numbers")]
+    pub struct LineMoveActionArgs {
+        #[serde(rename = "point1coords")]
+        pub point1_coords: [prop_type::Number; 2],
+        #[serde(rename = "point2coords")]
+        pub point2_coords: [prop_type::Number; 2],
+    }
+
+    enum Actions {
+        /// Move the line so that it passes through `point1coords` and `point2coords`,
+        /// which moves the points it is defined through.
+        MoveLine(ActionBody<LineMoveActionArgs>),
+    }
+}
+
+pub use component::Line;
+pub use component::LineActions;
+pub use component::LineAttributes;
+pub use component::LineMoveActionArgs;
+pub use component::LineProps;
+
+impl PropGetUpdater for LineProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            LineProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            LineProps::Points => as_updater_object::<_, component::props::types::Points>(
+                component::attrs::Through::get_prop_updater(),
+            ),
+            LineProps::NumericalPoints => {
+                as_updater_object::<_, component::props::types::NumericalPoints>(
+                    NumericalPointsProp::new(LineProps::Points.local_idx()),
+                )
+            }
+            LineProps::Slope => {
+                as_updater_object::<_, component::props::types::Slope>(custom_props::Slope::new())
+            }
+            LineProps::Intercept => as_updater_object::<_, component::props::types::Intercept>(
+                custom_props::Intercept::new(),
+            ),
+            LineProps::Equation => as_updater_object::<_, component::props::types::Equation>(
+                custom_props::Equation::new(),
+            ),
+        }
+    }
+}
+
+impl ComponentOnAction for Line {
+    fn on_action(
+        &self,
+        action: ActionsEnum,
+        _query_prop: ActionQueryProp,
+    ) -> Result<Vec<UpdateFromAction>, String> {
+        // The type of `action` should have already been verified, so an
+        // error here is a programming logic error, not an API error.
+        let action: LineActions = action.try_into()?;
+
+        match action {
+            LineActions::MoveLine(ActionBody { args }) => {
+                let points = [args.point1_coords, args.point2_coords]
+                    .into_iter()
+                    .map(|[x, y]| {
+                        PropValue::Math(Rc::new(MathExpr::new_vector(&[x.into(), y.into()])))
+                    })
+                    .collect::<Vec<_>>();
+
+                Ok(vec![UpdateFromAction {
+                    local_prop_idx: LineProps::Points.local_idx(),
+                    requested_value: points.into(),
+                }])
+            }
+        }
+    }
+}
+
+/// The numerical `[x, y]` coordinates of each point in `numerical_points`,
+/// skipping any that are malformed.
+fn numerical_coords(numerical_points: &prop_type::PropVec) -> Vec<[prop_type::Number; 2]> {
+    numerical_points
+        .iter()
+        .filter_map(|point| match point {
+            PropValue::PropVec(coords) => match coords[..] {
+                [PropValue::Number(x), PropValue::Number(y)] => Some([x, y]),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// The slope and y-intercept of the line through the first two of `points`.
+///
+/// The slope is infinite for a vertical line. Both are `NaN` if the line is not determined.
+fn slope_and_intercept(
+    points: &[[prop_type::Number; 2]],
+) -> (prop_type::Number, prop_type::Number) {
+    match points {
+        [[x1, y1], [x2, y2], ..] if x1 == x2 && y1 != y2 => {
+            (prop_type::Number::INFINITY, prop_type::Number::NAN)
+        }
+        [[x1, y1], [x2, y2], ..] if x1 != x2 => {
+            let slope = (y2 - y1) / (x2 - x1);
+            (slope, y1 - slope * x1)
+        }
+        _ => (prop_type::Number::NAN, prop_type::Number::NAN),
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    /// The query for the `numerical_points` prop of the `<line>`.
+    fn numerical_points_query() -> DataQuery {
+        DataQuery::Prop {
+            source: PropSource::Me,
+            prop_specifier: LineProps::NumericalPoints.local_idx().into(),
+        }
+    }
+
+    pub use slope::*;
+    mod slope {
+        use super::*;
+
+        /// The slope of the `<line>`.
+        #[derive(Debug, Default)]
+        pub struct Slope {}
+
+        impl Slope {
+            pub fn new() -> Self {
+                Slope {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Line)]
+        struct RequiredData {
+            numerical_points: PropView<prop_type::PropVec>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn numerical_points_query() -> DataQuery {
+                super::numerical_points_query()
+            }
+        }
+
+        impl PropUpdater for Slope {
+            type PropType = prop_type::Number;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let points = numerical_coords(&required_data.numerical_points.value);
+                PropCalcResult::Calculated(slope_and_intercept(&points).0)
+            }
+        }
+    }
+
+    pub use intercept::*;
+    mod intercept {
+        use super::*;
+
+        /// The y-intercept of the `<line>`.
+        #[derive(Debug, Default)]
+        pub struct Intercept {}
+
+        impl Intercept {
+            pub fn new() -> Self {
+                Intercept {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Line)]
+        struct RequiredData {
+            numerical_points: PropView<prop_type::PropVec>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn numerical_points_query() -> DataQuery {
+                super::numerical_points_query()
+            }
+        }
+
+        impl PropUpdater for Intercept {
+            type PropType = prop_type::Number;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let points = numerical_coords(&required_data.numerical_points.value);
+                PropCalcResult::Calculated(slope_and_intercept(&points).1)
+            }
+        }
+    }
+
+    pub use equation::*;
+    mod equation {
+        use super::*;
+
+        /// The equation of the `<line>`.
+        #[derive(Debug, Default)]
+        pub struct Equation {}
+
+        impl Equation {
+            pub fn new() -> Self {
+                Equation {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Line)]
+        struct RequiredData {
+            numerical_points: PropView<prop_type::PropVec>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn numerical_points_query() -> DataQuery {
+                super::numerical_points_query()
+            }
+        }
+
+        impl PropUpdater for Equation {
+            type PropType = prop_type::Math;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let points = numerical_coords(&required_data.numerical_points.value);
+
+                // The equation is assembled as a `math-expressions` tree,
+                // as simplifying `y = m x + b` would require `math-expressions`.
+                let math = |value: prop_type::Number| MathExpr::from(value).math_object.0;
+                let equation = match slope_and_intercept(&points) {
+                    (slope, _) if slope.is_infinite() => {
+                        format!(r#"["=","x",{}]"#, math(points[0][0]))
+                    }
+                    (slope, intercept) if slope.is_finite() && intercept.is_finite() => {
+                        let linear_term = if slope == 0.0 {
+                            None
+                        } else if slope == 1.0 {
+                            Some(r#""x""#.to_string())
+                        } else {
+                            Some(format!(r#"["*",{},"x"]"#, math(slope)))
+                        };
+                        match linear_term {
+                            None => format!(r#"["=","y",{}]"#, math(intercept)),
+                            Some(term) if intercept == 0.0 => format!(r#"["=","y",{term}]"#),
+                            Some(term) => {
+                                format!(r#"["=","y",["+",{term},{}]]"#, math(intercept))
+                            }
+                        }
+                    }
+                    _ => return PropCalcResult::Calculated(Rc::new(MathExpr::default())),
+                };
+
+                PropCalcResult::Calculated(Rc::new(MathExpr {
+                    math_object: JsMathExpr(equation),
+                }))
+            }
+        }
+    }
+}
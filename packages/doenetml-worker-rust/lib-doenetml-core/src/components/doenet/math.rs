@@ -1,20 +1,34 @@
 use crate::components::prelude::*;
 use crate::general_prop::BooleanProp;
+use crate::general_prop::EnumAttributeValue;
 use crate::general_prop::LatexProp;
 use crate::general_prop::MathProp;
+use crate::general_prop::MathToTextProp;
+use crate::general_prop::StringProp;
 use crate::props::UpdaterObject;
 use crate::state::types::math_expr::MathExpr;
 use crate::state::types::math_expr::MathParser;
+use crate::state::types::math_expr::MathSimplify;
+use crate::state::types::math_expr::NormalizeParams;
 
 /// The `<math>` component processes its content with a computer algebra system
 /// and displays the result as Latex to be formatted by MathJax
+///
+/// The `simplify` and `expand` attributes normalize the value, e.g.,
+/// `<math simplify expand>(x+1)(x+2)</math>` has the value `x^2+3x+2`.
 #[component(name = Math, extend_via_default_prop)]
 mod component {
 
     use super::*;
 
     enum Props {
-        /// The value of the `<math>` component as a math-expression.
+        /// The value of the `<math>` component from its children, before it is normalized
+        /// according to `simplify` and `expand`.
+        #[prop(value_type = PropValueType::Math)]
+        UnnormalizedValue,
+
+        /// The value of the `<math>` component as a math-expression,
+        /// normalized according to `simplify` and `expand`.
         #[prop(
             value_type = PropValueType::Math,
             profile = PropProfile::Math,
@@ -32,6 +46,22 @@ mod component {
         )]
         Latex,
 
+        /// A representation of `value` as a text string, e.g., `x^2 + 1`
+        #[prop(value_type = PropValueType::String, is_public, for_render)]
+        Text,
+
+        /// The `simplify` given by the attribute.
+        #[prop(value_type = PropValueType::String)]
+        SpecifiedSimplify,
+
+        /// How much `value` is simplified: `none`, `numbersPreserveOrder`, `numbers`, or `full`.
+        #[prop(value_type = PropValueType::String, is_public)]
+        Simplify,
+
+        /// Whether multiplication is expanded over addition and subtraction in `value`.
+        #[prop(value_type = PropValueType::Boolean, is_public)]
+        Expand,
+
         /// If true, then split multi-characters symbols that don't contain numbers into the product of their characters, e.g., "xy" and "x*y" are interpreted in the same way.
         #[prop(
             value_type = PropValueType::Boolean,
@@ -58,6 +88,15 @@ mod component {
     }
 
     enum Attributes {
+        /// How much the `<math>` is simplified: `none`, `numbersPreserveOrder`, `numbers`, or `full`.
+        /// The attribute without a value, i.e., `<math simplify>`, means `full`.
+        #[attribute(prop = StringProp, default = String::new())]
+        Simplify,
+
+        /// Whether the `<math>` should expand multiplication over addition and subtraction.
+        #[attribute(prop = BooleanProp, default = false)]
+        Expand,
+
         /// Whether the `<math>` should split multi-character symbols into the product of the characters.
         #[attribute(prop = BooleanProp, default = true)]
         SplitSymbols,
@@ -82,18 +121,37 @@ use component::props;
 impl PropGetUpdater for MathProps {
     fn get_updater(&self) -> UpdaterObject {
         match self {
+            MathProps::UnnormalizedValue => {
+                as_updater_object::<_, props::types::UnnormalizedValue>(
+                    MathProp::new_from_children(
+                        MathExpr::default(),
+                        // TODO: specify parser via attribute once we implement enum attributes and props
+                        MathParser::Text,
+                        // TODO: specify function_symbols via attribute once we implement array attributes and props
+                        vec!["f".to_string(), "g".to_string()],
+                    ),
+                )
+            }
             MathProps::Value => {
-                as_updater_object::<_, props::types::Value>(MathProp::new_from_children(
-                    MathExpr::default(),
-                    // TODO: specify parser via attribute once we implement enum attributes and props
-                    MathParser::Text,
-                    // TODO: specify function_symbols via attribute once we implement array attributes and props
-                    vec!["f".to_string(), "g".to_string()],
-                ))
+                as_updater_object::<_, props::types::Value>(custom_props::NormalizedValue::new())
             }
             MathProps::Latex => as_updater_object::<_, props::types::Latex>(LatexProp::new(
                 MathProps::Value.local_idx(),
             )),
+            MathProps::Text => as_updater_object::<_, props::types::Text>(MathToTextProp::new(
+                MathProps::Value.local_idx(),
+            )),
+            MathProps::SpecifiedSimplify => {
+                as_updater_object::<_, props::types::SpecifiedSimplify>(
+                    attrs::Simplify::get_prop_updater(),
+                )
+            }
+            MathProps::Simplify => {
+                as_updater_object::<_, props::types::Simplify>(custom_props::Simplify::new())
+            }
+            MathProps::Expand => {
+                as_updater_object::<_, props::types::Expand>(attrs::Expand::get_prop_updater())
+            }
             MathProps::SplitSymbols => as_updater_object::<_, props::types::SplitSymbols>(
                 attrs::SplitSymbols::get_prop_updater(),
             ),
@@ -106,3 +164,174 @@ impl PropGetUpdater for MathProps {
         }
     }
 }
+
+impl EnumAttributeValue for MathSimplify {
+    const VALID_VALUES: &'static [&'static str] =
+        &["none", "numbersPreserveOrder", "numbers", "full"];
+
+    /// Parse the `simplify` attribute. The attribute without a value means `full`,
+    /// and `true` and `false` mean `full` and `none`.
+    fn parse_attribute_value(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.is_empty()
+            || value.eq_ignore_ascii_case("true")
+            || value.eq_ignore_ascii_case("full")
+        {
+            Some(MathSimplify::Full)
+        } else if value.eq_ignore_ascii_case("false") || value.eq_ignore_ascii_case("none") {
+            Some(MathSimplify::None)
+        } else if value.eq_ignore_ascii_case("numbers") {
+            Some(MathSimplify::Numbers)
+        } else if value.eq_ignore_ascii_case("numbersPreserveOrder") {
+            Some(MathSimplify::NumbersPreserveOrder)
+        } else {
+            None
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use simplify::*;
+    mod simplify {
+        use super::*;
+
+        /// How much the value is simplified, `none` unless a valid `simplify` is given.
+        #[derive(Debug, Default)]
+        pub struct Simplify {}
+
+        impl Simplify {
+            pub fn new() -> Self {
+                Simplify {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Math)]
+        struct RequiredData {
+            specified_simplify: PropView<props::types::SpecifiedSimplify>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn specified_simplify_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: MathProps::SpecifiedSimplify.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for Simplify {
+            type PropType = prop_type::String;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let specified = required_data.specified_simplify;
+                let simplify = if specified.came_from_default {
+                    MathSimplify::None
+                } else {
+                    MathSimplify::parse_attribute_value(&specified.value)
+                        .unwrap_or(MathSimplify::None)
+                };
+                PropCalcResult::Calculated(std::rc::Rc::new(simplify.to_string()))
+            }
+        }
+    }
+
+    pub use normalized_value::*;
+    mod normalized_value {
+        use super::*;
+
+        /// The value from the children, normalized according to `simplify` and `expand`.
+        /// If neither normalizes the value, the value from the children is used unchanged.
+        #[derive(Debug, Default)]
+        pub struct NormalizedValue {}
+
+        impl NormalizedValue {
+            pub fn new() -> Self {
+                NormalizedValue {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, IntoDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Math)]
+        struct RequiredData {
+            unnormalized_value: PropView<props::types::UnnormalizedValue>,
+            simplify: PropView<props::types::Simplify>,
+            expand: PropView<props::types::Expand>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn unnormalized_value_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: MathProps::UnnormalizedValue.local_idx().into(),
+                }
+            }
+            fn simplify_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: MathProps::Simplify.local_idx().into(),
+                }
+            }
+            fn expand_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: MathProps::Expand.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for NormalizedValue {
+            type PropType = prop_type::Math;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let unnormalized_value = required_data.unnormalized_value;
+                let simplify = MathSimplify::parse_attribute_value(&required_data.simplify.value)
+                    .unwrap_or(MathSimplify::None);
+                let expand = required_data.expand.value;
+
+                if matches!(simplify, MathSimplify::None) && !expand {
+                    return if unnormalized_value.came_from_default {
+                        PropCalcResult::FromDefault(unnormalized_value.value)
+                    } else {
+                        PropCalcResult::Calculated(unnormalized_value.value)
+                    };
+                }
+                PropCalcResult::Calculated(std::rc::Rc::new(unnormalized_value.value.normalize(
+                    NormalizeParams {
+                        simplify,
+                        expand,
+                        ..Default::default()
+                    },
+                )))
+            }
+
+            /// The requested value is given to the children as is, so it is normalized when the value is recalculated.
+            fn invert(
+                &self,
+                data: DataQueryResults,
+                requested_value: Self::PropType,
+                _is_direct_change_from_action: bool,
+            ) -> Result<DataQueryResults, InvertError> {
+                let mut desired = RequiredData::try_new_desired(&data).unwrap();
+                desired.unnormalized_value.change_to(requested_value);
+                Ok(desired.into_data_query_results())
+            }
+        }
+    }
+}
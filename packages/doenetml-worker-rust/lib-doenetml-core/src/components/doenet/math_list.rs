@@ -0,0 +1,52 @@
+use crate::components::doenet::math::Math;
+use crate::components::prelude::*;
+use crate::general_prop::{ChildCountProp, RenderedChildrenPassthroughProp};
+use crate::props::UpdaterObject;
+
+/// The `<mathList>` component renders a list of maths. Its text children
+/// are split into items, each of which becomes a `<math>`. Commas and whitespace inside parentheses
+/// do not separate items, e.g., `<mathList>(1, 2) f(x)</mathList>` has two items.
+#[component(name = MathList, string_children_become = Math)]
+mod component {
+
+    use crate::general_prop::BooleanProp;
+
+    enum Props {
+        /// Whether the `<mathList>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+        /// The number of items in the `<mathList>`.
+        #[prop(value_type = PropValueType::Integer, is_public)]
+        Count,
+    }
+
+    enum Attributes {
+        /// Whether the `<mathList>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+    }
+}
+
+pub use component::MathList;
+pub use component::MathListActions;
+pub use component::MathListAttributes;
+pub use component::MathListProps;
+
+impl PropGetUpdater for MathListProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            MathListProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            MathListProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(RenderedChildrenPassthroughProp::new()),
+            MathListProps::Count => as_updater_object::<_, component::props::types::Count>(
+                ChildCountProp::new(Math::NAME),
+            ),
+        }
+    }
+}
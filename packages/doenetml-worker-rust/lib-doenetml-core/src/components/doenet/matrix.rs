@@ -0,0 +1,254 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::dast::flat_dast::FlatPathPart;
+use crate::props::UpdaterObject;
+use crate::state::types::number_matrix::NumberMatrix;
+use crate::utils::usize_to_integer;
+
+/// The `<matrix>` component is a rectangular array of numbers. Each `<numberList>` child is a row, e.g.,
+/// `<matrix><numberList>1 2</numberList><numberList>3 4</numberList></matrix>`.
+/// Rows shorter than the longest row are padded with missing entries.
+///
+/// An entry can be referenced by its row and column, starting from 1, e.g., `$m.entry[2][1]` is `3` above.
+#[component(name = Matrix)]
+mod component {
+
+    use crate::general_prop::BooleanProp;
+
+    enum Props {
+        /// Whether the `<matrix>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        /// The entries of the `<matrix>`.
+        #[prop(value_type = PropValueType::NumberMatrix)]
+        Value,
+        /// The number of rows.
+        #[prop(value_type = PropValueType::Integer, is_public)]
+        NumRows,
+        /// The number of columns, i.e., the number of entries in the longest row.
+        #[prop(value_type = PropValueType::Integer, is_public)]
+        NumColumns,
+        /// A representation of the `<matrix>` as a Latex string.
+        #[prop(value_type = PropValueType::String, profile = PropProfile::String, is_public, for_render)]
+        Latex,
+    }
+
+    enum Attributes {
+        /// Whether the `<matrix>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+    }
+}
+
+pub use component::Matrix;
+pub use component::MatrixActions;
+pub use component::MatrixAttributes;
+pub use component::MatrixProps;
+
+impl PropGetUpdater for MatrixProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            MatrixProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            MatrixProps::Value => {
+                as_updater_object::<_, component::props::types::Value>(custom_props::Value::new())
+            }
+            MatrixProps::NumRows => as_updater_object::<_, component::props::types::NumRows>(
+                custom_props::Size::num_rows(),
+            ),
+            MatrixProps::NumColumns => as_updater_object::<_, component::props::types::NumColumns>(
+                custom_props::Size::num_columns(),
+            ),
+            MatrixProps::Latex => {
+                as_updater_object::<_, component::props::types::Latex>(custom_props::Latex::new())
+            }
+        }
+    }
+}
+
+/// The name used to reference an entry of a `<matrix>`, as in `$m.entry[2][3]`.
+const ENTRY_PATH_NAME: &str = "entry";
+
+/// The row and column, starting from 1, of the entry referenced by `path`, e.g., `(2, 3)` for `entry[2][3]`.
+/// Returns `None` unless `path` references an entry with literal indices.
+pub fn matrix_entry_indices(path: &[FlatPathPart]) -> Option<(usize, usize)> {
+    let [path_part] = path else {
+        return None;
+    };
+    if !path_part.name.eq_ignore_ascii_case(ENTRY_PATH_NAME) {
+        return None;
+    }
+    match path_part.index.as_slice() {
+        [row, column] => Some((row.as_literal_index()?, column.as_literal_index()?)),
+        _ => None,
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use value::*;
+    mod value {
+        use super::*;
+
+        /// The entries of the `<matrix>`, read from the values of its `<numberList>` children.
+        #[derive(Debug, Default)]
+        pub struct Value {}
+
+        impl Value {
+            pub fn new() -> Self {
+                Value {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Matrix)]
+        struct RequiredData {
+            rows: Vec<PropView<prop_type::PropVec>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn rows_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::Children,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::NumberListValues]),
+                }
+            }
+        }
+
+        impl PropUpdater for Value {
+            type PropType = component::props::types::Value;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let rows = required_data
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        row.value
+                            .iter()
+                            .map(|entry| match entry {
+                                PropValue::Number(number) => *number,
+                                _ => f64::NAN,
+                            })
+                            .collect()
+                    })
+                    .collect();
+                PropCalcResult::Calculated(Rc::new(NumberMatrix::new(rows)))
+            }
+        }
+    }
+
+    pub use size::*;
+    mod size {
+        use super::*;
+
+        /// The number of rows or columns of the `<matrix>`.
+        #[derive(Debug)]
+        pub struct Size {
+            size: fn(&NumberMatrix) -> usize,
+        }
+
+        impl Size {
+            /// The number of rows of the `<matrix>`.
+            pub fn num_rows() -> Self {
+                Size {
+                    size: NumberMatrix::num_rows,
+                }
+            }
+
+            /// The number of columns of the `<matrix>`.
+            pub fn num_columns() -> Self {
+                Size {
+                    size: NumberMatrix::num_columns,
+                }
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Matrix)]
+        struct RequiredData {
+            value: PropView<component::props::types::Value>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn value_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: MatrixProps::Value.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for Size {
+            type PropType = prop_type::Integer;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(
+                    usize_to_integer((self.size)(&required_data.value.value))
+                        .expect("Size of a matrix must fit in an integer"),
+                )
+            }
+        }
+    }
+
+    pub use latex::*;
+    mod latex {
+        use super::*;
+
+        /// The `<matrix>` as a Latex `bmatrix`.
+        #[derive(Debug, Default)]
+        pub struct Latex {}
+
+        impl Latex {
+            pub fn new() -> Self {
+                Latex {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Matrix)]
+        struct RequiredData {
+            value: PropView<component::props::types::Value>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn value_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: MatrixProps::Value.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for Latex {
+            type PropType = component::props::types::Latex;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(Rc::new(required_data.value.value.to_latex()))
+            }
+        }
+    }
+}
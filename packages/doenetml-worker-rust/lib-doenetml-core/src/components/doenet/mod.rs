@@ -1,18 +1,58 @@
 //! DoenetML components, for example `<p />`, `<mathInput />`, etc.. These are the building blocks of the DoenetML document.
 
 pub mod _fragment;
+pub mod accordion;
+pub mod answer;
+pub mod award;
 pub mod boolean;
+pub mod case;
+pub mod cell;
+pub mod choice;
+pub mod choice_input;
+pub mod circle;
+pub mod collect;
+pub mod conditional_content;
 pub mod division;
 pub mod document;
+pub mod else_case;
+pub mod embedded_applet;
+pub mod experiment;
+pub mod for_instructor;
+pub mod function;
 pub mod graph;
+pub mod hint;
+pub mod html_block;
+pub mod image;
+pub mod interactive_only;
 pub mod li;
+pub mod line;
 pub mod math;
+pub mod math_list;
+pub mod matrix;
 pub mod number;
+pub mod number_list;
 pub mod ol;
 pub mod p;
 pub mod point;
+pub mod polygon;
+pub mod print_fallback;
+pub mod progress_bar;
+pub mod row;
+pub mod select;
+pub mod select_from_sequence;
+pub mod sequence;
+pub mod side_by_side;
+pub mod slider;
+pub mod solution;
+pub mod spacer;
+pub mod stack;
+pub mod tab;
+pub mod table;
+pub mod tabs;
 pub mod text;
 pub mod text_input;
+pub mod text_list;
 pub mod title;
 pub mod ul;
+pub mod video;
 pub mod xref;
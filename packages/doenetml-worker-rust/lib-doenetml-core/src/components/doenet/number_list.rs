@@ -0,0 +1,115 @@
+use std::rc::Rc;
+
+use crate::components::doenet::number::Number;
+use crate::components::prelude::*;
+use crate::general_prop::{ChildCountProp, RenderedChildrenPassthroughProp};
+use crate::props::UpdaterObject;
+
+/// The `<numberList>` component renders a list of numbers. Its text children
+/// are split into items, each of which becomes a `<number>`.
+#[component(name = NumberList, string_children_become = Number)]
+mod component {
+
+    use crate::general_prop::BooleanProp;
+
+    enum Props {
+        /// Whether the `<numberList>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+        /// The values of the `<number>`s in the list.
+        #[prop(value_type = PropValueType::PropVec, profile = PropProfile::NumberListValues)]
+        Values,
+        /// The number of items in the `<numberList>`.
+        #[prop(value_type = PropValueType::Integer, is_public)]
+        Count,
+    }
+
+    enum Attributes {
+        /// Whether the `<numberList>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+    }
+}
+
+pub use component::NumberList;
+pub use component::NumberListActions;
+pub use component::NumberListAttributes;
+pub use component::NumberListProps;
+
+impl PropGetUpdater for NumberListProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            NumberListProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            NumberListProps::RenderedChildren => {
+                as_updater_object::<_, component::props::types::RenderedChildren>(
+                    RenderedChildrenPassthroughProp::new(),
+                )
+            }
+            NumberListProps::Values => {
+                as_updater_object::<_, component::props::types::Values>(custom_props::Values::new())
+            }
+            NumberListProps::Count => as_updater_object::<_, component::props::types::Count>(
+                ChildCountProp::new(Number::NAME),
+            ),
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use values::*;
+    mod values {
+        use super::*;
+
+        /// The values of the `<number>` children of the `<numberList>`.
+        #[derive(Debug, Default)]
+        pub struct Values {}
+
+        impl Values {
+            pub fn new() -> Self {
+                Values {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(NumberList)]
+        struct RequiredData {
+            numbers: Vec<PropView<prop_type::Number>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn numbers_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::Children,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::Number]),
+                }
+            }
+        }
+
+        impl PropUpdater for Values {
+            type PropType = component::props::types::Values;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(Rc::new(
+                    required_data
+                        .numbers
+                        .iter()
+                        .map(|number| PropValue::Number(number.value))
+                        .collect(),
+                ))
+            }
+        }
+    }
+}
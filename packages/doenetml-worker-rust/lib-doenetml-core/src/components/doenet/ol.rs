@@ -173,7 +173,7 @@ mod custom_props {
                     // then we should start counting at 0 instead.
                     match required_data.marker.value {
                         prop_type::ListMarker::Decimal { start } => {
-                            PropCalcResult::Calculated(start as i64)
+                            PropCalcResult::Calculated(start.into())
                         }
                         _ => PropCalcResult::FromDefault(1),
                     }
@@ -4,7 +4,7 @@ use crate::components::prelude::*;
 use crate::general_prop::{BooleanProp, LatexProp, MathProp};
 use crate::props::UpdaterObject;
 
-#[component(name = Point)]
+#[component(name = Point, needs_pointer_events)]
 mod component {
 
     use super::*;
@@ -20,7 +20,7 @@ mod component {
             is_public, for_render(in_graph))]
         Y,
         #[prop(value_type = PropValueType::Math,
-            profile = PropProfile::Math,
+            profile = PropProfile::Point,
             is_public)]
         Coords,
         #[prop(value_type = PropValueType::String,
@@ -0,0 +1,143 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::general_prop::{BooleanProp, NumericalPointsProp, PointListProp};
+use crate::props::UpdaterObject;
+use crate::state::types::math_expr::MathExpr;
+
+/// The `<polygon>` component, given by its vertices,
+/// e.g., `<polygon vertices="$A $B $C"/>` where `A`, `B`, and `C` are `<point>`s.
+#[component(name = Polygon, needs_pointer_events)]
+mod component {
+
+    use super::*;
+
+    enum Props {
+        /// Whether the `<polygon>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        /// The coordinates of the vertices of the polygon, as math vectors.
+        #[prop(value_type = PropValueType::PropVec, is_public)]
+        Vertices,
+        /// The numerical `[x, y]` coordinates of the vertices of the polygon.
+        /// Coordinates that are not numerical are `NaN`.
+        #[prop(value_type = PropValueType::PropVec, for_render(in_graph))]
+        NumericalVertices,
+    }
+
+    enum Attributes {
+        /// Whether the `<polygon>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The vertices of the polygon, e.g., `vertices="$A $B $C"`.
+        #[attribute(prop = PointListProp, default = Rc::default())]
+        Vertices,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    #[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+    #[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+    #[serde(rename_all = "camelCase")]
+    #[serde(expecting = "`vertexCoords` must be an array of pairs of numbers")]
+    pub struct PolygonMoveActionArgs {
+        pub vertex_coords: Vec<[prop_type::Number; 2]>,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    #[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+    #[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+    #[serde(rename_all = "camelCase")]
+    #[serde(expecting = "`vertexIndex` must be an integer and `coords` must be a pair of numbers")]
+    pub struct PolygonMoveVertexActionArgs {
+        pub vertex_index: usize,
+        pub coords: [prop_type::Number; 2],
+    }
+
+    enum Actions {
+        /// Move every vertex of the polygon, e.g., when the whole polygon is dragged.
+        MovePolygon(ActionBody<PolygonMoveActionArgs>),
+        /// Move the single vertex with index `vertexIndex`, starting from 0, to `coords`.
+        MovePolygonVertex(ActionBody<PolygonMoveVertexActionArgs>),
+    }
+}
+
+pub use component::Polygon;
+pub use component::PolygonActions;
+pub use component::PolygonAttributes;
+pub use component::PolygonMoveActionArgs;
+pub use component::PolygonMoveVertexActionArgs;
+pub use component::PolygonProps;
+
+impl PropGetUpdater for PolygonProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            PolygonProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            PolygonProps::Vertices => as_updater_object::<_, component::props::types::Vertices>(
+                component::attrs::Vertices::get_prop_updater(),
+            ),
+            PolygonProps::NumericalVertices => {
+                as_updater_object::<_, component::props::types::NumericalVertices>(
+                    NumericalPointsProp::new(PolygonProps::Vertices.local_idx()),
+                )
+            }
+        }
+    }
+}
+
+/// The coordinates `[x, y]` as a math vector.
+fn vertex_from_coords([x, y]: [prop_type::Number; 2]) -> PropValue {
+    PropValue::Math(Rc::new(MathExpr::new_vector(&[x.into(), y.into()])))
+}
+
+impl ComponentOnAction for Polygon {
+    fn on_action(
+        &self,
+        action: ActionsEnum,
+        query_prop: ActionQueryProp,
+    ) -> Result<Vec<UpdateFromAction>, String> {
+        // The type of `action` should have already been verified, so an
+        // error here is a programming logic error, not an API error.
+        let action: PolygonActions = action.try_into()?;
+
+        let vertices: prop_type::PropVec = query_prop
+            .get_local_prop(PolygonProps::Vertices.local_idx())
+            .value
+            .try_into()
+            .unwrap();
+
+        let new_vertices = match action {
+            PolygonActions::MovePolygon(ActionBody { args }) => {
+                if args.vertex_coords.len() != vertices.len() {
+                    return Err(format!(
+                        "`vertexCoords` must have {} vertices, found {}",
+                        vertices.len(),
+                        args.vertex_coords.len()
+                    ));
+                }
+                args.vertex_coords
+                    .into_iter()
+                    .map(vertex_from_coords)
+                    .collect::<Vec<_>>()
+            }
+            PolygonActions::MovePolygonVertex(ActionBody { args }) => {
+                if args.vertex_index >= vertices.len() {
+                    return Err(format!(
+                        "`vertexIndex` {} is out of range for a polygon with {} vertices",
+                        args.vertex_index,
+                        vertices.len()
+                    ));
+                }
+                let mut new_vertices = (*vertices).clone();
+                new_vertices[args.vertex_index] = vertex_from_coords(args.coords);
+                new_vertices
+            }
+        };
+
+        Ok(vec![UpdateFromAction {
+            local_prop_idx: PolygonProps::Vertices.local_idx(),
+            requested_value: new_vertices.into(),
+        }])
+    }
+}
@@ -0,0 +1,39 @@
+use crate::components::prelude::*;
+use crate::general_prop::{RenderModeHiddenProp, RenderedChildrenPassthroughProp};
+use crate::props::{RenderMode, UpdaterObject};
+
+/// The `<printFallback>` component renders its children only when the document is rendered for print.
+/// Authors can use it to provide a static alternative to interactive content, which is placed in an
+/// `<interactiveOnly>`.
+#[component(name = PrintFallback)]
+mod component {
+
+    enum Props {
+        /// Whether the `<printFallback>` should be hidden,
+        /// which is the case unless the document is rendered for print.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+}
+
+pub use component::PrintFallback;
+pub use component::PrintFallbackActions;
+pub use component::PrintFallbackAttributes;
+pub use component::PrintFallbackProps;
+
+impl PropGetUpdater for PrintFallbackProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            PrintFallbackProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                RenderModeHiddenProp::new(RenderMode::Print),
+            ),
+            PrintFallbackProps::RenderedChildren => {
+                as_updater_object::<_, component::props::types::RenderedChildren>(
+                    RenderedChildrenPassthroughProp::new(),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::components::prelude::*;
+use crate::props::UpdaterObject;
+
+/// The `<progressBar>` component shows how much of the document has been completed,
+/// i.e., the fraction of its interactive components that have been answered.
+#[component(name = ProgressBar)]
+mod component {
+
+    use crate::general_prop::BooleanProp;
+
+    enum Props {
+        /// Whether the `<progressBar>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        /// The fraction of the document that has been completed, from 0 to 1.
+        #[prop(value_type = PropValueType::Number, is_public, for_render)]
+        Fraction,
+    }
+
+    enum Attributes {
+        /// Whether the `<progressBar>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+    }
+}
+
+pub use component::ProgressBar;
+pub use component::ProgressBarActions;
+pub use component::ProgressBarAttributes;
+pub use component::ProgressBarProps;
+
+impl PropGetUpdater for ProgressBarProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            ProgressBarProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            ProgressBarProps::Fraction => {
+                as_updater_object::<_, component::props::types::Fraction>(
+                    custom_props::Fraction::new(),
+                )
+            }
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use fraction::*;
+    mod fraction {
+        use super::*;
+
+        /// The completion fraction of the document containing the `<progressBar>`.
+        #[derive(Debug, Default)]
+        pub struct Fraction {}
+
+        impl Fraction {
+            pub fn new() -> Self {
+                Fraction {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(ProgressBar)]
+        struct RequiredData {
+            completion_fraction: Option<PropView<prop_type::Number>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn completion_fraction_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::NearestMatchingAncestor,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::CompletionFraction]),
+                }
+            }
+        }
+
+        impl PropUpdater for Fraction {
+            type PropType = prop_type::Number;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                match required_data.completion_fraction {
+                    Some(completion_fraction) => {
+                        PropCalcResult::Calculated(completion_fraction.value)
+                    }
+                    None => PropCalcResult::Calculated(0.0),
+                }
+            }
+        }
+    }
+}
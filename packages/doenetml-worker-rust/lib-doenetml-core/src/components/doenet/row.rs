@@ -0,0 +1,115 @@
+use std::rc::Rc;
+
+use crate::components::doenet::cell::Cell;
+use crate::components::prelude::*;
+use crate::general_prop::RenderedChildrenPassthroughProp;
+use crate::props::{ContentFilter, UpdaterObject};
+use crate::utils::usize_to_integer;
+
+/// The `<row>` component is a row of `<cell>`s in a `<table>`.
+#[component(name = Row)]
+mod component {
+
+    use crate::general_prop::{BooleanProp, StringProp};
+
+    enum Props {
+        /// Whether the `<row>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+        /// The number of `<cell>` children.
+        #[prop(value_type = PropValueType::Integer, is_public, profile = PropProfile::RowNumCells)]
+        NumCells,
+        /// The alignment of the cells of the `<row>` given by the `halign` attribute.
+        #[prop(value_type = PropValueType::String, profile = PropProfile::RowHalign)]
+        Halign,
+    }
+
+    enum Attributes {
+        /// Whether the `<row>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The horizontal alignment of all the cells of the `<row>`: `left`, `center`, `right`, or `justify`.
+        #[attribute(prop = StringProp, default = String::new())]
+        Halign,
+    }
+}
+
+pub use component::Row;
+pub use component::RowActions;
+pub use component::RowAttributes;
+pub use component::RowProps;
+
+impl PropGetUpdater for RowProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            RowProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            RowProps::RenderedChildren => {
+                as_updater_object::<_, component::props::types::RenderedChildren>(
+                    RenderedChildrenPassthroughProp::new_components_only(),
+                )
+            }
+            RowProps::NumCells => as_updater_object::<_, component::props::types::NumCells>(
+                custom_props::NumCells::new(),
+            ),
+            RowProps::Halign => as_updater_object::<_, component::props::types::Halign>(
+                component::attrs::Halign::get_prop_updater(),
+            ),
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use num_cells::*;
+    mod num_cells {
+        use super::*;
+
+        /// The number of `<cell>` children of the `<row>`.
+        #[derive(Debug, Default)]
+        pub struct NumCells {}
+
+        impl NumCells {
+            pub fn new() -> Self {
+                NumCells {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Row)]
+        struct RequiredData {
+            cells: PropView<prop_type::ContentRefs>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn cells_query() -> DataQuery {
+                DataQuery::ContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(ContentFilter::IsType(Cell::NAME)),
+                }
+            }
+        }
+
+        impl PropUpdater for NumCells {
+            type PropType = component::props::types::NumCells;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(
+                    usize_to_integer(required_data.cells.value.as_slice().len())
+                        .expect("Number of cells must fit in an integer"),
+                )
+            }
+        }
+    }
+}
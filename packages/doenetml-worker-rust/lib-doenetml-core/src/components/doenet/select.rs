@@ -0,0 +1,337 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::props::{ContentFilter, UpdaterObject};
+use crate::utils::{SeededRng, usize_to_integer};
+
+/// The `<select>` component renders `numToSelect` of its child components (one by default),
+/// chosen from the variant seed of the document. Options selected on a student's previous attempts
+/// (passed in the flags of the document) are avoided until every option has been seen.
+#[component(name = Select)]
+mod component {
+
+    use crate::general_prop::NumberProp;
+
+    enum Props {
+        /// The indices of the selected options among the child components of the `<select>`,
+        /// in the order they were selected.
+        #[prop(value_type = PropValueType::PropVec, is_public)]
+        SelectedIndices,
+
+        /// The index of the first selected option among the child components of the `<select>`,
+        /// or `-1` if nothing is selected.
+        #[prop(value_type = PropValueType::Integer, is_public)]
+        SelectedIndex,
+
+        /// The number of options to select.
+        #[prop(value_type = PropValueType::Number, is_public)]
+        NumToSelect,
+
+        #[prop(
+            value_type = PropValueType::AnnotatedContentRefs,
+            profile = PropProfile::RenderedChildren
+        )]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// The number of different options to select.
+        #[attribute(prop = NumberProp, default = 1.0)]
+        NumToSelect,
+    }
+}
+
+pub use component::Select;
+pub use component::SelectActions;
+pub use component::SelectAttributes;
+pub use component::SelectProps;
+
+impl PropGetUpdater for SelectProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            SelectProps::SelectedIndices => as_updater_object::<
+                _,
+                component::props::types::SelectedIndices,
+            >(custom_props::SelectedIndices::new()),
+            SelectProps::SelectedIndex => as_updater_object::<
+                _,
+                component::props::types::SelectedIndex,
+            >(custom_props::SelectedIndex::new()),
+            SelectProps::NumToSelect => {
+                as_updater_object::<_, component::props::types::NumToSelect>(
+                    component::attrs::NumToSelect::get_prop_updater(),
+                )
+            }
+            SelectProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(custom_props::RenderedChildren::new()),
+        }
+    }
+}
+
+/// Choose one of `num_options` options for the component `component_idx`, as for `select_options`.
+/// `num_options` must be positive.
+pub fn select_option(
+    variant_seed: u64,
+    component_idx: ComponentIdx,
+    num_options: usize,
+    seen_options: &[usize],
+) -> usize {
+    select_options(variant_seed, component_idx, num_options, 1, seen_options)[0]
+}
+
+/// Choose `num_to_select` different options of `num_options` for the component `component_idx`,
+/// preferring the options that appear least often in `seen_options`. Among equally seen options,
+/// the choice is determined by `variant_seed`, so the same seed and history always give the same selection.
+/// If `num_to_select` is larger than `num_options`, every option is chosen.
+pub fn select_options(
+    variant_seed: u64,
+    component_idx: ComponentIdx,
+    num_options: usize,
+    num_to_select: usize,
+    seen_options: &[usize],
+) -> Vec<usize> {
+    let mut times_seen = vec![0usize; num_options];
+    for &option in seen_options {
+        if let Some(count) = times_seen.get_mut(option) {
+            *count += 1;
+        }
+    }
+    let mut levels = times_seen.clone();
+    levels.sort_unstable();
+    levels.dedup();
+
+    // The generator is seeded from the component index so that each `<select>` makes an independent choice.
+    let mut rng = SeededRng::new(variant_seed, component_idx);
+    let mut selected = Vec::new();
+    for level in levels {
+        if selected.len() >= num_to_select {
+            break;
+        }
+        let candidates = (0..num_options)
+            .filter(|&option| times_seen[option] == level)
+            .collect::<Vec<_>>();
+        selected.extend(
+            rng.choose_indices(candidates.len(), num_to_select - selected.len())
+                .into_iter()
+                .map(|idx| candidates[idx]),
+        );
+    }
+    selected
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use selected_indices::*;
+    mod selected_indices {
+        use super::*;
+
+        /// The indices of the options that the `<select>` renders.
+        #[derive(Debug, Default)]
+        pub struct SelectedIndices {}
+
+        impl SelectedIndices {
+            pub fn new() -> Self {
+                SelectedIndices {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Select)]
+        struct RequiredData {
+            self_ref: PropView<prop_type::ComponentRef>,
+            options: PropView<prop_type::ContentRefs>,
+            num_to_select: PropView<prop_type::Number>,
+            variant_seed: PropView<prop_type::Integer>,
+            seen_options: Vec<PropView<prop_type::Integer>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn self_ref_query() -> DataQuery {
+                DataQuery::SelfRef
+            }
+            fn options_query() -> DataQuery {
+                DataQuery::ContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(ContentFilter::IsComponent),
+                }
+            }
+            fn num_to_select_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SelectProps::NumToSelect.local_idx().into(),
+                }
+            }
+            fn variant_seed_query() -> DataQuery {
+                DataQuery::VariantSeed
+            }
+            fn seen_options_query() -> DataQuery {
+                DataQuery::SeenSelections
+            }
+        }
+
+        impl PropUpdater for SelectedIndices {
+            type PropType = prop_type::PropVec;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let num_options = required_data.options.value.as_slice().len();
+                let num_to_select = required_data.num_to_select.value;
+                let num_to_select = if num_to_select.is_finite() && num_to_select > 0.0 {
+                    num_to_select.round() as usize
+                } else {
+                    0
+                };
+                let component_idx = required_data
+                    .self_ref
+                    .value
+                    .expect("SelfRef must always be valid")
+                    .0;
+                let seen_options = required_data
+                    .seen_options
+                    .iter()
+                    .filter_map(|option| usize::try_from(option.value).ok())
+                    .collect::<Vec<_>>();
+
+                let selected = select_options(
+                    // The seed was wrapped into a signed integer; wrap it back.
+                    required_data.variant_seed.value as u64,
+                    component_idx,
+                    num_options,
+                    num_to_select,
+                    &seen_options,
+                );
+                PropCalcResult::Calculated(Rc::new(
+                    selected
+                        .into_iter()
+                        .map(|idx| {
+                            PropValue::Integer(
+                                usize_to_integer(idx)
+                                    .expect("Number of options must fit in an integer"),
+                            )
+                        })
+                        .collect(),
+                ))
+            }
+        }
+    }
+
+    pub use selected_index::*;
+    mod selected_index {
+        use super::*;
+
+        /// The index of the first option that the `<select>` renders.
+        #[derive(Debug, Default)]
+        pub struct SelectedIndex {}
+
+        impl SelectedIndex {
+            pub fn new() -> Self {
+                SelectedIndex {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Select)]
+        struct RequiredData {
+            selected_indices: PropView<component::props::types::SelectedIndices>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn selected_indices_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SelectProps::SelectedIndices.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for SelectedIndex {
+            type PropType = prop_type::Integer;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let selected = match required_data.selected_indices.value.first() {
+                    Some(PropValue::Integer(idx)) => *idx,
+                    _ => -1,
+                };
+                PropCalcResult::Calculated(selected)
+            }
+        }
+    }
+
+    pub use rendered_children::*;
+    mod rendered_children {
+        use super::*;
+
+        /// The children that this component renders: only the selected options, in the order they were selected.
+        #[derive(Debug, Default)]
+        pub struct RenderedChildren {}
+
+        impl RenderedChildren {
+            pub fn new() -> Self {
+                RenderedChildren {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Select)]
+        struct RequiredData {
+            options: PropView<prop_type::AnnotatedContentRefs>,
+            selected_indices: PropView<component::props::types::SelectedIndices>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn options_query() -> DataQuery {
+                DataQuery::AnnotatedContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(ContentFilter::IsComponent),
+                }
+            }
+            fn selected_indices_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SelectProps::SelectedIndices.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for RenderedChildren {
+            type PropType = component::props::types::RenderedChildren;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let selected = required_data
+                    .selected_indices
+                    .value
+                    .iter()
+                    .filter_map(|idx| match idx {
+                        PropValue::Integer(idx) => usize::try_from(*idx).ok(),
+                        _ => None,
+                    })
+                    .filter_map(|idx| required_data.options.value.0.get(idx).cloned())
+                    .collect::<Vec<_>>();
+                PropCalcResult::Calculated(Rc::new(selected.into()))
+            }
+        }
+    }
+}
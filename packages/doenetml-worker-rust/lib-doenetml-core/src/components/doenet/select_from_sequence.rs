@@ -0,0 +1,306 @@
+use std::rc::Rc;
+
+use crate::components::doenet::sequence::sequence_members;
+use crate::components::prelude::*;
+use crate::general_prop::{BooleanProp, NumberProp};
+use crate::props::UpdaterObject;
+use crate::utils::SeededRng;
+
+/// The `<selectFromSequence>` component selects `numToSelect` different members of the sequence
+/// given by its `from`, `to`, `step`, and `length` attributes (as for `<sequence>`),
+/// e.g., `<selectFromSequence from="1" to="10" numToSelect="2"/>`.
+/// The selection is determined by the variant seed of the document, so the same seed gives the same selection.
+#[component(name = SelectFromSequence)]
+mod component {
+
+    use super::*;
+
+    enum Props {
+        /// The selected members, in the order they were selected.
+        #[prop(value_type = PropValueType::PropVec, is_public)]
+        SelectedValues,
+
+        /// The selected members, separated by commas.
+        #[prop(
+            value_type = PropValueType::String,
+            profile = PropProfile::String,
+            is_public,
+            for_render,
+        )]
+        Text,
+
+        /// The first member of the sequence.
+        #[prop(value_type = PropValueType::Number)]
+        From,
+
+        /// The last member of the sequence, unless `length` is specified.
+        #[prop(value_type = PropValueType::Number)]
+        To,
+
+        /// The difference between consecutive members of the sequence.
+        #[prop(value_type = PropValueType::Number)]
+        Step,
+
+        /// The number of members of the sequence.
+        #[prop(value_type = PropValueType::Number)]
+        Length,
+
+        /// The number of members to select.
+        #[prop(value_type = PropValueType::Number, is_public)]
+        NumToSelect,
+
+        /// Whether the `<selectFromSequence>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+    }
+
+    enum Attributes {
+        /// The first member of the sequence.
+        #[attribute(prop = NumberProp, default = 1.0)]
+        From,
+        /// The last member of the sequence, unless `length` is specified.
+        #[attribute(prop = NumberProp, default = prop_type::Number::NAN)]
+        To,
+        /// The difference between consecutive members of the sequence.
+        #[attribute(prop = NumberProp, default = 1.0)]
+        Step,
+        /// The number of members of the sequence, which takes precedence over `to`.
+        #[attribute(prop = NumberProp, default = prop_type::Number::NAN)]
+        Length,
+        /// The number of different members to select.
+        #[attribute(prop = NumberProp, default = 1.0)]
+        NumToSelect,
+        /// Whether the `<selectFromSequence>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+    }
+}
+
+pub use component::SelectFromSequence;
+pub use component::SelectFromSequenceActions;
+pub use component::SelectFromSequenceAttributes;
+pub use component::SelectFromSequenceProps;
+
+impl PropGetUpdater for SelectFromSequenceProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            SelectFromSequenceProps::SelectedValues => {
+                as_updater_object::<_, component::props::types::SelectedValues>(
+                    custom_props::SelectedValues::new(),
+                )
+            }
+            SelectFromSequenceProps::Text => {
+                as_updater_object::<_, component::props::types::Text>(custom_props::Text::new())
+            }
+            SelectFromSequenceProps::From => as_updater_object::<_, component::props::types::From>(
+                component::attrs::From::get_prop_updater(),
+            ),
+            SelectFromSequenceProps::To => as_updater_object::<_, component::props::types::To>(
+                component::attrs::To::get_prop_updater(),
+            ),
+            SelectFromSequenceProps::Step => as_updater_object::<_, component::props::types::Step>(
+                component::attrs::Step::get_prop_updater(),
+            ),
+            SelectFromSequenceProps::Length => {
+                as_updater_object::<_, component::props::types::Length>(
+                    component::attrs::Length::get_prop_updater(),
+                )
+            }
+            SelectFromSequenceProps::NumToSelect => {
+                as_updater_object::<_, component::props::types::NumToSelect>(
+                    component::attrs::NumToSelect::get_prop_updater(),
+                )
+            }
+            SelectFromSequenceProps::Hidden => {
+                as_updater_object::<_, component::props::types::Hidden>(
+                    component::attrs::Hide::get_prop_updater(),
+                )
+            }
+        }
+    }
+}
+
+/// Select `num_to_select` different members of `members` for the component `component_idx`,
+/// determined by `variant_seed`.
+pub fn select_from_members(
+    variant_seed: u64,
+    component_idx: ComponentIdx,
+    members: &[prop_type::Number],
+    num_to_select: usize,
+) -> Vec<prop_type::Number> {
+    SeededRng::new(variant_seed, component_idx)
+        .choose_indices(members.len(), num_to_select)
+        .into_iter()
+        .map(|idx| members[idx])
+        .collect()
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use selected_values::*;
+    mod selected_values {
+        use super::*;
+
+        /// The members selected by the `<selectFromSequence>`.
+        #[derive(Debug, Default)]
+        pub struct SelectedValues {}
+
+        impl SelectedValues {
+            pub fn new() -> Self {
+                SelectedValues {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(SelectFromSequence)]
+        struct RequiredData {
+            self_ref: PropView<prop_type::ComponentRef>,
+            variant_seed: PropView<prop_type::Integer>,
+            from: PropView<prop_type::Number>,
+            to: PropView<prop_type::Number>,
+            step: PropView<prop_type::Number>,
+            length: PropView<prop_type::Number>,
+            num_to_select: PropView<prop_type::Number>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn self_ref_query() -> DataQuery {
+                DataQuery::SelfRef
+            }
+            fn variant_seed_query() -> DataQuery {
+                DataQuery::VariantSeed
+            }
+            fn from_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SelectFromSequenceProps::From.local_idx().into(),
+                }
+            }
+            fn to_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SelectFromSequenceProps::To.local_idx().into(),
+                }
+            }
+            fn step_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SelectFromSequenceProps::Step.local_idx().into(),
+                }
+            }
+            fn length_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SelectFromSequenceProps::Length.local_idx().into(),
+                }
+            }
+            fn num_to_select_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SelectFromSequenceProps::NumToSelect.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for SelectedValues {
+            type PropType = prop_type::PropVec;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let component_idx = required_data
+                    .self_ref
+                    .value
+                    .expect("SelfRef must always be valid")
+                    .0;
+
+                // A `NaN` `to` or `length` means the attribute was not specified.
+                let specified = |value: prop_type::Number| (!value.is_nan()).then_some(value);
+                let members = sequence_members(
+                    required_data.from.value,
+                    specified(required_data.to.value),
+                    required_data.step.value,
+                    specified(required_data.length.value),
+                );
+                let num_to_select = required_data.num_to_select.value;
+                let num_to_select = if num_to_select.is_finite() && num_to_select > 0.0 {
+                    num_to_select.round() as usize
+                } else {
+                    0
+                };
+
+                let selected = select_from_members(
+                    // The seed was wrapped into a signed integer; wrap it back.
+                    required_data.variant_seed.value as u64,
+                    component_idx,
+                    &members,
+                    num_to_select,
+                );
+                PropCalcResult::Calculated(Rc::new(
+                    selected.into_iter().map(PropValue::Number).collect(),
+                ))
+            }
+        }
+    }
+
+    pub use text::*;
+    mod text {
+        use super::*;
+
+        /// The members selected by the `<selectFromSequence>`, separated by commas.
+        #[derive(Debug, Default)]
+        pub struct Text {}
+
+        impl Text {
+            pub fn new() -> Self {
+                Text {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(SelectFromSequence)]
+        struct RequiredData {
+            selected_values: PropView<prop_type::PropVec>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn selected_values_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SelectFromSequenceProps::SelectedValues.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for Text {
+            type PropType = prop_type::String;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let text = required_data
+                    .selected_values
+                    .value
+                    .iter()
+                    .map(|value| match value {
+                        PropValue::Number(number) => number.to_string(),
+                        _ => String::new(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                PropCalcResult::Calculated(Rc::new(text))
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::components::prelude::*;
+use crate::general_prop::RenderedChildrenPassthroughProp;
+use crate::props::UpdaterObject;
+
+/// The largest number of members a `<sequence>` generates.
+pub const MAX_SEQUENCE_LENGTH: usize = 10_000;
+
+/// The number of members of a `<sequence>` that specifies neither `to` nor `length`.
+pub const DEFAULT_SEQUENCE_LENGTH: usize = 10;
+
+/// The `<sequence>` component generates an arithmetic sequence of `<number>` children,
+/// e.g., `<sequence from="2" to="10" step="2"/>` has the members 2, 4, 6, 8, 10.
+///
+/// The members are generated when the document is built, so the `from`, `to`, `step`, and `length`
+/// attributes must be numbers rather than references to other components.
+#[component(name = Sequence)]
+mod component {
+
+    use super::*;
+    use crate::general_prop::{BooleanProp, NumberProp};
+
+    enum Props {
+        /// Whether the `<sequence>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// Whether the `<sequence>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The first member of the sequence.
+        #[attribute(prop = NumberProp, default = 1.0)]
+        From,
+        /// The last member of the sequence, unless `length` is specified.
+        #[attribute(prop = NumberProp, default = prop_type::Number::NAN)]
+        To,
+        /// The difference between consecutive members of the sequence.
+        #[attribute(prop = NumberProp, default = 1.0)]
+        Step,
+        /// The number of members of the sequence, which takes precedence over `to`.
+        #[attribute(prop = NumberProp, default = prop_type::Number::NAN)]
+        Length,
+    }
+}
+
+pub use component::Sequence;
+pub use component::SequenceActions;
+pub use component::SequenceAttributes;
+pub use component::SequenceProps;
+
+impl PropGetUpdater for SequenceProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            SequenceProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            SequenceProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(RenderedChildrenPassthroughProp::new()),
+        }
+    }
+}
+
+/// The members of the sequence that starts at `from` and increases by `step`.
+/// It has `length` members if specified, otherwise it ends at the last member that does not pass `to`,
+/// and otherwise it has `DEFAULT_SEQUENCE_LENGTH` members.
+///
+/// The sequence is empty if any value is not finite or if `step` is zero,
+/// and it is truncated to `MAX_SEQUENCE_LENGTH` members.
+pub fn sequence_members(
+    from: prop_type::Number,
+    to: Option<prop_type::Number>,
+    step: prop_type::Number,
+    length: Option<prop_type::Number>,
+) -> Vec<prop_type::Number> {
+    if !from.is_finite() || !step.is_finite() || step == 0.0 {
+        return Vec::new();
+    }
+    let length = match (length, to) {
+        (Some(length), _) if length.is_finite() && length >= 0.0 => length.round(),
+        (Some(_), _) => return Vec::new(),
+        (None, Some(to)) if to.is_finite() => {
+            // Allow for rounding error so that, e.g., `to` is included when `from="0" to="0.3" step="0.1"`.
+            let num_steps = (to - from) / step;
+            if num_steps < -1e-10 {
+                0.0
+            } else {
+                (num_steps + 1e-10).floor() + 1.0
+            }
+        }
+        (None, Some(_)) => return Vec::new(),
+        (None, None) => DEFAULT_SEQUENCE_LENGTH as prop_type::Number,
+    };
+    let length = (length as usize).min(MAX_SEQUENCE_LENGTH);
+
+    (0..length)
+        .map(|i| from + (i as prop_type::Number) * step)
+        .collect()
+}
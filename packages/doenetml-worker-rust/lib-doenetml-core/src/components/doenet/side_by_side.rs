@@ -0,0 +1,313 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::core::EnvironmentProperty;
+use crate::general_prop::{EnumAttributeValue, RenderedChildrenPassthroughProp};
+use crate::props::UpdaterObject;
+
+/// The `<sideBySide>` component places its child components next to each other.
+/// The width of each child, as a percentage of the width of the `<sideBySide>`, is computed from the `widths` attribute,
+/// e.g., `widths="30% 70%"`, with children whose width is not given sharing the remaining width equally.
+/// On a phone, the children are stacked instead, each taking the full width.
+#[component(name = SideBySide)]
+mod component {
+
+    use crate::general_prop::{BooleanProp, StringProp};
+
+    enum Props {
+        /// Whether the `<sideBySide>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+        /// The widths given by the `widths` attribute.
+        #[prop(value_type = PropValueType::String)]
+        SpecifiedWidths,
+        /// Whether the children are stacked vertically rather than placed side by side.
+        #[prop(value_type = PropValueType::Boolean, for_render, is_public)]
+        Stacked,
+        /// The width of each rendered child, as a percentage of the width of the `<sideBySide>`.
+        #[prop(value_type = PropValueType::PropVec, for_render)]
+        Widths,
+        /// The `valign` given by the attribute.
+        #[prop(value_type = PropValueType::String)]
+        SpecifiedValign,
+        /// How the children are aligned vertically: `top`, `middle`, or `bottom`.
+        #[prop(value_type = PropValueType::String, for_render, is_public)]
+        Valign,
+    }
+
+    enum Attributes {
+        /// Whether the `<sideBySide>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The widths of the children separated by spaces, e.g., `30% 70%`.
+        #[attribute(prop = StringProp, default = String::new())]
+        Widths,
+        /// How the children are aligned vertically: `top`, `middle`, or `bottom`.
+        #[attribute(prop = StringProp, default = String::new())]
+        Valign,
+    }
+}
+
+pub use component::SideBySide;
+pub use component::SideBySideActions;
+pub use component::SideBySideAttributes;
+pub use component::SideBySideProps;
+
+impl PropGetUpdater for SideBySideProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            SideBySideProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            SideBySideProps::RenderedChildren => {
+                as_updater_object::<_, component::props::types::RenderedChildren>(
+                    RenderedChildrenPassthroughProp::new_components_only(),
+                )
+            }
+            SideBySideProps::SpecifiedWidths => {
+                as_updater_object::<_, component::props::types::SpecifiedWidths>(
+                    component::attrs::Widths::get_prop_updater(),
+                )
+            }
+            SideBySideProps::Stacked => as_updater_object::<_, component::props::types::Stacked>(
+                custom_props::Stacked::new(),
+            ),
+            SideBySideProps::Widths => {
+                as_updater_object::<_, component::props::types::Widths>(custom_props::Widths::new())
+            }
+            SideBySideProps::SpecifiedValign => {
+                as_updater_object::<_, component::props::types::SpecifiedValign>(
+                    component::attrs::Valign::get_prop_updater(),
+                )
+            }
+            SideBySideProps::Valign => as_updater_object::<_, component::props::types::Valign>(
+                custom_props::ValignProp::new(),
+            ),
+        }
+    }
+}
+
+/// How the children of a `<sideBySide>` are aligned vertically.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, strum_macros::Display, strum_macros::EnumString,
+)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum Valign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+impl EnumAttributeValue for Valign {
+    const VALID_VALUES: &'static [&'static str] = &["top", "middle", "bottom"];
+
+    fn parse_attribute_value(value: &str) -> Option<Self> {
+        value.trim().parse().ok()
+    }
+}
+
+/// Parse a `widths` attribute, e.g., `30% 70%`, into a width for each item.
+/// An item that is not a number (with or without `%`), e.g., `auto`, gives `None`.
+pub fn parse_widths(widths: &str) -> Vec<Option<f64>> {
+    widths
+        .split_whitespace()
+        .map(|item| {
+            item.trim_end_matches('%')
+                .parse::<f64>()
+                .ok()
+                .filter(|width| width.is_finite() && *width >= 0.0)
+        })
+        .collect()
+}
+
+/// Compute the width of each of `num_children` children, as a percentage of the width of their container,
+/// from the `specified` widths of the first children.
+///
+/// Children without a specified width share the remaining width equally. If the specified widths
+/// add up to more than 100%, they are scaled down to fit. Stacked children each take the full width.
+pub fn compute_widths(specified: &[Option<f64>], num_children: usize, stacked: bool) -> Vec<f64> {
+    if stacked {
+        return vec![100.0; num_children];
+    }
+    let specified = (0..num_children)
+        .map(|idx| specified.get(idx).copied().flatten())
+        .collect::<Vec<_>>();
+    let total_specified: f64 = specified.iter().flatten().sum();
+    let num_unspecified = specified.iter().filter(|width| width.is_none()).count();
+
+    if total_specified > 100.0 {
+        let scale = 100.0 / total_specified;
+        specified
+            .into_iter()
+            .map(|width| width.map_or(0.0, |width| width * scale))
+            .collect()
+    } else {
+        let shared_width = (100.0 - total_specified) / num_unspecified.max(1) as f64;
+        specified
+            .into_iter()
+            .map(|width| width.unwrap_or(shared_width))
+            .collect()
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use stacked::*;
+    mod stacked {
+        use super::*;
+
+        /// Whether the children are stacked, which they are on a phone.
+        #[derive(Debug, Default)]
+        pub struct Stacked {}
+
+        impl Stacked {
+            pub fn new() -> Self {
+                Stacked {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(SideBySide)]
+        struct RequiredData {
+            device_class: PropView<prop_type::String>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn device_class_query() -> DataQuery {
+                DataQuery::Environment(EnvironmentProperty::DeviceClass)
+            }
+        }
+
+        impl PropUpdater for Stacked {
+            type PropType = prop_type::Boolean;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(required_data.device_class.value.as_str() == "phone")
+            }
+        }
+    }
+
+    pub use widths::*;
+    mod widths {
+        use super::*;
+
+        /// The width of each rendered child.
+        #[derive(Debug, Default)]
+        pub struct Widths {}
+
+        impl Widths {
+            pub fn new() -> Self {
+                Widths {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(SideBySide)]
+        struct RequiredData {
+            rendered_children: PropView<component::props::types::RenderedChildren>,
+            specified_widths: PropView<component::props::types::SpecifiedWidths>,
+            stacked: PropView<component::props::types::Stacked>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn rendered_children_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SideBySideProps::RenderedChildren.local_idx().into(),
+                }
+            }
+            fn specified_widths_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SideBySideProps::SpecifiedWidths.local_idx().into(),
+                }
+            }
+            fn stacked_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SideBySideProps::Stacked.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for Widths {
+            type PropType = prop_type::PropVec;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let widths = compute_widths(
+                    &parse_widths(&required_data.specified_widths.value),
+                    required_data.rendered_children.value.as_slice().len(),
+                    required_data.stacked.value,
+                );
+                PropCalcResult::Calculated(Rc::new(
+                    widths.into_iter().map(PropValue::Number).collect(),
+                ))
+            }
+        }
+    }
+
+    pub use valign::*;
+    mod valign {
+        use super::*;
+
+        /// The vertical alignment of the children, `top` unless a valid `valign` is given.
+        #[derive(Debug, Default)]
+        pub struct ValignProp {}
+
+        impl ValignProp {
+            pub fn new() -> Self {
+                ValignProp {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(SideBySide)]
+        struct RequiredData {
+            specified_valign: PropView<component::props::types::SpecifiedValign>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn specified_valign_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SideBySideProps::SpecifiedValign.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for ValignProp {
+            type PropType = prop_type::String;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let valign = Valign::parse_attribute_value(&required_data.specified_valign.value)
+                    .unwrap_or_default();
+                PropCalcResult::Calculated(std::rc::Rc::new(valign.to_string()))
+            }
+        }
+    }
+}
@@ -0,0 +1,380 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::general_prop::{BooleanProp, NumberProp, StringProp};
+use crate::props::UpdaterObject;
+
+/// The most markers that are shown on a `<slider>`. If stepping from `from` to `to` would take more
+/// steps, no markers are shown.
+const MAX_MARKERS: usize = 101;
+
+/// The `<slider>` component lets a user choose a number between `from` and `to` in increments of `step`,
+/// e.g., to drive a graph in an interactive demonstration.
+#[component(
+    name = Slider,
+    extend_via_default_prop,
+    ref_transmutes_to = "number",
+    needs_pointer_events
+)]
+mod component {
+
+    use super::*;
+
+    enum Props {
+        /// The value of the `<slider>`. It is changed when a user moves the slider.
+        ///
+        /// If the `bindValueTo` attribute is specified, the value is that of the referenced content,
+        /// and moving the slider changes the referenced content.
+        #[prop(
+            value_type = PropValueType::Number,
+            profile = PropProfile::Number,
+            is_public,
+            for_render,
+            default,
+        )]
+        Value,
+        /// The smallest value of the `<slider>`.
+        #[prop(value_type = PropValueType::Number, is_public, for_render)]
+        From,
+        /// The largest value of the `<slider>`.
+        #[prop(value_type = PropValueType::Number, is_public, for_render)]
+        To,
+        /// The increment between the values that the `<slider>` can take.
+        #[prop(value_type = PropValueType::Number, is_public, for_render)]
+        Step,
+        /// The values at which markers are shown: every `step` from `from` to `to`.
+        /// It is empty if there would be too many markers to show.
+        #[prop(value_type = PropValueType::PropVec, for_render)]
+        Markers,
+        /// The label shown with the `<slider>`.
+        #[prop(value_type = PropValueType::String, for_render)]
+        Label,
+        /// Whether the `<slider>` cannot be moved.
+        #[prop(value_type = PropValueType::Boolean, for_render)]
+        Disabled,
+        /// The value of the `<slider>` before any interaction, from the `initialValue` attribute.
+        /// If the attribute is not specified, then this prop is marked `came_from_default`.
+        #[prop(value_type = PropValueType::Number)]
+        InitialValue,
+        /// The value from the `bindValueTo` attribute.
+        /// If the attribute is not specified, then this prop is marked `came_from_default`.
+        #[prop(value_type = PropValueType::Number)]
+        BindValueTo,
+        /// Whether the `<slider>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+    }
+
+    enum Attributes {
+        /// Whether the `<slider>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The smallest value of the `<slider>`.
+        #[attribute(prop = NumberProp, default = 0.0)]
+        From,
+        /// The largest value of the `<slider>`.
+        #[attribute(prop = NumberProp, default = 10.0)]
+        To,
+        /// The increment between the values that the `<slider>` can take.
+        #[attribute(prop = NumberProp, default = 1.0)]
+        Step,
+        /// The value of the `<slider>` before any interaction. It defaults to `from`.
+        #[attribute(prop = NumberProp, default = prop_type::Number::NAN)]
+        InitialValue,
+        /// A reference to content, e.g., `bindValueTo="$n"`, that the value of the `<slider>` is bound to.
+        #[attribute(prop = NumberProp, default = prop_type::Number::NAN)]
+        BindValueTo,
+        /// The label shown with the `<slider>`.
+        #[attribute(prop = StringProp, default = String::new())]
+        Label,
+        /// Whether the `<slider>` cannot be moved.
+        #[attribute(prop = BooleanProp, default = false)]
+        Disabled,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    #[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+    #[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+    #[serde(expecting = "`value` must be a number")]
+    pub struct SliderChangeValueActionArgs {
+        pub value: prop_type::Number,
+    }
+
+    enum Actions {
+        /// Move the slider to the allowed value nearest to `value`.
+        ChangeValue(ActionBody<SliderChangeValueActionArgs>),
+    }
+}
+
+pub use component::Slider;
+pub use component::SliderActions;
+pub use component::SliderAttributes;
+pub use component::SliderChangeValueActionArgs;
+pub use component::SliderProps;
+
+impl PropGetUpdater for SliderProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            SliderProps::Value => {
+                as_updater_object::<_, component::props::types::Value>(custom_props::Value::new())
+            }
+            SliderProps::From => as_updater_object::<_, component::props::types::From>(
+                component::attrs::From::get_prop_updater(),
+            ),
+            SliderProps::To => as_updater_object::<_, component::props::types::To>(
+                component::attrs::To::get_prop_updater(),
+            ),
+            SliderProps::Step => as_updater_object::<_, component::props::types::Step>(
+                component::attrs::Step::get_prop_updater(),
+            ),
+            SliderProps::Markers => as_updater_object::<_, component::props::types::Markers>(
+                custom_props::Markers::new(),
+            ),
+            SliderProps::Label => as_updater_object::<_, component::props::types::Label>(
+                component::attrs::Label::get_prop_updater(),
+            ),
+            SliderProps::Disabled => as_updater_object::<_, component::props::types::Disabled>(
+                component::attrs::Disabled::get_prop_updater(),
+            ),
+            SliderProps::InitialValue => {
+                as_updater_object::<_, component::props::types::InitialValue>(
+                    component::attrs::InitialValue::get_prop_updater()
+                        .dont_propagate_came_from_default(),
+                )
+            }
+            SliderProps::BindValueTo => {
+                as_updater_object::<_, component::props::types::BindValueTo>(
+                    component::attrs::BindValueTo::get_prop_updater()
+                        .dont_propagate_came_from_default(),
+                )
+            }
+            SliderProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+        }
+    }
+}
+
+impl ComponentOnAction for Slider {
+    fn on_action(
+        &self,
+        action: ActionsEnum,
+        query_prop: ActionQueryProp,
+    ) -> Result<Vec<UpdateFromAction>, String> {
+        // The type of `action` should have already been verified, so an
+        // error here is a programming logic error, not an API error.
+        let action: SliderActions = action.try_into()?;
+
+        match action {
+            SliderActions::ChangeValue(ActionBody { args }) => {
+                let get_number = |prop: SliderProps| -> prop_type::Number {
+                    query_prop
+                        .get_local_prop(prop.local_idx())
+                        .value
+                        .try_into()
+                        .unwrap()
+                };
+                let value = snap_to_step(
+                    args.value,
+                    get_number(SliderProps::From),
+                    get_number(SliderProps::To),
+                    get_number(SliderProps::Step),
+                );
+
+                Ok(vec![UpdateFromAction {
+                    local_prop_idx: SliderProps::Value.local_idx(),
+                    requested_value: value.into(),
+                }])
+            }
+        }
+    }
+}
+
+/// The value nearest to `value` that is between `from` and `to` and is a whole number of `step`s from `from`.
+/// A `step` that is not positive allows any value between `from` and `to`.
+fn snap_to_step(
+    value: prop_type::Number,
+    from: prop_type::Number,
+    to: prop_type::Number,
+    step: prop_type::Number,
+) -> prop_type::Number {
+    let (min, max) = if from <= to { (from, to) } else { (to, from) };
+    let mut value = value.clamp(min, max);
+    if step > 0.0 {
+        value = from + ((value - from) / step).round() * step;
+        // Rounding may step just past the end of the range.
+        if value > max {
+            value -= step;
+        } else if value < min {
+            value += step;
+        }
+    }
+    value
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use value::*;
+    mod value {
+        use super::*;
+
+        /// The value of the slider. It comes from the `bindValueTo` attribute if it is specified.
+        /// Otherwise, it starts at the `initialValue` attribute (or `from`)
+        /// and then tracks the value the slider was moved to.
+        #[derive(Debug, Default)]
+        pub struct Value {}
+
+        impl Value {
+            pub fn new() -> Self {
+                Value {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, IntoDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Slider)]
+        struct RequiredData {
+            /// The value the slider was last moved to, if it has been moved.
+            independent_state: PropView<prop_type::Number>,
+            bind_value_to: PropView<prop_type::Number>,
+            initial_value: PropView<prop_type::Number>,
+            from: PropView<prop_type::Number>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn independent_state_query() -> DataQuery {
+                DataQuery::State
+            }
+            fn bind_value_to_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SliderProps::BindValueTo.local_idx().into(),
+                }
+            }
+            fn initial_value_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SliderProps::InitialValue.local_idx().into(),
+                }
+            }
+            fn from_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SliderProps::From.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for Value {
+            type PropType = prop_type::Number;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let value = if !required_data.bind_value_to.came_from_default {
+                    required_data.bind_value_to.value
+                } else if !required_data.independent_state.came_from_default {
+                    required_data.independent_state.value
+                } else if !required_data.initial_value.came_from_default {
+                    required_data.initial_value.value
+                } else {
+                    required_data.from.value
+                };
+                PropCalcResult::Calculated(value)
+            }
+            fn invert(
+                &self,
+                data: DataQueryResults,
+                requested_value: Self::PropType,
+                _is_direct_change_from_action: bool,
+            ) -> Result<DataQueryResults, InvertError> {
+                let mut desired = RequiredData::try_new_desired(&data).unwrap();
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                if required_data.bind_value_to.came_from_default {
+                    desired.independent_state.change_to(requested_value);
+                } else {
+                    // The slider is bound to other content, so change that content.
+                    desired.bind_value_to.change_to(requested_value);
+                }
+                Ok(desired.into_data_query_results())
+            }
+        }
+    }
+
+    pub use markers::*;
+    mod markers {
+        use super::*;
+
+        /// The values at which markers are shown on the slider.
+        #[derive(Debug, Default)]
+        pub struct Markers {}
+
+        impl Markers {
+            pub fn new() -> Self {
+                Markers {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Slider)]
+        struct RequiredData {
+            from: PropView<prop_type::Number>,
+            to: PropView<prop_type::Number>,
+            step: PropView<prop_type::Number>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn from_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SliderProps::From.local_idx().into(),
+                }
+            }
+            fn to_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SliderProps::To.local_idx().into(),
+                }
+            }
+            fn step_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SliderProps::Step.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for Markers {
+            type PropType = prop_type::PropVec;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                let from = required_data.from.value;
+                let to = required_data.to.value;
+                let step = required_data.step.value;
+
+                // The number of steps from `from` to `to`, allowing for rounding errors.
+                let num_steps = ((to - from) / step + 1e-9).floor();
+                let markers = if step > 0.0 && num_steps >= 0.0 && num_steps < MAX_MARKERS as f64 {
+                    (0..=num_steps as usize)
+                        .map(|i| PropValue::Number(from + i as f64 * step))
+                        .collect()
+                } else {
+                    Vec::new()
+                };
+                PropCalcResult::Calculated(Rc::new(markers))
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::general_prop::not_hidden_filter;
+use crate::props::UpdaterObject;
+
+/// The `<solution>` component shows a title that can be clicked to reveal its content, e.g.,
+/// `<solution><p>The answer is 4.</p></solution>`. Once revealed, a solution stays revealed,
+/// and the `<document>` counts the revealed solutions so that scoring policies can account for them.
+#[component(name = Solution)]
+mod component {
+
+    use crate::general_prop::{BooleanProp, StringProp};
+
+    enum Props {
+        /// Whether the `<solution>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        /// The title that is shown whether or not the `<solution>` is revealed.
+        #[prop(value_type = PropValueType::String, is_public, for_render)]
+        Title,
+        /// Whether the content of the `<solution>` has been revealed.
+        #[prop(
+            value_type = PropValueType::Boolean,
+            profile = PropProfile::SolutionRevealed,
+            is_public,
+            for_render
+        )]
+        Revealed,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// Whether the `<solution>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The title that is shown whether or not the `<solution>` is revealed.
+        #[attribute(prop = StringProp, default = "Solution".to_string())]
+        Title,
+        /// Whether the `<solution>` is revealed initially.
+        #[attribute(prop = BooleanProp, default = false)]
+        Revealed,
+    }
+
+    enum Actions {
+        RevealSolution,
+    }
+}
+
+pub use component::Solution;
+pub use component::SolutionActions;
+pub use component::SolutionAttributes;
+pub use component::SolutionProps;
+
+impl PropGetUpdater for SolutionProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            SolutionProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            SolutionProps::Title => as_updater_object::<_, component::props::types::Title>(
+                component::attrs::Title::get_prop_updater(),
+            ),
+            SolutionProps::Revealed => as_updater_object::<_, component::props::types::Revealed>(
+                component::attrs::Revealed::get_prop_updater(),
+            ),
+            SolutionProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(custom_props::RenderedChildren::new()),
+        }
+    }
+}
+
+impl ComponentOnAction for Solution {
+    fn on_action(
+        &self,
+        action: ActionsEnum,
+        _query_prop: ActionQueryProp,
+    ) -> Result<Vec<UpdateFromAction>, String> {
+        // The type of `action` should have already been verified, so an
+        // error here is a programming logic error, not an API error.
+        let action: SolutionActions = action.try_into()?;
+
+        match action {
+            SolutionActions::RevealSolution => Ok(vec![UpdateFromAction {
+                local_prop_idx: SolutionProps::Revealed.local_idx(),
+                requested_value: true.into(),
+            }]),
+        }
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use rendered_children::*;
+    mod rendered_children {
+        use super::*;
+
+        /// The children that this component renders: all of them once it is revealed, and none before.
+        #[derive(Debug, Default)]
+        pub struct RenderedChildren {}
+
+        impl RenderedChildren {
+            pub fn new() -> Self {
+                RenderedChildren {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Solution)]
+        struct RequiredData {
+            revealed: PropView<component::props::types::Revealed>,
+            children: PropView<prop_type::AnnotatedContentRefs>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn revealed_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: SolutionProps::Revealed.local_idx().into(),
+                }
+            }
+            fn children_query() -> DataQuery {
+                DataQuery::AnnotatedContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(not_hidden_filter()),
+                }
+            }
+        }
+
+        impl PropUpdater for RenderedChildren {
+            type PropType = component::props::types::RenderedChildren;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                if required_data.revealed.value {
+                    PropCalcResult::Calculated(required_data.children.value)
+                } else {
+                    PropCalcResult::Calculated(Rc::new(Vec::new().into()))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::components::prelude::*;
+use crate::props::UpdaterObject;
+
+/// The `<spacer>` component renders empty space. In a `<sideBySide>`, it takes up a width like any other child.
+#[component(name = Spacer)]
+mod component {
+
+    use crate::general_prop::BooleanProp;
+
+    enum Props {
+        /// Whether the `<spacer>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+    }
+
+    enum Attributes {
+        /// Whether the `<spacer>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+    }
+}
+
+pub use component::Spacer;
+pub use component::SpacerActions;
+pub use component::SpacerAttributes;
+pub use component::SpacerProps;
+
+impl PropGetUpdater for SpacerProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            SpacerProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::components::prelude::*;
+use crate::general_prop::RenderedChildrenPassthroughProp;
+use crate::props::UpdaterObject;
+
+/// A `<tab>` is one of the panels of a `<tabs>`. Its content is rendered only while it is the selected tab.
+#[component(name = Tab)]
+mod component {
+
+    use crate::general_prop::{BooleanProp, StringProp};
+
+    enum Props {
+        /// Whether the `<tab>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        /// The title shown on the button that selects the `<tab>`.
+        #[prop(value_type = PropValueType::String, profile = PropProfile::TabTitle, is_public, for_render)]
+        Title,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// Whether the `<tab>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The title shown on the button that selects the `<tab>`.
+        #[attribute(prop = StringProp, default = String::new())]
+        Title,
+    }
+}
+
+pub use component::Tab;
+pub use component::TabActions;
+pub use component::TabAttributes;
+pub use component::TabProps;
+
+impl PropGetUpdater for TabProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            TabProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            TabProps::Title => as_updater_object::<_, component::props::types::Title>(
+                component::attrs::Title::get_prop_updater(),
+            ),
+            TabProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(RenderedChildrenPassthroughProp::new()),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::components::prelude::*;
+use crate::general_prop::{EnumAttributeValue, RenderedChildrenPassthroughProp};
+use crate::props::UpdaterObject;
+use crate::utils::usize_to_integer;
+
+/// The `<table>` component lays out its `<row>` children in a grid, e.g.,
+/// `<table><row><cell>1</cell><cell>2</cell></row></table>`.
+/// The `halign` attribute gives the horizontal alignment of each column separated by spaces,
+/// e.g., `halign="left center right"`. A `<row>` or `<cell>` with its own `halign` overrides it.
+#[component(name = Table)]
+mod component {
+
+    use crate::general_prop::{BooleanProp, StringProp};
+
+    enum Props {
+        /// Whether the `<table>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+        /// The number of `<row>` children.
+        #[prop(value_type = PropValueType::Integer, is_public, for_render)]
+        NumRows,
+        /// The number of cells in the longest `<row>`.
+        #[prop(value_type = PropValueType::Integer, is_public, for_render)]
+        NumColumns,
+        /// The alignments of the columns given by the `halign` attribute.
+        #[prop(value_type = PropValueType::String, profile = PropProfile::TableHalign)]
+        Halign,
+    }
+
+    enum Attributes {
+        /// Whether the `<table>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The horizontal alignment of each column separated by spaces, e.g., `left center right`.
+        #[attribute(prop = StringProp, default = String::new())]
+        Halign,
+    }
+}
+
+pub use component::Table;
+pub use component::TableActions;
+pub use component::TableAttributes;
+pub use component::TableProps;
+
+impl PropGetUpdater for TableProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            TableProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            TableProps::RenderedChildren => {
+                as_updater_object::<_, component::props::types::RenderedChildren>(
+                    RenderedChildrenPassthroughProp::new_components_only(),
+                )
+            }
+            TableProps::NumRows => as_updater_object::<_, component::props::types::NumRows>(
+                custom_props::NumRows::new(),
+            ),
+            TableProps::NumColumns => as_updater_object::<_, component::props::types::NumColumns>(
+                custom_props::NumColumns::new(),
+            ),
+            TableProps::Halign => as_updater_object::<_, component::props::types::Halign>(
+                component::attrs::Halign::get_prop_updater(),
+            ),
+        }
+    }
+}
+
+/// The horizontal alignment of the content of a `<cell>`.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, strum_macros::Display, strum_macros::EnumString,
+)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum Halign {
+    #[default]
+    Left,
+    Center,
+    Right,
+    Justify,
+}
+
+impl EnumAttributeValue for Halign {
+    const VALID_VALUES: &'static [&'static str] = &["left", "center", "right", "justify"];
+
+    fn parse_attribute_value(value: &str) -> Option<Self> {
+        value.trim().parse().ok()
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use num_rows::*;
+    mod num_rows {
+        use super::*;
+
+        /// The number of `<row>` children of the `<table>`.
+        #[derive(Debug, Default)]
+        pub struct NumRows {}
+
+        impl NumRows {
+            pub fn new() -> Self {
+                NumRows {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Table)]
+        struct RequiredData {
+            row_num_cells: Vec<PropView<prop_type::Integer>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn row_num_cells_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::Children,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::RowNumCells]),
+                }
+            }
+        }
+
+        impl PropUpdater for NumRows {
+            type PropType = component::props::types::NumRows;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(
+                    usize_to_integer(required_data.row_num_cells.len())
+                        .expect("Number of rows must fit in an integer"),
+                )
+            }
+        }
+    }
+
+    pub use num_columns::*;
+    mod num_columns {
+        use super::*;
+
+        /// The number of cells in the longest `<row>` of the `<table>`.
+        #[derive(Debug, Default)]
+        pub struct NumColumns {}
+
+        impl NumColumns {
+            pub fn new() -> Self {
+                NumColumns {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Table)]
+        struct RequiredData {
+            row_num_cells: Vec<PropView<prop_type::Integer>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn row_num_cells_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::Children,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::RowNumCells]),
+                }
+            }
+        }
+
+        impl PropUpdater for NumColumns {
+            type PropType = component::props::types::NumColumns;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(
+                    required_data
+                        .row_num_cells
+                        .iter()
+                        .map(|num_cells| num_cells.value)
+                        .max()
+                        .unwrap_or(0),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use std::rc::Rc;
+
+use crate::components::doenet::tab::Tab;
+use crate::components::prelude::*;
+use crate::props::{ContentFilter, UpdaterObject};
+
+/// The `<tabs>` component shows one of its `<tab>` children at a time, along with a button to select each of them.
+/// Only the selected `<tab>` is rendered, so the content of the other tabs is not calculated until it is selected.
+#[component(name = Tabs)]
+mod component {
+
+    use crate::general_prop::{BooleanProp, StringToIntegerProp};
+
+    enum Props {
+        /// Whether the `<tabs>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        /// The tab that is selected before any interaction, from the `selectedIndex` attribute.
+        #[prop(value_type = PropValueType::Integer)]
+        InitialSelectedIndex,
+        /// The index of the selected `<tab>`, starting from 1.
+        #[prop(value_type = PropValueType::Integer, is_public, for_render)]
+        SelectedIndex,
+        /// The titles of the `<tab>` children, shown on the buttons that select them.
+        #[prop(value_type = PropValueType::PropVec, for_render)]
+        TabTitles,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+    }
+
+    enum Attributes {
+        /// Whether the `<tabs>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The index of the `<tab>` that is selected initially, starting from 1.
+        #[attribute(prop = StringToIntegerProp, default = 1)]
+        SelectedIndex,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    #[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+    #[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+    #[serde(expecting = "`index` must be an integer")]
+    pub struct TabsSelectTabActionArgs {
+        pub index: prop_type::Integer,
+    }
+
+    enum Actions {
+        SelectTab(ActionBody<TabsSelectTabActionArgs>),
+        /// Select the tab after the selected one, wrapping around to the first tab.
+        SelectNextTab,
+        /// Select the tab before the selected one, wrapping around to the last tab.
+        SelectPreviousTab,
+    }
+}
+
+pub use component::Tabs;
+pub use component::TabsActions;
+pub use component::TabsAttributes;
+pub use component::TabsProps;
+pub use component::TabsSelectTabActionArgs;
+
+impl PropGetUpdater for TabsProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            TabsProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            TabsProps::InitialSelectedIndex => {
+                as_updater_object::<_, component::props::types::InitialSelectedIndex>(
+                    component::attrs::SelectedIndex::get_prop_updater(),
+                )
+            }
+            TabsProps::SelectedIndex => as_updater_object::<
+                _,
+                component::props::types::SelectedIndex,
+            >(custom_props::SelectedIndex::new()),
+            TabsProps::TabTitles => as_updater_object::<_, component::props::types::TabTitles>(
+                custom_props::TabTitles::new(),
+            ),
+            TabsProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(custom_props::RenderedChildren::new()),
+        }
+    }
+}
+
+impl ComponentOnAction for Tabs {
+    fn on_action(
+        &self,
+        action: ActionsEnum,
+        query_prop: ActionQueryProp,
+    ) -> Result<Vec<UpdateFromAction>, String> {
+        // The type of `action` should have already been verified, so an
+        // error here is a programming logic error, not an API error.
+        let action: TabsActions = action.try_into()?;
+
+        let index = match action {
+            TabsActions::SelectTab(ActionBody { args }) => args.index,
+            TabsActions::SelectNextTab | TabsActions::SelectPreviousTab => {
+                let tab_titles: prop_type::PropVec = query_prop
+                    .get_local_prop(TabsProps::TabTitles.local_idx())
+                    .value
+                    .try_into()
+                    .unwrap();
+                let num_tabs = tab_titles.len() as prop_type::Integer;
+                if num_tabs == 0 {
+                    return Ok(Vec::new());
+                }
+                let selected_index: prop_type::Integer = query_prop
+                    .get_local_prop(TabsProps::SelectedIndex.local_idx())
+                    .value
+                    .try_into()
+                    .unwrap();
+                // The tab is selected from the keyboard, so move focus to it, as in the ARIA tabs pattern.
+                query_prop.request_focus();
+                let step = if matches!(action, TabsActions::SelectNextTab) {
+                    1
+                } else {
+                    -1
+                };
+                // `selected_index` starts from 1.
+                (selected_index - 1 + step).rem_euclid(num_tabs) + 1
+            }
+        };
+
+        Ok(vec![UpdateFromAction {
+            local_prop_idx: TabsProps::SelectedIndex.local_idx(),
+            requested_value: index.into(),
+        }])
+    }
+
+    fn get_keyboard_shortcuts(&self) -> Vec<KeyboardShortcut> {
+        vec![
+            KeyboardShortcut::new("Alt+PageDown", "selectNextTab", ShortcutScope::Document),
+            KeyboardShortcut::new("Alt+PageUp", "selectPreviousTab", ShortcutScope::Document),
+        ]
+    }
+}
+
+mod custom_props {
+    use super::*;
+
+    pub use selected_index::*;
+    mod selected_index {
+        use super::*;
+
+        /// The index of the selected tab. It starts at the `selectedIndex` attribute
+        /// and is changed when a tab is selected.
+        #[derive(Debug, Default)]
+        pub struct SelectedIndex {}
+
+        impl SelectedIndex {
+            pub fn new() -> Self {
+                SelectedIndex {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, IntoDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Tabs)]
+        struct RequiredData {
+            /// The index of the tab that was last selected, if a tab has been selected.
+            independent_state: PropView<prop_type::Integer>,
+            initial_selected_index: PropView<component::props::types::InitialSelectedIndex>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn independent_state_query() -> DataQuery {
+                DataQuery::State
+            }
+            fn initial_selected_index_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: TabsProps::InitialSelectedIndex.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for SelectedIndex {
+            type PropType = prop_type::Integer;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                if required_data.independent_state.came_from_default {
+                    PropCalcResult::Calculated(required_data.initial_selected_index.value)
+                } else {
+                    PropCalcResult::Calculated(required_data.independent_state.value)
+                }
+            }
+            fn invert(
+                &self,
+                data: DataQueryResults,
+                requested_value: Self::PropType,
+                _is_direct_change_from_action: bool,
+            ) -> Result<DataQueryResults, InvertError> {
+                let mut desired = RequiredData::try_new_desired(&data).unwrap();
+                desired.independent_state.change_to(requested_value);
+                Ok(desired.into_data_query_results())
+            }
+        }
+    }
+
+    pub use tab_titles::*;
+    mod tab_titles {
+        use super::*;
+
+        /// The titles of the `<tab>` children.
+        #[derive(Debug, Default)]
+        pub struct TabTitles {}
+
+        impl TabTitles {
+            pub fn new() -> Self {
+                TabTitles {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Tabs)]
+        struct RequiredData {
+            titles: Vec<PropView<prop_type::String>>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn titles_query() -> DataQuery {
+                DataQuery::PickProp {
+                    source: PickPropSource::Children,
+                    prop_specifier: PropSpecifier::Matching(vec![PropProfile::TabTitle]),
+                }
+            }
+        }
+
+        impl PropUpdater for TabTitles {
+            type PropType = prop_type::PropVec;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                PropCalcResult::Calculated(Rc::new(
+                    required_data
+                        .titles
+                        .into_iter()
+                        .map(|title| PropValue::String(title.value))
+                        .collect(),
+                ))
+            }
+        }
+    }
+
+    pub use rendered_children::*;
+    mod rendered_children {
+        use super::*;
+
+        /// The children that this component renders: only the selected `<tab>`.
+        #[derive(Debug, Default)]
+        pub struct RenderedChildren {}
+
+        impl RenderedChildren {
+            pub fn new() -> Self {
+                RenderedChildren {}
+            }
+        }
+
+        /// Structure to hold data generated from the data queries
+        #[derive(TryFromDataQueryResults, Debug)]
+        #[data_query(query_trait = DataQueries)]
+        #[derive(TestDataQueryTypes)]
+        #[owning_component(Tabs)]
+        struct RequiredData {
+            tabs: PropView<prop_type::AnnotatedContentRefs>,
+            selected_index: PropView<component::props::types::SelectedIndex>,
+        }
+
+        impl DataQueries for RequiredData {
+            fn tabs_query() -> DataQuery {
+                DataQuery::AnnotatedContentRefs {
+                    container: PropSource::Me,
+                    filter: Rc::new(ContentFilter::IsType(Tab::NAME)),
+                }
+            }
+            fn selected_index_query() -> DataQuery {
+                DataQuery::Prop {
+                    source: PropSource::Me,
+                    prop_specifier: TabsProps::SelectedIndex.local_idx().into(),
+                }
+            }
+        }
+
+        impl PropUpdater for RenderedChildren {
+            type PropType = component::props::types::RenderedChildren;
+
+            fn data_queries(&self) -> Vec<DataQuery> {
+                RequiredData::to_data_queries()
+            }
+            fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+                let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+                // `selected_index` starts from 1; an index out of range selects no tab.
+                let selected = usize::try_from(required_data.selected_index.value - 1)
+                    .ok()
+                    .and_then(|idx| required_data.tabs.value.0.get(idx).cloned());
+                PropCalcResult::Calculated(Rc::new(selected.into_iter().collect::<Vec<_>>().into()))
+            }
+        }
+    }
+}
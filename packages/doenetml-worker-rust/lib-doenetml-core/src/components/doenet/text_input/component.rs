@@ -1,8 +1,13 @@
 use crate::components::prelude::*;
-use crate::general_prop::{BooleanProp, IndependentProp, StringProp};
+use crate::general_prop::{BooleanProp, IndependentProp, PropAlias, StringProp};
 use crate::props::UpdaterObject;
 
-#[component(name = TextInput, extend_via_default_prop, ref_transmutes_to="text")]
+#[component(
+    name = TextInput,
+    extend_via_default_prop,
+    ref_transmutes_to = "text",
+    needs_keyboard
+)]
 pub(super) mod component {
 
     use super::*;
@@ -31,7 +36,7 @@ pub(super) mod component {
             value_type = PropValueType::String,
             is_public,
             profile = PropProfile::String,
-            for_render,
+            for_render(interactive_only),
         )]
         ImmediateValue,
 
@@ -52,9 +57,21 @@ pub(super) mod component {
         #[prop(value_type = PropValueType::String)]
         ValueFromChildren,
 
+        /// The string value computed from the `bindValueTo` attribute.
+        /// If the attribute is specified, then this prop will not be marked `came_from_default`.
+        #[prop(value_type = PropValueType::String)]
+        BindValueTo,
+
+        /// The value that the `value` prop is bound to: `bind_value_to` if the `bindValueTo` attribute
+        /// is specified, otherwise `value_from_children`.
+        /// If neither is specified, this prop is marked `came_from_default`,
+        /// and the `value` and `immediate_value` props are determined by interacting with the `<textInput>`.
+        #[prop(value_type = PropValueType::String)]
+        BoundValue,
+
         /// The content that should prefill the `<textInput>`, giving it a default value before a user has interacted with the input.
         ///
-        /// It is ignored if `value_from_children` is specified.
+        /// It is ignored if `bound_value` is specified.
         #[prop(value_type = PropValueType::String)]
         Prefill,
 
@@ -70,6 +87,23 @@ pub(super) mod component {
         /// and the input box should display as disabled (e.g., grayed out)
         #[prop(value_type = PropValueType::Boolean)]
         Disabled,
+
+        /// The content to print in place of the input box when the document is rendered for print.
+        ///
+        /// It is the current `value` of the `<textInput>`, which is empty (printed as a blank)
+        /// if a user has not entered a response.
+        #[prop(value_type = PropValueType::String, for_render(print_only))]
+        PrintValue,
+
+        /// Whether a user has entered a response, i.e., whether `value` is a non-blank string
+        /// that differs from the `prefill` attribute.
+        #[prop(value_type = PropValueType::Boolean, is_public, profile = PropProfile::Answered)]
+        Answered,
+
+        /// The response of the `<textInput>` that is collected by an enclosing `<answer>`.
+        /// It is the current `value`.
+        #[prop(value_type = PropValueType::String, profile = PropProfile::Response)]
+        Response,
     }
 
     enum Attributes {
@@ -82,6 +116,11 @@ pub(super) mod component {
         /// The content that should prefill the `<textInput>`, giving it a default value before a user has interacted with the input.
         #[attribute(prop = StringProp, default = String::new())]
         Prefill,
+        /// A reference to content, e.g., `bindValueTo="$t"`, that the value of the `<textInput>` is bound to.
+        /// The content determines the value, and entering a response changes the content.
+        /// It takes precedence over any children of the `<textInput>`.
+        #[attribute(prop = StringProp, default = String::new())]
+        BindValueTo,
     }
 
     #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
@@ -106,7 +145,7 @@ pub use component::TextInputProps;
 use component::attrs;
 pub(super) use component::props;
 
-use super::custom_props::{ImmediateValueProp, ValueProp};
+use super::custom_props::{AnsweredProp, BoundValueProp, ImmediateValueProp, ValueProp};
 
 impl PropGetUpdater for TextInputProps {
     fn get_updater(&self) -> UpdaterObject {
@@ -125,6 +164,12 @@ impl PropGetUpdater for TextInputProps {
                     StringProp::new_from_children("").dont_propagate_came_from_default(),
                 )
             }
+            TextInputProps::BindValueTo => as_updater_object::<_, props::types::BindValueTo>(
+                attrs::BindValueTo::get_prop_updater().dont_propagate_came_from_default(),
+            ),
+            TextInputProps::BoundValue => {
+                as_updater_object::<_, props::types::BoundValue>(BoundValueProp::new())
+            }
             TextInputProps::Prefill => {
                 as_updater_object::<_, props::types::Prefill>(attrs::Prefill::get_prop_updater())
             }
@@ -134,6 +179,15 @@ impl PropGetUpdater for TextInputProps {
             TextInputProps::Disabled => {
                 as_updater_object::<_, props::types::Disabled>(attrs::Disabled::get_prop_updater())
             }
+            TextInputProps::PrintValue => as_updater_object::<_, props::types::PrintValue>(
+                PropAlias::new(TextInputProps::Value.local_idx()),
+            ),
+            TextInputProps::Answered => {
+                as_updater_object::<_, props::types::Answered>(AnsweredProp::new())
+            }
+            TextInputProps::Response => as_updater_object::<_, props::types::Response>(
+                PropAlias::new(TextInputProps::Value.local_idx()),
+            ),
         }
     }
 }
@@ -166,4 +220,13 @@ impl ComponentOnAction for TextInput {
             }
         }
     }
+
+    fn get_keyboard_shortcuts(&self) -> Vec<KeyboardShortcut> {
+        // Pressing `Enter` in the input commits the value that was typed.
+        vec![KeyboardShortcut::new(
+            "Enter",
+            "updateValue",
+            ShortcutScope::Focused,
+        )]
+    }
 }
@@ -0,0 +1,60 @@
+use super::super::*;
+use crate::components::{doenet::text_input::TextInputProps, prelude::*};
+
+#[derive(Debug, Default)]
+pub struct AnsweredProp {}
+
+impl AnsweredProp {
+    pub fn new() -> Self {
+        AnsweredProp {}
+    }
+}
+
+/// Structure to hold data generated from the data queries
+#[derive(TryFromDataQueryResults)]
+#[data_query(query_trait = DataQueries)]
+#[derive(TestDataQueryTypes)]
+#[owning_component(TextInput)]
+struct RequiredData {
+    /// The `value` prop of this `text_input`.
+    value: PropView<prop_type::String>,
+
+    /// The `prefill` prop of this `text_input`, which `value` starts from before any response.
+    prefill: PropView<prop_type::String>,
+}
+
+impl DataQueries for RequiredData {
+    fn value_query() -> DataQuery {
+        DataQuery::Prop {
+            source: PropSource::Me,
+            prop_specifier: TextInputProps::Value.local_idx().into(),
+        }
+    }
+
+    fn prefill_query() -> DataQuery {
+        DataQuery::Prop {
+            source: PropSource::Me,
+            prop_specifier: TextInputProps::Prefill.local_idx().into(),
+        }
+    }
+}
+
+impl PropUpdater for AnsweredProp {
+    type PropType = props::types::Answered;
+
+    fn data_queries(&self) -> Vec<DataQuery> {
+        RequiredData::to_data_queries()
+    }
+
+    fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+        let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+
+        // A value that is blank or still equal to the prefill is not counted as a response.
+        let value = &required_data.value;
+        PropCalcResult::Calculated(
+            !value.came_from_default
+                && !value.value.trim().is_empty()
+                && *value.value != *required_data.prefill.value,
+        )
+    }
+}
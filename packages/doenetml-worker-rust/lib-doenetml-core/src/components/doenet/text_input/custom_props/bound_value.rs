@@ -0,0 +1,81 @@
+use super::super::*;
+use crate::components::{doenet::text_input::TextInputProps, prelude::*};
+
+#[derive(Debug, Default)]
+pub struct BoundValueProp {}
+
+impl BoundValueProp {
+    pub fn new() -> Self {
+        BoundValueProp {}
+    }
+}
+
+/// Structure to hold data generated from the data queries
+#[derive(TryFromDataQueryResults, IntoDataQueryResults)]
+#[data_query(query_trait = DataQueries)]
+#[derive(TestDataQueryTypes)]
+#[owning_component(TextInput)]
+struct RequiredData {
+    /// The value coming from any `bindValueTo` attribute that was specified on the `text_input`.
+    bind_value_to: PropView<prop_type::String>,
+
+    /// The value coming from any children of the `text_input`.
+    value_from_children: PropView<prop_type::String>,
+}
+
+impl DataQueries for RequiredData {
+    fn bind_value_to_query() -> DataQuery {
+        DataQuery::Prop {
+            source: PropSource::Me,
+            prop_specifier: TextInputProps::BindValueTo.local_idx().into(),
+        }
+    }
+
+    fn value_from_children_query() -> DataQuery {
+        DataQuery::Prop {
+            source: PropSource::Me,
+            prop_specifier: TextInputProps::ValueFromChildren.local_idx().into(),
+        }
+    }
+}
+
+impl PropUpdater for BoundValueProp {
+    type PropType = props::types::BoundValue;
+
+    fn data_queries(&self) -> Vec<DataQuery> {
+        RequiredData::to_data_queries()
+    }
+
+    fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+        let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+
+        // A `bindValueTo` attribute takes precedence over any children.
+        if !required_data.bind_value_to.came_from_default {
+            PropCalcResult::Calculated(required_data.bind_value_to.value)
+        } else if !required_data.value_from_children.came_from_default {
+            PropCalcResult::Calculated(required_data.value_from_children.value)
+        } else {
+            // The value isn't bound to anything,
+            // so the `value` and `immediate_value` props will ignore this prop.
+            PropCalcResult::FromDefault(required_data.value_from_children.value)
+        }
+    }
+
+    fn invert(
+        &self,
+        data: DataQueryResults,
+        requested_value: Self::PropType,
+        _is_direct_change_from_action: bool,
+    ) -> Result<DataQueryResults, InvertError> {
+        let mut desired = RequiredData::try_new_desired(&data).unwrap();
+        let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+
+        if !required_data.bind_value_to.came_from_default {
+            desired.bind_value_to.change_to(requested_value);
+        } else {
+            desired.value_from_children.change_to(requested_value);
+        }
+
+        Ok(desired.into_data_query_results())
+    }
+}
@@ -17,15 +17,15 @@ impl ImmediateValueProp {
 #[owning_component(TextInput)]
 struct RequiredData {
     /// An independent state variable (that doesn't have any dependencies)
-    /// that stores `immediate_value` when it isn't synced with `value_from_children`
+    /// that stores `immediate_value` when it isn't synced with `bound_value`
     independent_state: PropView<prop_type::String>,
 
     /// Ths `sync_value_to_immediate_value` prop from this `text_input`.
     /// If `true`, then `value` is synchronized to `immediate_value`.
     sync_value_to_immediate_value: PropView<prop_type::Boolean>,
 
-    /// The value coming from any children of the `text_input`.
-    value_from_children: PropView<prop_type::String>,
+    /// The value the `text_input` is bound to, from a `bindValueTo` attribute or from any children.
+    bound_value: PropView<prop_type::String>,
 
     /// The value coming from any `prefill` attribute that was specified on the `text_input`
     prefill: PropView<prop_type::String>,
@@ -43,10 +43,10 @@ impl DataQueries for RequiredData {
         }
     }
 
-    fn value_from_children_query() -> DataQuery {
+    fn bound_value_query() -> DataQuery {
         DataQuery::Prop {
             source: PropSource::Me,
-            prop_specifier: TextInputProps::ValueFromChildren.local_idx().into(),
+            prop_specifier: TextInputProps::BoundValue.local_idx().into(),
         }
     }
 
@@ -70,26 +70,26 @@ impl PropUpdater for ImmediateValueProp {
 
         // for simplicity, we don't work out scenarios where immediate_value didn't change,
         // as it typically does change if one of its dependencies changed
-        let immediate_value = if !required_data.value_from_children.came_from_default
+        let immediate_value = if !required_data.bound_value.came_from_default
             && required_data.sync_value_to_immediate_value.value
         {
             // The value prop is synchronized with the immediate_value prop,
             // which happens when invert is called on the value prop,
             // (which happens, for example, when the user presses enter in text input or it loses focus).
-            // Moreover, since there is a non-default value coming from the children,
-            // this means the value prop is calculating its value from the children.
-            // The immediate_value prop must therefore also calculate its value from the children.
-            required_data.value_from_children.value
+            // Moreover, since there is a non-default bound value,
+            // this means the value prop is calculating its value from the bound value.
+            // The immediate_value prop must therefore also calculate its value from the bound value.
+            required_data.bound_value.value
         } else if required_data.independent_state.came_from_default {
             // If the independent_state variable hasn't been changed from its default,
-            // and we're not synchronized to value_from_children,
+            // and we're not synchronized to bound_value,
             // then we set immediate_value from prefill.
             // This is the initial situation before any interactions involving the text input have occurred.
             required_data.prefill.value
         } else {
             // Since the independent_state is no longer at its default,
             // some interactions with the text input have occurred and the immediate_value prop
-            // is now tracking its independent_state variable whenever not synchronized to value_from_children.
+            // is now tracking its independent_state variable whenever not synchronized to bound_value.
             // This is the situation once interactions with the text input have begun.
             required_data.independent_state.value
         };
@@ -117,16 +117,16 @@ impl PropUpdater for ImmediateValueProp {
             if required_data.sync_value_to_immediate_value.value {
                 desired.sync_value_to_immediate_value.change_to(false);
             }
-        } else if !required_data.value_from_children.came_from_default
+        } else if !required_data.bound_value.came_from_default
             && required_data.sync_value_to_immediate_value.value
         {
-            // The value of the text input is being calculated from its children,
+            // The value of the text input is being calculated from its bound value,
             // but this invert() function is being called for a reason
             // other than a user typing into the text input.
             // (This means that immediate_value is being changed due to another component extending from it.)
             // Since we are in this situation and sync_value_to_immediate_value is true,
-            // we change value_from_children so that immediate_value is changed.
-            desired.value_from_children.change_to(requested_value);
+            // we change bound_value so that immediate_value is changed.
+            desired.bound_value.change_to(requested_value);
         }
 
         Ok(desired.into_data_query_results())
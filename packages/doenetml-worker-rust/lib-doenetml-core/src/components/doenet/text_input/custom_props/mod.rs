@@ -1,5 +1,9 @@
+mod answered;
+mod bound_value;
 mod immediate_value;
 mod value;
 
+pub use answered::*;
+pub use bound_value::*;
 pub use immediate_value::*;
 pub use value::*;
@@ -18,7 +18,7 @@ impl ValueProp {
 struct RequiredData {
     /// An independent state variable (that doesn't have any dependencies)
     /// that stores `value` when it isn't synced with 'immediate_value`
-    /// or being determined by `value_from_children`.
+    /// or being determined by `bound_value`.
     independent_state: PropView<prop_type::String>,
 
     /// The `immediate_value` prop of this `text_input`.
@@ -28,8 +28,8 @@ struct RequiredData {
     /// If `true`, then `value` is synchronized to `immediate_value`.
     sync_value_to_immediate_value: PropView<prop_type::Boolean>,
 
-    /// The value coming from any children of the `text_input`.
-    value_from_children: PropView<prop_type::String>,
+    /// The value the `text_input` is bound to, from a `bindValueTo` attribute or from any children.
+    bound_value: PropView<prop_type::String>,
 
     /// The value coming from any `prefill` attribute that was specified on the `text_input`
     prefill: PropView<prop_type::String>,
@@ -54,10 +54,10 @@ impl DataQueries for RequiredData {
         }
     }
 
-    fn value_from_children_query() -> DataQuery {
+    fn bound_value_query() -> DataQuery {
         DataQuery::Prop {
             source: PropSource::Me,
-            prop_specifier: TextInputProps::ValueFromChildren.local_idx().into(),
+            prop_specifier: TextInputProps::BoundValue.local_idx().into(),
         }
     }
 
@@ -83,16 +83,16 @@ impl PropUpdater for ValueProp {
         // because this calculate_old() function will get called whenever immediate_value is changed
         // even though that often does not influence value.
 
-        if !required_data.value_from_children.came_from_default {
-            // The text input has children, so the value prop is locked to be the value of those children.
-            return if required_data.value_from_children.changed {
-                PropCalcResult::Calculated(required_data.value_from_children.value)
+        if !required_data.bound_value.came_from_default {
+            // The text input is bound to a value, so the value prop is locked to be that value.
+            return if required_data.bound_value.changed {
+                PropCalcResult::Calculated(required_data.bound_value.value)
             } else {
                 PropCalcResult::NoChange
             };
         }
 
-        // Given that the text input does not have any children, value will be determined by
+        // Given that the text input is not bound to a value, value will be determined by
         // - immediate_value if value is synced to immediate_value, else
         // - prefill if no interactions with the text input have occurred yet, else
         // - value's independent state variable in all other cases
@@ -102,13 +102,8 @@ impl PropUpdater for ValueProp {
         // and the other prop affecting the value did not change,
         // we'll report that value does not change.
 
-        // TODO: this algorithm is not properly returning NoChange when a user is typing in the textInput
-        // because sync_value_to_immediate_value_changed ends up being true
-        // even though sync_value_to_immediate_value is staying at false.
-        // Since the invert() of immediate_value keeps setting sync_value_to_immediate_value to false,
-        // and we don't yet have a mechanism to check if the value actually changed,
-        // changed_since_last_viewed() returns true.
-        // To improve efficiency, we could check for changes on types like bool where the check is fast.
+        // Setting a prop to the value it already has is not reported as a change,
+        // so while a user is typing, sync_value_to_immediate_value stays false and is not changed.
 
         let sync_value_to_immediate_value_changed =
             required_data.sync_value_to_immediate_value.changed;
@@ -138,7 +133,7 @@ impl PropUpdater for ValueProp {
             }
         } else {
             // We are in the typical state when a user is interacting with the text input
-            // that has no children.
+            // that is not bound to a value.
             // The value state variable stays at the value stored in independent state
             // until the user presses enter or the text input loses focus,
             // at which point invert() below will be called,
@@ -161,11 +156,11 @@ impl PropUpdater for ValueProp {
         let mut desired = RequiredData::try_new_desired(&data).unwrap();
         let required_data = RequiredData::try_from_data_query_results(data).unwrap();
 
-        if required_data.value_from_children.came_from_default {
+        if required_data.bound_value.came_from_default {
             desired.independent_state.change_to(requested_value.clone());
             desired.immediate_value.change_to(requested_value);
         } else {
-            desired.value_from_children.change_to(requested_value);
+            desired.bound_value.change_to(requested_value);
         }
         desired.sync_value_to_immediate_value.change_to(true);
 
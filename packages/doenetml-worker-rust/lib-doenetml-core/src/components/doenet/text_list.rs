@@ -0,0 +1,51 @@
+use crate::components::doenet::text::Text;
+use crate::components::prelude::*;
+use crate::general_prop::{ChildCountProp, RenderedChildrenPassthroughProp};
+use crate::props::UpdaterObject;
+
+/// The `<textList>` component renders a list of texts. Its text children
+/// are split into items, each of which becomes a `<text>`, e.g., `<textList>red, blue</textList>` has two items.
+#[component(name = TextList, string_children_become = Text)]
+mod component {
+
+    use crate::general_prop::BooleanProp;
+
+    enum Props {
+        /// Whether the `<textList>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+        #[prop(value_type = PropValueType::AnnotatedContentRefs, profile = PropProfile::RenderedChildren)]
+        RenderedChildren,
+        /// The number of items in the `<textList>`.
+        #[prop(value_type = PropValueType::Integer, is_public)]
+        Count,
+    }
+
+    enum Attributes {
+        /// Whether the `<textList>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+    }
+}
+
+pub use component::TextList;
+pub use component::TextListActions;
+pub use component::TextListAttributes;
+pub use component::TextListProps;
+
+impl PropGetUpdater for TextListProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            TextListProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            TextListProps::RenderedChildren => as_updater_object::<
+                _,
+                component::props::types::RenderedChildren,
+            >(RenderedChildrenPassthroughProp::new()),
+            TextListProps::Count => as_updater_object::<_, component::props::types::Count>(
+                ChildCountProp::new(Text::NAME),
+            ),
+        }
+    }
+}
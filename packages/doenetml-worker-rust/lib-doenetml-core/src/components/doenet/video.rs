@@ -0,0 +1,199 @@
+use std::rc::Rc;
+
+use crate::components::prelude::*;
+use crate::general_prop::{BooleanProp, IndependentProp, StringProp};
+use crate::props::UpdaterObject;
+
+/// The `state` of a `<video>` that is playing.
+pub const VIDEO_PLAYING: &str = "playing";
+/// The `state` of a `<video>` that is paused, or has not been started.
+pub const VIDEO_PAUSED: &str = "paused";
+
+/// The `<video>` component plays the video at the URL given by its `source` attribute.
+///
+/// The renderer reports playback with the `recordVideoStarted`, `recordVideoWatched`, and `recordVideoPaused` actions.
+/// These change the essential data of `state`, `duration`, and `segmentsWatched`,
+/// so hosts can persist how much of the video has been watched.
+#[component(name = Video, needs_pointer_events, print_unsupported)]
+mod component {
+
+    use super::*;
+
+    enum Props {
+        /// Whether the `<video>` should be hidden.
+        #[prop(value_type = PropValueType::Boolean, profile = PropProfile::Hidden)]
+        Hidden,
+
+        /// The URL of the video.
+        #[prop(value_type = PropValueType::String, is_public, for_render)]
+        Source,
+
+        /// Whether the video is `playing` or `paused`. A video that has not been started is `paused`.
+        #[prop(value_type = PropValueType::String, is_public, for_render)]
+        State,
+
+        /// The length of the video in seconds, or `NaN` if the video has not been started.
+        #[prop(value_type = PropValueType::Number, is_public)]
+        Duration,
+
+        /// The parts of the video that have been watched, as pairs `[begin, end]` of times in seconds.
+        /// The pairs are sorted and do not overlap.
+        #[prop(value_type = PropValueType::PropVec, is_public)]
+        SegmentsWatched,
+    }
+
+    enum Attributes {
+        /// Whether the `<video>` should be hidden.
+        #[attribute(prop = BooleanProp, default = false)]
+        Hide,
+        /// The URL of the video.
+        #[attribute(prop = StringProp, default = String::new())]
+        Source,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    #[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+    #[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+    #[serde(expecting = "`duration` must be a number")]
+    pub struct VideoStartedActionArgs {
+        pub duration: prop_type::Number,
+    }
+
+    #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+    #[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+    #[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+    #[serde(rename_all = "camelCase")]
+    #[serde(expecting = "`beginTime` and `endTime` must be numbers")]
+    pub struct VideoWatchedActionArgs {
+        pub begin_time: prop_type::Number,
+        pub end_time: prop_type::Number,
+    }
+
+    enum Actions {
+        /// Record that the video started playing, along with its length in seconds.
+        RecordVideoStarted(ActionBody<VideoStartedActionArgs>),
+        /// Record that the video was watched from `beginTime` to `endTime`, in seconds.
+        RecordVideoWatched(ActionBody<VideoWatchedActionArgs>),
+        /// Record that the video was paused.
+        RecordVideoPaused,
+    }
+}
+
+pub use component::Video;
+pub use component::VideoActions;
+pub use component::VideoAttributes;
+pub use component::VideoProps;
+pub use component::VideoStartedActionArgs;
+pub use component::VideoWatchedActionArgs;
+
+impl PropGetUpdater for VideoProps {
+    fn get_updater(&self) -> UpdaterObject {
+        match self {
+            VideoProps::Hidden => as_updater_object::<_, component::props::types::Hidden>(
+                component::attrs::Hide::get_prop_updater(),
+            ),
+            VideoProps::Source => as_updater_object::<_, component::props::types::Source>(
+                component::attrs::Source::get_prop_updater(),
+            ),
+            VideoProps::State => as_updater_object::<_, component::props::types::State>(
+                IndependentProp::new(Rc::new(VIDEO_PAUSED.to_string())),
+            ),
+            VideoProps::Duration => as_updater_object::<_, component::props::types::Duration>(
+                IndependentProp::new(prop_type::Number::NAN),
+            ),
+            VideoProps::SegmentsWatched => as_updater_object::<
+                _,
+                component::props::types::SegmentsWatched,
+            >(IndependentProp::new(Rc::default())),
+        }
+    }
+}
+
+impl ComponentOnAction for Video {
+    fn on_action(
+        &self,
+        action: ActionsEnum,
+        query_prop: ActionQueryProp,
+    ) -> Result<Vec<UpdateFromAction>, String> {
+        // The type of `action` should have already been verified, so an
+        // error here is a programming logic error, not an API error.
+        let action: VideoActions = action.try_into()?;
+
+        match action {
+            VideoActions::RecordVideoStarted(ActionBody { args }) => Ok(vec![
+                UpdateFromAction {
+                    local_prop_idx: VideoProps::State.local_idx(),
+                    requested_value: VIDEO_PLAYING.into(),
+                },
+                UpdateFromAction {
+                    local_prop_idx: VideoProps::Duration.local_idx(),
+                    requested_value: args.duration.into(),
+                },
+            ]),
+            VideoActions::RecordVideoWatched(ActionBody { args }) => {
+                let segments: prop_type::PropVec = query_prop
+                    .get_local_prop(VideoProps::SegmentsWatched.local_idx())
+                    .value
+                    .try_into()
+                    .unwrap();
+                let segments = add_watched_segment(
+                    segments
+                        .iter()
+                        .filter_map(segment_from_prop_value)
+                        .collect(),
+                    args.begin_time,
+                    args.end_time,
+                );
+
+                Ok(vec![UpdateFromAction {
+                    local_prop_idx: VideoProps::SegmentsWatched.local_idx(),
+                    requested_value: PropValue::PropVec(Rc::new(
+                        segments
+                            .into_iter()
+                            .map(|(begin, end)| {
+                                PropValue::PropVec(Rc::new(vec![
+                                    PropValue::Number(begin),
+                                    PropValue::Number(end),
+                                ]))
+                            })
+                            .collect(),
+                    )),
+                }])
+            }
+            VideoActions::RecordVideoPaused => Ok(vec![UpdateFromAction {
+                local_prop_idx: VideoProps::State.local_idx(),
+                requested_value: VIDEO_PAUSED.into(),
+            }]),
+        }
+    }
+}
+
+/// The pair `[begin, end]` stored in `segmentsWatched`, if `value` is such a pair.
+fn segment_from_prop_value(value: &PropValue) -> Option<(f64, f64)> {
+    match value {
+        PropValue::PropVec(pair) => match pair.as_slice() {
+            [PropValue::Number(begin), PropValue::Number(end)] => Some((*begin, *end)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Add the segment from `begin` to `end` to the sorted, non-overlapping `segments`,
+/// merging it with any segments that it overlaps or touches.
+/// A segment that is empty or not finite is ignored.
+pub fn add_watched_segment(mut segments: Vec<(f64, f64)>, begin: f64, end: f64) -> Vec<(f64, f64)> {
+    if begin.is_finite() && end.is_finite() && begin < end {
+        segments.push((begin, end));
+    }
+    segments.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut merged: Vec<(f64, f64)> = Vec::with_capacity(segments.len());
+    for (begin, end) in segments {
+        match merged.last_mut() {
+            Some(last) if begin <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((begin, end)),
+        }
+    }
+    merged
+}
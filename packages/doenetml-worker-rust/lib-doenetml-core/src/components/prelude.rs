@@ -36,7 +36,8 @@ pub use crate::components::{
 
 pub use crate::components::{ActionsEnum, ComponentEnum};
 pub use crate::dast::{
-    DastAttribute, ElementData, FlatDastElement, FlatDastElementContent, FlatDastElementUpdate,
+    ComponentCapabilities, DastAttribute, ElementData, FlatDastElement, FlatDastElementContent,
+    FlatDastElementUpdate, KeyboardShortcut, ShortcutScope,
 };
 
 pub use crate::core::{graph_node::GraphNode, props::PropValue};
@@ -3,18 +3,35 @@
 
 use crate::{
     components::prelude::*,
-    props::{ForRenderOutputs, RenderContext, UpdaterObject},
+    dast::{
+        DiagnosticCode,
+        flat_dast::{ErrorType, FlatError},
+    },
+    props::{ForRenderOutputs, RenderContext, RenderMode, UpdaterObject},
 };
 
 #[derive(Debug, Default, Clone)]
 pub struct _Error {
     pub message: String,
+    pub error_type: ErrorType,
+    pub code: Option<DiagnosticCode>,
+    pub suggestion: Option<String>,
 }
 
 impl _Error {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Create an `_Error` with the information from `error`.
+    pub fn from_flat_error(error: &FlatError) -> Self {
+        Self {
+            message: error.message.clone(),
+            error_type: error.error_type,
+            code: error.code,
+            suggestion: error.suggestion.clone(),
+        }
+    }
 }
 
 impl ComponentNode for _Error {
@@ -45,6 +62,7 @@ impl ComponentProps for _Error {
     fn get_for_render_local_prop_indices(
         &self,
         _render_context: RenderContext,
+        _render_mode: RenderMode,
     ) -> impl Iterator<Item = LocalPropIdx> {
         vec![].into_iter()
     }
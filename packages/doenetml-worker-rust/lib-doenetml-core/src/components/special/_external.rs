@@ -1,10 +1,14 @@
 //! An external component is a component not recognized by DoenetML. It is left untouched by the DoenetML processor and returned (mostly)
 //! as-is when converted into `FlatDast`.
+//!
+//! External components that are not HTML tags, e.g., a component from a newer version of DoenetML, are _unknown_.
+//! They keep their children and attributes, but are marked in the `FlatDast` so that renderers can show a placeholder.
 
 use crate::{
     components::prelude::*,
     general_prop::RenderedChildrenPassthroughProp,
-    props::{ForRenderOutputs, RenderContext, UpdaterObject},
+    props::{ForRenderOutputs, RenderContext, RenderMode, UpdaterObject},
+    utils::is_allowed_html_tag,
 };
 
 #[derive(Debug, Default, Clone)]
@@ -20,10 +24,24 @@ impl _External {
     const PROP_FOR_RENDERS: &'static [ForRenderOutputs] = &[ForRenderOutputs {
         in_graph: false,
         in_text: false,
+        only_in_mode: None,
     }];
     const PROP_IS_PUBLICS: &'static [bool] = &[false];
     const PROP_VALUE_TYPES: &'static [PropValueType] = &[PropValueType::ContentRefs];
     const DEFAULT_PROP: Option<LocalPropIdx> = None;
+
+    /// Whether this is an unknown component rather than an HTML tag, such as `<span>`.
+    pub fn is_unknown(&self) -> bool {
+        !is_allowed_html_tag(&self.name)
+    }
+
+    /// The message explaining how an unknown component was handled.
+    pub fn unknown_message(&self) -> String {
+        format!(
+            "`<{}>` is not a DoenetML component, so it was left unchanged",
+            self.name
+        )
+    }
 }
 
 ///The props for this component are: `renderedChildren`
@@ -62,6 +80,7 @@ impl ComponentProps for _External {
     fn get_for_render_local_prop_indices(
         &self,
         _render_context: RenderContext,
+        _render_mode: RenderMode,
     ) -> impl Iterator<Item = LocalPropIdx> {
         vec![].into_iter()
     }
@@ -10,7 +10,7 @@ use std::rc::Rc;
 use crate::{
     components::prelude::*,
     general_prop::IndependentProp,
-    props::{ForRenderOutputs, RenderContext, UpdaterObject},
+    props::{ForRenderOutputs, RenderContext, RenderMode, UpdaterObject},
     state::types::component_refs::ComponentRef,
 };
 
@@ -46,6 +46,7 @@ impl ComponentProps for _Ref {
     fn get_for_render_local_prop_indices(
         &self,
         _render_context: RenderContext,
+        _render_mode: RenderMode,
     ) -> impl Iterator<Item = LocalPropIdx> {
         vec![].into_iter()
     }
@@ -2,6 +2,7 @@ use enum_dispatch::enum_dispatch;
 
 use crate::{
     components::{ComponentEnum, types::LocalPropIdx},
+    dast::ComponentCapabilities,
     props::PropProfile,
 };
 
@@ -37,6 +38,27 @@ pub trait ComponentNode {
         false
     }
 
+    /// The name of the component that the text children of this component become.
+    /// For example, in `<numberList>1 2</numberList>`, the text is split into the items `1` and `2`,
+    /// and each becomes a `<number>` child. In this case `self.string_children_become()` should return `Some("number")`.
+    ///
+    /// If `None` is returned, text children are kept as text.
+    fn string_children_become(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// What a renderer must be able to do to render this component as intended,
+    /// e.g., whether it needs pointer events or can be printed.
+    fn capabilities(&self) -> ComponentCapabilities {
+        ComponentCapabilities::DEFAULT
+    }
+
+    /// Whether this component may only be used when its name is listed in the
+    /// `experimental_components` of the document's flags.
+    fn is_experimental(&self) -> bool {
+        false
+    }
+
     /// A vector of the possible profiles this component provides along with the
     /// index of the prop that you should refer to if you want data satisfying
     /// that profile.
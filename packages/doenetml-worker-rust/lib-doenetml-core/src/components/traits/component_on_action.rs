@@ -2,12 +2,19 @@ use enum_dispatch::enum_dispatch;
 
 use serde::{Deserialize, Serialize};
 
-use crate::components::{
-    ComponentEnum,
-    doenet::{
-        graph::GraphActions, point::PointActions, text::TextActions, text_input::TextInputActions,
+use crate::{
+    components::{
+        ComponentEnum,
+        doenet::{
+            accordion::AccordionActions, answer::AnswerActions, choice_input::ChoiceInputActions,
+            circle::CircleActions, embedded_applet::EmbeddedAppletActions, graph::GraphActions,
+            hint::HintActions, line::LineActions, point::PointActions, polygon::PolygonActions,
+            slider::SliderActions, solution::SolutionActions, tabs::TabsActions, text::TextActions,
+            text_input::TextInputActions, video::VideoActions,
+        },
+        types::{ActionQueryProp, UpdateFromAction},
     },
-    types::{ActionQueryProp, UpdateFromAction},
+    dast::KeyboardShortcut,
 };
 
 use super::{ComponentActions, ComponentNode};
@@ -22,8 +29,20 @@ use super::{ComponentActions, ComponentNode};
 pub enum ActionsEnum {
     Text(TextActions),
     TextInput(TextInputActions),
+    ChoiceInput(ChoiceInputActions),
+    Slider(SliderActions),
+    Answer(AnswerActions),
     Point(PointActions),
+    Line(LineActions),
+    Circle(CircleActions),
+    Polygon(PolygonActions),
     Graph(GraphActions),
+    EmbeddedApplet(EmbeddedAppletActions),
+    Tabs(TabsActions),
+    Accordion(AccordionActions),
+    Hint(HintActions),
+    Solution(SolutionActions),
+    Video(VideoActions),
 }
 
 /// The `ComponentOnAction` trait allows a component to handle actions sent to the component.
@@ -50,4 +69,13 @@ pub trait ComponentOnAction: ComponentNode + ComponentActions {
             self.get_action_names()
         ))
     }
+
+    /// The keyboard shortcuts that call actions of this component.
+    /// Each shortcut's `action_name` must be in the vector returned by `get_action_names()`.
+    ///
+    /// Conflicts between document-wide shortcuts of different components are resolved by core,
+    /// so the shortcuts sent to the renderers may be a subset of these.
+    fn get_keyboard_shortcuts(&self) -> Vec<KeyboardShortcut> {
+        Vec::new()
+    }
 }
@@ -1,4 +1,8 @@
-use crate::{components::types::LocalPropIdx, core::props::PropDefinition, props::RenderContext};
+use crate::{
+    components::types::LocalPropIdx,
+    core::props::PropDefinition,
+    props::{RenderContext, RenderMode},
+};
 
 /// The main `Component` struct, which wraps all component variants, implements
 /// `ComponentProps`. This is used by `Core` to initialize props.
@@ -28,8 +32,10 @@ pub trait ComponentProps {
     /// Get the vector of the indices of all this component's props
     /// that have been marked `for_render`,
     /// depending on the render context (either `in_graph` or `in_text`)
+    /// and the mode the document is rendered in.
     fn get_for_render_local_prop_indices(
         &self,
         render_context: RenderContext,
+        render_mode: RenderMode,
     ) -> impl Iterator<Item = LocalPropIdx>;
 }
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    DocumentModel, core::props::PropValue, graph_node::GraphNode, props::cache::PropWithMeta,
+    DocumentModel,
+    core::props::PropValue,
+    graph::directed_graph::Taggable,
+    graph_node::{GraphNode, GraphNodeLookup},
+    props::cache::PropWithMeta,
 };
 
 use super::ActionsEnum;
@@ -62,6 +66,8 @@ impl<T: IntoGraphNode> From<T> for PropDefinitionIdx {
     Deserialize,
     Default,
     Hash,
+    PartialOrd,
+    Ord,
 )]
 #[serde(transparent)]
 #[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
@@ -215,13 +221,20 @@ pub struct UpdateFromAction {
 pub struct ActionQueryProp<'a> {
     component_idx: ComponentIdx,
     document_model: &'a DocumentModel,
+    /// Values requested by earlier actions of the same batch, which have not been applied yet.
+    pending_values: &'a GraphNodeLookup<PropValue>,
 }
 
 impl<'a> ActionQueryProp<'a> {
-    pub fn new(component_idx: ComponentIdx, document_model: &'a DocumentModel) -> Self {
+    pub fn new(
+        component_idx: ComponentIdx,
+        document_model: &'a DocumentModel,
+        pending_values: &'a GraphNodeLookup<PropValue>,
+    ) -> Self {
         ActionQueryProp {
             component_idx,
             document_model,
+            pending_values,
         }
     }
 
@@ -229,6 +242,9 @@ impl<'a> ActionQueryProp<'a> {
     ///
     /// **Note**: the `changed` meta data indicates whether or not this prop has changed
     /// since *any* action of this component called `get_local_prop()`.
+    ///
+    /// If an earlier action of the same batch requested a value for the prop, that value is returned,
+    /// since the changes of the batch are made only after all its actions have run.
     pub fn get_local_prop(&self, local_prop_idx: LocalPropIdx) -> PropWithMeta {
         let prop_pointer = PropPointer {
             component_idx: self.component_idx,
@@ -236,10 +252,24 @@ impl<'a> ActionQueryProp<'a> {
         };
         let prop_node = self.document_model.prop_pointer_to_prop_node(prop_pointer);
 
+        if let Some(pending_value) = self.pending_values.get_tag(&prop_node) {
+            return PropWithMeta {
+                value: pending_value.clone(),
+                came_from_default: false,
+                changed: true,
+                origin: Some(prop_node),
+            };
+        }
+
         let origin = GraphNode::Component(self.component_idx.as_usize());
 
         self.document_model.get_prop(prop_node, origin)
     }
+
+    /// Request that this component receive keyboard focus once the changes from the action are rendered.
+    pub fn request_focus(&self) {
+        self.document_model.request_focus(self.component_idx);
+    }
 }
 
 /// The `camelCase` name of an attribute.
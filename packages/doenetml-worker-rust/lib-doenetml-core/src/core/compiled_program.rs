@@ -0,0 +1,207 @@
+//! A document compiled once so that many sessions, e.g., one per student, can be started from it.
+//!
+//! Flattening the DAST and expanding its references gives the same result for every session,
+//! so a server or worker can compile a document once and cheaply start a `Core` for each student.
+//! Each session still builds its own components and dependency graph, since dependencies are created
+//! lazily as props are resolved and so are part of the mutable state of a session.
+//!
+//! A compiled program can also be stored, e.g., in a cache keyed by [`CompiledProgram::content_hash`],
+//! so that loading the same content again skips compilation. The stored bytes consist of a header followed by the payload:
+//! - 4 bytes: the magic bytes `DMLP`,
+//! - 1 byte: the version of the format (currently `2`),
+//! - 32 bytes: the content hash of the document,
+//! - 32 bytes: the SHA-256 digest of the content hash followed by the payload,
+//! - the payload: the normalized document and resolver serialized as JSON.
+//!
+//! Since the cache is shared across content, the content hash is a SHA-256 digest, and restoring a program
+//! checks both that it was compiled from the expected content and that its bytes are intact.
+
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::dast::{
+    DastRoot,
+    flat_dast::{FlatRoot, NormalizedRoot},
+    ref_expand::Expander,
+    ref_resolve::Resolver,
+};
+
+use super::{Core, component_builder::ComponentBuilder};
+
+const MAGIC: &[u8; 4] = b"DMLP";
+const FORMAT_VERSION: u8 = 2;
+const HASH_LEN: usize = 32;
+const HEADER_LEN: usize = 5 + 2 * HASH_LEN;
+
+/// The SHA-256 hash of a document, as given by [`CompiledProgram::hash_content`].
+pub type ContentHash = [u8; HASH_LEN];
+
+/// The normalized document and its resolver, shared by all sessions started from it.
+/// Cloning a `CompiledProgram` is cheap.
+#[derive(Debug, Clone)]
+pub struct CompiledProgram {
+    content_hash: ContentHash,
+    normalized_root: Rc<NormalizedRoot>,
+    resolver: Rc<Resolver>,
+}
+
+/// An error compiling a `CompiledProgram` or restoring it from bytes.
+#[derive(Debug, Error, PartialEq)]
+pub enum ProgramBlobError {
+    #[error("the document could not be serialized to compute its content hash: {0}")]
+    UnserializableDocument(String),
+    #[error("the data is not a compiled DoenetML program")]
+    NotAProgramBlob,
+    #[error("compiled program version {0} is not supported")]
+    UnsupportedVersion(u8),
+    #[error("the compiled program was compiled from different content")]
+    ContentHashMismatch,
+    #[error("the compiled program is corrupted")]
+    Corrupted,
+    #[error("the compiled program is invalid: {0}")]
+    InvalidProgram(String),
+}
+
+/// The payload of the bytes of a `CompiledProgram`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProgramPayloadRef<'a> {
+    normalized_root: &'a NormalizedRoot,
+    resolver: &'a Resolver,
+}
+
+/// The payload of the bytes of a `CompiledProgram`, as restored.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProgramPayload {
+    normalized_root: NormalizedRoot,
+    resolver: Resolver,
+}
+
+impl CompiledProgram {
+    /// Compile `dast_root` by flattening it and expanding all references to elements (or errors).
+    pub fn compile(dast_root: &DastRoot) -> Result<Self, ProgramBlobError> {
+        let content_hash = Self::hash_content(dast_root)?;
+        let (normalized_root, resolver) = normalize_dast_root(dast_root);
+        Ok(CompiledProgram {
+            content_hash,
+            normalized_root: Rc::new(normalized_root),
+            resolver: Rc::new(resolver),
+        })
+    }
+
+    /// The SHA-256 hash of `dast_root`, which is stable across runs and platforms,
+    /// so that it can be used to look up a stored program before compiling `dast_root`.
+    pub fn hash_content(dast_root: &DastRoot) -> Result<ContentHash, ProgramBlobError> {
+        // Converting to a `serde_json::Value` sorts the keys of all objects,
+        // so the hash does not depend on the order of fields in `dast_root`.
+        let json = serde_json::to_value(dast_root)
+            .map_err(|err| ProgramBlobError::UnserializableDocument(err.to_string()))?
+            .to_string();
+        Ok(Sha256::digest(json.as_bytes()).into())
+    }
+
+    /// The hash, as given by `hash_content`, of the document this program was compiled from.
+    pub fn content_hash(&self) -> &ContentHash {
+        &self.content_hash
+    }
+
+    /// The normalized document that sessions are built from.
+    pub fn normalized_root(&self) -> &NormalizedRoot {
+        &self.normalized_root
+    }
+
+    /// Serialize the program so that it can be stored and restored with `from_bytes`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let payload = serde_json::to_vec(&ProgramPayloadRef {
+            normalized_root: &self.normalized_root,
+            resolver: &self.resolver,
+        })
+        .expect("A compiled program can always be serialized");
+
+        let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(FORMAT_VERSION);
+        bytes.extend_from_slice(&self.content_hash);
+        bytes.extend_from_slice(&payload_digest(&self.content_hash, &payload));
+        bytes.extend(payload);
+        bytes
+    }
+
+    /// Restore a program from bytes produced by `to_bytes`, e.g., bytes stored in a cache under `content_hash`.
+    /// The program must have been compiled from the content with hash `content_hash`, and the bytes must be intact.
+    pub fn from_bytes(bytes: &[u8], content_hash: &ContentHash) -> Result<Self, ProgramBlobError> {
+        if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+            return Err(ProgramBlobError::NotAProgramBlob);
+        }
+        let version = bytes[4];
+        if version != FORMAT_VERSION {
+            return Err(ProgramBlobError::UnsupportedVersion(version));
+        }
+
+        let (stored_hash, rest) = bytes[5..].split_at(HASH_LEN);
+        let (digest, payload) = rest.split_at(HASH_LEN);
+        if stored_hash != content_hash {
+            return Err(ProgramBlobError::ContentHashMismatch);
+        }
+        if digest != payload_digest(content_hash, payload).as_slice() {
+            return Err(ProgramBlobError::Corrupted);
+        }
+
+        let payload: ProgramPayload = serde_json::from_slice(payload)
+            .map_err(|err| ProgramBlobError::InvalidProgram(err.to_string()))?;
+        Ok(CompiledProgram {
+            content_hash: *content_hash,
+            normalized_root: Rc::new(payload.normalized_root),
+            resolver: Rc::new(payload.resolver),
+        })
+    }
+}
+
+/// The SHA-256 digest of `content_hash` followed by `payload`, which detects changes to either.
+fn payload_digest(content_hash: &ContentHash, payload: &[u8]) -> [u8; HASH_LEN] {
+    Sha256::new()
+        .chain_update(content_hash)
+        .chain_update(payload)
+        .finalize()
+        .into()
+}
+
+/// Flatten `dast_root` and expand all references, returning the `NormalizedRoot`
+/// along with the `Resolver` used to look up its elements by name.
+pub(super) fn normalize_dast_root(dast_root: &DastRoot) -> (NormalizedRoot, Resolver) {
+    let mut flat_root = FlatRoot::from_dast(dast_root);
+    let mut resolver = Expander::expand(&mut flat_root);
+    flat_root.compactify(Some(&mut resolver));
+    (flat_root.into_normalized_root(), resolver)
+}
+
+impl Core {
+    /// Start a new session of `program`, with its own state, e.g., for one student.
+    pub fn new_session(program: &CompiledProgram) -> Self {
+        let mut core = Core::new();
+        core.init_from_compiled_program(program);
+        core
+    }
+
+    /// Initialize from `program` as in `init_from_dast_root`, without compiling the document again.
+    /// As with `init_from_dast_root`, settings such as the flags and the environment are kept.
+    pub fn init_from_compiled_program(&mut self, program: &CompiledProgram) {
+        self.reset_document();
+        // The resolver is updated as nodes are added to or deleted from the session, so each session has its own copy.
+        self.resolver = Some(Resolver::clone(&program.resolver));
+
+        let component_builder = ComponentBuilder::from_normalized_root(
+            &program.normalized_root,
+            self.document_model.get_flags().experimental_components,
+        );
+        self.document_model.init_from_builder(component_builder);
+    }
+}
+
+#[cfg(test)]
+#[path = "compiled_program.test.rs"]
+mod test;
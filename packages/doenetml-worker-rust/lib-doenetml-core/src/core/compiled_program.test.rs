@@ -0,0 +1,155 @@
+use crate::{
+    components::types::ComponentIdx, extract_text, props::PropValue,
+    test_utils::dast_root_no_position,
+};
+
+use super::*;
+
+const SOURCE: &str = r#"<textInput name="ti" prefill="start"/><text name="t">$ti</text>"#;
+
+#[test]
+fn sessions_render_like_a_directly_initialized_core() {
+    let dast_root = dast_root_no_position(SOURCE);
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let program = CompiledProgram::compile(&dast_root).unwrap();
+    let mut session = Core::new_session(&program);
+
+    assert_eq!(
+        extract_text(&session.to_flat_dast()),
+        extract_text(&core.to_flat_dast())
+    );
+    // The resolver is available, so components can be found by name.
+    assert_eq!(
+        session.get_prop_value("t", "value"),
+        Some(PropValue::String(std::rc::Rc::new("start".to_string())))
+    );
+}
+
+#[test]
+fn sessions_of_a_program_have_independent_state() {
+    let program = CompiledProgram::compile(&dast_root_no_position(SOURCE)).unwrap();
+    let mut first = Core::new_session(&program);
+    let mut second = Core::new_session(&program);
+
+    first
+        .override_prop(
+            ComponentIdx::new(1),
+            "value",
+            serde_json::json!("changed"),
+            "Testing",
+        )
+        .unwrap();
+
+    assert_eq!(extract_text(&first.to_flat_dast()).trim(), "changed");
+    assert_eq!(extract_text(&second.to_flat_dast()).trim(), "start");
+
+    // A session started after the change is unaffected as well.
+    let mut third = Core::new_session(&program);
+    assert_eq!(extract_text(&third.to_flat_dast()).trim(), "start");
+}
+
+#[test]
+fn restored_program_starts_equivalent_sessions() {
+    let dast_root = dast_root_no_position(SOURCE);
+    let program = CompiledProgram::compile(&dast_root).unwrap();
+    let restored =
+        CompiledProgram::from_bytes(&program.to_bytes(), program.content_hash()).unwrap();
+
+    assert_eq!(restored.content_hash(), program.content_hash());
+
+    let mut session = Core::new_session(&program);
+    let mut restored_session = Core::new_session(&restored);
+    assert_eq!(
+        extract_text(&restored_session.to_flat_dast()),
+        extract_text(&session.to_flat_dast())
+    );
+    // The restored resolver finds components by name.
+    assert_eq!(
+        restored_session.get_prop_value("t", "value"),
+        Some(PropValue::String(std::rc::Rc::new("start".to_string())))
+    );
+}
+
+#[test]
+fn content_hash_depends_only_on_content() {
+    let dast_root = dast_root_no_position(SOURCE);
+    let hash = CompiledProgram::hash_content(&dast_root).unwrap();
+
+    assert_eq!(
+        CompiledProgram::compile(&dast_root).unwrap().content_hash(),
+        &hash
+    );
+    assert_eq!(
+        CompiledProgram::hash_content(&dast_root_no_position(SOURCE)).unwrap(),
+        hash
+    );
+    assert_ne!(
+        CompiledProgram::hash_content(&dast_root_no_position(r#"<text>other</text>"#)).unwrap(),
+        hash
+    );
+}
+
+#[test]
+fn invalid_bytes_are_rejected() {
+    let program = CompiledProgram::compile(&dast_root_no_position(SOURCE)).unwrap();
+    let hash = *program.content_hash();
+
+    assert_eq!(
+        CompiledProgram::from_bytes(b"nope", &hash).unwrap_err(),
+        ProgramBlobError::NotAProgramBlob
+    );
+
+    let mut bytes = program.to_bytes();
+    bytes[4] = 9;
+    assert_eq!(
+        CompiledProgram::from_bytes(&bytes, &hash).unwrap_err(),
+        ProgramBlobError::UnsupportedVersion(9)
+    );
+}
+
+#[test]
+fn tampered_bytes_are_rejected() {
+    let program = CompiledProgram::compile(&dast_root_no_position(SOURCE)).unwrap();
+    let hash = *program.content_hash();
+    let bytes = program.to_bytes();
+
+    // A program stored under the hash of different content is not loaded.
+    let other_hash =
+        CompiledProgram::hash_content(&dast_root_no_position(r#"<text>other</text>"#)).unwrap();
+    assert_eq!(
+        CompiledProgram::from_bytes(&bytes, &other_hash).unwrap_err(),
+        ProgramBlobError::ContentHashMismatch
+    );
+
+    // Changing the payload, e.g., the prefill of the text input, is detected.
+    let mut tampered = bytes.clone();
+    let start = tampered
+        .windows(5)
+        .position(|window| window == b"start")
+        .unwrap();
+    tampered[start..start + 5].copy_from_slice(b"evil!");
+    assert_eq!(
+        CompiledProgram::from_bytes(&tampered, &hash).unwrap_err(),
+        ProgramBlobError::Corrupted
+    );
+
+    // So is changing the content hash in the header along with the hash it is looked up by.
+    let mut tampered = bytes.clone();
+    tampered[5] ^= 1;
+    let mut tampered_hash = hash;
+    tampered_hash[0] ^= 1;
+    assert_eq!(
+        CompiledProgram::from_bytes(&tampered, &tampered_hash).unwrap_err(),
+        ProgramBlobError::Corrupted
+    );
+
+    // A truncated payload is detected as well.
+    let mut truncated = bytes;
+    truncated.pop();
+    assert_eq!(
+        CompiledProgram::from_bytes(&truncated, &hash).unwrap_err(),
+        ProgramBlobError::Corrupted
+    );
+}
@@ -1,32 +1,59 @@
 //! Build the `structure_graph` and initialize `components`.
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
-use anyhow::anyhow;
+use thiserror::Error;
 use typed_index_collections::TiVec;
 
 use crate::{
     components::{
-        _Ref, Component, ComponentAttributes, ComponentCommon, ComponentCommonData, ComponentEnum,
-        ComponentNode, ComponentProps,
+        _Error, _Ref, Component, ComponentAttributes, ComponentCommon, ComponentCommonData,
+        ComponentEnum, ComponentNode, ComponentProps, ComponentVariantProps,
+        doenet::{
+            matrix::matrix_entry_indices, number::Number, number_list::NumberList,
+            sequence::sequence_members,
+        },
         prelude::{
             ComponentIdx, Extending, ExtendingPropSource, FlatAttribute, KeyValueIgnoreCase,
             UntaggedContent,
         },
-        types::{PropDefinitionIdx, PropPointer},
+        types::{LocalPropIdx, PropDefinitionIdx, PropPointer},
     },
     dast::{
-        flat_dast::{Index, NormalizedNode, NormalizedRoot, Source},
+        DiagnosticCode, Position as DastPosition,
+        diagnostics::format_did_you_mean,
+        flat_dast::{ErrorType, FlatPathPart, Index, NormalizedNode, NormalizedRoot, Source},
         ref_resolve::RefResolution,
     },
     graph::directed_graph::{DirectedGraph, Taggable},
+    utils::closest_matches,
 };
 
 use super::{
+    document_model::has_independent_state,
     graph_node::{GraphNode, GraphNodeLookup},
     props::{PropDefinition, StringCache},
 };
 
+/// The reason a component could not extend its referent.
+#[derive(Debug, Error)]
+#[error("{message}")]
+struct ExtendingError {
+    message: String,
+    code: DiagnosticCode,
+    suggestion: Option<String>,
+}
+
+impl ExtendingError {
+    fn invalid_source(message: impl Into<String>) -> Self {
+        ExtendingError {
+            message: message.into(),
+            code: DiagnosticCode::InvalidExtendSource,
+            suggestion: None,
+        }
+    }
+}
+
 /// Initialize `structure_graph` and `components` based on a provided `normalized_root`.
 /// This is a utility to assist in the initialization of `Core`.
 pub struct ComponentBuilder {
@@ -50,6 +77,17 @@ pub struct ComponentBuilder {
     /// Stores whether a particular virtual node was created to house the children coming from another component
     /// because it was `extend`ing another component.
     pub children_came_from_extending_marker: GraphNodeLookup<bool>,
+    /// The component that each component extends, if any, e.g., via `$foo` or `extend="$foo.value"`.
+    pub extended_from: TiVec<ComponentIdx, Option<ComponentIdx>>,
+    /// The position in the DoenetML source of each attribute that was specified, keyed by its virtual node.
+    pub attribute_positions: GraphNodeLookup<DastPosition>,
+    /// Components created by the builder rather than written in the document, i.e., from the text children
+    /// of components with `string_children_become()` and the members of `<sequence>`s.
+    /// They are appended to `components` once all the components of the document have been created.
+    generated_components: Vec<Component>,
+    /// The names of the experimental components that may be used. Any other experimental component
+    /// is replaced by an error.
+    experimental_components: Vec<String>,
 }
 
 impl Default for ComponentBuilder {
@@ -68,15 +106,34 @@ impl ComponentBuilder {
             props: TiVec::new(),
             virtual_node_count: 0,
             children_came_from_extending_marker: GraphNodeLookup::new(),
+            extended_from: TiVec::new(),
+            attribute_positions: GraphNodeLookup::new(),
+            generated_components: Vec::new(),
+            experimental_components: Vec::new(),
         }
     }
 
-    pub fn from_normalized_root(normalized_root: &NormalizedRoot) -> Self {
+    /// Build the components of `normalized_root`, allowing the experimental components
+    /// named in `experimental_components` to be used.
+    pub fn from_normalized_root(
+        normalized_root: &NormalizedRoot,
+        experimental_components: Vec<String>,
+    ) -> Self {
         let mut builder = Self::new();
+        builder.experimental_components = experimental_components;
         builder.init_from_normalized_root(normalized_root);
         builder
     }
 
+    /// Whether `component` is experimental and was not enabled by the `experimentalComponents` flag.
+    fn is_disabled_experimental_component(&self, component: &Component) -> bool {
+        component.is_experimental()
+            && !self
+                .experimental_components
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(component.get_component_type()))
+    }
+
     /// Generate a new `VirtualNode` with a unique id.
     fn new_virtual_node(&mut self) -> GraphNode {
         let node = GraphNode::Virtual(self.virtual_node_count);
@@ -92,12 +149,14 @@ impl ComponentBuilder {
     /// Initialize `structure_graph`, `state_graph`, and other data
     /// from `dast`.
     ///
-    /// This function relies upon the fact that `dast.nodes` will be the same length as `self.components`
-    /// and exactly mirror it's structure (i.e., `dast.nodes[i].idx == self.components[i].idx`).
+    /// This function relies upon the fact that the first `dast.nodes.len()` components
+    /// exactly mirror the structure of `dast.nodes` (i.e., `dast.nodes[i].idx == self.components[i].idx`).
+    /// Any further components were created from text children and never extend another component.
     fn init_from_normalized_root(&mut self, dast: &NormalizedRoot) {
         self.init_normalized_root_without_extending(dast);
+        self.extended_from = TiVec::from_iter(std::iter::repeat_n(None, self.components.len()));
 
-        for idx in 0..self.components.len() {
+        for idx in 0..dast.nodes.len() {
             let component_idx = ComponentIdx::new(idx);
             let elm = match &dast.nodes[idx] {
                 NormalizedNode::Element(elm) => elm,
@@ -113,11 +172,19 @@ impl ComponentBuilder {
                 // but never getting connected to it.
                 continue;
             }
-            let ref_source = elm.extending.clone().unwrap();
+            if matches!(component.variant, ComponentEnum::_Error(_)) {
+                // The reference could not be used, so the component became an error when it was created.
+                continue;
+            }
+            let mut ref_source = elm.extending.clone().unwrap();
+            self.resolve_matrix_entry(&mut ref_source);
+            self.resolve_list_member(&mut ref_source);
             let referent = &self.components[ComponentIdx::from(ref_source.idx())];
 
             match Self::determine_extending(ref_source, component, referent) {
                 Ok(extending) => {
+                    let source_idx = referent.get_idx();
+                    self.extended_from[component_idx] = Some(source_idx);
                     match extending {
                         Extending::Component(referent_idx) => {
                             self.add_component_extending_structure(component_idx, referent_idx);
@@ -141,6 +208,7 @@ impl ComponentBuilder {
                                     new_child_idx.as_graph_node(),
                                 );
                                 self.components.push(new_child);
+                                self.extended_from.push(Some(source_idx));
 
                                 // For the new child, we mark the `prop_source` with `from_direct_ref`,
                                 // so that it will have a child pointing to the referent's prop.
@@ -153,12 +221,17 @@ impl ComponentBuilder {
                 }
                 Err(err) => {
                     self.components[component_idx] = Component::new_error(
-                        format!("Error while extending: {err}"),
+                        _Error {
+                            message: format!("Error while extending: {err}"),
+                            error_type: ErrorType::Error,
+                            code: Some(err.code),
+                            suggestion: err.suggestion,
+                        },
                         ComponentCommonData {
                             idx: component_idx,
                             parent: elm.parent.map(ComponentIdx::from),
                             position: elm.position.clone(),
-                            unrecognized_attributes: HashMap::new(),
+                            unrecognized_attributes: BTreeMap::new(),
                         },
                     );
                 }
@@ -166,6 +239,82 @@ impl ComponentBuilder {
         }
     }
 
+    /// If `ref_source` references an entry of a `<matrix>`, e.g., `$m.entry[2][3]`,
+    /// resolve it to the `<number>` that gives the entry, i.e., the third `<number>` of the second `<numberList>` of `m`.
+    /// Otherwise, or if there is no such entry, `ref_source` is unchanged.
+    fn resolve_matrix_entry(&self, ref_source: &mut Source<RefResolution>) {
+        let resolution = ref_source.get_resolution_mut();
+        let Some((row, column)) = resolution
+            .unresolved_path
+            .as_deref()
+            .and_then(matrix_entry_indices)
+        else {
+            return;
+        };
+        let matrix_idx = ComponentIdx::from(resolution.node_idx);
+        if !matches!(
+            self.components[matrix_idx].variant,
+            ComponentEnum::Matrix(_)
+        ) {
+            return;
+        }
+
+        let entry_idx = self
+            .children_of_type(matrix_idx, NumberList::NAME)
+            .get(row - 1)
+            .and_then(|row_idx| {
+                self.children_of_type(*row_idx, Number::NAME)
+                    .get(column - 1)
+                    .copied()
+            });
+        if let Some(entry_idx) = entry_idx {
+            resolution.node_idx = entry_idx.as_usize();
+            resolution.unresolved_path = None;
+        }
+    }
+
+    /// If `ref_source` references a member of a list, e.g., `$l[2]` where `l` is a `<numberList>`,
+    /// resolve it to that member, i.e., the second `<number>` child of `l`.
+    /// Otherwise, or if there is no such member, `ref_source` is unchanged.
+    fn resolve_list_member(&self, ref_source: &mut Source<RefResolution>) {
+        let resolution = ref_source.get_resolution_mut();
+        let Some(index) = resolution
+            .unresolved_path
+            .as_deref()
+            .and_then(list_member_index)
+        else {
+            return;
+        };
+        let list_idx = ComponentIdx::from(resolution.node_idx);
+        let Some(member_type) = self.components[list_idx].string_children_become() else {
+            return;
+        };
+        if let Some(member_idx) = self.children_of_type(list_idx, member_type).get(index - 1) {
+            resolution.node_idx = member_idx.as_usize();
+            resolution.unresolved_path = None;
+        }
+    }
+
+    /// The children of `component_idx` of type `component_type`, in order.
+    fn children_of_type(
+        &self,
+        component_idx: ComponentIdx,
+        component_type: &str,
+    ) -> Vec<ComponentIdx> {
+        self.structure_graph
+            .get_children(
+                self.structure_graph
+                    .get_component_children_virtual_node(component_idx),
+            )
+            .into_iter()
+            .filter_map(|node| match node {
+                GraphNode::Component(idx) => Some(ComponentIdx::new(idx)),
+                _ => None,
+            })
+            .filter(|idx| self.components[*idx].get_component_type() == component_type)
+            .collect()
+    }
+
     /// DoenetML coerces the type of `extending` to allow users to be sloppy with types.
     ///
     /// The default behavior is to ignore the fact that the type changed,
@@ -190,40 +339,76 @@ impl ComponentBuilder {
         ref_source: Source<RefResolution>,
         component: &Component,
         referent: &Component,
-    ) -> Result<Extending, anyhow::Error> {
+    ) -> Result<Extending, ExtendingError> {
+        // We need to keep track if the extension was from a direct ref
+        // because the children were already copied to the component if it came from an attribute,
+        // but not if it came from a direct ref.
+        let from_direct_ref = match ref_source {
+            Source::ExtendAttribute(..) => false,
+            Source::CopyAttribute(..) => false,
+            Source::Ref(..) => true,
+        };
+
+        let ref_resolution = ref_source.take_resolution();
+
         // If the referent is an error or external, we're immediately done.
         match referent.variant {
             ComponentEnum::_Error(_) => {
-                return Err(anyhow!(
-                    "Attempted to extend component from an error component"
+                return Err(ExtendingError::invalid_source(
+                    "Attempted to extend component from an error component",
                 ));
             }
             ComponentEnum::_External(_) => {
-                return Err(anyhow!(
-                    "Attempted to extend component from an external component"
+                return Err(ExtendingError::invalid_source(
+                    "Attempted to extend component from an external component",
                 ));
             }
             _ => {}
         }
 
-        // We need to keep track if the extension was from a direct ref
-        // because the children were already copied to the component if it came from an attribute,
-        // but not if it came from a direct ref.
-        let from_direct_ref = match ref_source {
-            Source::ExtendAttribute(..) => false,
-            Source::CopyAttribute(..) => false,
-            Source::Ref(..) => true,
-        };
-
-        let ref_resolution = ref_source.take_resolution();
+        // The resolver resolves literal indices into a component, such as the `[2]` of `$g[2].x`,
+        // that match a component. If such an index remains, it is the first part of the unresolved path
+        // and either does not match a component or, like the `[$n]` of `$g[$n].x`,
+        // could only be determined from the values of props.
+        // Since the referent is fixed when the structure graph is built, neither can be extended.
+        if let Some(unresolved_index) = ref_resolution
+            .unresolved_path
+            .as_deref()
+            .and_then(|path| path.first())
+            .filter(|part| part.name.is_empty() || part.name == "__invalid_index")
+            .and_then(|part| part.index.first())
+        {
+            let is_dynamic = unresolved_index
+                .value
+                .iter()
+                .any(|content| matches!(content, UntaggedContent::Ref(_)));
+            return Err(if is_dynamic {
+                ExtendingError {
+                    message: "Indices that depend on other components (e.g., `$g[$n]`) are not yet supported"
+                        .to_string(),
+                    code: DiagnosticCode::InvalidExtendSource,
+                    suggestion: Some("Use a number as the index, e.g., `$g[2]`".to_string()),
+                }
+            } else {
+                ExtendingError {
+                    message: "The index does not match a component".to_string(),
+                    code: DiagnosticCode::NoReferent,
+                    suggestion: None,
+                }
+            });
+        }
 
         // Handle the case where there is a remaining path
         if let Some(unresolved_path) = &ref_resolution.unresolved_path {
             if unresolved_path.len() != 1 {
-                return Err(anyhow!("Nested props not implemented yet"));
+                return Err(ExtendingError::invalid_source(
+                    "Nested props not implemented yet",
+                ));
             }
             if !unresolved_path[0].index.is_empty() {
-                return Err(anyhow!("Path indices not yet supported"));
+                return Err(ExtendingError::invalid_source(
+                    "Path indices not yet supported",
+                ));
             }
             let referenced_prop_name = &unresolved_path[0].name;
 
@@ -238,11 +423,15 @@ impl ComponentBuilder {
                     },
                     from_direct_ref,
                 })),
-                None => Err(anyhow!(
-                    "prop {} not found on component {}",
-                    referenced_prop_name,
-                    referent.get_component_type()
-                )),
+                None => Err(ExtendingError {
+                    message: format!(
+                        "prop {} not found on component {}",
+                        referenced_prop_name,
+                        referent.get_component_type()
+                    ),
+                    code: DiagnosticCode::PropDoesNotExist,
+                    suggestion: Self::suggest_prop_names(referent, referenced_prop_name),
+                }),
             };
         }
         // If we're here, there is no remaining path.
@@ -266,14 +455,30 @@ impl ComponentBuilder {
                     },
                     from_direct_ref,
                 })),
-                None => Err(anyhow!(
+                None => Err(ExtendingError::invalid_source(format!(
                     "Cannot extend {} via default prop because a default prop was not defined.",
                     referent.get_component_type()
-                )),
+                ))),
             }
         }
     }
 
+    /// Suggest public props of `referent` whose names are close to `prop_name`,
+    /// which is not the name of a prop of `referent`.
+    fn suggest_prop_names(referent: &Component, prop_name: &str) -> Option<String> {
+        let public_prop_names = referent
+            .variant
+            .get_prop_names()
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| referent.variant.get_prop_is_public(LocalPropIdx::new(*idx)))
+            .map(|(_, name)| *name);
+
+        format_did_you_mean(&closest_matches(prop_name, public_prop_names, 3), |name| {
+            format!("`.{name}`")
+        })
+    }
+
     /// If component `component_idx` extended a prop using the `extend` attribute,
     /// then create a child corresponding to that prop that should be prepended to the children of the component.
     ///
@@ -302,7 +507,7 @@ impl ComponentBuilder {
                     idx: self.components.len().into(),
                     parent: Some(component.get_idx()),
                     position: None,
-                    unrecognized_attributes: HashMap::new(),
+                    unrecognized_attributes: BTreeMap::new(),
                 },
             );
 
@@ -356,7 +561,11 @@ impl ComponentBuilder {
         }
 
         // Every component should now be created, so this unwrap should be safe.
-        self.components = components.into_iter().map(|c| c.unwrap()).collect();
+        self.components = components
+            .into_iter()
+            .map(|c| c.unwrap())
+            .chain(std::mem::take(&mut self.generated_components))
+            .collect();
     }
 
     /// Create a component from `node`.
@@ -380,7 +589,7 @@ impl ComponentBuilder {
                         idx: elm.idx.into(),
                         parent: elm.parent.map(ComponentIdx::from),
                         position: elm.position.clone(),
-                        unrecognized_attributes: HashMap::new(),
+                        unrecognized_attributes: BTreeMap::new(),
                     },
                 );
 
@@ -424,13 +633,27 @@ impl ComponentBuilder {
                                 "Encountered an extend of style <foo extend='$xxx' /> inside of an attribute. It should be syntactically impossible to parse such an element in an attribute."
                             ),
                         };
-                        if ref_resolution.unresolved_path.is_some() {
-                            // XXX: this should result in the component being converted into an error, not a hard panic.
-                            // TODO: Not only should this not be an error, but sometimes it is valid. For example `<point name="p">(3,2)</point><updateValue target="$p.x" newValue="$p.x+1" />`
-                            panic!(
-                                "Cannot preserve_refs if there is a remaining path part. Remaining: {:?}",
-                                ref_resolution.unresolved_path
-                            );
+                        if let Some(unresolved_path) = &ref_resolution.unresolved_path {
+                            // The reference points to a prop, which cannot be preserved, so the reference
+                            // becomes an error rather than aborting the whole document.
+                            // TODO: Sometimes such a reference is valid. For example `<point name="p">(3,2)</point><updateValue target="$p.x" newValue="$p.x+1" />`
+                            let path = unresolved_path
+                                .iter()
+                                .map(|path_part| path_part.name.as_str())
+                                .collect::<Vec<_>>()
+                                .join(".");
+                            return Ok(Component::new_error(
+                                _Error {
+                                    message: format!(
+                                        "Expected a reference to a component, but the reference has the path `.{path}`"
+                                    ),
+                                    // The reference is in an attribute, so it is not rendered in the tree.
+                                    error_type: ErrorType::Warning,
+                                    code: Some(DiagnosticCode::ComponentReferenceExpected),
+                                    suggestion: None,
+                                },
+                                component.common,
+                            ));
                         }
                         // If we made it here, we are a ref pointing to a component and we should not actually be expanded
                         // to a copy of our referent. Instead we should be replaced with a special `_ref` component that preserves the pointer.
@@ -487,40 +710,194 @@ impl ComponentBuilder {
                                     );
                                 }
                             }
+                        } else if matrix_entry_indices(path).is_some() {
+                            // An entry of a `<matrix>`, e.g., `$m.entry[2][3]`, is a `<number>`.
+                            let referent = components[ref_resolution.node_idx].as_ref();
+                            if referent.is_none() {
+                                // We need information from this component, so it must be created first.
+                                return Err(ref_resolution.node_idx);
+                            }
+                            if matches!(referent.unwrap().variant, ComponentEnum::Matrix(_)) {
+                                component =
+                                    Component::from_tag_name(Number::NAME, component.common);
+                            }
+                        } else if list_member_index(path).is_some() {
+                            // A member of a list, e.g., `$l[2]` where `l` is a `<numberList>`, is a `<number>`.
+                            let referent = components[ref_resolution.node_idx].as_ref();
+                            if referent.is_none() {
+                                // We need information from this component, so it must be created first.
+                                return Err(ref_resolution.node_idx);
+                            }
+                            if let Some(member_type) = referent.unwrap().string_children_become() {
+                                component = Component::from_tag_name(member_type, component.common);
+                            }
                         }
                     }
                 }
 
-                let unrecognized_attributes = self.add_component_to_structure_graph(
-                    &component,
-                    &elm.children,
-                    &elm.attributes,
-                );
+                let children = match (&component.variant, component.string_children_become()) {
+                    (ComponentEnum::Sequence(_), _) => self.create_sequence_member_components(
+                        &component,
+                        &elm.attributes,
+                        components.len(),
+                    ),
+                    (_, Some(name)) => self.create_string_child_components(
+                        &component,
+                        name,
+                        &elm.children,
+                        components.len(),
+                    ),
+                    (_, None) => elm.children.clone(),
+                };
+
+                let unrecognized_attributes =
+                    self.add_component_to_structure_graph(&component, &children, &elm.attributes);
                 component.set_unrecognized_attributes(unrecognized_attributes);
 
+                if self.is_disabled_experimental_component(&component) {
+                    // As with a component that cannot extend its referent, the component keeps its place
+                    // in the structure of the document but becomes an error.
+                    let error = experimental_component_error(component.get_component_type());
+                    return Ok(Component::new_error(error, component.common));
+                }
+
                 component
             }
             NormalizedNode::Error(e) => Component::new_error(
-                e.message.clone(),
+                _Error::from_flat_error(e),
                 ComponentCommonData {
                     idx: e.idx.into(),
                     parent: e.parent.map(ComponentIdx::from),
                     position: e.position.clone(),
-                    unrecognized_attributes: HashMap::new(),
+                    unrecognized_attributes: BTreeMap::new(),
                 },
             ),
         };
         Ok(component)
     }
 
+    /// Replace the text children of `component` with components of type `name`, e.g., so that in
+    /// `<numberList>1 2</numberList>` the items `1` and `2` become `<number>` children.
+    /// Text is split into items separated by commas or whitespace outside of parentheses, e.g., `(1, 2) f(x)` has two items,
+    /// and each item becomes the text child of a new component.
+    /// Returns the children of `component` with the new components in place of the text.
+    ///
+    /// The new components are indexed after the `num_document_components` components of the document.
+    fn create_string_child_components(
+        &mut self,
+        component: &Component,
+        name: &str,
+        children: &[UntaggedContent],
+        num_document_components: usize,
+    ) -> Vec<UntaggedContent> {
+        let mut new_children = Vec::with_capacity(children.len());
+        for child in children {
+            let text = match child {
+                UntaggedContent::Text(text) => text,
+                UntaggedContent::Ref(_) => {
+                    new_children.push(child.clone());
+                    continue;
+                }
+            };
+            for item in split_list_items(text) {
+                new_children.push(self.create_generated_component(
+                    component,
+                    name,
+                    item.to_string(),
+                    num_document_components,
+                ));
+            }
+        }
+        new_children
+    }
+
+    /// Create the members of the `<sequence>` `component` as `<number>` children,
+    /// based on its `from`, `to`, `step`, and `length` attributes.
+    /// Returns the new children of `component`.
+    ///
+    /// The members can only be determined when the attributes are literal numbers,
+    /// so the sequence has no members if any attribute is given by a reference.
+    /// The new components are indexed after the `num_document_components` components of the document.
+    fn create_sequence_member_components(
+        &mut self,
+        component: &Component,
+        attributes: &[FlatAttribute],
+        num_document_components: usize,
+    ) -> Vec<UntaggedContent> {
+        // `Some(None)` if the attribute was not specified and `None` if it is not a literal number.
+        let literal_number = |attr_name: &str| -> Option<Option<f64>> {
+            let Some(attribute) = attributes
+                .iter()
+                .find(|attribute| attribute.name.eq_ignore_ascii_case(attr_name))
+            else {
+                return Some(None);
+            };
+            let text = attribute
+                .children
+                .iter()
+                .map(|child| match child {
+                    UntaggedContent::Text(text) => Some(text.as_str()),
+                    UntaggedContent::Ref(_) => None,
+                })
+                .collect::<Option<String>>()?;
+            text.trim().parse().ok().map(Some)
+        };
+
+        let (Some(from), Some(to), Some(step), Some(length)) = (
+            literal_number("from"),
+            literal_number("to"),
+            literal_number("step"),
+            literal_number("length"),
+        ) else {
+            return Vec::new();
+        };
+
+        sequence_members(from.unwrap_or(1.0), to, step.unwrap_or(1.0), length)
+            .into_iter()
+            .map(|member| {
+                self.create_generated_component(
+                    component,
+                    "number",
+                    member.to_string(),
+                    num_document_components,
+                )
+            })
+            .collect()
+    }
+
+    /// Create a component of type `name` whose only child is `text`, as a child of `parent`.
+    /// Returns a reference to the new component, which is indexed after the `num_document_components`
+    /// components of the document and any components generated before it.
+    fn create_generated_component(
+        &mut self,
+        parent: &Component,
+        name: &str,
+        text: String,
+        num_document_components: usize,
+    ) -> UntaggedContent {
+        let idx = num_document_components + self.generated_components.len();
+        let new_child = Component::from_tag_name(
+            name,
+            ComponentCommonData {
+                idx: idx.into(),
+                parent: Some(parent.get_idx()),
+                position: None,
+                unrecognized_attributes: BTreeMap::new(),
+            },
+        );
+        self.add_component_to_structure_graph(&new_child, &[UntaggedContent::Text(text)], &[]);
+        self.generated_components.push(new_child);
+        UntaggedContent::Ref(idx)
+    }
+
     /// Add `component` to the `structure_graph` along with links to its attributes, children, and props.
-    /// Returns a HashMap of attributes that were not recognized by the component.
+    /// Returns a BTreeMap of attributes that were not recognized by the component.
     fn add_component_to_structure_graph(
         &mut self,
         component: &Component,
         children: &[UntaggedContent],
         attributes: &[FlatAttribute],
-    ) -> HashMap<String, FlatAttribute> {
+    ) -> BTreeMap<String, FlatAttribute> {
         let graph_component_node = component.get_idx().as_graph_node();
         self.structure_graph.add_node(graph_component_node);
 
@@ -543,7 +920,7 @@ impl ComponentBuilder {
         self.structure_graph
             .add_edge(graph_component_node, graph_virtual_node);
         // These are the unused attributes that are not recognized by the component
-        let mut unused_attributes = HashMap::<String, _>::from_iter(
+        let mut unused_attributes = BTreeMap::<String, _>::from_iter(
             attributes
                 .iter()
                 .map(|attr| (attr.name.clone(), attr.clone())),
@@ -556,19 +933,21 @@ impl ComponentBuilder {
             self.structure_graph
                 .add_edge(graph_virtual_node, attr_virtual_node);
 
-            let attr_content =
-                unused_attributes
-                    .remove_ignore_case(attr_name)
-                    .map_or_else(Vec::new, |v| {
-                        if v.children.is_empty() {
-                            // if an attribute was supplied by given no content,
-                            // then make it the same as giving it a empty string
-                            // (e.g., `<textInput hide/>` should be the same as `<textInput hide=""/>`)
-                            vec![UntaggedContent::Text("".to_string())]
-                        } else {
-                            v.children
-                        }
-                    });
+            let attr = unused_attributes.remove_ignore_case(attr_name);
+            if let Some(position) = attr.as_ref().and_then(|attr| attr.position.clone()) {
+                self.attribute_positions
+                    .set_tag(attr_virtual_node, position);
+            }
+            let attr_content = attr.map_or_else(Vec::new, |v| {
+                if v.children.is_empty() {
+                    // if an attribute was supplied by given no content,
+                    // then make it the same as giving it a empty string
+                    // (e.g., `<textInput hide/>` should be the same as `<textInput hide=""/>`)
+                    vec![UntaggedContent::Text("".to_string())]
+                } else {
+                    v.children
+                }
+            });
             self.add_content_to_structure_graph(attr_virtual_node, &attr_content);
         }
 
@@ -691,11 +1070,13 @@ impl ComponentBuilder {
 
         if self.components[component_idx].get_component_type()
             == self.components[referent_idx].get_component_type()
+            && !has_independent_state(&self.components[component_idx])
         {
             // Since the component types is the same, we make each prop of `referent` be a dependency
             // of the prop from `component`.
             // This dependency indicates that any state props requested by each prop of `component`
             // should be the corresponding state prop of `referent`.
+            // A component with `independentState="true"` instead keeps its own state.
 
             let component_props = self.structure_graph.get_component_props(component_idx);
             let referent_props = self.structure_graph.get_component_props(referent_idx);
@@ -766,3 +1147,58 @@ impl ComponentBuilder {
         }
     }
 }
+
+/// The index, starting from 1, of the list member referenced by `path`, e.g., `2` for `$l[2]`.
+/// Returns `None` unless `path` is a single literal index.
+fn list_member_index(path: &[FlatPathPart]) -> Option<usize> {
+    let [path_part] = path else {
+        return None;
+    };
+    if !path_part.name.is_empty() {
+        return None;
+    }
+    match path_part.index.as_slice() {
+        [index] => index.as_literal_index(),
+        _ => None,
+    }
+}
+
+/// Split `text` into the items of a list, separated by commas or whitespace that are not enclosed by parentheses or brackets.
+/// Empty items are skipped.
+fn split_list_items(text: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut item_start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            c if depth == 0 && (c == ',' || c.is_whitespace()) => {
+                items.push(&text[item_start..i]);
+                item_start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    items.push(&text[item_start..]);
+    items.retain(|item| !item.is_empty());
+    items
+}
+
+/// The error that replaces the experimental component `component_type` when it was not enabled.
+fn experimental_component_error(component_type: &str) -> _Error {
+    _Error {
+        message: format!(
+            "`<{component_type}>` is an experimental component and must be enabled before it can be used"
+        ),
+        error_type: ErrorType::Error,
+        code: Some(DiagnosticCode::ExperimentalComponentNotEnabled),
+        suggestion: Some(format!(
+            "Add `{component_type}` to the `experimentalComponents` flag to enable it."
+        )),
+    }
+}
+
+#[cfg(test)]
+#[path = "component_builder.test.rs"]
+mod test;
@@ -0,0 +1,41 @@
+use super::*;
+
+#[test]
+fn experimental_component_error_names_the_flag() {
+    let error = experimental_component_error("matrix");
+    assert_eq!(
+        error.code,
+        Some(DiagnosticCode::ExperimentalComponentNotEnabled)
+    );
+    assert_eq!(error.error_type, ErrorType::Error);
+    assert!(error.message.contains("`<matrix>`"));
+    assert_eq!(
+        error.suggestion.as_deref(),
+        Some("Add `matrix` to the `experimentalComponents` flag to enable it.")
+    );
+}
+
+#[test]
+fn components_are_not_experimental_by_default() {
+    let component = Component::from_tag_name(
+        "text",
+        ComponentCommonData {
+            idx: ComponentIdx::new(0),
+            parent: None,
+            position: None,
+            unrecognized_attributes: BTreeMap::new(),
+        },
+    );
+    assert!(!component.is_experimental());
+    assert!(!ComponentBuilder::new().is_disabled_experimental_component(&component));
+}
+
+#[test]
+fn list_items_are_not_split_inside_parentheses() {
+    assert_eq!(split_list_items(" 1, 2  3 "), vec!["1", "2", "3"]);
+    assert_eq!(
+        split_list_items("(1, 2) f(x y) [a,b]"),
+        vec!["(1, 2)", "f(x y)", "[a,b]"]
+    );
+    assert_eq!(split_list_items(",,"), Vec::<&str>::new());
+}
@@ -1,17 +1,28 @@
 //! A version of `Core` based on `DirectedGraph`
 
+use std::{collections::BTreeMap, rc::Rc};
+
+use crate::components::{
+    ComponentNode, ComponentProps,
+    types::{ComponentIdx, PropPointer},
+};
 use crate::dast::{
-    DastRoot, FlatDastRoot,
-    flat_dast::{
-        FlatFragment, FlatNode, FlatPathPart, FlatRoot, Index, NormalizedRoot, UntaggedContent,
-    },
-    ref_expand::Expander,
+    DastRoot, DastWarning, FlatDastElementUpdate, FlatDastRoot,
+    flat_dast::{FlatFragment, FlatNode, FlatPathPart, Index, NormalizedRoot, UntaggedContent},
     ref_resolve::{IndexResolution, RefResolution, ResolutionError, Resolver},
 };
+use crate::graph_node::GraphNode;
+use crate::props::PropValue;
+use crate::utils::to_canonical_json;
 
 use super::{
-    component_builder::ComponentBuilder, document_model::DocumentModel,
-    document_renderer::DocumentRenderer,
+    Annotation, ComponentProvenance, DebugStateSummary, DocumentEnvironment, DocumentFlags,
+    DocumentScore, DocumentStats, EssentialData, EssentialDataEvent, ExperimentAssignment,
+    NewAnnotation, ObjectiveReport, PropBreakpointEvent, PropOverride, ResolutionTraceEntry,
+    SavedStateValue, SelectionDescriptor, StateBlobError, StateDiff, StateEncryption, Telemetry,
+    compiled_program::normalize_dast_root, component_builder::ComponentBuilder, decode_state_blob,
+    document_model::DocumentModel, document_renderer::DocumentRenderer, encode_state_blob,
+    render_html,
 };
 
 /// Core stores all hydrated components, keeps track of caching data, and tracks dependencies.
@@ -22,6 +33,8 @@ pub struct Core {
     pub document_model: DocumentModel,
     pub document_renderer: DocumentRenderer,
     pub resolver: Option<Resolver>,
+    /// Encryption supplied by the platform that is applied to saved state.
+    pub state_encryption: Option<Rc<dyn StateEncryption>>,
 }
 
 impl Default for Core {
@@ -36,6 +49,7 @@ impl Core {
             document_model: DocumentModel::new_with_root_data_query(),
             document_renderer: DocumentRenderer::new(),
             resolver: None,
+            state_encryption: None,
         }
     }
 
@@ -44,11 +58,9 @@ impl Core {
     /// Sets the `resolver` so that it can be reused if needed.
     /// Returns the `NormalizedRoot`
     pub fn normalized_root_from_dast_root(&mut self, dast_root: &DastRoot) -> NormalizedRoot {
-        let mut flat_root = FlatRoot::from_dast(dast_root);
-        let mut resolver = Expander::expand(&mut flat_root);
-        flat_root.compactify(Some(&mut resolver));
+        let (normalized_root, resolver) = normalize_dast_root(dast_root);
         self.resolver = Some(resolver);
-        flat_root.into_normalized_root()
+        normalized_root
     }
 
     pub fn add_nodes_to_resolver(
@@ -110,15 +122,299 @@ impl Core {
     /// A [`Resolver`] is saved to `core. It can be used to look up a `ComponentIdx` by name (useful for testing).
     /// One can also add or delete nodes from it.
     pub fn init_from_dast_root(&mut self, dast_root: &DastRoot) {
+        self.reset_document();
+
+        let normalized_flat_root = self.normalized_root_from_dast_root(dast_root);
+
+        let component_builder = ComponentBuilder::from_normalized_root(
+            &normalized_flat_root,
+            self.document_model.get_flags().experimental_components,
+        );
+        self.document_model.init_from_builder(component_builder);
+    }
+
+    /// Discard the document and all of its state before initializing a new one.
+    pub(super) fn reset_document(&mut self) {
         // If we are initializing, we need to make sure that pre-existing data doesn't mess things up.
         // The easiest way is to recreate ourself.
         // TODO: think about whether we can update existing structures.
+        // Tracing, telemetry, flags, the environment, and encryption are settings rather than document data,
+        // so they survive re-initialization.
+        let trace_capacity = self.document_model.resolution_trace_capacity();
+        let flags = self.document_model.get_flags();
+        let environment = self.document_model.get_environment();
+        let state_encryption = self.state_encryption.take();
+        let telemetry = self.document_model.get_telemetry();
         *self = Self::new();
+        self.state_encryption = state_encryption;
+        if let Some((telemetry, sample_interval)) = telemetry {
+            self.document_model
+                .set_telemetry(Some(telemetry), sample_interval);
+        }
+        if let Some(capacity) = trace_capacity {
+            self.document_model.enable_resolution_trace(capacity);
+        }
+        self.document_model.set_flags(flags);
+        self.document_model.set_environment(environment);
+    }
 
-        let normalized_flat_root = self.normalized_root_from_dast_root(dast_root);
+    /// Set the flags that configure how the document is presented, e.g., the render mode.
+    /// The flags should be set before the document is rendered.
+    pub fn set_flags(&mut self, flags: DocumentFlags) {
+        self.document_model.set_flags(flags);
+    }
 
-        let component_builder = ComponentBuilder::from_normalized_root(&normalized_flat_root);
-        self.document_model.init_from_builder(component_builder);
+    /// Set the environment in which the document is displayed, e.g., when the window is resized.
+    /// Props that depend on a property of the environment that changed are recalculated.
+    ///
+    /// Returns updates to the FlatDast.
+    pub fn set_environment(
+        &mut self,
+        environment: DocumentEnvironment,
+    ) -> BTreeMap<ComponentIdx, FlatDastElementUpdate> {
+        let changed_components = self.document_model.set_environment(environment);
+
+        self.document_renderer
+            .get_flat_dast_updates(changed_components, &self.document_model)
+    }
+
+    /// Initialize from `dast_root` as in `init_from_dast_root`, restoring the state saved in `state_blob`,
+    /// e.g., to let a student resume their work.
+    pub fn init_from_dast_root_and_state(
+        &mut self,
+        dast_root: &DastRoot,
+        state_blob: &[u8],
+    ) -> Result<(), StateBlobError> {
+        self.init_from_dast_root(dast_root);
+        self.load_state(state_blob)
+    }
+
+    /// Set the encryption applied to saved state by `export_state` and `load_state`.
+    /// Without encryption, the saved state is stored as plain JSON.
+    pub fn set_state_encryption(&mut self, encryption: Option<Rc<dyn StateEncryption>>) {
+        self.state_encryption = encryption;
+    }
+
+    /// Install telemetry supplied by the platform, e.g., to monitor performance in production,
+    /// or remove it if `None`. One of every `sample_interval` prop calculations and batches of actions is timed.
+    /// Events include only the type of the component involved, never values entered by a student.
+    pub fn set_telemetry(&mut self, telemetry: Option<Rc<dyn Telemetry>>, sample_interval: usize) {
+        self.document_model
+            .set_telemetry(telemetry, sample_interval);
+    }
+
+    /// Save the state of the document (e.g., the responses a student has entered) as a blob
+    /// that the platform can store. The blob is encrypted if encryption has been set.
+    pub fn export_state(&self) -> Result<Vec<u8>, StateBlobError> {
+        encode_state_blob(
+            &self.document_model.export_state(),
+            self.state_encryption.as_deref(),
+        )
+    }
+
+    /// Restore the state saved in `state_blob` by `export_state`. The document must have been initialized
+    /// from the same source that it had when the state was saved.
+    pub fn load_state(&mut self, state_blob: &[u8]) -> Result<(), StateBlobError> {
+        let saved_state = decode_state_blob(state_blob, self.state_encryption.as_deref())?;
+        self.document_model.load_state(saved_state);
+        Ok(())
+    }
+
+    /// List the values that differ between two states saved by `export_state`,
+    /// e.g., so an instructor can review what a student changed between submissions.
+    pub fn diff_states(
+        &self,
+        before_blob: &[u8],
+        after_blob: &[u8],
+    ) -> Result<StateDiff, StateBlobError> {
+        let encryption = self.state_encryption.as_deref();
+        let before = decode_state_blob(before_blob, encryption)?;
+        let after = decode_state_blob(after_blob, encryption)?;
+        let component_names = match &self.resolver {
+            Some(resolver) => resolver.calculate_root_names(),
+            None => Vec::new(),
+        };
+        Ok(self
+            .document_model
+            .diff_states(&before, &after, &component_names))
+    }
+
+    /// Override the value of the prop named `prop_name` of the component with index `component_idx`,
+    /// e.g., so that an instructor can adjust credit or unlock content. The override, along with `reason`,
+    /// is recorded so that it can be audited, and it is included in the state saved by `export_state`.
+    ///
+    /// Returns any changes to the output flat dast.
+    pub fn override_prop(
+        &mut self,
+        component_idx: ComponentIdx,
+        prop_name: &str,
+        value: serde_json::Value,
+        reason: &str,
+    ) -> Result<BTreeMap<ComponentIdx, FlatDastElementUpdate>, String> {
+        let changed_components = self.document_model.override_prop(PropOverride {
+            component_idx,
+            prop_name: prop_name.to_string(),
+            value,
+            reason: reason.to_string(),
+        })?;
+
+        Ok(self
+            .document_renderer
+            .get_flat_dast_updates(changed_components, &self.document_model))
+    }
+
+    /// Move keyboard focus to the component with index `component_idx`, e.g., when a host
+    /// wants to direct a learner to the first answer that needs attention.
+    ///
+    /// Returns any changes to the output flat dast, which include the focus request
+    /// if the component is rendered.
+    pub fn set_focus(
+        &mut self,
+        component_idx: ComponentIdx,
+    ) -> Result<BTreeMap<ComponentIdx, FlatDastElementUpdate>, String> {
+        if !self.document_model.has_component(component_idx) {
+            return Err(format!(
+                "No component with index {}",
+                component_idx.as_usize()
+            ));
+        }
+        self.document_model.request_focus(component_idx);
+
+        Ok(self
+            .document_renderer
+            .get_flat_dast_updates(Vec::new(), &self.document_model))
+    }
+
+    /// Return every override applied to the document, in the order they were applied.
+    pub fn get_prop_overrides(&self) -> Vec<PropOverride> {
+        self.document_model.get_prop_overrides()
+    }
+
+    /// Return the essential data of the component with index `component_idx`, i.e., the state stored by its props,
+    /// such as the value typed into a `<textInput>`, so that debugging tools and instructor dashboards can inspect it.
+    pub fn list_essential_data(
+        &self,
+        component_idx: ComponentIdx,
+    ) -> Result<Vec<EssentialData>, String> {
+        self.document_model.list_essential_data(component_idx)
+    }
+
+    /// Set the essential data stored by the prop named `origin` of the component with index `component_idx`,
+    /// or only its entry at `index` (starting from 1) if the data is a vector. The change is recorded
+    /// as an `EssentialDataEvent` that can be retrieved with `take_essential_data_events`.
+    ///
+    /// Returns any changes to the output flat dast.
+    pub fn set_essential_data(
+        &mut self,
+        component_idx: ComponentIdx,
+        origin: &str,
+        index: Option<usize>,
+        value: serde_json::Value,
+    ) -> Result<BTreeMap<ComponentIdx, FlatDastElementUpdate>, String> {
+        let changed_components =
+            self.document_model
+                .set_essential_data(component_idx, origin, index, value)?;
+
+        Ok(self
+            .document_renderer
+            .get_flat_dast_updates(changed_components, &self.document_model))
+    }
+
+    /// Return all essential data events recorded since the last call, oldest first.
+    pub fn take_essential_data_events(&self) -> Vec<EssentialDataEvent> {
+        self.document_model.take_essential_data_events()
+    }
+
+    /// Add a student's annotation, e.g., a highlight or a note, to the document.
+    /// Annotations are saved with the state of the document.
+    ///
+    /// Returns the annotation with the id that identifies it.
+    pub fn add_annotation(&mut self, new_annotation: NewAnnotation) -> Result<Annotation, String> {
+        self.document_model.add_annotation(new_annotation)
+    }
+
+    /// Remove the annotation with id `id`.
+    pub fn remove_annotation(&mut self, id: usize) -> Result<(), String> {
+        self.document_model.remove_annotation(id)
+    }
+
+    /// Return every annotation of the document, in the order they were added.
+    pub fn get_annotations(&self) -> Vec<Annotation> {
+        self.document_model.get_annotations()
+    }
+
+    /// Make `user_id` the active user, e.g., when a different member of a group takes over the document.
+    /// The state of components with `scope="user"` is swapped for that of `user_id`; shared state is unchanged.
+    ///
+    /// Returns any changes to the output flat dast.
+    pub fn set_active_user(
+        &mut self,
+        user_id: &str,
+    ) -> BTreeMap<ComponentIdx, FlatDastElementUpdate> {
+        let changed_components = self.document_model.set_active_user(user_id);
+
+        self.document_renderer
+            .get_flat_dast_updates(changed_components, &self.document_model)
+    }
+
+    /// Return the ephemeral state of the document (from components with `scope="ephemeral"`),
+    /// e.g., the position of the user's cursor, to be broadcast to collaborators.
+    pub fn get_ephemeral_state(&self) -> Vec<SavedStateValue> {
+        self.document_model.get_ephemeral_state()
+    }
+
+    /// Apply ephemeral state broadcast by a collaborator. Ephemeral state is never saved by `export_state`.
+    ///
+    /// Returns any changes to the output flat dast.
+    pub fn apply_ephemeral_state(
+        &mut self,
+        values: Vec<SavedStateValue>,
+    ) -> Result<BTreeMap<ComponentIdx, FlatDastElementUpdate>, String> {
+        let changed_components = self.document_model.apply_ephemeral_state(values)?;
+
+        Ok(self
+            .document_renderer
+            .get_flat_dast_updates(changed_components, &self.document_model))
+    }
+
+    /// Return the user whose state is currently in the document.
+    pub fn get_active_user(&self) -> String {
+        self.document_model.get_active_user()
+    }
+
+    /// Return the credit earned on each learning objective, from components tagged with the `objective` attribute.
+    pub fn get_objective_report(&self) -> ObjectiveReport {
+        self.document_model.get_objective_report()
+    }
+
+    /// Return the score of the document, where each `<answer>` is worth one point.
+    pub fn get_score(&self) -> DocumentScore {
+        self.document_model.get_score()
+    }
+
+    /// Return the option selected by each `<select>` of the document, so that later attempts can avoid repeating it.
+    pub fn get_selections(&self) -> Vec<SelectionDescriptor> {
+        self.document_model.get_selections()
+    }
+
+    /// Return the arm assigned by each `<experiment>` of the document, for analytics.
+    pub fn get_experiment_assignments(&self) -> Vec<ExperimentAssignment> {
+        self.document_model.get_experiment_assignments()
+    }
+
+    /// Return the authorship metadata of the component with index `component_idx`,
+    /// along with the chain of components it was copied from.
+    pub fn get_component_provenance(
+        &self,
+        component_idx: ComponentIdx,
+    ) -> Result<ComponentProvenance, String> {
+        if !self.document_model.has_component(component_idx) {
+            return Err(format!(
+                "No component with index {}",
+                component_idx.as_usize()
+            ));
+        }
+        Ok(self.document_model.get_component_provenance(component_idx))
     }
 
     pub fn to_flat_dast(&mut self) -> FlatDastRoot {
@@ -126,6 +422,164 @@ impl Core {
             .render_flat_dast(&self.document_model)
     }
 
+    /// The render tree, as given by `to_flat_dast`, as JSON in a canonical form with sorted keys and rounded numbers.
+    /// The same document always gives the same text, so it can be compared against a stored snapshot.
+    pub fn render_tree_canonical(&mut self) -> String {
+        to_canonical_json(&self.to_flat_dast())
+    }
+
+    /// Find the components of the document that are never rendered, reported as informational warnings
+    /// so that authors can remove the unused content.
+    pub fn find_dead_content(&mut self) -> Vec<DastWarning> {
+        self.document_renderer
+            .find_dead_content(&self.document_model)
+    }
+
+    /// Check the document for problems, e.g., before publishing it. Errors, warnings,
+    /// and content that is never rendered are reported together, sorted by their position in the source.
+    pub fn validate(&mut self) -> Vec<DastWarning> {
+        self.document_renderer.validate(&self.document_model)
+    }
+
+    /// The rendered document as a static HTML fragment, as given by `render_html`,
+    /// e.g., to preview the document without a renderer.
+    pub fn to_html(&mut self) -> String {
+        render_html(&self.to_flat_dast())
+    }
+
+    /// Compute summary statistics of the document, such as the number of components of each type
+    /// and the number of words displayed, so that platforms can summarize an assignment.
+    pub fn document_stats(&mut self) -> DocumentStats {
+        self.document_renderer.document_stats(&self.document_model)
+    }
+
+    /// Summarize the status and last computed value of every prop in the document.
+    /// Nothing is computed, so this does not alter the state of the document.
+    pub fn debug_state_summary(&self) -> DebugStateSummary {
+        self.document_model.debug_state_summary()
+    }
+
+    /// Start recording how props are calculated, keeping the `capacity` most recent calculations.
+    pub fn enable_resolution_trace(&self, capacity: usize) {
+        self.document_model.enable_resolution_trace(capacity);
+    }
+
+    /// Stop recording how props are calculated and discard the recorded calculations.
+    pub fn disable_resolution_trace(&self) {
+        self.document_model.disable_resolution_trace();
+    }
+
+    /// The most recent prop calculations, oldest first. Empty unless tracing is enabled.
+    pub fn get_resolution_trace(&self) -> Vec<ResolutionTraceEntry> {
+        self.document_model.get_resolution_trace()
+    }
+
+    /// Set a breakpoint on the prop named `prop_name` of the component with index `component_idx`.
+    /// Whenever that prop is resolved or marked stale, a `PropBreakpointEvent` is recorded
+    /// that can be retrieved with `take_breakpoint_events`.
+    ///
+    /// Breakpoints refer to components of the current document, so they are cleared by `init_from_dast_root`.
+    pub fn set_prop_breakpoint(
+        &self,
+        component_idx: ComponentIdx,
+        prop_name: &str,
+    ) -> Result<(), String> {
+        let prop_node = self.prop_node_from_name(component_idx, prop_name)?;
+        self.document_model.set_prop_breakpoint(prop_node);
+        Ok(())
+    }
+
+    /// Remove the breakpoint from the prop named `prop_name` of the component with index `component_idx`.
+    pub fn clear_prop_breakpoint(
+        &self,
+        component_idx: ComponentIdx,
+        prop_name: &str,
+    ) -> Result<(), String> {
+        let prop_node = self.prop_node_from_name(component_idx, prop_name)?;
+        self.document_model.clear_prop_breakpoint(prop_node);
+        Ok(())
+    }
+
+    /// Return the value of the prop named `prop_name` of the component named `component_name`,
+    /// calculating it first if necessary. Return `None` if there is no such component or prop.
+    ///
+    /// `component_name` is resolved from the document root, so it may be a path such as `s1.t`,
+    /// just as in a reference `$s1.t`. Since the prop is read without being marked as rendered,
+    /// this does not alter the updates sent to the renderer.
+    pub fn get_prop_value(&mut self, component_name: &str, prop_name: &str) -> Option<PropValue> {
+        let component_idx = self.component_idx_from_name(component_name)?;
+        let prop_node = self.prop_node_from_name(component_idx, prop_name).ok()?;
+        Some(
+            self.document_renderer
+                .get_prop_for_render_untracked(prop_node, &self.document_model)
+                .value,
+        )
+    }
+
+    /// Return the entry at `index` (starting from 1, as in DoenetML) of the vector-valued prop
+    /// named `prop_name` of the component named `component_name`.
+    /// Return `None` if there is no such component or prop, the prop is not a vector, or `index` is out of range.
+    pub fn get_prop_value_at(
+        &mut self,
+        component_name: &str,
+        prop_name: &str,
+        index: usize,
+    ) -> Option<PropValue> {
+        match self.get_prop_value(component_name, prop_name)? {
+            PropValue::PropVec(values) => values.get(index.checked_sub(1)?).cloned(),
+            _ => None,
+        }
+    }
+
+    /// The index of the component named `component_name`, resolved from the document root.
+    fn component_idx_from_name(&self, component_name: &str) -> Option<ComponentIdx> {
+        let path = component_name
+            .split('.')
+            .map(|name| FlatPathPart {
+                name: name.to_string(),
+                index: Vec::new(),
+                position: None,
+                source_doc: None,
+            })
+            .collect::<Vec<_>>();
+        let resolution = self.resolver.as_ref()?.resolve(path, 0, false).ok()?;
+        resolution
+            .unresolved_path
+            .is_none()
+            .then(|| ComponentIdx::new(resolution.node_idx))
+    }
+
+    /// Return all breakpoint events recorded since the last call, oldest first.
+    pub fn take_breakpoint_events(&self) -> Vec<PropBreakpointEvent> {
+        self.document_model.take_breakpoint_events()
+    }
+
+    fn prop_node_from_name(
+        &self,
+        component_idx: ComponentIdx,
+        prop_name: &str,
+    ) -> Result<GraphNode, String> {
+        if !self.document_model.has_component(component_idx) {
+            return Err(format!(
+                "No component with index {}",
+                component_idx.as_usize()
+            ));
+        }
+        let component = self.document_model.get_component(component_idx);
+        let local_prop_idx = component
+            .get_local_prop_index_from_name(prop_name)
+            .ok_or_else(|| {
+                format!(
+                    "Component `{}` has no prop named `{prop_name}`",
+                    component.get_component_type()
+                )
+            })?;
+        Ok(self.document_model.prop_pointer_to_prop_node(PropPointer {
+            component_idx,
+            local_prop_idx,
+        }))
+    }
+
     pub fn _run_test(&mut self, test_name: &str) {
         //   self.document_renderer.run_test(test_name, &self.document_model);
         test_browser::run_test(test_name);
@@ -28,3 +28,89 @@ fn test_can_get_annotated_children() {
         ]
     );
 }
+
+#[test]
+fn test_debug_state_summary_reports_status_without_computing() {
+    use crate::props::{PropValue, cache::PropStatus};
+
+    let dast_root = dast_root_no_position(r#"<text>Hello</text>"#);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    // Nothing has been rendered, so no prop has been resolved
+    let summary = core.debug_state_summary();
+    let text = &summary.components[1];
+    assert_eq!(text.component_type, "text");
+    let value = text.props.iter().find(|p| p.name == "value").unwrap();
+    assert_eq!(value.status, PropStatus::Unresolved);
+    assert_eq!(value.value, None);
+
+    // Rendering computes the `for_render` props
+    core.to_flat_dast();
+    let summary = core.debug_state_summary();
+    let value = summary.components[1]
+        .props
+        .iter()
+        .find(|p| p.name == "value")
+        .unwrap();
+    assert_eq!(value.status, PropStatus::Fresh);
+    assert_eq!(
+        value.value,
+        Some(PropValue::String(std::rc::Rc::new("Hello".to_string())))
+    );
+
+    // Inspecting must not have changed anything
+    assert_eq!(summary, core.debug_state_summary());
+}
+
+#[test]
+fn test_get_prop_value_by_name() {
+    let dast_root = dast_root_no_position(
+        r#"<section name="s"><text name="t">Hello</text></section>
+<tabs name="tb"><tab title="First" /><tab title="Second" /></tabs>"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let hello = Some(PropValue::String(std::rc::Rc::new("Hello".to_string())));
+    assert_eq!(core.get_prop_value("t", "value"), hello);
+    assert_eq!(core.get_prop_value("s.t", "value"), hello);
+
+    assert_eq!(
+        core.get_prop_value_at("tb", "tabTitles", 2),
+        Some(PropValue::String(std::rc::Rc::new("Second".to_string())))
+    );
+    assert_eq!(core.get_prop_value_at("tb", "tabTitles", 0), None);
+    assert_eq!(core.get_prop_value_at("tb", "tabTitles", 3), None);
+    assert_eq!(core.get_prop_value_at("t", "value", 1), None);
+
+    assert_eq!(core.get_prop_value("notAName", "value"), None);
+    assert_eq!(core.get_prop_value("t", "notAProp"), None);
+    assert_eq!(core.get_prop_value("s.notAName", "value"), None);
+}
+
+#[test]
+fn test_set_focus() {
+    let dast_root = dast_root_no_position(r#"<textInput name="ti" /><text name="t">Hi</text>"#);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+
+    let updates = core.set_focus(ComponentIdx::new(1)).unwrap();
+    assert_eq!(updates.len(), 1);
+    assert!(updates[&ComponentIdx::new(1)].focus);
+    assert!(updates[&ComponentIdx::new(1)].changed_state.is_none());
+
+    // The request is delivered only once.
+    assert!(core.set_focus(ComponentIdx::new(2)).unwrap()[&ComponentIdx::new(2)].focus);
+    assert!(
+        core.document_renderer
+            .get_flat_dast_updates(Vec::new(), &core.document_model)
+            .is_empty()
+    );
+
+    assert!(core.set_focus(ComponentIdx::new(100)).is_err());
+}
@@ -1,12 +1,17 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
-use crate::components::{
-    ComponentOnAction,
-    prelude::{ComponentIdx, FlatDastElementUpdate},
-    types::{Action, ActionQueryProp},
+use crate::{
+    components::{
+        ComponentOnAction,
+        prelude::{ComponentIdx, FlatDastElementUpdate},
+        types::{Action, ActionQueryProp},
+    },
+    graph::directed_graph::Taggable,
+    graph_node::GraphNodeLookup,
+    props::PropValue,
 };
 
-use super::core::Core;
+use super::{core::Core, document_model::TelemetryEventKind};
 
 impl Core {
     /// Run the action specified by the `action` json and return any changes to the output flat dast.
@@ -23,11 +28,86 @@ impl Core {
     pub fn dispatch_action(
         &mut self,
         action: Action,
-    ) -> Result<HashMap<ComponentIdx, FlatDastElementUpdate>, String> {
+    ) -> Result<BTreeMap<ComponentIdx, FlatDastElementUpdate>, String> {
+        self.dispatch_actions(vec![action])
+    }
+
+    /// Run a batch of actions, e.g., the burst of `movePoint` actions from dragging a point,
+    /// and return any changes to the output flat dast.
+    ///
+    /// The changes requested by all the actions are made together, so the props that depend on them
+    /// are marked stale and recalculated only once. The actions are run in order, and each action
+    /// sees the values requested by the actions before it, e.g., `updateValue` of a `<textInput>`
+    /// uses the immediate value requested by an `updateImmediateValue` before it.
+    /// Where actions change the same state, the last action wins. If any action fails, no changes are made.
+    ///
+    /// The batch is undone as a single step.
+    pub fn dispatch_actions(
+        &mut self,
+        actions: Vec<Action>,
+    ) -> Result<BTreeMap<ComponentIdx, FlatDastElementUpdate>, String> {
+        // The batch is timed as a whole, attributed to the component of its first action.
+        let first_component_idx = actions.first().map(|action| action.component_idx);
+        let sample = first_component_idx.and_then(|_| {
+            self.document_model
+                .start_telemetry_sample(TelemetryEventKind::Action)
+        });
+
+        // The values requested by the actions run so far, which later actions see in place of the current values.
+        let mut pending_values = GraphNodeLookup::new();
+        let mut changes_to_make = GraphNodeLookup::new();
+        for action in actions {
+            for (node, value) in self
+                .calculate_changes_from_action(action, &mut pending_values)?
+                .iter()
+            {
+                changes_to_make.set_tag(node, value.clone());
+            }
+        }
+
+        self.document_model.record_undo_step(&changes_to_make);
+        let changed_components = self.document_model.execute_changes(changes_to_make);
+
+        let updates = self
+            .document_renderer
+            .get_flat_dast_updates(changed_components, &self.document_model);
+        if let Some(component_idx) = first_component_idx {
+            self.document_model
+                .finish_telemetry_sample(sample, component_idx);
+        }
+        Ok(updates)
+    }
+
+    /// Calculate the changes to `State` and `String` nodes requested by `action`, without making them.
+    ///
+    /// `pending_values` holds the values requested by earlier actions of the batch, which `action` sees
+    /// in place of the current values. The values requested by `action` are added to them.
+    fn calculate_changes_from_action(
+        &mut self,
+        action: Action,
+        pending_values: &mut GraphNodeLookup<PropValue>,
+    ) -> Result<GraphNodeLookup<PropValue>, String> {
         let component_idx = action.component_idx;
 
+        if self.document_model.is_hidden_by_role(component_idx) {
+            return Err(format!(
+                "Actions on component {} are not permitted for the role {:?}",
+                component_idx.as_usize(),
+                self.document_model.get_role()
+            ));
+        }
+        if self
+            .document_model
+            .is_in_unavailable_division(component_idx)
+        {
+            return Err(format!(
+                "Actions on component {} are not permitted until its section is available",
+                component_idx.as_usize()
+            ));
+        }
+
         // We allow actions to resolve and get the value of any prop from the component.
-        let query_prop = ActionQueryProp::new(component_idx, &self.document_model);
+        let query_prop = ActionQueryProp::new(component_idx, &self.document_model, pending_values);
 
         // A call to on_action from a component processes the arguments and returns a vector
         // of component props with requested new values
@@ -36,14 +116,48 @@ impl Core {
             .get_component(component_idx)
             .on_action(action.action, query_prop)?;
 
-        let changes_to_make = self
+        Ok(self.document_model.calculate_changes_from_action_updates(
+            updates_from_action,
+            component_idx,
+            pending_values,
+        ))
+    }
+
+    /// Revert the changes made by the most recent action that has not been undone,
+    /// e.g., an accidental drag of a point, and return any changes to the output flat dast.
+    pub fn undo(&mut self) -> Result<BTreeMap<ComponentIdx, FlatDastElementUpdate>, String> {
+        let changed_components = self
             .document_model
-            .calculate_changes_from_action_updates(updates_from_action, component_idx);
+            .undo()
+            .ok_or_else(|| "There is nothing to undo".to_string())?;
 
-        let changed_components = self.document_model.execute_changes(changes_to_make);
+        Ok(self
+            .document_renderer
+            .get_flat_dast_updates(changed_components, &self.document_model))
+    }
+
+    /// Reapply the changes of the most recent action reverted by `undo`
+    /// and return any changes to the output flat dast.
+    ///
+    /// Dispatching another action discards the changes that could be redone.
+    pub fn redo(&mut self) -> Result<BTreeMap<ComponentIdx, FlatDastElementUpdate>, String> {
+        let changed_components = self
+            .document_model
+            .redo()
+            .ok_or_else(|| "There is nothing to redo".to_string())?;
 
         Ok(self
             .document_renderer
             .get_flat_dast_updates(changed_components, &self.document_model))
     }
+
+    /// Whether there is an action that can be undone, e.g., to enable an undo button.
+    pub fn can_undo(&self) -> bool {
+        self.document_model.can_undo()
+    }
+
+    /// Whether there is an undone action that can be redone.
+    pub fn can_redo(&self) -> bool {
+        self.document_model.can_redo()
+    }
 }
@@ -0,0 +1,38 @@
+//! Adaptive release of content: the contents of a `<division>` are released only once the condition
+//! in its `availableAfterCredit` attribute holds, e.g., after the student earns enough credit on a problem.
+
+use crate::{
+    components::{
+        ComponentEnum,
+        doenet::division::DivisionProps,
+        types::{ComponentIdx, PropPointer},
+    },
+    graph_node::GraphNode,
+    props::PropValue,
+};
+
+use super::DocumentModel;
+
+impl DocumentModel {
+    /// Whether the component `component_idx` is inside a division whose contents are not yet available.
+    pub fn is_in_unavailable_division(&self, component_idx: ComponentIdx) -> bool {
+        let ancestors = self
+            .document_structure
+            .borrow()
+            .get_true_component_ancestors(component_idx)
+            .collect::<Vec<_>>();
+
+        ancestors.into_iter().any(|idx| {
+            if !matches!(self.get_component(idx).variant, ComponentEnum::Division(_)) {
+                return false;
+            }
+            let prop_node = self.prop_pointer_to_prop_node(PropPointer {
+                component_idx: idx,
+                local_prop_idx: DivisionProps::Available.local_idx(),
+            });
+            self.get_prop_untracked(prop_node, GraphNode::Query(0))
+                .value
+                == PropValue::Boolean(false)
+        })
+    }
+}
@@ -0,0 +1,131 @@
+//! Annotations of a document by a student, e.g., highlights and notes, which are saved with the state
+//! of the document so that platforms can offer note-taking.
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::components::types::ComponentIdx;
+
+use super::DocumentModel;
+
+/// The kind of an annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub enum AnnotationKind {
+    Highlight,
+    Note,
+}
+
+/// A range of characters of the text of a component, from `start` up to but not including `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct TextRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// An annotation to add to a document. Core assigns it an id when it is added.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+#[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+pub struct NewAnnotation {
+    /// The component the annotation is anchored to. Like saved state, annotations are anchored by
+    /// the index of the component, so they are restored only to a document created from the same source.
+    pub component_idx: ComponentIdx,
+    pub kind: AnnotationKind,
+    /// The characters of the component that are annotated, or `None` if the annotation applies to the whole component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<TextRange>,
+    /// The text of a note. Highlights may leave it empty.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub text: String,
+}
+
+/// An annotation of a document, identified by the `id` assigned when it was added.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+#[cfg_attr(feature = "web", tsify(into_wasm_abi))]
+pub struct Annotation {
+    pub id: usize,
+    pub component_idx: ComponentIdx,
+    pub kind: AnnotationKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<TextRange>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub text: String,
+}
+
+impl DocumentModel {
+    /// Add an annotation to the document, returning it with its assigned id.
+    pub fn add_annotation(&self, new_annotation: NewAnnotation) -> Result<Annotation, String> {
+        let NewAnnotation {
+            component_idx,
+            kind,
+            range,
+            text,
+        } = new_annotation;
+        if !self.has_component(component_idx) {
+            return Err(format!(
+                "No component with index {}",
+                component_idx.as_usize()
+            ));
+        }
+        if let Some(TextRange { start, end }) = range.filter(|range| range.start > range.end) {
+            return Err(format!(
+                "The range of an annotation cannot start ({start}) after it ends ({end})"
+            ));
+        }
+
+        // Ids are never reused, so that a platform cannot confuse a removed annotation with a new one.
+        let id = self.next_annotation_id.get();
+        self.next_annotation_id.set(id + 1);
+
+        let annotation = Annotation {
+            id,
+            component_idx,
+            kind,
+            range,
+            text,
+        };
+        self.annotations.borrow_mut().push(annotation.clone());
+        Ok(annotation)
+    }
+
+    /// Remove the annotation with id `id`.
+    pub fn remove_annotation(&self, id: usize) -> Result<(), String> {
+        let mut annotations = self.annotations.borrow_mut();
+        let position = annotations
+            .iter()
+            .position(|annotation| annotation.id == id)
+            .ok_or_else(|| format!("No annotation with id {id}"))?;
+        annotations.remove(position);
+        Ok(())
+    }
+
+    /// Every annotation of the document, in the order they were added.
+    pub fn get_annotations(&self) -> Vec<Annotation> {
+        self.annotations.borrow().clone()
+    }
+
+    /// Replace the annotations of the document with `annotations`, e.g., when restoring saved state.
+    /// Annotations of components that are not in the document are skipped.
+    pub(super) fn load_annotations(&self, annotations: Vec<Annotation>) {
+        let annotations: Vec<_> = annotations
+            .into_iter()
+            .filter(|annotation| self.has_component(annotation.component_idx))
+            .collect();
+        if let Some(max_id) = annotations.iter().map(|annotation| annotation.id).max() {
+            self.next_annotation_id
+                .set(self.next_annotation_id.get().max(max_id + 1));
+        }
+        *self.annotations.borrow_mut() = annotations;
+    }
+}
+
+#[cfg(test)]
+#[path = "annotations.test.rs"]
+mod test;
@@ -0,0 +1,89 @@
+use crate::{Core, test_utils::*};
+
+use super::*;
+
+const SOURCE: &str = r#"<p name="p">Some text to annotate.</p><p>More text.</p>"#;
+
+fn highlight(component_idx: usize, start: usize, end: usize) -> NewAnnotation {
+    NewAnnotation {
+        component_idx: ComponentIdx::new(component_idx),
+        kind: AnnotationKind::Highlight,
+        range: Some(TextRange { start, end }),
+        text: String::new(),
+    }
+}
+
+fn note(component_idx: usize, text: &str) -> NewAnnotation {
+    NewAnnotation {
+        component_idx: ComponentIdx::new(component_idx),
+        kind: AnnotationKind::Note,
+        range: None,
+        text: text.to_string(),
+    }
+}
+
+#[test]
+fn annotations_can_be_added_and_removed() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+
+    let first = core.add_annotation(highlight(1, 5, 9)).unwrap();
+    let second = core.add_annotation(note(2, "Review this")).unwrap();
+    assert_ne!(first.id, second.id);
+    assert_eq!(core.get_annotations(), vec![first.clone(), second.clone()]);
+
+    core.remove_annotation(first.id).unwrap();
+    assert_eq!(core.get_annotations(), vec![second.clone()]);
+    assert!(core.remove_annotation(first.id).is_err());
+
+    // Ids of removed annotations are not reused.
+    let third = core.add_annotation(note(1, "Again")).unwrap();
+    assert_ne!(third.id, first.id);
+    assert_ne!(third.id, second.id);
+}
+
+#[test]
+fn invalid_annotations_are_rejected() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+
+    assert!(core.add_annotation(note(100, "Nowhere")).is_err());
+    assert!(core.add_annotation(highlight(1, 9, 5)).is_err());
+    assert!(core.get_annotations().is_empty());
+}
+
+#[test]
+fn annotations_are_saved_with_state() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    let highlight = core.add_annotation(highlight(1, 5, 9)).unwrap();
+    let note = core.add_annotation(note(2, "Review this")).unwrap();
+
+    let saved_state = core.document_model.export_state();
+    assert_eq!(
+        serde_json::to_value(&saved_state).unwrap(),
+        serde_json::json!({
+            "values": [],
+            "annotations": [
+                { "id": 0, "componentIdx": 1, "kind": "highlight", "range": { "start": 5, "end": 9 } },
+                { "id": 1, "componentIdx": 2, "kind": "note", "text": "Review this" }
+            ]
+        })
+    );
+
+    let mut restored_core = Core::new();
+    restored_core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    restored_core.document_model.load_state(saved_state);
+    assert_eq!(restored_core.get_annotations(), vec![highlight, note]);
+
+    // New annotations do not reuse the ids of restored annotations.
+    let added = restored_core
+        .add_annotation(NewAnnotation {
+            component_idx: ComponentIdx::new(1),
+            kind: AnnotationKind::Note,
+            range: None,
+            text: "Another".to_string(),
+        })
+        .unwrap();
+    assert_eq!(added.id, 2);
+}
@@ -0,0 +1,157 @@
+//! Breakpoints on props. When a prop with a breakpoint is resolved or marked stale,
+//! a `PropBreakpointEvent` is recorded describing the chain of dependencies that caused it.
+
+use serde::Serialize;
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::{
+    components::types::ComponentIdx,
+    graph::directed_graph::Taggable,
+    graph_node::{GraphNode, GraphNodeLookup},
+    utils::log_debug,
+};
+
+use super::DocumentModel;
+
+/// What happened to a prop with a breakpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub enum PropBreakpointTrigger {
+    /// The prop's dependencies were added to the dependency graph.
+    Resolved,
+    /// A value the prop depends on changed, so the prop must be recalculated.
+    MarkedStale,
+}
+
+/// A node on the dependency path that led to a breakpoint.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub enum DependencyPathNode {
+    #[serde(rename_all = "camelCase")]
+    Prop {
+        component_idx: ComponentIdx,
+        component_type: String,
+        prop_name: String,
+    },
+    /// A piece of state, e.g., the value typed into an input.
+    State,
+    /// A string in the document source.
+    String,
+}
+
+/// Emitted when a prop with a breakpoint is resolved or marked stale.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct PropBreakpointEvent {
+    pub trigger: PropBreakpointTrigger,
+    /// The dependency path from the node that started the update to the prop with the breakpoint.
+    /// The last entry is always the prop with the breakpoint.
+    ///
+    /// For `Resolved`, the path starts at the prop whose value was requested.
+    /// For `MarkedStale`, the path starts at the `State` or `String` node that was changed.
+    pub path: Vec<DependencyPathNode>,
+}
+
+/// The props with breakpoints and the events they have emitted.
+#[derive(Debug, Default)]
+pub struct PropBreakpoints {
+    props: GraphNodeLookup<bool>,
+    num_breakpoints: usize,
+    events: Vec<PropBreakpointEvent>,
+}
+
+impl DocumentModel {
+    /// Set a breakpoint on `prop_node`.
+    pub fn set_prop_breakpoint(&self, prop_node: GraphNode) {
+        let mut breakpoints = self.breakpoints.borrow_mut();
+        if breakpoints.props.get_tag(&prop_node) != Some(&true) {
+            breakpoints.props.set_tag(prop_node, true);
+            breakpoints.num_breakpoints += 1;
+        }
+    }
+
+    /// Remove the breakpoint from `prop_node`, if there is one.
+    pub fn clear_prop_breakpoint(&self, prop_node: GraphNode) {
+        let mut breakpoints = self.breakpoints.borrow_mut();
+        if breakpoints.props.get_tag(&prop_node) == Some(&true) {
+            breakpoints.props.set_tag(prop_node, false);
+            breakpoints.num_breakpoints -= 1;
+        }
+    }
+
+    /// Return all breakpoint events emitted since the last call, oldest first.
+    pub fn take_breakpoint_events(&self) -> Vec<PropBreakpointEvent> {
+        std::mem::take(&mut self.breakpoints.borrow_mut().events)
+    }
+
+    /// Whether `prop_node` has a breakpoint.
+    pub(super) fn has_breakpoint(&self, prop_node: GraphNode) -> bool {
+        let breakpoints = self.breakpoints.borrow();
+        breakpoints.num_breakpoints > 0 && breakpoints.props.get_tag(&prop_node) == Some(&true)
+    }
+
+    /// Record a breakpoint event for the path `path`, which ends with the prop that has the breakpoint.
+    pub(super) fn emit_breakpoint_event(&self, trigger: PropBreakpointTrigger, path: &[GraphNode]) {
+        let path = path
+            .iter()
+            .filter_map(|node| match node {
+                GraphNode::Prop(_) => {
+                    let prop_definition = self.get_prop_definition(*node);
+                    let component_idx = prop_definition.meta.prop_pointer.component_idx;
+                    Some(DependencyPathNode::Prop {
+                        component_idx,
+                        component_type: self.get_component_type(component_idx),
+                        prop_name: prop_definition.meta.name.to_string(),
+                    })
+                }
+                GraphNode::State(_) => Some(DependencyPathNode::State),
+                GraphNode::String(_) => Some(DependencyPathNode::String),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let event = PropBreakpointEvent { trigger, path };
+        log_debug!("Prop breakpoint: {:?}", event);
+        self.breakpoints.borrow_mut().events.push(event);
+    }
+
+    /// Find a path in the dependency graph from `prop_node` down to any of `targets`.
+    /// The path is returned starting from the target and ending with `prop_node`.
+    pub(super) fn dependency_path_to_prop(
+        &self,
+        prop_node: GraphNode,
+        targets: &[GraphNode],
+    ) -> Vec<GraphNode> {
+        let dependency_graph = self.dependency_graph.borrow();
+        let mut visited = GraphNodeLookup::new();
+        // Depth-first search keeping track of the current path.
+        let mut stack = vec![(prop_node, 0)];
+        let mut path = vec![];
+        while let Some((node, depth)) = stack.pop() {
+            path.truncate(depth);
+            path.push(node);
+            if targets.contains(&node) {
+                path.reverse();
+                return path;
+            }
+            if visited.get_tag(&node).is_some() {
+                continue;
+            }
+            visited.set_tag(node, ());
+            stack.extend(
+                dependency_graph
+                    .get_children(node)
+                    .into_iter()
+                    .map(|child| (child, depth + 1)),
+            );
+        }
+        vec![prop_node]
+    }
+}
+
+#[cfg(test)]
+#[path = "breakpoints.test.rs"]
+mod test;
@@ -0,0 +1,104 @@
+use crate::{
+    Core, PropBreakpointTrigger,
+    components::{
+        ActionsEnum,
+        doenet::text_input::{TextInputActionArgs, TextInputActions},
+        types::{Action, ActionBody, ComponentIdx},
+    },
+    test_utils::*,
+};
+
+use super::*;
+
+fn prop_names(path: &[DependencyPathNode]) -> Vec<&str> {
+    path.iter()
+        .filter_map(|node| match node {
+            DependencyPathNode::Prop { prop_name, .. } => Some(prop_name.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn breakpoint_records_resolution_and_staleness() {
+    // <document>: idx 0
+    //  <textInput name="ti">: idx 1
+    //  <text>: idx 2
+    let dast_root = dast_root_no_position(r#"<textInput name="ti"/><text>$ti</text>"#);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let text_input_idx = ComponentIdx::new(1);
+    core.set_prop_breakpoint(text_input_idx, "value").unwrap();
+
+    core.to_flat_dast();
+    let events = core.take_breakpoint_events();
+    let resolved = events
+        .iter()
+        .find(|e| e.trigger == PropBreakpointTrigger::Resolved)
+        .expect("`value` should have been resolved while rendering");
+    assert!(matches!(
+        resolved.path.last(),
+        Some(DependencyPathNode::Prop { component_idx, prop_name, .. })
+            if component_idx == &text_input_idx && prop_name == "value"
+    ));
+    assert!(core.take_breakpoint_events().is_empty());
+
+    let _ = core.dispatch_action(Action {
+        component_idx: text_input_idx,
+        action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+            args: TextInputActionArgs {
+                text: "hello".to_string(),
+            },
+        })),
+    });
+    let _ = core.dispatch_action(Action {
+        component_idx: text_input_idx,
+        action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+    });
+
+    let events = core.take_breakpoint_events();
+    let stale = events
+        .iter()
+        .find(|e| e.trigger == PropBreakpointTrigger::MarkedStale)
+        .expect("`value` should have been marked stale by the action");
+    assert_eq!(
+        stale.path.first(),
+        Some(&DependencyPathNode::State),
+        "the path should start at the state that was changed"
+    );
+    assert_eq!(prop_names(&stale.path).last(), Some(&"value"));
+
+    core.clear_prop_breakpoint(text_input_idx, "value").unwrap();
+    let _ = core.dispatch_action(Action {
+        component_idx: text_input_idx,
+        action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+            args: TextInputActionArgs {
+                text: "bye".to_string(),
+            },
+        })),
+    });
+    let _ = core.dispatch_action(Action {
+        component_idx: text_input_idx,
+        action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+    });
+    assert!(core.take_breakpoint_events().is_empty());
+}
+
+#[test]
+fn breakpoint_on_unknown_prop_is_an_error() {
+    let dast_root = dast_root_no_position(r#"<text>hi</text>"#);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    assert!(
+        core.set_prop_breakpoint(ComponentIdx::new(1), "not_a_prop")
+            .is_err()
+    );
+    assert!(
+        core.set_prop_breakpoint(ComponentIdx::new(7), "value")
+            .is_err()
+    );
+}
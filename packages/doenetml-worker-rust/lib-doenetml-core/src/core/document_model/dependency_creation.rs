@@ -9,7 +9,11 @@ use crate::{
     props::{FilterData, PickPropSource, PropSource, PropSpecifier, cache::PropStatus},
 };
 
-use crate::{graph_node::GraphNode, props::PropValue};
+use crate::{
+    graph::directed_graph::Taggable,
+    graph_node::{GraphNode, GraphNodeLookup},
+    props::{PropProfile, PropValue},
+};
 
 use super::DocumentModel;
 use super::dependency_creation_helpers::*;
@@ -30,13 +34,18 @@ impl DocumentModel {
                     GraphNode::Prop(_) => {
                         let prop_updater = self.get_prop_updater(leaf_node);
 
-                        // TODO: when we load saved data from a data base, then set value and came_from_default
-                        // from the data.
-                        let default_value = prop_updater.default();
-                        let came_from_default = true;
+                        // Use the saved value of the state, if there is one. A copy with independent state
+                        // otherwise starts from the state of its source.
+                        let (value, came_from_default) = match self.get_pending_state(leaf_node) {
+                            Some(saved_value) => (saved_value, false),
+                            None => self
+                                .get_independent_initial_state(leaf_node)
+                                .unwrap_or_else(|| (prop_updater.default(), true)),
+                        };
 
                         let state_node: GraphNode =
-                            self.add_state_node(prop_node, default_value, came_from_default);
+                            self.add_state_node(prop_node, value, came_from_default);
+                        self.register_state_scope(leaf_node, state_node, prop_updater.default());
                         self.document_structure
                             .borrow_mut()
                             .add_edge(leaf_node, state_node);
@@ -86,6 +95,9 @@ impl DocumentModel {
             }
             | DataQuery::AnnotatedContentRefs {
                 container: source, ..
+            }
+            | DataQuery::DescendantContentRefs {
+                container: source, ..
             } => {
                 // If resolving the prop source requires resolving additional dependencies first,
                 // we get an `Err` object here, which is passed to the caller so that they can resolve the dependency for us.
@@ -95,6 +107,12 @@ impl DocumentModel {
             | DataQuery::Attribute { .. }
             | DataQuery::State
             | DataQuery::SelfRef
+            | DataQuery::RenderMode
+            | DataQuery::Role
+            | DataQuery::VariantSeed
+            | DataQuery::StudentSeed
+            | DataQuery::SeenSelections
+            | DataQuery::Environment(_)
             | DataQuery::Null => None,
         };
 
@@ -121,6 +139,10 @@ impl DocumentModel {
                     prop_node, to,
                     "Self-loop detected; DataQuery requested a prop that is the same as the origin prop."
                 );
+                // Content hidden from the viewer because of their role resolves to nothing.
+                if self.is_dependency_hidden_by_role(prop_pointer.component_idx, to) {
+                    continue;
+                }
                 dependency_graph.add_edge(from, to);
                 linked_nodes.push(to);
             }
@@ -143,8 +165,14 @@ impl DocumentModel {
                 fn_add_edges(vec![(query_node, state_node)]);
             }
 
-            DataQuery::SelfRef => {
-                // SelfRef queries are computed on-the-fly, so there is no need to link them
+            DataQuery::SelfRef
+            | DataQuery::RenderMode
+            | DataQuery::Role
+            | DataQuery::VariantSeed
+            | DataQuery::StudentSeed
+            | DataQuery::SeenSelections
+            | DataQuery::Environment(_) => {
+                // SelfRef, flag, and environment queries are computed on-the-fly, so there is no need to link them
                 // to anything.
             }
 
@@ -256,6 +284,13 @@ impl DocumentModel {
                             .collect_vec();
                         container_nodes
                     }
+
+                    PickPropSource::Descendants => {
+                        let document_structure = self.document_structure.borrow();
+                        let container_nodes = document_structure
+                            .get_component_content_descendants(prop_pointer.component_idx);
+                        container_nodes
+                    }
                 };
 
                 match prop_specifier {
@@ -269,8 +304,10 @@ impl DocumentModel {
                             .map(|node| (query_node, node));
 
                         match source {
-                            PickPropSource::Children | PickPropSource::Attribute { .. } => {
-                                // for children or attribute, use all edges
+                            PickPropSource::Children
+                            | PickPropSource::Attribute { .. }
+                            | PickPropSource::Descendants => {
+                                // for children, attribute, or descendants, use all edges
                                 fn_add_edges(edges.collect());
                             }
                             PickPropSource::NearestMatchingAncestor => {
@@ -309,8 +346,10 @@ impl DocumentModel {
                             });
 
                         match source {
-                            PickPropSource::Children | PickPropSource::Attribute { .. } => {
-                                // for children or attribute, use all edges
+                            PickPropSource::Children
+                            | PickPropSource::Attribute { .. }
+                            | PickPropSource::Descendants => {
+                                // for children, attribute, or descendants, use all edges
                                 let edges =
                                     matching_props.flat_map(|(virtual_node, prop1, prop2)| {
                                         [
@@ -384,6 +423,54 @@ impl DocumentModel {
 
                 fn_add_edges(edges_to_add);
             }
+
+            DataQuery::DescendantContentRefs {
+                container: _container,
+                filter,
+            } => {
+                let component_idx = match resolved_component_idx {
+                    Some(idx) => idx,
+                    None => {
+                        // As for `DataQuery::ContentRefs`, an invalid component ref resolves to no content.
+                        return Ok(linked_nodes);
+                    }
+                };
+
+                let descendants = self
+                    .document_structure
+                    .borrow()
+                    .get_component_content_descendants(component_idx);
+
+                // The rendered children of the container and of each descendant determine which descendants are rendered,
+                // so depend on all of them, as well as on everything the filter could depend on.
+                let mut dep_nodes = Vec::new();
+                for node in std::iter::once(component_idx.as_graph_node())
+                    .chain(descendants.iter().copied())
+                {
+                    let rendered_children = self
+                        .get_component_prop_by_profile(node, &[PropProfile::RenderedChildren])
+                        .map(|prop_pointer| self.prop_pointer_to_prop_node(prop_pointer));
+                    dep_nodes.extend(rendered_children);
+                }
+                for &node in descendants.iter() {
+                    dep_nodes.extend(filter.accumulate_deps(&FilterData {
+                        node,
+                        origin: query_node,
+                        document_model: self,
+                    }));
+                }
+
+                // Different descendants may share dependencies; link each only once.
+                let mut linked = GraphNodeLookup::new();
+                let mut edges_to_add = Vec::new();
+                for node in dep_nodes {
+                    if linked.get_tag(&node).is_none() {
+                        linked.set_tag(node, true);
+                        edges_to_add.push((query_node, node));
+                    }
+                }
+                fn_add_edges(edges_to_add);
+            }
         }
         Ok(linked_nodes)
     }
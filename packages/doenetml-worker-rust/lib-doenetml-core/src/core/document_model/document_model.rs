@@ -11,16 +11,20 @@ use crate::{
         Component, ComponentNode, ComponentProps,
         types::{ComponentIdx, LocalPropIdx, PropPointer},
     },
-    dast::ElementRefAnnotation,
-    graph_node::{DependencyGraph, GraphNode},
+    dast::{ElementRefAnnotation, Position as DastPosition},
+    graph_node::{DependencyGraph, GraphNode, GraphNodeLookup},
     props::{
-        DataQuery, DataQueryResults, PropDefinition, PropProfile, RenderContext, StateCache,
-        UpdaterObject,
+        DataQuery, DataQueryResults, PropDefinition, PropProfile, PropValue, RenderContext,
+        StateCache, UpdaterObject,
         cache::{PropCache, PropStatus, PropWithMeta},
     },
 };
 
-use super::super::document_structure::DocumentStructure;
+use super::{
+    super::document_structure::DocumentStructure, Annotation, DocumentEnvironment, DocumentFlags,
+    EssentialDataEvent, PropBreakpoints, PropOverrides, ResolutionTrace, TelemetrySampler,
+    UndoHistory, UserScopes,
+};
 
 #[derive(Debug)]
 pub struct DocumentModel {
@@ -42,6 +46,34 @@ pub struct DocumentModel {
     // XXX: Revisit if we still need this.
     #[allow(unused)]
     pub(super) virtual_node_count: Cell<usize>,
+    /// A record of recent prop calculations. `None` unless tracing has been enabled.
+    pub(super) resolution_trace: RefCell<Option<ResolutionTrace>>,
+    /// Telemetry installed by the host that receives sampled timing events. `None` unless installed.
+    pub(super) telemetry: RefCell<Option<TelemetrySampler>>,
+    /// Props with breakpoints and the events emitted when they were hit.
+    pub(super) breakpoints: RefCell<PropBreakpoints>,
+    /// Flags supplied by the host, e.g., the mode the document is rendered in.
+    pub(super) flags: RefCell<DocumentFlags>,
+    /// The environment in which the document is displayed, e.g., the width of the viewport.
+    pub(super) environment: RefCell<DocumentEnvironment>,
+    /// Saved values for state that has not been created yet, keyed by the prop that will store the state.
+    pub(super) pending_state: RefCell<GraphNodeLookup<PropValue>>,
+    /// Values that override the calculated values of props, e.g., to adjust credit.
+    pub(super) overrides: RefCell<PropOverrides>,
+    /// The active user and the state stored separately for each user.
+    pub(super) user_scopes: RefCell<UserScopes>,
+    /// The component that should receive focus once the next update is rendered.
+    pub(super) focus_request: Cell<Option<ComponentIdx>>,
+    /// The changes made by actions that can be undone and redone.
+    pub(super) undo_history: RefCell<UndoHistory>,
+    /// The annotations of the document, e.g., a student's highlights and notes.
+    pub(super) annotations: RefCell<Vec<Annotation>>,
+    /// The id of the next annotation to be added.
+    pub(super) next_annotation_id: Cell<usize>,
+    /// The changes made to essential data by `set_essential_data` that have not been taken yet.
+    pub(super) essential_data_events: RefCell<Vec<EssentialDataEvent>>,
+    /// The number of calls to `mark_dependents_stale`.
+    pub(super) staleness_passes: Cell<usize>,
 }
 
 impl DocumentModel {
@@ -57,6 +89,20 @@ impl DocumentModel {
             // will be used to represent null,
             // i.e., the lack of a node in that spot in the dependency graph.
             virtual_node_count: Cell::new(1),
+            resolution_trace: RefCell::new(None),
+            telemetry: RefCell::new(None),
+            breakpoints: RefCell::new(PropBreakpoints::default()),
+            flags: RefCell::new(DocumentFlags::default()),
+            environment: RefCell::new(DocumentEnvironment::default()),
+            pending_state: RefCell::new(GraphNodeLookup::new()),
+            overrides: RefCell::new(PropOverrides::default()),
+            user_scopes: RefCell::new(UserScopes::default()),
+            focus_request: Cell::new(None),
+            undo_history: RefCell::new(UndoHistory::default()),
+            annotations: RefCell::new(Vec::new()),
+            next_annotation_id: Cell::new(0),
+            essential_data_events: RefCell::new(Vec::new()),
+            staleness_passes: Cell::new(0),
         }
     }
 
@@ -95,8 +141,7 @@ impl DocumentModel {
                     .collect(),
             );
 
-            let prop = &self.get_prop_definition(prop_node.prop_idx());
-            prop.updater.calculate_untyped(required_data)
+            self.recalculate_prop(prop_node, required_data)
         })
     }
 
@@ -115,8 +160,7 @@ impl DocumentModel {
                     .collect(),
             );
 
-            let prop_definition = self.get_prop_definition(prop_node);
-            prop_definition.updater.calculate_untyped(required_data)
+            self.recalculate_prop(prop_node, required_data)
         })
     }
 
@@ -168,6 +212,14 @@ impl DocumentModel {
         self.document_structure.borrow().get_component_indices()
     }
 
+    /// Whether there is a component with index `component_idx`.
+    /// Components are stored by index, so this does not need to iterate over them.
+    pub fn has_component(&self, component_idx: ComponentIdx) -> bool {
+        self.document_structure
+            .borrow()
+            .has_component(component_idx)
+    }
+
     /// Get prop pointers to all `for_render` props of a component.
     pub fn get_for_render_prop_pointers(
         &self,
@@ -194,7 +246,7 @@ impl DocumentModel {
 
             let iterator = document_structure
                 .get_component(component_idx)
-                .get_for_render_local_prop_indices(render_context);
+                .get_for_render_local_prop_indices(render_context, self.get_render_mode());
             // Note: collect into a vector so that stop borrowing from document_structure.components
             iterator.collect::<Vec<_>>()
         };
@@ -206,6 +258,21 @@ impl DocumentModel {
             })
     }
 
+    /// Whether `query_node` is a query whose value depends on the flags or the environment of the document,
+    /// e.g., a `DataQuery::RenderMode`, `DataQuery::Role`, or `DataQuery::Environment`.
+    pub fn is_flag_query(&self, query_node: GraphNode) -> bool {
+        matches!(query_node, GraphNode::Query(_))
+            && matches!(
+                self.queries.borrow()[query_node.idx()],
+                DataQuery::RenderMode
+                    | DataQuery::Role
+                    | DataQuery::VariantSeed
+                    | DataQuery::StudentSeed
+                    | DataQuery::SeenSelections
+                    | DataQuery::Environment(_)
+            )
+    }
+
     /// Convert a `PropPointer` into a `GraphNode::Prop`
     pub fn prop_pointer_to_prop_node(&self, prop_pointer: PropPointer) -> GraphNode {
         let document_structure = self.document_structure.borrow();
@@ -241,6 +308,46 @@ impl DocumentModel {
         document_structure.get_string_value(string_node)
     }
 
+    /// The position in the DoenetML source of the attribute `attr_name` of the component `component_idx`,
+    /// e.g., so that a warning about the value of the attribute can point to it.
+    pub fn get_attribute_position(
+        &self,
+        component_idx: ComponentIdx,
+        attr_name: &str,
+    ) -> Option<DastPosition> {
+        self.document_structure
+            .borrow()
+            .get_attr_position(component_idx, attr_name)
+            .cloned()
+    }
+
+    /// The text of the attribute `attr_name` of the component `component_idx`, if the attribute is given
+    /// and consists only of literal text (and not, e.g., references to other components).
+    pub fn get_literal_attribute_text(
+        &self,
+        component_idx: ComponentIdx,
+        attr_name: &str,
+    ) -> Option<String> {
+        let document_structure = self.document_structure.borrow();
+        let attr_node = document_structure.get_attr_node(component_idx, attr_name)?;
+        let content = document_structure
+            .get_attribute_content_children(attr_node)
+            .collect::<Vec<_>>();
+        if content.is_empty()
+            || !content
+                .iter()
+                .all(|node| matches!(node, GraphNode::String(_)))
+        {
+            return None;
+        }
+        Some(
+            content
+                .iter()
+                .map(|node| document_structure.get_string_value(node))
+                .collect(),
+        )
+    }
+
     /// A vector of the possible profiles this component provides
     /// along with the index of the prop that you should refer to
     /// if you want data satisfying that profile.
@@ -284,6 +391,13 @@ impl DocumentModel {
         document_structure.get_component_content_children(component_idx)
     }
 
+    /// Whether another component extends the component `component_idx`, e.g., via `$foo` or `extend="$foo"`.
+    pub fn is_component_extended(&self, component_idx: ComponentIdx) -> bool {
+        self.document_structure
+            .borrow()
+            .is_component_extended(component_idx)
+    }
+
     /// Get the children of a component bit include information about whether they are
     /// "original" or whether they came from extending another component.
     pub fn get_component_content_children_annotated<T: Into<ComponentIdx>>(
@@ -0,0 +1,121 @@
+//! Information supplied by the host about the environment in which the document is displayed,
+//! e.g., the width of the viewport. Unlike flags, the environment may change after the document is rendered.
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::{
+    components::{prelude::DataQuery, types::ComponentIdx},
+    graph_node::GraphNode,
+    props::cache::PropStatus,
+};
+
+use super::DocumentModel;
+
+/// The kind of device on which the document is displayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub enum DeviceClass {
+    /// A desktop or laptop computer.
+    #[default]
+    Desktop,
+    /// A tablet.
+    Tablet,
+    /// A phone, where space is most limited.
+    Phone,
+}
+
+impl DeviceClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeviceClass::Desktop => "desktop",
+            DeviceClass::Tablet => "tablet",
+            DeviceClass::Phone => "phone",
+        }
+    }
+}
+
+/// The environment in which the document is displayed. Hosts update it as it changes, e.g., when the window is resized.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+#[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+pub struct DocumentEnvironment {
+    /// The width of the viewport in pixels, if known.
+    pub viewport_width: Option<f64>,
+    /// The kind of device on which the document is displayed.
+    pub device_class: DeviceClass,
+    /// Whether the person viewing the document asked for animations to be minimized.
+    pub prefers_reduced_motion: bool,
+}
+
+/// A property of the [`DocumentEnvironment`] that a prop can depend on with a `DataQuery::Environment`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvironmentProperty {
+    /// The width of the viewport in pixels, as a `Number`. There is no value if the width is not known.
+    ViewportWidth,
+    /// The kind of device, as a `String`, e.g., `"phone"`.
+    DeviceClass,
+    /// Whether animations should be minimized, as a `Boolean`.
+    PrefersReducedMotion,
+}
+
+impl EnvironmentProperty {
+    /// Whether this property differs between `old` and `new`.
+    fn changed(self, old: &DocumentEnvironment, new: &DocumentEnvironment) -> bool {
+        match self {
+            EnvironmentProperty::ViewportWidth => old.viewport_width != new.viewport_width,
+            EnvironmentProperty::DeviceClass => old.device_class != new.device_class,
+            EnvironmentProperty::PrefersReducedMotion => {
+                old.prefers_reduced_motion != new.prefers_reduced_motion
+            }
+        }
+    }
+}
+
+impl DocumentModel {
+    pub fn get_environment(&self) -> DocumentEnvironment {
+        self.environment.borrow().clone()
+    }
+
+    /// Set the environment of the document and mark the props that depend on the changed properties as stale.
+    ///
+    /// Return the components that have had one of their `for_render` props newly marked as stale.
+    pub fn set_environment(&self, environment: DocumentEnvironment) -> Vec<ComponentIdx> {
+        let old_environment = self.environment.replace(environment);
+        let environment = self.environment.borrow();
+
+        let changed_queries = self
+            .queries
+            .borrow()
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, query)| match query {
+                DataQuery::Environment(property)
+                    if property.changed(&old_environment, &environment) =>
+                {
+                    Some(GraphNode::Query(idx))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let changed_components = self.mark_dependents_stale(&changed_queries);
+
+        // A changed property may now have no value at all, e.g., when the viewport width becomes unknown,
+        // leaving no changed value to be seen when the props that query it are recalculated.
+        // Those props must be recalculated regardless.
+        for prop_node in changed_queries
+            .into_iter()
+            .filter_map(|query_node| self.get_nearest_prop_ancestor_of_query(query_node))
+        {
+            if self.prop_cache.get_prop_status(prop_node) == PropStatus::Stale {
+                self.prop_cache.invalidate_prop(prop_node);
+            }
+        }
+
+        changed_components
+    }
+}
@@ -0,0 +1,192 @@
+//! Inspecting and editing essential data, i.e., the state that props store rather than calculate,
+//! such as the value typed into a `<textInput>`. This is intended for debugging tools and instructor dashboards.
+//! Every edit is recorded as an `EssentialDataEvent` so that it can be audited.
+
+use std::rc::Rc;
+
+use serde::Serialize;
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::{
+    components::{
+        ComponentNode, ComponentProps, ComponentVariantProps,
+        types::{ComponentIdx, LocalPropIdx, PropPointer},
+    },
+    graph::directed_graph::Taggable,
+    graph_node::{GraphNode, GraphNodeLookup},
+    props::{PropValue, PropValueType},
+};
+
+use super::{DocumentModel, saved_state::prop_value_from_json};
+
+/// A piece of essential data of a component.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct EssentialData {
+    /// The name of the prop that stores the data, e.g., `value`.
+    pub origin: String,
+    pub value: PropValue,
+    /// Whether the data still has its default value.
+    pub came_from_default: bool,
+}
+
+/// Recorded when essential data is changed by `set_essential_data`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct EssentialDataEvent {
+    pub component_idx: ComponentIdx,
+    /// The name of the prop that stores the data.
+    pub origin: String,
+    /// The entry that was changed, starting from 1, if only one entry of a vector was changed.
+    pub index: Option<usize>,
+    pub previous_value: PropValue,
+    pub value: PropValue,
+}
+
+impl DocumentModel {
+    /// The essential data of the component `component_idx`, in the order of the props that store it.
+    ///
+    /// Only data that has been created is listed, since a prop creates its data when it is first calculated.
+    /// Nothing is calculated by this call.
+    pub fn list_essential_data(
+        &self,
+        component_idx: ComponentIdx,
+    ) -> Result<Vec<EssentialData>, String> {
+        if !self.has_component(component_idx) {
+            return Err(format!(
+                "No component with index {}",
+                component_idx.as_usize()
+            ));
+        }
+        let component = self.get_component(component_idx);
+        Ok(component
+            .variant
+            .get_prop_names()
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, prop_name)| {
+                let state_node = self.get_essential_data_node(PropPointer {
+                    component_idx,
+                    local_prop_idx: LocalPropIdx::new(idx),
+                })?;
+                let state = self.states.peek_state(state_node);
+                Some(EssentialData {
+                    origin: prop_name.to_string(),
+                    value: state.value,
+                    came_from_default: state.came_from_default,
+                })
+            })
+            .collect())
+    }
+
+    /// Set the essential data stored by the prop named `origin` of the component `component_idx` to `value`,
+    /// which must be the JSON form of a value of the prop's type. If `index` is given, the data must be a vector,
+    /// and only its entry at `index`, starting from 1, is set.
+    ///
+    /// Return the components that have had one of their `for_render` props newly marked as stale.
+    pub fn set_essential_data(
+        &self,
+        component_idx: ComponentIdx,
+        origin: &str,
+        index: Option<usize>,
+        value: serde_json::Value,
+    ) -> Result<Vec<ComponentIdx>, String> {
+        if !self.has_component(component_idx) {
+            return Err(format!(
+                "No component with index {}",
+                component_idx.as_usize()
+            ));
+        }
+        let component = self.get_component(component_idx);
+        let local_prop_idx = component
+            .get_local_prop_index_from_name(origin)
+            .ok_or_else(|| {
+                format!(
+                    "Component `{}` has no prop named `{origin}`",
+                    component.get_component_type()
+                )
+            })?;
+        let state_node = self
+            .get_essential_data_node(PropPointer {
+                component_idx,
+                local_prop_idx,
+            })
+            .ok_or_else(|| format!("Prop `{origin}` has no essential data"))?;
+
+        let previous_value = self.states.peek_state(state_node).value;
+        let new_value = match index {
+            None => {
+                let value_type = component.variant.get_prop_value_type(local_prop_idx);
+                prop_value_from_json(value.clone(), value_type).ok_or_else(|| {
+                    format!("Cannot set `{origin}` of type {value_type:?} to {value}")
+                })?
+            }
+            Some(index) => {
+                let PropValue::PropVec(entries) = &previous_value else {
+                    return Err(format!(
+                        "`{origin}` is not a vector, so it cannot be indexed"
+                    ));
+                };
+                let entry_idx = index
+                    .checked_sub(1)
+                    .filter(|entry_idx| *entry_idx < entries.len())
+                    .ok_or_else(|| {
+                        format!(
+                            "`{origin}` has no entry {index}; it has {} entries",
+                            entries.len()
+                        )
+                    })?;
+                let entry_type = PropValueType::from(&entries[entry_idx]);
+                let entry = prop_value_from_json(value.clone(), entry_type).ok_or_else(|| {
+                    format!(
+                        "Cannot set entry {index} of `{origin}` of type {entry_type:?} to {value}"
+                    )
+                })?;
+                let mut entries = entries.as_ref().clone();
+                entries[entry_idx] = entry;
+                PropValue::PropVec(Rc::new(entries))
+            }
+        };
+
+        self.essential_data_events
+            .borrow_mut()
+            .push(EssentialDataEvent {
+                component_idx,
+                origin: origin.to_string(),
+                index,
+                previous_value,
+                value: new_value.clone(),
+            });
+
+        self.clear_undo_history();
+        let mut changes_to_make = GraphNodeLookup::new();
+        changes_to_make.set_tag(state_node, new_value);
+        Ok(self.execute_changes(changes_to_make))
+    }
+
+    /// Return all essential data events recorded since the last call, oldest first.
+    pub fn take_essential_data_events(&self) -> Vec<EssentialDataEvent> {
+        std::mem::take(&mut self.essential_data_events.borrow_mut())
+    }
+
+    /// The state node storing the essential data of `prop_pointer`, if the prop stores data that has been created.
+    ///
+    /// The data of a prop extending another prop is stored by the prop at the bottom of the chain,
+    /// so only that prop links directly to the state node.
+    fn get_essential_data_node(&self, prop_pointer: PropPointer) -> Option<GraphNode> {
+        let prop_node = self.prop_pointer_to_prop_node(prop_pointer);
+        self.document_structure
+            .borrow()
+            .get_structure_graph()
+            .get_children(prop_node)
+            .into_iter()
+            .find(|node| matches!(node, GraphNode::State(_)))
+    }
+}
+
+#[cfg(test)]
+#[path = "essential_data.test.rs"]
+mod test;
@@ -0,0 +1,146 @@
+use std::rc::Rc;
+
+use crate::{Core, extract_text, test_utils::*};
+
+use super::*;
+
+const SOURCE: &str = r#"<textInput name="ti"/><text>$ti</text>"#;
+
+fn string_value(value: &str) -> PropValue {
+    PropValue::String(Rc::new(value.to_string()))
+}
+
+#[test]
+fn essential_data_is_listed_once_created() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    let text_input_idx = ComponentIdx::new(1);
+    assert!(
+        core.document_model
+            .list_essential_data(text_input_idx)
+            .unwrap()
+            .is_empty()
+    );
+
+    core.to_flat_dast();
+    let essential_data = core
+        .document_model
+        .list_essential_data(text_input_idx)
+        .unwrap();
+    let value = essential_data
+        .iter()
+        .find(|data| data.origin == "value")
+        .unwrap();
+    assert_eq!(value.value, string_value(""));
+    assert!(value.came_from_default);
+
+    assert!(
+        core.document_model
+            .list_essential_data(ComponentIdx::new(100))
+            .is_err()
+    );
+}
+
+#[test]
+fn setting_essential_data_updates_dependents_and_is_recorded() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.to_flat_dast();
+
+    let changed_components = core
+        .document_model
+        .set_essential_data(
+            ComponentIdx::new(1),
+            "value",
+            None,
+            serde_json::json!("hello"),
+        )
+        .unwrap();
+    assert!(!changed_components.is_empty());
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "hello");
+
+    assert_eq!(
+        core.document_model.take_essential_data_events(),
+        vec![EssentialDataEvent {
+            component_idx: ComponentIdx::new(1),
+            origin: "value".to_string(),
+            index: None,
+            previous_value: string_value(""),
+            value: string_value("hello"),
+        }]
+    );
+    assert!(core.document_model.take_essential_data_events().is_empty());
+}
+
+#[test]
+fn an_entry_of_vector_essential_data_can_be_set() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(
+        r#"<choiceInput name="ci"><choice>a</choice><choice>b</choice></choiceInput>"#,
+    ));
+    core.to_flat_dast();
+    let choice_input_idx = ComponentIdx::new(1);
+
+    core.document_model
+        .set_essential_data(
+            choice_input_idx,
+            "selectedIndices",
+            None,
+            serde_json::json!([1]),
+        )
+        .unwrap();
+    core.document_model
+        .set_essential_data(
+            choice_input_idx,
+            "selectedIndices",
+            Some(1),
+            serde_json::json!(2),
+        )
+        .unwrap();
+
+    let selected_indices = core
+        .document_model
+        .list_essential_data(choice_input_idx)
+        .unwrap()
+        .into_iter()
+        .find(|data| data.origin == "selectedIndices")
+        .unwrap();
+    assert_eq!(
+        selected_indices.value,
+        PropValue::from(vec![PropValue::Integer(2)])
+    );
+
+    // There is no second entry to set.
+    assert!(
+        core.document_model
+            .set_essential_data(
+                choice_input_idx,
+                "selectedIndices",
+                Some(2),
+                serde_json::json!(1),
+            )
+            .is_err()
+    );
+}
+
+#[test]
+fn invalid_edits_are_rejected() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.to_flat_dast();
+    let text_input_idx = ComponentIdx::new(1);
+
+    for (origin, index, value) in [
+        ("notAProp", None, serde_json::json!("a")),
+        ("value", None, serde_json::json!(3)),
+        ("value", Some(1), serde_json::json!("a")),
+    ] {
+        assert!(
+            core.document_model
+                .set_essential_data(text_input_idx, origin, index, value)
+                .is_err()
+        );
+    }
+    assert!(core.document_model.take_essential_data_events().is_empty());
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "");
+}
@@ -0,0 +1,91 @@
+//! Experiments that compare versions of content, e.g., for learning-engineering studies.
+//! Each `<experiment>` assigns a student to one of its arms from the student's seed,
+//! and the assignment is saved so that the student stays in the same arm.
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::{
+    components::{
+        ComponentEnum,
+        doenet::experiment::ExperimentProps,
+        types::{ComponentIdx, PropPointer},
+    },
+    graph_node::GraphNode,
+    props::PropValue,
+};
+
+use super::{DocumentModel, SavedStateValue};
+
+/// The arm to which an `<experiment>` assigned the student, for analytics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct ExperimentAssignment {
+    pub component_idx: ComponentIdx,
+    /// The index of the arm among the child components of the `<experiment>`.
+    pub arm_index: usize,
+}
+
+impl DocumentModel {
+    /// The seed from which experiments assign arms.
+    pub fn get_student_seed(&self) -> u64 {
+        self.flags.borrow().student_seed
+    }
+
+    /// Whether the component `component_idx` is inside an `<experiment>`, so it is an arm
+    /// that is rendered only for some students.
+    pub fn is_in_experiment(&self, component_idx: ComponentIdx) -> bool {
+        self.document_structure
+            .borrow()
+            .get_true_component_ancestors(component_idx)
+            .any(|idx| {
+                matches!(
+                    self.get_component(idx).variant,
+                    ComponentEnum::Experiment(_)
+                )
+            })
+    }
+
+    /// The arm assigned by each `<experiment>` of the document. An `<experiment>` without arms is not included.
+    pub fn get_experiment_assignments(&self) -> Vec<ExperimentAssignment> {
+        self.get_component_indices()
+            .filter(|component_idx| {
+                matches!(
+                    self.get_component(*component_idx).variant,
+                    ComponentEnum::Experiment(_)
+                )
+            })
+            .filter_map(|component_idx| {
+                let prop_node = self.prop_pointer_to_prop_node(PropPointer {
+                    component_idx,
+                    local_prop_idx: ExperimentProps::ArmIndex.local_idx(),
+                });
+                match self
+                    .get_prop_untracked(prop_node, GraphNode::Query(0))
+                    .value
+                {
+                    PropValue::Integer(arm_index) => Some(ExperimentAssignment {
+                        component_idx,
+                        arm_index: usize::try_from(arm_index).ok()?,
+                    }),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// The assignments of the experiments as saved state of their `armIndex` prop, so that
+    /// restoring the state keeps each student in their arm even if their seed or the arms change.
+    pub(super) fn get_experiment_assignment_values(&self) -> Vec<SavedStateValue> {
+        self.get_experiment_assignments()
+            .into_iter()
+            .map(|assignment| SavedStateValue {
+                component_idx: assignment.component_idx,
+                prop_name: "armIndex".to_string(),
+                value: serde_json::json!(assignment.arm_index),
+            })
+            .collect()
+    }
+}
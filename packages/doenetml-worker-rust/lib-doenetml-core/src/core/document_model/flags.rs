@@ -0,0 +1,117 @@
+//! Flags supplied by the host that configure how a document is presented.
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::{
+    components::{
+        ComponentEnum, doenet::for_instructor::FOR_INSTRUCTOR_ROLES, types::ComponentIdx,
+    },
+    core::document_renderer::RenderPrecision,
+    graph_node::GraphNode,
+    props::{RenderMode, Role},
+};
+
+use super::{DocumentModel, SelectionDescriptor};
+
+/// Flags that configure how a document is presented. Flags not recognized by core are ignored,
+/// so hosts may pass the same flags object they give to the renderers.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+#[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+pub struct DocumentFlags {
+    /// The medium the document is rendered for.
+    pub render_mode: RenderMode,
+    /// The role of the person viewing the document.
+    pub role: Role,
+    /// The seed from which selections are made, e.g., which option a `<select>` shows,
+    /// so that different students can be given different variants of the document.
+    pub variant_seed: u64,
+    /// The seed assigned to the student viewing the document, from which an `<experiment>` assigns an arm.
+    /// Unlike `variant_seed`, it should stay the same across all the attempts of a student.
+    pub student_seed: u64,
+    /// The options selected on previous attempts (see `get_selections`), which selections avoid repeating.
+    pub seen_selections: Vec<SelectionDescriptor>,
+    /// The precision of the numbers sent to the renderers, which may differ by component type.
+    pub render_precision: RenderPrecision,
+    /// The names of the experimental components that may be used in the document.
+    /// An experimental component that is not listed is replaced by an error.
+    /// Unlike the other flags, it takes effect only when the document is next initialized.
+    pub experimental_components: Vec<String>,
+}
+
+impl DocumentModel {
+    /// Set the flags of the document.
+    ///
+    /// The flags determine which props are rendered, and the role determines which props may be depended on,
+    /// so they should be set before the document is rendered.
+    pub fn set_flags(&self, flags: DocumentFlags) {
+        *self.flags.borrow_mut() = flags;
+    }
+
+    pub fn get_flags(&self) -> DocumentFlags {
+        self.flags.borrow().clone()
+    }
+
+    /// The medium the document is rendered for.
+    pub fn get_render_mode(&self) -> RenderMode {
+        self.flags.borrow().render_mode
+    }
+
+    /// The precision of the numbers sent to the renderers.
+    pub fn get_render_precision(&self) -> RenderPrecision {
+        self.flags.borrow().render_precision.clone()
+    }
+
+    /// The role of the person viewing the document.
+    pub fn get_role(&self) -> Role {
+        self.flags.borrow().role
+    }
+
+    /// Whether the component `component_idx` must not reach the person viewing the document because of their role,
+    /// i.e., it is inside a `<forInstructor>` or was copied from a component inside a `<forInstructor>`,
+    /// and the document is not viewed by an instructor or grader.
+    pub fn is_hidden_by_role(&self, component_idx: ComponentIdx) -> bool {
+        if FOR_INSTRUCTOR_ROLES.contains(&self.get_role()) {
+            return false;
+        }
+
+        let document_structure = self.document_structure.borrow();
+        let mut source_idx = Some(component_idx);
+        while let Some(idx) = source_idx {
+            let in_for_instructor = std::iter::once(idx)
+                .chain(document_structure.get_true_component_ancestors(idx))
+                .any(|idx| {
+                    matches!(
+                        document_structure.get_component(idx).variant,
+                        ComponentEnum::ForInstructor(_)
+                    )
+                });
+            if in_for_instructor {
+                return true;
+            }
+            source_idx = document_structure.get_extended_from(idx);
+        }
+        false
+    }
+
+    /// Whether a data query of the component `origin_idx` must not depend on `node` because of the role of the viewer,
+    /// i.e., `node` is a prop of a component that is hidden by role (see `is_hidden_by_role`),
+    /// while `origin_idx` is not. Otherwise, instructor content could reach a student through a reference
+    /// such as `$ans.value` outside of the `<forInstructor>`, since only rendering is gated by role.
+    pub(super) fn is_dependency_hidden_by_role(
+        &self,
+        origin_idx: ComponentIdx,
+        node: GraphNode,
+    ) -> bool {
+        if FOR_INSTRUCTOR_ROLES.contains(&self.get_role()) || !matches!(node, GraphNode::Prop(_)) {
+            return false;
+        }
+        let target_idx = self.get_prop_pointer(node).component_idx;
+        target_idx != origin_idx
+            && self.is_hidden_by_role(target_idx)
+            && !self.is_hidden_by_role(origin_idx)
+    }
+}
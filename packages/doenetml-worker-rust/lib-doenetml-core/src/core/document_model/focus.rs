@@ -0,0 +1,19 @@
+//! Moving keyboard focus in response to interaction, e.g., to the tab that was selected with a keyboard shortcut,
+//! so that assistive technologies announce the content that changed.
+
+use crate::components::types::ComponentIdx;
+
+use super::DocumentModel;
+
+impl DocumentModel {
+    /// Request that `component_idx` receive focus once the next update is rendered.
+    /// A later request replaces an earlier one that has not been delivered yet.
+    pub fn request_focus(&self, component_idx: ComponentIdx) {
+        self.focus_request.set(Some(component_idx));
+    }
+
+    /// Take the pending focus request, if any, so that it is delivered with only one update.
+    pub fn take_focus_request(&self) -> Option<ComponentIdx> {
+        self.focus_request.take()
+    }
+}
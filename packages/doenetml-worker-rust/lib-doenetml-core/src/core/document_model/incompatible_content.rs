@@ -0,0 +1,103 @@
+//! Content that is ignored because its value cannot be coerced into the type that is expected,
+//! e.g., a `<boolean>` in the `x` attribute of a `<point>`.
+
+use crate::{
+    components::{
+        ComponentEnum, ComponentNode,
+        prelude::DataQuery,
+        types::{AttributeName, ComponentIdx},
+    },
+    graph_node::GraphNode,
+    props::{PickPropSource, PropProfile, PropSpecifier},
+};
+
+use super::DocumentModel;
+
+/// A component that was ignored because it provides no prop that can be coerced into the expected type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncompatibleContent {
+    /// The component that was ignored.
+    pub component_idx: ComponentIdx,
+    /// The component whose child (or attribute) it is.
+    pub container_idx: ComponentIdx,
+    /// The attribute it appears in, or `None` if it is a child.
+    pub attribute_name: Option<AttributeName>,
+}
+
+impl DocumentModel {
+    /// Find the components that were ignored by props calculated from children or attributes
+    /// because they provide none of the profiles that the prop accepts.
+    ///
+    /// Since props are calculated lazily, only the props that have been calculated are checked.
+    pub fn get_incompatible_content(&self) -> Vec<IncompatibleContent> {
+        let queries = self.queries.borrow().clone();
+        let dependency_graph = self.get_dependency_graph();
+        let document_structure = self.document_structure.borrow();
+
+        let mut found = Vec::new();
+        for (idx, query) in queries.iter().enumerate() {
+            let (attribute_name, match_profiles): (Option<AttributeName>, &[PropProfile]) =
+                match query {
+                    DataQuery::Attribute {
+                        attribute_name,
+                        match_profiles,
+                    } => (Some(*attribute_name), match_profiles),
+                    DataQuery::PickProp {
+                        source: PickPropSource::Children,
+                        prop_specifier: PropSpecifier::Matching(match_profiles),
+                    } => (None, match_profiles),
+                    _ => continue,
+                };
+
+            let query_node = GraphNode::Query(idx);
+            if !dependency_graph.contains_node(query_node) {
+                continue;
+            }
+            for prop_node in dependency_graph.get_parents(query_node) {
+                let container_idx = self.get_prop_pointer(prop_node).component_idx;
+                let content = match attribute_name {
+                    Some(attribute_name) => {
+                        match document_structure.get_attr_node(container_idx, attribute_name) {
+                            Some(attr_node) => document_structure
+                                .get_attribute_content_children(attr_node)
+                                .collect(),
+                            None => Vec::new(),
+                        }
+                    }
+                    None => document_structure.get_component_content_children(container_idx),
+                };
+
+                for node in content {
+                    let GraphNode::Component(_) = node else {
+                        continue;
+                    };
+                    let component_idx = ComponentIdx::from(node);
+                    let component = document_structure.get_component(component_idx);
+                    // Special components, such as elements that are not DoenetML components, never provide profiles.
+                    if matches!(
+                        component.variant,
+                        ComponentEnum::_Error(_)
+                            | ComponentEnum::_External(_)
+                            | ComponentEnum::_Fragment(_)
+                            | ComponentEnum::_Ref(_)
+                    ) || component.get_component_type().starts_with('_')
+                    {
+                        continue;
+                    }
+                    let incompatible = IncompatibleContent {
+                        component_idx,
+                        container_idx,
+                        attribute_name,
+                    };
+                    if component.get_prop_by_profile(match_profiles).is_none()
+                        && !found.contains(&incompatible)
+                    {
+                        found.push(incompatible);
+                    }
+                }
+            }
+        }
+
+        found
+    }
+}
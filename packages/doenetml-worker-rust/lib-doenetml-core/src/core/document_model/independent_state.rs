@@ -0,0 +1,75 @@
+//! Copies that do not share state with the component they extend.
+//!
+//! Normally, a component extending another of the same type shares its state, so that, e.g.,
+//! typing into either `<textInput>` changes both. With `independentState="true"`, the copy gets
+//! its own state instead, starting from the state of its source at the time the copy's state is created.
+
+use crate::{
+    components::{
+        Component, ComponentCommon, ComponentNode,
+        types::{ComponentIdx, PropPointer},
+    },
+    graph_node::GraphNode,
+    props::PropValue,
+};
+
+use super::{DocumentModel, provenance::attribute_text};
+
+/// The attribute with which a copy declares that it does not share state with its source.
+pub const INDEPENDENT_STATE_ATTRIBUTE: &str = "independentState";
+
+/// Whether `component` has `independentState="true"`.
+pub fn has_independent_state(component: &Component) -> bool {
+    component
+        .get_unrecognized_attributes()
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(INDEPENDENT_STATE_ATTRIBUTE))
+        .is_some_and(|(_, attribute)| {
+            attribute_text(attribute)
+                .trim()
+                .eq_ignore_ascii_case("true")
+        })
+}
+
+impl DocumentModel {
+    /// The prop whose state initializes the state of `prop_node`, if the component of `prop_node`
+    /// is a copy with independent state of a component of the same type.
+    fn get_independent_state_source(&self, prop_node: GraphNode) -> Option<GraphNode> {
+        let prop_pointer = self.get_prop_pointer(prop_node);
+        let component = self.get_component(prop_pointer.component_idx);
+        if !has_independent_state(&component) {
+            return None;
+        }
+        let source_idx: ComponentIdx = self
+            .document_structure
+            .borrow()
+            .get_extended_from(prop_pointer.component_idx)?;
+        if self.get_component(source_idx).get_component_type() != component.get_component_type() {
+            return None;
+        }
+        Some(self.prop_pointer_to_prop_node(PropPointer {
+            component_idx: source_idx,
+            local_prop_idx: prop_pointer.local_prop_idx,
+        }))
+    }
+
+    /// The initial state of `prop_node` if it is the state of a copy with independent state,
+    /// along with whether that value came from a default. The value is the current state of the source,
+    /// or its saved value if the source's state has not been created.
+    pub(super) fn get_independent_initial_state(
+        &self,
+        prop_node: GraphNode,
+    ) -> Option<(PropValue, bool)> {
+        let source_prop = self.get_independent_state_source(prop_node)?;
+        let source_leaf = self.document_structure.borrow().get_prop_leaf(source_prop);
+        match source_leaf {
+            GraphNode::State(_) => {
+                let state = self.states.peek_state(source_leaf);
+                Some((state.value, state.came_from_default))
+            }
+            _ => self
+                .get_pending_state(source_leaf)
+                .map(|value| (value, false)),
+        }
+    }
+}
@@ -3,14 +3,55 @@
 //! This module contains information about the structure of the document as well as the state of the document.
 //! It can be queried for information about the document and its components.
 
+mod adaptive_release;
+mod annotations;
+mod breakpoints;
 mod dependency_creation;
 mod dependency_creation_helpers;
 #[allow(clippy::module_inception)]
 mod document_model;
+mod environment;
+mod essential_data;
+mod experiments;
+mod flags;
+mod focus;
+mod incompatible_content;
+mod independent_state;
+mod objectives;
+mod presence;
 mod prop_calculation;
+mod prop_overrides;
 mod prop_updates;
+mod provenance;
+mod resolution_trace;
+mod saved_state;
+mod selections;
+mod state_diff;
+mod state_summary;
+mod telemetry;
+mod undo;
+mod user_scopes;
 
+pub use annotations::*;
+pub use breakpoints::*;
 pub use document_model::*;
+pub use environment::*;
+pub use essential_data::*;
+pub use experiments::*;
+pub use flags::*;
+pub use incompatible_content::*;
+pub use independent_state::*;
+pub use objectives::*;
+pub use prop_overrides::*;
+pub use provenance::*;
+pub use resolution_trace::*;
+pub use saved_state::*;
+pub use selections::*;
+pub use state_diff::*;
+pub use state_summary::*;
+pub use telemetry::*;
+pub use undo::*;
+pub use user_scopes::*;
 
 #[cfg(any(feature = "testing", test, not(feature = "web")))]
 mod debug;
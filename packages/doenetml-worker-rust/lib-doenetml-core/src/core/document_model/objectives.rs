@@ -0,0 +1,159 @@
+//! Learning objectives attached to components with the `objective` attribute,
+//! and a report of the credit earned on each objective, e.g., for standards-based grading.
+//! Also, the overall score of the document from the credit earned on its answers.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::{
+    components::{
+        ComponentCommon, ComponentNode, prelude::PropProfile, types::ComponentIdx,
+        types::PropPointer,
+    },
+    graph_node::GraphNode,
+    props::PropValue,
+};
+
+use super::{DocumentModel, provenance::attribute_text};
+
+/// The attribute that tags a component with the learning objectives it assesses,
+/// e.g., `objective="limits derivatives"`. Objectives are separated by whitespace or commas.
+pub const OBJECTIVE_ATTRIBUTE: &str = "objective";
+
+/// The credit earned on a single learning objective.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct ObjectiveCredit {
+    pub objective: String,
+    /// The components tagged with the objective, in document order.
+    pub component_indices: Vec<ComponentIdx>,
+    /// The credit of each component in `component_indices`.
+    pub credits: Vec<f64>,
+}
+
+/// The credit earned on each learning objective of a document, ordered by objective.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+#[cfg_attr(feature = "web", tsify(into_wasm_abi))]
+pub struct ObjectiveReport {
+    pub objectives: Vec<ObjectiveCredit>,
+}
+
+/// The score of a document from the credit achieved on its `<answer>` components.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+#[cfg_attr(feature = "web", tsify(into_wasm_abi))]
+pub struct DocumentScore {
+    /// The `<answer>` components, in document order.
+    pub component_indices: Vec<ComponentIdx>,
+    /// The credit, from 0 to 1, achieved on each answer in `component_indices`.
+    pub credits: Vec<f64>,
+    /// The total credit achieved, i.e., the sum of `credits`.
+    pub score: f64,
+    /// The total number of points available. Each answer is worth one point.
+    pub total_points: f64,
+}
+
+impl DocumentModel {
+    /// The learning objectives the component `component_idx` is tagged with.
+    pub fn get_component_objectives(&self, component_idx: ComponentIdx) -> Vec<String> {
+        let component = self.get_component(component_idx);
+        component
+            .get_unrecognized_attributes()
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case(OBJECTIVE_ATTRIBUTE))
+            .flat_map(|(_, attribute)| {
+                attribute_text(attribute)
+                    .split(|c: char| c.is_whitespace() || c == ',')
+                    .filter(|objective| !objective.is_empty())
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Aggregate the credit of the components tagged with each learning objective.
+    ///
+    /// The credit of a component is the value of its numeric prop, e.g., the value of a `<number>`.
+    /// Tagged components without a numeric prop are not included.
+    pub fn get_objective_report(&self) -> ObjectiveReport {
+        let mut objectives: BTreeMap<String, ObjectiveCredit> = BTreeMap::new();
+        for component_idx in self.get_component_indices() {
+            let component_objectives = self.get_component_objectives(component_idx);
+            if component_objectives.is_empty() {
+                continue;
+            }
+            let Some(credit) = self.get_component_credit(component_idx) else {
+                continue;
+            };
+
+            for objective in component_objectives {
+                let entry =
+                    objectives
+                        .entry(objective.clone())
+                        .or_insert_with(|| ObjectiveCredit {
+                            objective,
+                            component_indices: Vec::new(),
+                            credits: Vec::new(),
+                        });
+                entry.component_indices.push(component_idx);
+                entry.credits.push(credit);
+            }
+        }
+
+        ObjectiveReport {
+            objectives: objectives.into_values().collect(),
+        }
+    }
+
+    /// Compute the score of the document, where each `<answer>` is worth one point
+    /// and earns the credit it has achieved.
+    pub fn get_score(&self) -> DocumentScore {
+        let (component_indices, credits): (Vec<_>, Vec<_>) = self
+            .get_component_indices()
+            .filter(|component_idx| {
+                self.get_component(*component_idx).get_component_type() == "answer"
+            })
+            .map(|component_idx| {
+                (
+                    component_idx,
+                    self.get_component_credit(component_idx).unwrap_or(0.0),
+                )
+            })
+            .unzip();
+
+        DocumentScore {
+            score: credits.iter().sum(),
+            total_points: credits.len() as f64,
+            component_indices,
+            credits,
+        }
+    }
+
+    fn get_component_credit(&self, component_idx: ComponentIdx) -> Option<f64> {
+        let local_prop_idx = self
+            .get_component(component_idx)
+            .get_prop_by_profile(&[PropProfile::Number])?;
+        let prop_node = self.prop_pointer_to_prop_node(PropPointer {
+            component_idx,
+            local_prop_idx,
+        });
+        match self
+            .get_prop_untracked(prop_node, GraphNode::Query(0))
+            .value
+        {
+            PropValue::Number(credit) => Some(credit),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "objectives.test.rs"]
+mod test;
@@ -0,0 +1,60 @@
+use crate::{Core, components::types::ComponentIdx, test_utils::*};
+
+use super::*;
+
+#[test]
+fn objectives_are_read_from_the_objective_attribute() {
+    // <document>: idx 0
+    //  <number>: idx 1
+    let dast_root = dast_root_no_position(r#"<number objective="limits, derivatives">1</number>"#);
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    assert_eq!(
+        core.document_model
+            .get_component_objectives(ComponentIdx::new(1)),
+        vec!["limits", "derivatives"]
+    );
+}
+
+#[test]
+fn credit_is_aggregated_by_objective() {
+    // <document>: idx 0
+    //  <number>: idx 1
+    //  <number>: idx 2
+    //  <p>: idx 3
+    let dast_root = dast_root_no_position(
+        r#"<number objective="limits derivatives">1</number><number objective="limits">0.5</number><p objective="limits">Not graded</p>"#,
+    );
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    assert_eq!(
+        core.document_model.get_objective_report(),
+        ObjectiveReport {
+            objectives: vec![
+                ObjectiveCredit {
+                    objective: "derivatives".to_string(),
+                    component_indices: vec![ComponentIdx::new(1)],
+                    credits: vec![1.0],
+                },
+                ObjectiveCredit {
+                    objective: "limits".to_string(),
+                    component_indices: vec![ComponentIdx::new(1), ComponentIdx::new(2)],
+                    credits: vec![1.0, 0.5],
+                },
+            ]
+        }
+    );
+}
+
+#[test]
+fn document_without_objectives_has_empty_report() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(r#"<number>1</number>"#));
+
+    assert_eq!(
+        core.document_model.get_objective_report(),
+        ObjectiveReport::default()
+    );
+}
@@ -0,0 +1,46 @@
+//! Ephemeral state that is shared among the collaborators on a document but never saved,
+//! e.g., the position of each collaborator's cursor on a graph.
+//!
+//! A component declares that its state is ephemeral with `scope="ephemeral"`. Changes to ephemeral state
+//! are broadcast to the other collaborators, who apply them to their copies of the document.
+
+use crate::components::types::ComponentIdx;
+
+use super::{DocumentModel, SavedStateValue, StateScope};
+
+impl DocumentModel {
+    /// The ephemeral state of the document that has changed from its default, to be broadcast to collaborators.
+    pub fn get_ephemeral_state(&self) -> Vec<SavedStateValue> {
+        self.get_component_indices()
+            .filter(|component_idx| self.get_state_scope(*component_idx) == StateScope::Ephemeral)
+            .flat_map(|component_idx| self.get_changed_state_values(component_idx))
+            .collect()
+    }
+
+    /// Apply ephemeral state broadcast by a collaborator. Values that do not match a prop of the document,
+    /// or cannot be converted to the type of the prop, are skipped.
+    ///
+    /// Return the components that have had one of their `for_render` props newly marked as stale,
+    /// or an error if a value is for a component whose state is not ephemeral.
+    pub fn apply_ephemeral_state(
+        &self,
+        values: Vec<SavedStateValue>,
+    ) -> Result<Vec<ComponentIdx>, String> {
+        for value in &values {
+            let is_component = self.has_component(value.component_idx);
+            if is_component && self.get_state_scope(value.component_idx) != StateScope::Ephemeral {
+                return Err(format!(
+                    "The state of component {} is not ephemeral",
+                    value.component_idx.as_usize()
+                ));
+            }
+        }
+
+        self.clear_undo_history();
+        Ok(self.load_state_values(values))
+    }
+}
+
+#[cfg(test)]
+#[path = "presence.test.rs"]
+mod test;
@@ -0,0 +1,94 @@
+use crate::{
+    Core,
+    components::{
+        ActionsEnum,
+        doenet::text_input::{TextInputActionArgs, TextInputActions},
+        types::{Action, ActionBody},
+    },
+    extract_text,
+    test_utils::*,
+};
+
+use super::*;
+
+const SOURCE: &str = r#"<textInput name="cursor" scope="ephemeral"/><textInput name="answer"/>"#;
+
+/// Type `text` into the text input with index `component_idx` and submit it.
+fn enter_text(core: &mut Core, component_idx: usize, text: &str) {
+    let component_idx = ComponentIdx::new(component_idx);
+    let _ = core.dispatch_action(Action {
+        component_idx,
+        action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+            args: TextInputActionArgs {
+                text: text.to_string(),
+            },
+        })),
+    });
+    let _ = core.dispatch_action(Action {
+        component_idx,
+        action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+    });
+}
+
+#[test]
+fn ephemeral_state_is_not_saved() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.to_flat_dast();
+    enter_text(&mut core, 1, "(1,2)");
+    enter_text(&mut core, 2, "x");
+
+    let saved_state = core.document_model.export_state();
+    assert!(!saved_state.values.is_empty());
+    assert!(
+        saved_state
+            .values
+            .iter()
+            .all(|value| value.component_idx == ComponentIdx::new(2))
+    );
+    let ephemeral_state = core.document_model.get_ephemeral_state();
+    assert!(!ephemeral_state.is_empty());
+    assert!(
+        ephemeral_state
+            .iter()
+            .all(|value| value.component_idx == ComponentIdx::new(1))
+    );
+}
+
+#[test]
+fn ephemeral_state_is_broadcast_to_collaborators() {
+    let dast_root = dast_root_no_position(SOURCE);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+    enter_text(&mut core, 1, "(1,2)");
+
+    let mut collaborator_core = Core::new();
+    collaborator_core.init_from_dast_root(&dast_root);
+    collaborator_core.to_flat_dast();
+    let changed_components = collaborator_core
+        .document_model
+        .apply_ephemeral_state(core.document_model.get_ephemeral_state())
+        .unwrap();
+    assert!(!changed_components.is_empty());
+    assert_eq!(
+        extract_text(&collaborator_core.to_flat_dast()).trim(),
+        "(1,2)"
+    );
+}
+
+#[test]
+fn state_that_is_not_ephemeral_cannot_be_broadcast() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+
+    let result = core
+        .document_model
+        .apply_ephemeral_state(vec![SavedStateValue {
+            component_idx: ComponentIdx::new(2),
+            prop_name: "value".to_string(),
+            value: serde_json::json!("x"),
+        }]);
+    assert!(result.is_err());
+}
@@ -1,9 +1,16 @@
 use std::rc::Rc;
 
 use crate::{
+    components::types::{ComponentIdx, PropPointer},
     dast::ElementRefAnnotation,
-    props::{DataQuery, DataQueryResults, FilterData, PropSource, PropValue},
+    graph::directed_graph::Taggable,
+    graph_node::GraphNodeLookup,
+    props::{
+        ApplyTest, DataQuery, DataQueryResults, FilterData, PropCalcResult, PropProfile,
+        PropSource, PropValue,
+    },
     state::types::content_refs::{ContentRef, ContentRefs},
+    utils::usize_to_integer,
 };
 
 use super::{
@@ -14,7 +21,7 @@ use super::{
             cache::{PropStatus, PropWithMeta},
         },
     },
-    DocumentModel,
+    DocumentModel, EnvironmentProperty, PropBreakpointTrigger,
 };
 
 #[derive(Debug, Copy, Clone)]
@@ -106,6 +113,19 @@ impl DocumentModel {
                         // after all its dependencies have been resolved; it's now resolved.
                         self.prop_cache
                             .set_prop_status(prop_node, PropStatus::Resolved);
+                        if self.has_breakpoint(prop_node) {
+                            // The props still being resolved below us on the stack are the ones
+                            // whose resolution led to this prop being resolved.
+                            let path = resolve_stack[..processing_state_idx]
+                                .iter()
+                                .map(|state| state.prop_node)
+                                .filter(|node| {
+                                    self.prop_cache.get_prop_status(node) == PropStatus::Resolving
+                                })
+                                .chain(std::iter::once(prop_node))
+                                .collect::<Vec<_>>();
+                            self.emit_breakpoint_event(PropBreakpointTrigger::Resolved, &path);
+                        }
                         resolve_stack.pop();
                         continue;
                     }
@@ -290,10 +310,9 @@ impl DocumentModel {
                             .collect(),
                     );
 
-                    let prop_definition = self.get_prop_definition(node);
                     self.prop_cache.set_prop(
                         node,
-                        prop_definition.updater.calculate_untyped(required_data),
+                        self.recalculate_prop(dependency_prop_node, required_data),
                     );
                 }
                 _ => {
@@ -307,6 +326,32 @@ impl DocumentModel {
         self._execute_data_query_with_fresh_deps(query_node)
     }
 
+    /// Calculate the value of `prop_node` from `required_data`, the results of its data queries.
+    ///
+    /// A `Stale` prop keeps its previous value, so if none of `required_data` has changed since
+    /// the prop was last calculated, `calculate` is skipped and `PropCalcResult::NoChange` is returned.
+    /// Since the prop's value is then unchanged, the props that depend on it are skipped in turn,
+    /// unless something else they depend on changed.
+    pub(super) fn recalculate_prop(
+        &self,
+        prop_node: GraphNode,
+        required_data: DataQueryResults,
+    ) -> PropCalcResult<PropValue> {
+        let dependencies_unchanged = self.prop_cache.get_prop_status(prop_node)
+            == PropStatus::Stale
+            && !self.prop_cache.must_recalculate(prop_node)
+            && required_data
+                .vec
+                .iter()
+                .flat_map(|result| &result.values)
+                .all(|value| !value.changed);
+        if dependencies_unchanged {
+            return PropCalcResult::NoChange;
+        }
+
+        self.calculate_prop(prop_node, required_data)
+    }
+
     /// Executes a data query assuming all props for the data query are already fresh.
     ///
     /// Will panic if any required prop is not fresh.
@@ -451,6 +496,47 @@ impl DocumentModel {
                     _ => unreachable!(),
                 }
             }
+            DataQuery::DescendantContentRefs { container, filter } => {
+                let prop_pointer = get_prop_pointer();
+
+                // Get the correct "root" for the query.
+                let component_idx = match container {
+                    PropSource::Me => Some(prop_pointer.component_idx),
+                    PropSource::Parent => self
+                        .document_structure
+                        .borrow()
+                        .get_true_component_parent(prop_pointer.component_idx),
+                    PropSource::ByIdx(component_idx) => Some(*component_idx),
+                    PropSource::StaticComponentRef(local_prop_idx) => {
+                        // The ref is static and was resolved when the query was created, so it is fresh.
+                        let ref_node = self.prop_pointer_to_prop_node(PropPointer {
+                            component_idx: prop_pointer.component_idx,
+                            local_prop_idx: *local_prop_idx,
+                        });
+                        match self._get_prop_unchecked(ref_node, query_node).value {
+                            PropValue::ComponentRef(component_ref) => {
+                                component_ref.map(|component_ref| component_ref.0)
+                            }
+                            _ => None,
+                        }
+                    }
+                };
+
+                let content_refs = component_idx
+                    .map(|component_idx| {
+                        self.get_rendered_descendants(component_idx, filter.as_ref(), query_node)
+                    })
+                    .unwrap_or_default();
+
+                DataQueryResult {
+                    values: vec![PropWithMeta {
+                        value: PropValue::ContentRefs(Rc::new(content_refs.into())),
+                        came_from_default: false,
+                        changed: true,
+                        origin: Some(query_node),
+                    }],
+                }
+            }
             DataQuery::SelfRef => {
                 // This query is computed on the fly. We need to figure out who asked for this query.
                 let prop_pointer = get_prop_pointer();
@@ -464,6 +550,98 @@ impl DocumentModel {
                     }],
                 }
             }
+            DataQuery::RenderMode => {
+                // This query is computed on the fly from the flags of the document.
+                DataQueryResult {
+                    values: vec![PropWithMeta {
+                        value: PropValue::RenderMode(self.get_render_mode()),
+                        came_from_default: false,
+                        changed: true,
+                        origin: None,
+                    }],
+                }
+            }
+            DataQuery::Role => {
+                // This query is computed on the fly from the flags of the document.
+                DataQueryResult {
+                    values: vec![PropWithMeta {
+                        value: PropValue::Role(self.get_role()),
+                        came_from_default: false,
+                        changed: true,
+                        origin: None,
+                    }],
+                }
+            }
+            DataQuery::VariantSeed => {
+                // This query is computed on the fly from the flags of the document.
+                DataQueryResult {
+                    values: vec![PropWithMeta {
+                        // The seed is only used to mix bits, so wrapping into a signed integer is harmless.
+                        value: PropValue::Integer(self.get_variant_seed() as i64),
+                        came_from_default: false,
+                        changed: true,
+                        origin: None,
+                    }],
+                }
+            }
+            DataQuery::StudentSeed => {
+                // This query is computed on the fly from the flags of the document.
+                DataQueryResult {
+                    values: vec![PropWithMeta {
+                        // The seed is only used to mix bits, so wrapping into a signed integer is harmless.
+                        value: PropValue::Integer(self.get_student_seed() as i64),
+                        came_from_default: false,
+                        changed: true,
+                        origin: None,
+                    }],
+                }
+            }
+            DataQuery::SeenSelections => {
+                // This query is computed on the fly from the flags of the document.
+                // We need to figure out who asked for this query.
+                let prop_pointer = get_prop_pointer();
+
+                DataQueryResult {
+                    values: self
+                        .get_seen_selections(prop_pointer.component_idx)
+                        .into_iter()
+                        // An index too large to be an integer cannot be the index of an option.
+                        .filter_map(|option_index| usize_to_integer(option_index).ok())
+                        .map(|option_index| PropWithMeta {
+                            value: PropValue::Integer(option_index),
+                            came_from_default: false,
+                            changed: true,
+                            origin: None,
+                        })
+                        .collect(),
+                }
+            }
+            DataQuery::Environment(property) => {
+                // This query is computed on the fly from the environment of the document.
+                let environment = self.get_environment();
+                let value = match property {
+                    EnvironmentProperty::ViewportWidth => {
+                        environment.viewport_width.map(PropValue::Number)
+                    }
+                    EnvironmentProperty::DeviceClass => Some(PropValue::String(Rc::new(
+                        environment.device_class.as_str().to_string(),
+                    ))),
+                    EnvironmentProperty::PrefersReducedMotion => {
+                        Some(PropValue::Boolean(environment.prefers_reduced_motion))
+                    }
+                };
+                DataQueryResult {
+                    values: value
+                        .into_iter()
+                        .map(|value| PropWithMeta {
+                            value,
+                            came_from_default: false,
+                            changed: true,
+                            origin: None,
+                        })
+                        .collect(),
+                }
+            }
             _ => {
                 //
                 // default behavior
@@ -535,7 +713,7 @@ impl DocumentModel {
                                     }
                                 }).collect();
                                 PropWithMeta {
-                                    value: PropValue::PropVec(values),
+                                    value: PropValue::PropVec(Rc::new(values)),
                                     came_from_default: false,
                                     changed: found_change,
                                     origin: Some(node)
@@ -553,6 +731,67 @@ impl DocumentModel {
         }
     }
 
+    /// The components rendered inside of `container_idx` that match `filter`, in document order.
+    /// Only descendants of `container_idx` in the document structure are searched,
+    /// since the query only depends on their rendered children.
+    fn get_rendered_descendants(
+        &self,
+        container_idx: ComponentIdx,
+        filter: &dyn for<'a> ApplyTest<FilterData<'a>, GraphNode>,
+        query_node: GraphNode,
+    ) -> Vec<ContentRef> {
+        let mut unvisited = GraphNodeLookup::new();
+        for node in self
+            .document_structure
+            .borrow()
+            .get_component_content_descendants(container_idx)
+        {
+            unvisited.set_tag(node, true);
+        }
+
+        let mut content_refs = Vec::new();
+        let mut stack = vec![container_idx.as_graph_node()];
+        while let Some(node) = stack.pop() {
+            if node != container_idx.as_graph_node()
+                && filter.apply_test(&FilterData {
+                    node,
+                    origin: query_node,
+                    document_model: self,
+                })
+            {
+                content_refs.push(ContentRef::Component(node.into()));
+            }
+
+            let Some(prop_pointer) =
+                self.get_component_prop_by_profile(node, &[PropProfile::RenderedChildren])
+            else {
+                continue;
+            };
+            let rendered_children = match self
+                ._get_prop_unchecked(self.prop_pointer_to_prop_node(prop_pointer), query_node)
+                .value
+            {
+                PropValue::AnnotatedContentRefs(rendered_children) => rendered_children,
+                _ => continue,
+            };
+            let children = rendered_children
+                .as_slice()
+                .iter()
+                .filter_map(|(content_ref, _)| match content_ref {
+                    ContentRef::Component(child_idx) => Some(child_idx.as_graph_node()),
+                    _ => None,
+                })
+                .filter(|child| unvisited.get_tag(child) == Some(&true))
+                .collect::<Vec<_>>();
+            for &child in children.iter() {
+                unvisited.set_tag(child, false);
+            }
+            // Push in reverse so that the first child is visited first.
+            stack.extend(children.into_iter().rev());
+        }
+        content_refs
+    }
+
     /// Get all `GraphNodes` that correspond to the data queries that determine the value of `prop_node`
     pub fn get_data_query_nodes_for_prop(&self, prop_node: GraphNode) -> Vec<GraphNode> {
         self.dependency_graph.borrow().get_children(prop_node).into_iter().inspect(|n| {
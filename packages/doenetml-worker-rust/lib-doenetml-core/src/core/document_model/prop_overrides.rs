@@ -0,0 +1,115 @@
+//! Overrides of prop values by an instructor, e.g., to adjust the credit a student received
+//! or to unlock content. An overridden prop takes the override value instead of its calculated value.
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::{
+    components::{
+        ComponentNode, ComponentProps, ComponentVariantProps, types::ComponentIdx,
+        types::PropPointer,
+    },
+    graph::directed_graph::Taggable,
+    graph_node::{GraphNode, GraphNodeLookup},
+    props::{PropValue, cache::PropStatus},
+};
+
+use super::{DocumentModel, saved_state::prop_value_from_json};
+
+/// An override of the value of a prop, recorded along with the reason for it so that it can be audited.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct PropOverride {
+    pub component_idx: ComponentIdx,
+    pub prop_name: String,
+    #[cfg_attr(feature = "web", tsify(type = "any"))]
+    pub value: serde_json::Value,
+    /// Why the value was overridden, e.g., "Credit for a correct answer in an alternate form".
+    pub reason: String,
+}
+
+/// The overrides that have been applied to a document.
+#[derive(Debug)]
+pub struct PropOverrides {
+    /// Every override in the order it was applied, including overrides that were later replaced.
+    log: Vec<PropOverride>,
+    /// The current override value of each overridden prop.
+    values: GraphNodeLookup<PropValue>,
+}
+
+impl Default for PropOverrides {
+    fn default() -> Self {
+        PropOverrides {
+            log: Vec::new(),
+            values: GraphNodeLookup::new(),
+        }
+    }
+}
+
+impl DocumentModel {
+    /// Override the value of a prop so that it is used in place of the prop's calculated value.
+    /// Overriding a prop again replaces the previous override, though both remain in the log.
+    ///
+    /// Return the components that have had one of their `for_render` props newly marked as stale.
+    pub fn override_prop(&self, prop_override: PropOverride) -> Result<Vec<ComponentIdx>, String> {
+        let component_idx = prop_override.component_idx;
+        if !self.has_component(component_idx) {
+            return Err(format!(
+                "No component with index {}",
+                component_idx.as_usize()
+            ));
+        }
+        let component = self.get_component(component_idx);
+        let local_prop_idx = component
+            .get_local_prop_index_from_name(&prop_override.prop_name)
+            .ok_or_else(|| {
+                format!(
+                    "Component `{}` has no prop named `{}`",
+                    component.get_component_type(),
+                    prop_override.prop_name
+                )
+            })?;
+        let value_type = component.variant.get_prop_value_type(local_prop_idx);
+        let value =
+            prop_value_from_json(prop_override.value.clone(), value_type).ok_or_else(|| {
+                format!(
+                    "Cannot override prop `{}` of type {value_type:?} with {}",
+                    prop_override.prop_name, prop_override.value
+                )
+            })?;
+
+        let prop_node = self.prop_pointer_to_prop_node(PropPointer {
+            component_idx,
+            local_prop_idx,
+        });
+        self.clear_undo_history();
+        {
+            let mut overrides = self.overrides.borrow_mut();
+            overrides.values.set_tag(prop_node, value);
+            overrides.log.push(prop_override);
+        }
+
+        // A prop that has not been resolved is not in the dependency graph, and nothing depends on it yet.
+        // It will use the override when it is calculated.
+        if self.get_prop_status(prop_node) == PropStatus::Unresolved {
+            return Ok(Vec::new());
+        }
+        Ok(self.mark_dependents_stale(&[prop_node]))
+    }
+
+    /// Every override applied to the document, in the order they were applied.
+    pub fn get_prop_overrides(&self) -> Vec<PropOverride> {
+        self.overrides.borrow().log.clone()
+    }
+
+    /// The value that overrides the calculated value of `prop_node`, if any.
+    pub(super) fn get_override_value(&self, prop_node: GraphNode) -> Option<PropValue> {
+        self.overrides.borrow().values.get_tag(&prop_node).cloned()
+    }
+}
+
+#[cfg(test)]
+#[path = "prop_overrides.test.rs"]
+mod test;
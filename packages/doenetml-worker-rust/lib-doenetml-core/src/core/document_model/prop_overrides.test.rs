@@ -0,0 +1,93 @@
+use crate::{Core, extract_text, test_utils::*};
+
+use super::*;
+
+const SOURCE: &str = r#"<textInput name="ti"/><text>$ti</text>"#;
+
+fn value_override(value: serde_json::Value, reason: &str) -> PropOverride {
+    PropOverride {
+        component_idx: ComponentIdx::new(1),
+        prop_name: "value".to_string(),
+        value,
+        reason: reason.to_string(),
+    }
+}
+
+#[test]
+fn override_applies_before_rendering() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.document_model
+        .override_prop(value_override(serde_json::json!("excused"), "Absent"))
+        .unwrap();
+
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "excused");
+}
+
+#[test]
+fn override_replaces_calculated_value_after_rendering() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "");
+
+    let changed_components = core
+        .document_model
+        .override_prop(value_override(serde_json::json!("a"), "First"))
+        .unwrap();
+    assert!(!changed_components.is_empty());
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "a");
+
+    core.document_model
+        .override_prop(value_override(serde_json::json!("b"), "Second"))
+        .unwrap();
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "b");
+
+    let reasons: Vec<_> = core
+        .document_model
+        .get_prop_overrides()
+        .into_iter()
+        .map(|prop_override| prop_override.reason)
+        .collect();
+    assert_eq!(reasons, vec!["First", "Second"]);
+}
+
+#[test]
+fn invalid_overrides_are_rejected() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+
+    let mut prop_override = value_override(serde_json::json!("a"), "");
+    prop_override.prop_name = "notAProp".to_string();
+    assert!(core.document_model.override_prop(prop_override).is_err());
+
+    assert!(
+        core.document_model
+            .override_prop(value_override(serde_json::json!(3), ""))
+            .is_err()
+    );
+    assert!(core.document_model.get_prop_overrides().is_empty());
+}
+
+#[test]
+fn overrides_are_saved_with_state() {
+    let dast_root = dast_root_no_position(SOURCE);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    core.document_model
+        .override_prop(value_override(serde_json::json!("excused"), "Absent"))
+        .unwrap();
+    let saved_state = core.document_model.export_state();
+    assert_eq!(
+        saved_state.overrides,
+        vec![value_override(serde_json::json!("excused"), "Absent")]
+    );
+
+    let mut restored_core = Core::new();
+    restored_core.init_from_dast_root(&dast_root);
+    restored_core.document_model.load_state(saved_state);
+    assert_eq!(
+        extract_text(&restored_core.to_flat_dast()).trim(),
+        "excused"
+    );
+}
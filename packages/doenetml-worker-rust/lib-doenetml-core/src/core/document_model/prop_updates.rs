@@ -1,7 +1,7 @@
 use itertools::Itertools;
 
 use crate::{
-    DocumentModel,
+    DocumentModel, PropBreakpointTrigger,
     components::{
         prelude::ComponentIdx,
         types::{PropPointer, UpdateFromAction},
@@ -23,11 +23,19 @@ impl DocumentModel {
     /// Even when all updates succeed, the final values of the props may not match their requested values
     /// due to constraints of the system.
     ///
+    /// All the props in `updates_from_action` are updated as a single transaction:
+    /// each `invert()` sees the values requested by the earlier ones,
+    /// and no changes are made until `execute_changes` applies them all at once.
+    ///
+    /// `pending_values` holds the values requested by earlier actions of the same batch, whose changes have not been made yet.
+    /// Each `invert()` sees them in place of the current values, and the values requested here are added to them.
+    ///
     /// Return a `GraphNodeLookup` that will records the requested values of the `State` and `String` nodes.
     pub fn calculate_changes_from_action_updates(
         &mut self,
         updates_from_action: Vec<UpdateFromAction>,
         component_idx: ComponentIdx,
+        pending_values: &mut GraphNodeLookup<PropValue>,
     ) -> GraphNodeLookup<PropValue> {
         let mut requested_value_lookup = GraphNodeLookup::new();
 
@@ -121,7 +129,24 @@ impl DocumentModel {
 
             let prop_updater = self.get_prop_updater(prop_node);
 
-            let required_data = self._get_data_query_results_assuming_fresh_deps(prop_node);
+            // Invert from the values already requested for the dependencies of `prop_node`
+            // by the props visited before it or by earlier actions of the batch, rather than from their current values.
+            // The requests then build on each other, e.g., two props computed from the same vector
+            // each change their own component of the vector, rather than the last request undoing the first.
+            let mut required_data = self._get_data_query_results_assuming_fresh_deps(prop_node);
+            for prop in required_data
+                .vec
+                .iter_mut()
+                .flat_map(|data_query_result| data_query_result.values.iter_mut())
+            {
+                if let Some(requested_value) = prop.origin.and_then(|origin| {
+                    requested_value_lookup
+                        .get_tag(&origin)
+                        .or_else(|| pending_values.get_tag(&origin))
+                }) {
+                    prop.value = requested_value.clone();
+                }
+            }
 
             // if node is one of the original nodes specified by the action,
             // then we have a direct change from action
@@ -152,28 +177,69 @@ impl DocumentModel {
             }
         }
 
+        for (node, value) in requested_value_lookup.iter() {
+            pending_values.set_tag(node, value.clone());
+        }
+
         changes_to_make
     }
 
     /// Change all the `State` and `String` nodes in `changes_to_make` to their requested values.
-    /// Mark all dependencies of those nodes as stale.
+    /// Mark all dependencies of the nodes whose values actually changed as stale.
+    ///
+    /// A node that is requested to change to the value it already has is left alone,
+    /// so an action that sets a value to what it already was does not cause any recalculation.
     ///
     /// Return the components that have had one of their `for_render` props newly marked as stale.
     pub fn execute_changes(
         &self,
         changes_to_make: GraphNodeLookup<PropValue>,
     ) -> Vec<ComponentIdx> {
+        let mut nodes_changed = Vec::new();
+
         for (node, val) in changes_to_make.iter() {
-            match node {
-                GraphNode::State(_) => self.states.set_state(node, val.clone()),
-                GraphNode::String(_) => self
-                    .document_structure
-                    .borrow()
-                    .set_string(node, val.clone().try_into().unwrap()),
+            let changed = match node {
+                GraphNode::State(_) => {
+                    // Setting a state also clears its `came_from_default`, which is a change in itself.
+                    let current = self.states.peek_state(node);
+                    let changed = current.came_from_default || current.value != *val;
+                    if changed {
+                        self.states.set_state(node, val.clone());
+                    }
+                    changed
+                }
+                GraphNode::String(_) => {
+                    let document_structure = self.document_structure.borrow();
+                    let new_string: String = val.clone().try_into().unwrap();
+                    let changed = document_structure.get_string_value(node) != new_string;
+                    if changed {
+                        document_structure.set_string(node, new_string);
+                    }
+                    changed
+                }
                 _ => panic!("Can set the value of only State and String nodes, found {node:?}"),
+            };
+            if changed {
+                nodes_changed.push(node);
             }
         }
 
+        self.mark_dependents_stale(&nodes_changed)
+    }
+
+    /// The number of times that props have been marked stale because the nodes they depend on changed,
+    /// e.g., to check that a batch of actions marks props stale only once.
+    pub fn get_staleness_pass_count(&self) -> usize {
+        self.staleness_passes.get()
+    }
+
+    /// Mark all props that depend on `nodes_changed`, including any props in `nodes_changed` themselves, as stale.
+    /// Every node in `nodes_changed` must be in the dependency graph.
+    ///
+    /// Return the components that have had one of their `for_render` props (or their rendered children) newly marked as stale.
+    pub(super) fn mark_dependents_stale(&self, nodes_changed: &[GraphNode]) -> Vec<ComponentIdx> {
+        self.staleness_passes.set(self.staleness_passes.get() + 1);
+
         let skip_fn = |node: &GraphNode| {
             if matches!(node, GraphNode::Prop(_)) {
                 self.prop_cache.get_prop_status(node) != PropStatus::Fresh
@@ -182,7 +248,6 @@ impl DocumentModel {
             }
         };
 
-        let nodes_changed = changes_to_make.keys().collect_vec();
         let mut changed_components =
             vec![false; self.document_structure.borrow()._get_num_components()];
 
@@ -190,20 +255,37 @@ impl DocumentModel {
         for node in self
             .dependency_graph
             .borrow()
-            .ancestors_reverse_topological_multiroot_with_skip(&nodes_changed, skip_fn)
+            .ancestors_reverse_topological_multiroot_with_skip(nodes_changed, skip_fn)
         {
             if matches!(node, GraphNode::Prop(_)) {
                 self.prop_cache.set_prop_status(node, PropStatus::Stale);
 
-                // if prop is marked for render, add to components_with_changed_for_render_prop
+                if self.has_breakpoint(*node) {
+                    let path = self.dependency_path_to_prop(*node, nodes_changed);
+                    self.emit_breakpoint_event(PropBreakpointTrigger::MarkedStale, &path);
+                }
+
+                // if prop is marked for render or determines the rendered children,
+                // add to components_with_changed_for_render_prop
                 let prop_meta = &self.get_prop_definition(node).meta;
-                if prop_meta.for_render.in_graph || prop_meta.for_render.in_text {
+                if prop_meta.for_render.in_graph
+                    || prop_meta.for_render.in_text
+                    || prop_meta.profile == Some(PropProfile::RenderedChildren)
+                {
                     let component_idx = prop_meta.prop_pointer.component_idx;
                     changed_components[component_idx.as_usize()] = true;
                 }
             }
         }
 
+        // Props in `nodes_changed` were changed directly rather than through their dependencies,
+        // so they must be recalculated even though their dependencies are unchanged.
+        for node in nodes_changed {
+            if matches!(node, GraphNode::Prop(_)) {
+                self.prop_cache.invalidate_prop(node);
+            }
+        }
+
         changed_components
             .into_iter()
             .enumerate()
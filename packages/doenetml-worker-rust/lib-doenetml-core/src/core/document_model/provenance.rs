@@ -0,0 +1,146 @@
+//! Authorship metadata of components and where copied components came from,
+//! so that content remixed from several sources keeps its attribution.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::{
+    components::{ComponentCommon, ComponentNode, prelude::UntaggedContent, types::ComponentIdx},
+    dast::flat_dast::FlatAttribute,
+};
+
+use super::DocumentModel;
+
+/// The prefix of attributes that attach metadata to a component, e.g., `<p meta:author="Jo">`.
+pub const METADATA_ATTRIBUTE_PREFIX: &str = "meta:";
+
+/// Metadata attached to a component by the author of a document.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct ComponentMetadata {
+    pub component_idx: ComponentIdx,
+    pub component_type: String,
+    /// From the `meta:author` attribute.
+    pub author: Option<String>,
+    /// From the `meta:license` attribute.
+    pub license: Option<String>,
+    /// From the `meta:version` attribute.
+    pub version: Option<String>,
+    /// The line of the DoenetML source on which the component begins.
+    pub source_line: Option<usize>,
+    /// Any other `meta:` attributes, keyed by their name without the prefix.
+    pub other: BTreeMap<String, String>,
+}
+
+/// The metadata of a component along with the chain of components it was copied from.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+#[cfg_attr(feature = "web", tsify(into_wasm_abi))]
+pub struct ComponentProvenance {
+    /// The metadata of the component. Metadata the component does not specify itself
+    /// is inherited from the component it was copied from.
+    pub metadata: ComponentMetadata,
+    /// The metadata of the component followed by that of each component it was copied from,
+    /// e.g., for `$b` where `<p name="b" extend="$a" />`, the chain is `$b`, `b`, `a`.
+    /// Each entry contains only the metadata specified on that component.
+    pub chain: Vec<ComponentMetadata>,
+}
+
+impl DocumentModel {
+    /// The metadata specified on the component `component_idx` itself.
+    pub fn get_own_component_metadata(&self, component_idx: ComponentIdx) -> ComponentMetadata {
+        let component = self.get_component(component_idx);
+
+        let mut metadata = ComponentMetadata {
+            component_idx,
+            component_type: component.get_component_type().to_string(),
+            author: None,
+            license: None,
+            version: None,
+            source_line: component.get_position().map(|position| position.start.line),
+            other: BTreeMap::new(),
+        };
+
+        for (name, attribute) in component.get_unrecognized_attributes() {
+            let Some(key) = strip_prefix_ignore_case(name, METADATA_ATTRIBUTE_PREFIX) else {
+                continue;
+            };
+            let value = attribute_text(attribute);
+            match key.to_ascii_lowercase().as_str() {
+                "author" => metadata.author = Some(value),
+                "license" => metadata.license = Some(value),
+                "version" => metadata.version = Some(value),
+                _ => {
+                    metadata.other.insert(key.to_string(), value);
+                }
+            }
+        }
+
+        metadata
+    }
+
+    /// The metadata of the component `component_idx` and the chain of components it was copied from.
+    pub fn get_component_provenance(&self, component_idx: ComponentIdx) -> ComponentProvenance {
+        let mut chain = vec![self.get_own_component_metadata(component_idx)];
+        let mut current_idx = component_idx;
+        while let Some(source_idx) = self
+            .document_structure
+            .borrow()
+            .get_extended_from(current_idx)
+        {
+            // Extending cannot be circular, but guard against it so that a bug does not hang the document.
+            if chain.iter().any(|entry| entry.component_idx == source_idx) {
+                break;
+            }
+            chain.push(self.get_own_component_metadata(source_idx));
+            current_idx = source_idx;
+        }
+
+        let mut metadata = chain[0].clone();
+        for source in &chain[1..] {
+            metadata.author = metadata.author.or_else(|| source.author.clone());
+            metadata.license = metadata.license.or_else(|| source.license.clone());
+            metadata.version = metadata.version.or_else(|| source.version.clone());
+            for (key, value) in &source.other {
+                metadata
+                    .other
+                    .entry(key.clone())
+                    .or_insert_with(|| value.clone());
+            }
+        }
+
+        ComponentProvenance { metadata, chain }
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(name: &'a str, prefix: &str) -> Option<&'a str> {
+    if name.len() > prefix.len()
+        && name.is_char_boundary(prefix.len())
+        && name[..prefix.len()].eq_ignore_ascii_case(prefix)
+    {
+        Some(&name[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+/// The text of `attribute`. References in metadata are not resolved, so only literal text is kept.
+pub(crate) fn attribute_text(attribute: &FlatAttribute) -> String {
+    attribute
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            UntaggedContent::Text(text) => Some(text.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "provenance.test.rs"]
+mod test;
@@ -0,0 +1,86 @@
+use crate::{Core, components::types::ComponentIdx, test_utils::*};
+
+use super::*;
+
+#[test]
+fn metadata_attributes_are_collected() {
+    // <document>: idx 0
+    //  <p name="a">: idx 1
+    let dast_root = dast_root(
+        r#"<p name="a" meta:author="Jo" meta:License="CC-BY" meta:topic="limits">hi</p>"#,
+    );
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let metadata = core
+        .document_model
+        .get_own_component_metadata(ComponentIdx::new(1));
+    assert_eq!(
+        metadata,
+        ComponentMetadata {
+            component_idx: ComponentIdx::new(1),
+            component_type: "p".to_string(),
+            author: Some("Jo".to_string()),
+            license: Some("CC-BY".to_string()),
+            version: None,
+            source_line: Some(1),
+            other: BTreeMap::from([("topic".to_string(), "limits".to_string())]),
+        }
+    );
+}
+
+#[test]
+fn copies_inherit_metadata_through_provenance_chain() {
+    // <document>: idx 0
+    //  <p name="a">: idx 1
+    //  <p name="b">: idx 2
+    //  $b: idx 3
+    let dast_root = dast_root(
+        r#"<p name="a" meta:author="Jo" meta:license="CC-BY">hi</p>
+<p name="b" extend="$a" meta:version="2" />
+$b"#,
+    );
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let provenance = core
+        .document_model
+        .get_component_provenance(ComponentIdx::new(3));
+
+    let chain: Vec<_> = provenance
+        .chain
+        .iter()
+        .map(|entry| (entry.component_idx.as_usize(), entry.source_line))
+        .collect();
+    assert_eq!(chain, vec![(3, Some(3)), (2, Some(2)), (1, Some(1))]);
+
+    assert_eq!(provenance.metadata.component_idx, ComponentIdx::new(3));
+    assert_eq!(provenance.metadata.author.as_deref(), Some("Jo"));
+    assert_eq!(provenance.metadata.license.as_deref(), Some("CC-BY"));
+    assert_eq!(provenance.metadata.version.as_deref(), Some("2"));
+    assert_eq!(provenance.chain[0].author, None);
+}
+
+#[test]
+fn own_metadata_takes_precedence_over_source() {
+    // <document>: idx 0
+    //  <p name="a">: idx 1
+    //  <p>: idx 2
+    let dast_root = dast_root_no_position(
+        r#"<p name="a" meta:author="Jo">hi</p><p extend="$a" meta:author="Sam" />"#,
+    );
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let provenance = core
+        .document_model
+        .get_component_provenance(ComponentIdx::new(2));
+    assert_eq!(provenance.metadata.author.as_deref(), Some("Sam"));
+    assert_eq!(provenance.chain.len(), 2);
+    assert_eq!(provenance.metadata.source_line, None);
+
+    let provenance = core
+        .document_model
+        .get_component_provenance(ComponentIdx::new(1));
+    assert_eq!(provenance.chain.len(), 1);
+}
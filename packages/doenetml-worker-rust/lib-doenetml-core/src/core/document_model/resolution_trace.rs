@@ -0,0 +1,172 @@
+//! Opt-in tracing of prop calculations, so that devtools can show exactly how a value was computed.
+
+use std::collections::VecDeque;
+
+use serde::Serialize;
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::{
+    components::types::ComponentIdx,
+    graph_node::GraphNode,
+    props::{DataQueryResults, PropCalcResult, PropValue},
+};
+
+use super::{DocumentModel, TelemetryEventKind};
+
+/// A record of a single call to a prop's `calculate` function.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct ResolutionTraceEntry {
+    pub component_idx: ComponentIdx,
+    pub component_type: String,
+    pub prop_name: String,
+    /// The data queries of the prop, formatted for display.
+    pub data_queries: Vec<String>,
+    /// The values returned by each data query, in the same order as `data_queries`.
+    pub dependency_values: Vec<Vec<PropValue>>,
+    /// The value produced by `calculate`, or `None` if `calculate` reported no change.
+    pub value: Option<PropValue>,
+    pub came_from_default: bool,
+}
+
+/// A bounded ring buffer of the most recent prop calculations.
+#[derive(Debug)]
+pub struct ResolutionTrace {
+    capacity: usize,
+    entries: VecDeque<ResolutionTraceEntry>,
+}
+
+impl ResolutionTrace {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Record `entry`, discarding the oldest entry if the buffer is full.
+    pub fn push(&mut self, entry: ResolutionTraceEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// The recorded entries, oldest first.
+    pub fn entries(&self) -> impl Iterator<Item = &ResolutionTraceEntry> {
+        self.entries.iter()
+    }
+}
+
+impl DocumentModel {
+    /// Start recording every prop calculation, keeping at most `capacity` of the most recent ones.
+    /// Any previously recorded entries are discarded.
+    pub fn enable_resolution_trace(&self, capacity: usize) {
+        *self.resolution_trace.borrow_mut() = Some(ResolutionTrace::new(capacity));
+    }
+
+    /// Stop recording prop calculations and discard any recorded entries.
+    pub fn disable_resolution_trace(&self) {
+        *self.resolution_trace.borrow_mut() = None;
+    }
+
+    /// The capacity of the resolution trace, or `None` if tracing is disabled.
+    pub fn resolution_trace_capacity(&self) -> Option<usize> {
+        self.resolution_trace
+            .borrow()
+            .as_ref()
+            .map(|trace| trace.capacity())
+    }
+
+    /// The recorded prop calculations, oldest first. Empty if tracing is disabled.
+    pub fn get_resolution_trace(&self) -> Vec<ResolutionTraceEntry> {
+        self.resolution_trace
+            .borrow()
+            .as_ref()
+            .map(|trace| trace.entries().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Call the `calculate` function of `prop_node` on `required_data`,
+    /// recording the calculation if tracing is enabled and timing it if it is sampled by telemetry.
+    /// If the prop has been overridden, the override value is used instead.
+    pub(super) fn calculate_prop(
+        &self,
+        prop_node: GraphNode,
+        required_data: DataQueryResults,
+    ) -> PropCalcResult<PropValue> {
+        if let Some(value) = self.get_override_value(prop_node) {
+            return PropCalcResult::Calculated(value);
+        }
+
+        let sample = self.start_telemetry_sample(TelemetryEventKind::PropCalculation);
+        let result = self.calculate_prop_with_trace(prop_node, required_data);
+        let component_idx = self
+            .get_prop_definition(prop_node)
+            .meta
+            .prop_pointer
+            .component_idx;
+        self.finish_telemetry_sample(sample, component_idx);
+
+        result
+    }
+
+    /// Call the `calculate` function of `prop_node` on `required_data`,
+    /// recording the calculation if tracing is enabled.
+    fn calculate_prop_with_trace(
+        &self,
+        prop_node: GraphNode,
+        required_data: DataQueryResults,
+    ) -> PropCalcResult<PropValue> {
+        let prop_definition = self.get_prop_definition(prop_node);
+        if self.resolution_trace.borrow().is_none() {
+            return prop_definition.updater.calculate_untyped(required_data);
+        }
+
+        let dependency_values = required_data
+            .vec
+            .iter()
+            .map(|result| result.values.iter().map(|v| v.value.clone()).collect())
+            .collect();
+        let result = prop_definition.updater.calculate_untyped(required_data);
+
+        let (value, came_from_default) = match &result {
+            PropCalcResult::Calculated(value) => (Some(value.clone()), false),
+            PropCalcResult::FromDefault(value) => (Some(value.clone()), true),
+            PropCalcResult::NoChange => (None, false),
+        };
+        let component_idx = prop_definition.meta.prop_pointer.component_idx;
+        let entry = ResolutionTraceEntry {
+            component_idx,
+            component_type: self.get_component_type(component_idx),
+            prop_name: prop_definition.meta.name.to_string(),
+            data_queries: prop_definition
+                .updater
+                .data_queries()
+                .iter()
+                .map(|query| format!("{query:?}"))
+                .collect(),
+            dependency_values,
+            value,
+            came_from_default,
+        };
+        if let Some(trace) = self.resolution_trace.borrow_mut().as_mut() {
+            trace.push(entry);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+#[path = "resolution_trace.test.rs"]
+mod test;
@@ -0,0 +1,118 @@
+use crate::{
+    Core,
+    components::{
+        ActionsEnum,
+        doenet::text_input::{TextInputActionArgs, TextInputActions},
+        types::{Action, ActionBody},
+    },
+    props::PropValue,
+    test_utils::*,
+};
+
+use super::*;
+
+fn entry_with_name(prop_name: &str) -> ResolutionTraceEntry {
+    ResolutionTraceEntry {
+        component_idx: ComponentIdx::new(0),
+        component_type: "text".to_string(),
+        prop_name: prop_name.to_string(),
+        data_queries: vec![],
+        dependency_values: vec![],
+        value: None,
+        came_from_default: false,
+    }
+}
+
+/// Enter `text` into the `<textInput>` with index `text_input_idx` and render the document.
+fn submit_text(core: &mut Core, text_input_idx: ComponentIdx, text: &str) {
+    core.dispatch_action(Action {
+        component_idx: text_input_idx,
+        action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+            args: TextInputActionArgs {
+                text: text.to_string(),
+            },
+        })),
+    })
+    .unwrap();
+    core.dispatch_action(Action {
+        component_idx: text_input_idx,
+        action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+    })
+    .unwrap();
+    core.to_flat_dast();
+}
+
+#[test]
+fn ring_buffer_keeps_most_recent_entries() {
+    let mut trace = ResolutionTrace::new(2);
+    trace.push(entry_with_name("a"));
+    trace.push(entry_with_name("b"));
+    trace.push(entry_with_name("c"));
+
+    let names = trace
+        .entries()
+        .map(|e| e.prop_name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["b", "c"]);
+
+    let mut trace = ResolutionTrace::new(0);
+    trace.push(entry_with_name("a"));
+    assert_eq!(trace.entries().count(), 0);
+}
+
+#[test]
+fn records_calculations_only_when_enabled() {
+    let dast_root = dast_root_no_position(r#"<text>Hello</text>"#);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+    assert!(core.get_resolution_trace().is_empty());
+
+    core.enable_resolution_trace(100);
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+
+    let trace = core.get_resolution_trace();
+    let value_entry = trace
+        .iter()
+        .find(|e| e.component_type == "text" && e.prop_name == "value")
+        .expect("the `value` prop of the text should have been calculated");
+    assert_eq!(
+        value_entry.value,
+        Some(PropValue::String(std::rc::Rc::new("Hello".to_string())))
+    );
+    assert_eq!(
+        value_entry.data_queries.len(),
+        value_entry.dependency_values.len()
+    );
+
+    core.disable_resolution_trace();
+    assert!(core.get_resolution_trace().is_empty());
+}
+
+#[test]
+fn props_are_not_recalculated_when_their_dependencies_are_unchanged() {
+    let dast_root = dast_root_no_position(
+        r#"<answer><textInput name="ti"/><award name="aw">x</award></answer>$aw.creditAchieved"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+
+    submit_text(&mut core, ComponentIdx::new(2), "a");
+
+    core.enable_resolution_trace(1000);
+    submit_text(&mut core, ComponentIdx::new(2), "b");
+
+    // The credit is recalculated for the new response, but it is still 0,
+    // so the `<number>` that references it does not need to be recalculated.
+    let trace = core.get_resolution_trace();
+    let credit_entry = trace
+        .iter()
+        .find(|e| e.component_type == "award" && e.prop_name == "creditAchieved")
+        .expect("the credit should have been recalculated");
+    assert_eq!(credit_entry.value, Some(PropValue::Number(0.0)));
+    assert!(!trace.iter().any(|e| e.component_type == "number"));
+}
@@ -0,0 +1,264 @@
+//! Saving and restoring the state of a document, e.g., the responses a student has entered,
+//! so that a student can resume their work when the document is loaded again.
+
+use std::{collections::BTreeMap, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::{
+    components::{
+        ComponentProps, ComponentVariantProps,
+        types::{ComponentIdx, LocalPropIdx, PropPointer},
+    },
+    graph::directed_graph::Taggable,
+    graph_node::{GraphNode, GraphNodeLookup},
+    props::{PropValue, PropValueType, cache::PropStatus},
+    state::types::math_expr::MathExpr,
+};
+
+use super::{Annotation, DocumentModel, PropOverride, StateScope};
+
+/// The state of a document that resulted from interaction with it.
+///
+/// State is saved only for props whose value has changed from its default. Since components are identified
+/// by their index, a saved state should be restored only to a document created from the same source.
+///
+/// Platforms store the saved state, so its JSON shape is stable. For example, after typing `hello`
+/// into a `<textInput>`, with an override by an instructor and a value of another member of a group,
+/// the state serializes as
+/// ```json
+/// {
+///   "values": [
+///     { "componentIdx": 1, "propName": "value", "value": "hello" },
+///     { "componentIdx": 1, "propName": "immediateValue", "value": "hello" }
+///   ],
+///   "overrides": [
+///     { "componentIdx": 3, "propName": "value", "value": "excused", "reason": "Absent" }
+///   ],
+///   "userValues": {
+///     "student2": [{ "componentIdx": 5, "propName": "value", "value": "hi" }]
+///   }
+/// }
+/// ```
+/// `overrides`, `userValues`, and `annotations` are omitted when empty. Each `value` is the JSON form
+/// of the prop's value, e.g., a string, number, or boolean.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedState {
+    pub values: Vec<SavedStateValue>,
+    /// The overrides applied by instructors, in the order they were applied.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<PropOverride>,
+    /// The values of state that is stored separately for each user (see [`StateScope`]), keyed by user.
+    /// `values` contains only the state shared by all users.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub user_values: BTreeMap<String, Vec<SavedStateValue>>,
+    /// The student's annotations of the document, e.g., highlights and notes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Annotation>,
+}
+
+/// The saved value of the state of a single prop.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct SavedStateValue {
+    pub component_idx: ComponentIdx,
+    /// The name of the prop that stores the state.
+    pub prop_name: String,
+    #[cfg_attr(feature = "web", tsify(type = "any"))]
+    pub value: serde_json::Value,
+}
+
+impl DocumentModel {
+    /// Save the state of the document.
+    ///
+    /// State that has not been created yet (because no prop has needed it) has its default value,
+    /// so it is not saved. Neither is state with `scope="ephemeral"`, such as the position of a collaborator's cursor.
+    /// The arm assigned by an `<experiment>` is always saved, even though it is calculated from the student's seed.
+    pub fn export_state(&self) -> SavedState {
+        let active_user = self.get_active_user();
+        let mut values = Vec::new();
+        let mut user_values = self.get_inactive_user_values();
+        for component_idx in self.get_component_indices() {
+            match self.get_state_scope(component_idx) {
+                StateScope::Shared => values.extend(self.get_changed_state_values(component_idx)),
+                StateScope::User => user_values
+                    .entry(active_user.clone())
+                    .or_default()
+                    .extend(self.get_changed_state_values(component_idx)),
+                StateScope::Ephemeral => {}
+            }
+        }
+        for assignment in self.get_experiment_assignment_values() {
+            let already_saved = values.iter().any(|value| {
+                value.component_idx == assignment.component_idx
+                    && value.prop_name == assignment.prop_name
+            });
+            if !already_saved {
+                values.push(assignment);
+            }
+        }
+
+        SavedState {
+            values,
+            overrides: self.get_prop_overrides(),
+            user_values,
+            annotations: self.get_annotations(),
+        }
+    }
+
+    /// The values of the state of the component `component_idx` that have changed from their defaults.
+    pub(super) fn get_changed_state_values(
+        &self,
+        component_idx: ComponentIdx,
+    ) -> Vec<SavedStateValue> {
+        let document_structure = self.document_structure.borrow();
+        let structure_graph = document_structure.get_structure_graph();
+        let component = document_structure.get_component(component_idx);
+
+        let mut values = Vec::new();
+        for (idx, prop_name) in component.variant.get_prop_names().iter().enumerate() {
+            let prop_node = PropPointer {
+                component_idx,
+                local_prop_idx: LocalPropIdx::new(idx),
+            }
+            .into_prop_node(&document_structure);
+
+            // State is stored on the prop at the bottom of a chain of extended props,
+            // so only that prop links directly to the state node.
+            let state_node = structure_graph
+                .get_children(prop_node)
+                .into_iter()
+                .find(|node| matches!(node, GraphNode::State(_)));
+            let Some(state_node) = state_node else {
+                continue;
+            };
+
+            let state = self.states.peek_state(state_node);
+            if state.came_from_default {
+                continue;
+            }
+            values.push(SavedStateValue {
+                component_idx,
+                prop_name: prop_name.to_string(),
+                value: serde_json::to_value(&state.value)
+                    .expect("Prop values should serialize to JSON"),
+            });
+        }
+        values
+    }
+
+    /// Restore the state of the document from `saved_state`. Values that do not match a prop of the document,
+    /// or cannot be converted to the type of the prop, are skipped, as are such overrides
+    /// and annotations of components that are not in the document.
+    ///
+    /// State that has already been created is changed immediately, marking its dependencies as stale.
+    /// Otherwise, the saved value is used when the state is created.
+    pub fn load_state(&self, saved_state: SavedState) {
+        let SavedState {
+            values,
+            overrides,
+            user_values,
+            annotations,
+        } = saved_state;
+
+        self.clear_undo_history();
+        *self.pending_state.borrow_mut() = GraphNodeLookup::new();
+        self.load_state_values(values);
+        self.load_user_values(user_values);
+
+        for prop_override in overrides {
+            let _ = self.override_prop(prop_override);
+        }
+        self.load_annotations(annotations);
+    }
+
+    /// Restore the state of the props in `values`, in addition to any state that is already pending.
+    ///
+    /// Return the components that have had one of their `for_render` props newly marked as stale.
+    pub(super) fn load_state_values(&self, values: Vec<SavedStateValue>) -> Vec<ComponentIdx> {
+        let mut pending_state = self.pending_state.borrow_mut();
+        let mut changes_to_make = GraphNodeLookup::new();
+
+        for SavedStateValue {
+            component_idx,
+            prop_name,
+            value,
+        } in values
+        {
+            if !self.has_component(component_idx) {
+                continue;
+            }
+            let component = self.get_component(component_idx);
+            let Some(local_prop_idx) = component.get_local_prop_index_from_name(&prop_name) else {
+                continue;
+            };
+            let Some(value) =
+                prop_value_from_json(value, component.variant.get_prop_value_type(local_prop_idx))
+            else {
+                continue;
+            };
+
+            let prop_node = self.prop_pointer_to_prop_node(PropPointer {
+                component_idx,
+                local_prop_idx,
+            });
+            match self.get_prop_status(prop_node) {
+                PropStatus::Unresolved => pending_state.set_tag(prop_node, value),
+                _ => match self.document_structure.borrow().get_prop_leaf(prop_node) {
+                    state_node @ GraphNode::State(_) => changes_to_make.set_tag(state_node, value),
+                    _ => pending_state.set_tag(prop_node, value),
+                },
+            }
+        }
+
+        drop(pending_state);
+        self.execute_changes(changes_to_make)
+    }
+
+    /// The saved value, if any, for the state of `prop_node`, which is about to be created.
+    pub(super) fn get_pending_state(&self, prop_node: GraphNode) -> Option<PropValue> {
+        self.pending_state.borrow().get_tag(&prop_node).cloned()
+    }
+}
+
+/// Convert a JSON `value` to a `PropValue` of type `value_type`. Only types that are stored as state are supported.
+pub(super) fn prop_value_from_json(
+    value: serde_json::Value,
+    value_type: PropValueType,
+) -> Option<PropValue> {
+    match value_type {
+        PropValueType::String => value
+            .as_str()
+            .map(|s| PropValue::String(Rc::new(s.to_string()))),
+        PropValueType::Number => value.as_f64().map(PropValue::Number),
+        PropValueType::Integer => value.as_i64().map(PropValue::Integer),
+        PropValueType::Boolean => value.as_bool().map(PropValue::Boolean),
+        PropValueType::Math => serde_json::from_value::<MathExpr>(value)
+            .ok()
+            .map(|math| PropValue::Math(Rc::new(math))),
+        // The entries of a vector are not typed, so only vectors of integers (e.g., selected indices)
+        // and strings (e.g., submitted responses) are supported.
+        PropValueType::PropVec => value.as_array().and_then(|values| {
+            values
+                .iter()
+                .map(|value| {
+                    value.as_i64().map(PropValue::Integer).or_else(|| {
+                        value
+                            .as_str()
+                            .map(|s| PropValue::String(Rc::new(s.to_string())))
+                    })
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(PropValue::from)
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "saved_state.test.rs"]
+mod test;
@@ -0,0 +1,134 @@
+use crate::{
+    Core,
+    components::{
+        ActionsEnum,
+        doenet::text_input::{TextInputActionArgs, TextInputActions},
+        types::{Action, ActionBody},
+    },
+    extract_text,
+    test_utils::*,
+};
+
+use super::*;
+
+const SOURCE: &str = r#"<textInput name="ti"/><text>$ti</text>"#;
+
+/// Type `text` into the text input with index 1 and submit it.
+fn enter_text(core: &mut Core, text: &str) {
+    let component_idx = ComponentIdx::new(1);
+    let _ = core.dispatch_action(Action {
+        component_idx,
+        action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+            args: TextInputActionArgs {
+                text: text.to_string(),
+            },
+        })),
+    });
+    let _ = core.dispatch_action(Action {
+        component_idx,
+        action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+    });
+}
+
+#[test]
+fn state_is_not_saved_before_interaction() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.to_flat_dast();
+
+    assert_eq!(core.document_model.export_state(), SavedState::default());
+}
+
+#[test]
+fn saved_state_is_restored_before_rendering() {
+    let dast_root = dast_root_no_position(SOURCE);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+    enter_text(&mut core, "hello");
+    let saved_state = core.document_model.export_state();
+    assert!(
+        saved_state
+            .values
+            .iter()
+            .any(|value| value.value == serde_json::json!("hello"))
+    );
+
+    let mut restored_core = Core::new();
+    restored_core.init_from_dast_root(&dast_root);
+    restored_core.document_model.load_state(saved_state.clone());
+    assert_eq!(extract_text(&restored_core.to_flat_dast()), "hello hello");
+    assert_eq!(restored_core.document_model.export_state(), saved_state);
+}
+
+#[test]
+fn saved_state_is_restored_after_rendering() {
+    let dast_root = dast_root_no_position(SOURCE);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+    enter_text(&mut core, "hello");
+    let saved_state = core.document_model.export_state();
+
+    let mut restored_core = Core::new();
+    restored_core.init_from_dast_root(&dast_root);
+    assert_eq!(extract_text(&restored_core.to_flat_dast()).trim(), "");
+    restored_core.document_model.load_state(saved_state);
+    assert_eq!(extract_text(&restored_core.to_flat_dast()), "hello hello");
+}
+
+#[test]
+fn values_that_do_not_match_the_document_are_skipped() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.document_model.load_state(SavedState {
+        values: vec![
+            SavedStateValue {
+                component_idx: ComponentIdx::new(1),
+                prop_name: "notAProp".to_string(),
+                value: serde_json::json!("a"),
+            },
+            SavedStateValue {
+                component_idx: ComponentIdx::new(1),
+                prop_name: "value".to_string(),
+                value: serde_json::json!(3),
+            },
+            SavedStateValue {
+                component_idx: ComponentIdx::new(100),
+                prop_name: "value".to_string(),
+                value: serde_json::json!("a"),
+            },
+        ],
+        ..Default::default()
+    });
+
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "");
+}
+
+/// Platforms store the saved state, so its JSON shape must not change.
+#[test]
+fn saved_state_has_a_stable_json_shape() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.to_flat_dast();
+    enter_text(&mut core, "hello");
+
+    let saved_state = core.document_model.export_state();
+    let json = serde_json::to_value(&saved_state).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "values": [
+                { "componentIdx": 1, "propName": "value", "value": "hello" },
+                { "componentIdx": 1, "propName": "immediateValue", "value": "hello" },
+                { "componentIdx": 1, "propName": "syncValueToImmediateValue", "value": true },
+            ]
+        })
+    );
+    assert_eq!(
+        serde_json::from_value::<SavedState>(json).unwrap(),
+        saved_state
+    );
+}
@@ -0,0 +1,92 @@
+//! Selections made from pools of options, e.g., by a `<select>`. Hosts pass the selections
+//! seen on a student's previous attempts in the flags, so that new attempts avoid repeating them.
+
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::{
+    components::{
+        ComponentEnum,
+        doenet::select::SelectProps,
+        types::{ComponentIdx, PropPointer},
+    },
+    graph_node::GraphNode,
+    props::PropValue,
+};
+
+use super::DocumentModel;
+
+/// The option selected by a component, e.g., the index of the child a `<select>` shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct SelectionDescriptor {
+    pub component_idx: ComponentIdx,
+    pub option_index: usize,
+}
+
+impl DocumentModel {
+    /// The seed from which selections are made.
+    pub fn get_variant_seed(&self) -> u64 {
+        self.flags.borrow().variant_seed
+    }
+
+    /// The options the component `component_idx` selected on previous attempts.
+    pub fn get_seen_selections(&self, component_idx: ComponentIdx) -> Vec<usize> {
+        self.flags
+            .borrow()
+            .seen_selections
+            .iter()
+            .filter(|selection| selection.component_idx == component_idx)
+            .map(|selection| selection.option_index)
+            .collect()
+    }
+
+    /// Whether the component `component_idx` is inside a `<select>`, so it is an option
+    /// that is rendered only in some variants of the document.
+    pub fn is_in_select(&self, component_idx: ComponentIdx) -> bool {
+        self.document_structure
+            .borrow()
+            .get_true_component_ancestors(component_idx)
+            .any(|idx| matches!(self.get_component(idx).variant, ComponentEnum::Select(_)))
+    }
+
+    /// The options selected by each `<select>` of the document, to be saved by the host
+    /// and passed back in the flags of later attempts. A `<select>` without options is not included.
+    pub fn get_selections(&self) -> Vec<SelectionDescriptor> {
+        self.get_component_indices()
+            .filter(|component_idx| {
+                matches!(
+                    self.get_component(*component_idx).variant,
+                    ComponentEnum::Select(_)
+                )
+            })
+            .flat_map(|component_idx| {
+                let prop_node = self.prop_pointer_to_prop_node(PropPointer {
+                    component_idx,
+                    local_prop_idx: SelectProps::SelectedIndices.local_idx(),
+                });
+                let selected_indices = match self
+                    .get_prop_untracked(prop_node, GraphNode::Query(0))
+                    .value
+                {
+                    PropValue::PropVec(selected_indices) => selected_indices,
+                    _ => Rc::new(Vec::new()),
+                };
+                selected_indices
+                    .iter()
+                    .filter_map(|option_index| match option_index {
+                        PropValue::Integer(option_index) => Some(SelectionDescriptor {
+                            component_idx,
+                            option_index: usize::try_from(*option_index).ok()?,
+                        }),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
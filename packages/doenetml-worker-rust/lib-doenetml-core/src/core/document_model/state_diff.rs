@@ -0,0 +1,119 @@
+//! Comparison of two saved states of a document, e.g., so an instructor can review
+//! what a student changed between submissions.
+
+use serde::Serialize;
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::components::{ComponentNode, types::ComponentIdx};
+
+use super::{DocumentModel, SavedState, SavedStateValue};
+
+/// The differences between two saved states of a document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+#[cfg_attr(feature = "web", tsify(into_wasm_abi))]
+pub struct StateDiff {
+    /// The changed values, in document order.
+    pub changes: Vec<StateChange>,
+}
+
+/// A value that differs between two saved states.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct StateChange {
+    pub component_idx: ComponentIdx,
+    pub component_type: String,
+    /// The name by which the component can be referenced, if it has one.
+    pub component_name: Option<String>,
+    pub prop_name: String,
+    /// The value in the earlier state, or `None` if it had its default value.
+    #[cfg_attr(feature = "web", tsify(type = "any"))]
+    pub before: Option<serde_json::Value>,
+    /// The value in the later state, or `None` if it had its default value.
+    #[cfg_attr(feature = "web", tsify(type = "any"))]
+    pub after: Option<serde_json::Value>,
+    /// A description of the change for people, e.g., "textInput 3 value changed".
+    pub label: String,
+}
+
+impl DocumentModel {
+    /// List the values that differ between the saved states `before` and `after`.
+    ///
+    /// Components are identified in labels by their type and their position among components of that type,
+    /// e.g., the third `<textInput>` is "textInput 3". `component_names[i]`, if present, is the name
+    /// of the component with index `i`.
+    pub fn diff_states(
+        &self,
+        before: &SavedState,
+        after: &SavedState,
+        component_names: &[Option<String>],
+    ) -> StateDiff {
+        let find_value = |state: &SavedState, component_idx: ComponentIdx, prop_name: &str| {
+            state
+                .values
+                .iter()
+                .find(|value| value.component_idx == component_idx && value.prop_name == prop_name)
+                .map(|value| value.value.clone())
+        };
+
+        let mut keys: Vec<(ComponentIdx, &str)> = Vec::new();
+        for SavedStateValue {
+            component_idx,
+            prop_name,
+            ..
+        } in before.values.iter().chain(after.values.iter())
+        {
+            if !keys.contains(&(*component_idx, prop_name.as_str())) {
+                keys.push((*component_idx, prop_name));
+            }
+        }
+        keys.sort_by_key(|(component_idx, _)| component_idx.as_usize());
+
+        let changes = keys
+            .into_iter()
+            .filter_map(|(component_idx, prop_name)| {
+                let before_value = find_value(before, component_idx, prop_name);
+                let after_value = find_value(after, component_idx, prop_name);
+                if before_value == after_value || !self.has_component(component_idx) {
+                    return None;
+                }
+
+                let component_type = self
+                    .get_component(component_idx)
+                    .get_component_type()
+                    .to_string();
+                let ordinal = self
+                    .get_component_indices()
+                    .take_while(|idx| idx.as_usize() <= component_idx.as_usize())
+                    .filter(|idx| self.get_component(*idx).get_component_type() == component_type)
+                    .count();
+                let label = match after_value {
+                    Some(_) => format!("{component_type} {ordinal} {prop_name} changed"),
+                    None => format!("{component_type} {ordinal} {prop_name} was reset"),
+                };
+
+                Some(StateChange {
+                    component_idx,
+                    component_type,
+                    component_name: component_names
+                        .get(component_idx.as_usize())
+                        .cloned()
+                        .flatten(),
+                    prop_name: prop_name.to_string(),
+                    before: before_value,
+                    after: after_value,
+                    label,
+                })
+            })
+            .collect();
+
+        StateDiff { changes }
+    }
+}
+
+#[cfg(test)]
+#[path = "state_diff.test.rs"]
+mod test;
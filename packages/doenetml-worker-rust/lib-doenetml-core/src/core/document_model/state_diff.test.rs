@@ -0,0 +1,84 @@
+use crate::{Core, components::types::ComponentIdx, test_utils::*};
+
+use super::*;
+
+fn saved_value(component_idx: usize, value: serde_json::Value) -> SavedStateValue {
+    SavedStateValue {
+        component_idx: ComponentIdx::new(component_idx),
+        prop_name: "value".to_string(),
+        value,
+    }
+}
+
+fn diff(before: Vec<SavedStateValue>, after: Vec<SavedStateValue>) -> StateDiff {
+    // <document>: idx 0
+    //  <textInput>: idx 1
+    //  <p>: idx 2
+    //  <textInput name="b">: idx 3
+    let dast_root = dast_root_no_position(r#"<textInput/><p/><textInput name="b"/>"#);
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let component_names = core.resolver.as_ref().unwrap().calculate_root_names();
+    core.document_model.diff_states(
+        &SavedState {
+            values: before,
+            ..Default::default()
+        },
+        &SavedState {
+            values: after,
+            ..Default::default()
+        },
+        &component_names,
+    )
+}
+
+#[test]
+fn identical_states_have_no_changes() {
+    let state = vec![saved_value(1, serde_json::json!("a"))];
+    assert_eq!(diff(state.clone(), state), StateDiff::default());
+}
+
+#[test]
+fn changed_values_are_labeled_by_type_and_position() {
+    let changes = diff(
+        vec![saved_value(3, serde_json::json!("a"))],
+        vec![
+            saved_value(3, serde_json::json!("b")),
+            saved_value(1, serde_json::json!("c")),
+        ],
+    )
+    .changes;
+
+    assert_eq!(
+        changes,
+        vec![
+            StateChange {
+                component_idx: ComponentIdx::new(1),
+                component_type: "textInput".to_string(),
+                component_name: None,
+                prop_name: "value".to_string(),
+                before: None,
+                after: Some(serde_json::json!("c")),
+                label: "textInput 1 value changed".to_string(),
+            },
+            StateChange {
+                component_idx: ComponentIdx::new(3),
+                component_type: "textInput".to_string(),
+                component_name: Some("b".to_string()),
+                prop_name: "value".to_string(),
+                before: Some(serde_json::json!("a")),
+                after: Some(serde_json::json!("b")),
+                label: "textInput 2 value changed".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn values_returned_to_default_are_reset() {
+    let changes = diff(vec![saved_value(1, serde_json::json!("a"))], vec![]).changes;
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].label, "textInput 1 value was reset");
+    assert_eq!(changes[0].after, None);
+}
@@ -0,0 +1,87 @@
+//! A read-only snapshot of the prop cache, intended for devtools panels.
+
+use serde::Serialize;
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::{
+    components::{
+        ComponentNode, ComponentVariantProps,
+        types::{ComponentIdx, LocalPropIdx, PropPointer},
+    },
+    props::{PropValue, cache::PropStatus},
+};
+
+use super::DocumentModel;
+
+/// The status and last computed value of every prop of every component in the document.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+#[cfg_attr(feature = "web", tsify(into_wasm_abi))]
+pub struct DebugStateSummary {
+    pub components: Vec<ComponentStateSummary>,
+}
+
+/// The props of a single component instance.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct ComponentStateSummary {
+    pub component_idx: ComponentIdx,
+    pub component_type: String,
+    pub props: Vec<PropStateSummary>,
+}
+
+/// The status of a single prop. `value` is the last value that was computed,
+/// which may be out of date if `status` is `Stale`, and is `None` if the prop was never computed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct PropStateSummary {
+    pub name: String,
+    pub status: PropStatus,
+    pub value: Option<PropValue>,
+}
+
+impl DocumentModel {
+    /// Summarize the status of every prop in the document.
+    ///
+    /// No props are resolved or computed by this call, so it is safe to use for inspection
+    /// at any time without altering the state of the document.
+    pub fn debug_state_summary(&self) -> DebugStateSummary {
+        let document_structure = self.document_structure.borrow();
+        let components = document_structure
+            .get_component_indices()
+            .map(|component_idx| {
+                let component = document_structure.get_component(component_idx);
+                let props = component
+                    .variant
+                    .get_prop_names()
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, name)| {
+                        let prop_node = PropPointer {
+                            component_idx,
+                            local_prop_idx: LocalPropIdx::new(idx),
+                        }
+                        .into_prop_node(&document_structure);
+                        let (status, value) = self.prop_cache.peek_prop(prop_node);
+                        PropStateSummary {
+                            name: name.to_string(),
+                            status,
+                            value,
+                        }
+                    })
+                    .collect();
+                ComponentStateSummary {
+                    component_idx,
+                    component_type: component.get_component_type().to_string(),
+                    props,
+                }
+            })
+            .collect();
+
+        DebugStateSummary { components }
+    }
+}
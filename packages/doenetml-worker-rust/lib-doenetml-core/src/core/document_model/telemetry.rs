@@ -0,0 +1,139 @@
+//! Sampled timing of prop calculations and actions, so that hosts can monitor the performance
+//! of documents in production.
+//!
+//! Events identify work only by the type of the component involved. They never include the values of props,
+//! the names or indices of components, or the arguments of actions, so that student data cannot leak.
+
+use std::{cell::Cell, fmt::Debug, rc::Rc};
+
+use serde::Serialize;
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::components::types::ComponentIdx;
+
+use super::DocumentModel;
+
+/// Telemetry supplied by the host that receives sampled timing events.
+pub trait Telemetry: Debug {
+    /// The current time in milliseconds. Core has no clock of its own on the web,
+    /// so the host supplies one, e.g., `performance.now()`.
+    fn now(&self) -> f64;
+    /// Receive a sampled `event`.
+    fn record(&self, event: TelemetryEvent);
+}
+
+/// The kind of work timed by a `TelemetryEvent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub enum TelemetryEventKind {
+    /// A call to a prop's `calculate` function.
+    PropCalculation,
+    /// A batch of actions, including recalculating and rendering the props they changed.
+    Action,
+}
+
+/// The time taken by a single prop calculation or batch of actions.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct TelemetryEvent {
+    pub kind: TelemetryEventKind,
+    /// The type of the component whose prop was calculated or that received the (first) action.
+    pub component_type: String,
+    pub duration_ms: f64,
+}
+
+/// Installed telemetry along with how often its events are sampled.
+#[derive(Debug)]
+pub struct TelemetrySampler {
+    telemetry: Rc<dyn Telemetry>,
+    sample_interval: usize,
+    /// The number of events of each kind that have occurred, sampled or not.
+    events_seen: [Cell<usize>; 2],
+}
+
+impl TelemetrySampler {
+    /// Sample one of every `sample_interval` events of each kind. An interval of `0` is treated as `1`.
+    pub fn new(telemetry: Rc<dyn Telemetry>, sample_interval: usize) -> Self {
+        Self {
+            telemetry,
+            sample_interval: sample_interval.max(1),
+            events_seen: [Cell::new(0), Cell::new(0)],
+        }
+    }
+
+    /// Count an event of `kind`, returning whether it is sampled.
+    /// The first event of each kind is always sampled.
+    fn sample(&self, kind: TelemetryEventKind) -> bool {
+        let seen = &self.events_seen[kind as usize];
+        let is_sampled = seen.get() % self.sample_interval == 0;
+        seen.set(seen.get().wrapping_add(1));
+        is_sampled
+    }
+}
+
+/// The start of a sampled event, to be passed to `finish_telemetry_sample` once the work is done.
+#[derive(Debug, Clone, Copy)]
+pub struct TelemetrySample {
+    kind: TelemetryEventKind,
+    start: f64,
+}
+
+impl DocumentModel {
+    /// Install `telemetry`, which receives one of every `sample_interval` timing events of each kind,
+    /// or remove the installed telemetry if `None`.
+    pub fn set_telemetry(&self, telemetry: Option<Rc<dyn Telemetry>>, sample_interval: usize) {
+        *self.telemetry.borrow_mut() =
+            telemetry.map(|telemetry| TelemetrySampler::new(telemetry, sample_interval));
+    }
+
+    /// The installed telemetry and its sample interval, if any.
+    pub fn get_telemetry(&self) -> Option<(Rc<dyn Telemetry>, usize)> {
+        self.telemetry
+            .borrow()
+            .as_ref()
+            .map(|sampler| (sampler.telemetry.clone(), sampler.sample_interval))
+    }
+
+    /// Count the start of an event of `kind`. If the event is sampled, return its start time.
+    pub fn start_telemetry_sample(&self, kind: TelemetryEventKind) -> Option<TelemetrySample> {
+        let telemetry = self.telemetry.borrow();
+        let sampler = telemetry.as_ref()?;
+        sampler.sample(kind).then(|| TelemetrySample {
+            kind,
+            start: sampler.telemetry.now(),
+        })
+    }
+
+    /// Send the event started by `sample`, if it was sampled, attributing it to the type of `component_idx`.
+    pub fn finish_telemetry_sample(
+        &self,
+        sample: Option<TelemetrySample>,
+        component_idx: ComponentIdx,
+    ) {
+        let Some(TelemetrySample { kind, start }) = sample else {
+            return;
+        };
+        let component_type = self.get_component_type(component_idx);
+        // Clone the telemetry so that it is not borrowed if recording the event calls back into core.
+        let Some(telemetry) = self
+            .telemetry
+            .borrow()
+            .as_ref()
+            .map(|sampler| sampler.telemetry.clone())
+        else {
+            return;
+        };
+        telemetry.record(TelemetryEvent {
+            kind,
+            component_type,
+            duration_ms: telemetry.now() - start,
+        });
+    }
+}
+
+#[cfg(test)]
+#[path = "telemetry.test.rs"]
+mod test;
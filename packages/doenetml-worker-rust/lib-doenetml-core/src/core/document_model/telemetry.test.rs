@@ -0,0 +1,109 @@
+use std::cell::RefCell;
+
+use crate::{
+    Core,
+    components::{
+        ActionsEnum,
+        doenet::text_input::{TextInputActionArgs, TextInputActions},
+        types::{Action, ActionBody},
+    },
+    test_utils::*,
+};
+
+use super::*;
+
+/// Telemetry that records its events, with a clock that advances by one millisecond each time it is read.
+#[derive(Debug, Default)]
+struct RecordingTelemetry {
+    time: Cell<f64>,
+    events: RefCell<Vec<TelemetryEvent>>,
+}
+
+impl Telemetry for RecordingTelemetry {
+    fn now(&self) -> f64 {
+        self.time.set(self.time.get() + 1.0);
+        self.time.get()
+    }
+    fn record(&self, event: TelemetryEvent) {
+        self.events.borrow_mut().push(event);
+    }
+}
+
+fn count_events(telemetry: &RecordingTelemetry, kind: TelemetryEventKind) -> usize {
+    telemetry
+        .events
+        .borrow()
+        .iter()
+        .filter(|event| event.kind == kind)
+        .count()
+}
+
+#[test]
+fn samples_one_of_every_interval_events() {
+    let sampler = TelemetrySampler::new(Rc::new(RecordingTelemetry::default()), 3);
+    let sampled = (0..7)
+        .map(|_| sampler.sample(TelemetryEventKind::PropCalculation))
+        .collect::<Vec<_>>();
+    assert_eq!(sampled, vec![true, false, false, true, false, false, true]);
+
+    // Each kind of event is counted separately.
+    assert!(sampler.sample(TelemetryEventKind::Action));
+
+    // An interval of zero samples every event.
+    let sampler = TelemetrySampler::new(Rc::new(RecordingTelemetry::default()), 0);
+    assert!((0..3).all(|_| sampler.sample(TelemetryEventKind::Action)));
+}
+
+#[test]
+fn records_prop_calculations_and_actions_without_values() {
+    let telemetry = Rc::new(RecordingTelemetry::default());
+    let mut core = Core::new();
+    core.set_telemetry(Some(telemetry.clone()), 1);
+    // Telemetry survives re-initialization.
+    core.init_from_dast_root(&dast_root_no_position(
+        r#"<textInput name="ti"/><text>$ti</text>"#,
+    ));
+    core.to_flat_dast();
+
+    assert!(count_events(&telemetry, TelemetryEventKind::PropCalculation) > 0);
+    assert!(
+        telemetry
+            .events
+            .borrow()
+            .iter()
+            .any(|event| event.component_type == "text" && event.duration_ms == 1.0)
+    );
+
+    core.dispatch_action(Action {
+        component_idx: ComponentIdx::new(1),
+        action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+            args: TextInputActionArgs {
+                text: "secret answer".to_string(),
+            },
+        })),
+    })
+    .unwrap();
+
+    let events = telemetry.events.borrow();
+    let action_event = events
+        .iter()
+        .find(|event| event.kind == TelemetryEventKind::Action)
+        .expect("the action should have been recorded");
+    assert_eq!(action_event.component_type, "textInput");
+
+    // Nothing entered by the student reaches the telemetry.
+    let serialized = serde_json::to_string(&*events).unwrap();
+    assert!(!serialized.contains("secret"));
+}
+
+#[test]
+fn records_nothing_once_removed() {
+    let telemetry = Rc::new(RecordingTelemetry::default());
+    let mut core = Core::new();
+    core.set_telemetry(Some(telemetry.clone()), 1);
+    core.set_telemetry(None, 1);
+    core.init_from_dast_root(&dast_root_no_position(r#"<text>Hello</text>"#));
+    core.to_flat_dast();
+
+    assert!(telemetry.events.borrow().is_empty());
+}
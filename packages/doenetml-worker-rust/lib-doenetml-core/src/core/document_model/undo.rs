@@ -0,0 +1,158 @@
+//! Undoing and redoing the changes made by actions, e.g., so that a learner can revert
+//! an accidental drag of a point or edit of an input.
+
+use std::collections::VecDeque;
+
+use crate::{
+    components::types::ComponentIdx,
+    graph::directed_graph::Taggable,
+    graph_node::{GraphNode, GraphNodeLookup},
+    props::PropValue,
+};
+
+use super::DocumentModel;
+
+/// The maximum number of steps that can be undone. Older steps are discarded.
+const MAX_UNDO_STEPS: usize = 100;
+
+/// The value of a `State` or `String` node before it was changed, so that the change can be reverted.
+#[derive(Debug, Clone)]
+struct RecordedValue {
+    node: GraphNode,
+    value: PropValue,
+    came_from_default: bool,
+}
+
+/// The changes that can be undone and redone, each recorded as the values that it replaced.
+#[derive(Debug, Default)]
+pub struct UndoHistory {
+    undo_steps: VecDeque<Vec<RecordedValue>>,
+    redo_steps: Vec<Vec<RecordedValue>>,
+}
+
+impl DocumentModel {
+    /// Record the current values of the nodes in `changes_to_make` as a step that can be undone.
+    /// This must be called before the changes are executed. Recording a new step discards the steps that could be redone.
+    ///
+    /// As in `execute_changes`, nodes that already have their new value are not changed, so they are not recorded.
+    /// If no nodes would change, no step is recorded.
+    pub fn record_undo_step(&self, changes_to_make: &GraphNodeLookup<PropValue>) {
+        let step = self
+            .record_values(changes_to_make.keys())
+            .into_iter()
+            .filter(|recorded| {
+                // Setting a state also clears its `came_from_default`, which is a change in itself.
+                recorded.came_from_default
+                    || changes_to_make.get_tag(&recorded.node) != Some(&recorded.value)
+            })
+            .collect::<Vec<_>>();
+        if step.is_empty() {
+            return;
+        }
+
+        let mut history = self.undo_history.borrow_mut();
+        history.redo_steps.clear();
+        history.undo_steps.push_back(step);
+        if history.undo_steps.len() > MAX_UNDO_STEPS {
+            history.undo_steps.pop_front();
+        }
+    }
+
+    /// Revert the most recent step recorded by `record_undo_step`, so that it can be redone.
+    ///
+    /// Return the components that have had one of their `for_render` props newly marked as stale,
+    /// or `None` if there is nothing to undo.
+    pub fn undo(&self) -> Option<Vec<ComponentIdx>> {
+        let step = self.undo_history.borrow_mut().undo_steps.pop_back()?;
+        let redo_step = self.record_values(step.iter().map(|recorded| recorded.node));
+        self.undo_history.borrow_mut().redo_steps.push(redo_step);
+        Some(self.restore_values(step))
+    }
+
+    /// Reapply the most recent step reverted by `undo`.
+    ///
+    /// Return the components that have had one of their `for_render` props newly marked as stale,
+    /// or `None` if there is nothing to redo.
+    pub fn redo(&self) -> Option<Vec<ComponentIdx>> {
+        let step = self.undo_history.borrow_mut().redo_steps.pop()?;
+        let undo_step = self.record_values(step.iter().map(|recorded| recorded.node));
+        self.undo_history
+            .borrow_mut()
+            .undo_steps
+            .push_back(undo_step);
+        Some(self.restore_values(step))
+    }
+
+    /// Discard all the steps that can be undone or redone.
+    ///
+    /// The steps record values of state as it was when they were taken, so they must be discarded
+    /// whenever the state is replaced by other means, e.g., when another user becomes active or a saved state is loaded.
+    /// Otherwise, undoing would write values from the replaced state, such as a previous user's response, into the new state.
+    pub(super) fn clear_undo_history(&self) {
+        let mut history = self.undo_history.borrow_mut();
+        history.undo_steps.clear();
+        history.redo_steps.clear();
+    }
+
+    /// Whether there is a step that can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_history.borrow().undo_steps.is_empty()
+    }
+
+    /// Whether there is a step that can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.undo_history.borrow().redo_steps.is_empty()
+    }
+
+    /// The current values of the `State` and `String` nodes in `nodes`.
+    fn record_values(&self, nodes: impl IntoIterator<Item = GraphNode>) -> Vec<RecordedValue> {
+        nodes
+            .into_iter()
+            .map(|node| match node {
+                GraphNode::State(_) => {
+                    let state = self.states.peek_state(node);
+                    RecordedValue {
+                        node,
+                        value: state.value,
+                        came_from_default: state.came_from_default,
+                    }
+                }
+                GraphNode::String(_) => RecordedValue {
+                    node,
+                    value: self.get_string_value(node).into(),
+                    came_from_default: false,
+                },
+                _ => panic!("Can record the value of only State and String nodes, found {node:?}"),
+            })
+            .collect()
+    }
+
+    /// Set the nodes in `values` back to their recorded values and mark their dependents as stale.
+    fn restore_values(&self, values: Vec<RecordedValue>) -> Vec<ComponentIdx> {
+        let nodes_changed = values
+            .iter()
+            .map(|recorded| recorded.node)
+            .collect::<Vec<_>>();
+        for RecordedValue {
+            node,
+            value,
+            came_from_default,
+        } in values
+        {
+            match node {
+                GraphNode::State(_) if came_from_default => self.states.reset_state(node, value),
+                GraphNode::State(_) => self.states.set_state(node, value),
+                GraphNode::String(_) => self
+                    .document_structure
+                    .borrow()
+                    .set_string(node, value.try_into().unwrap()),
+                _ => unreachable!("Only State and String nodes are recorded"),
+            }
+        }
+        self.mark_dependents_stale(&nodes_changed)
+    }
+}
+
+#[cfg(test)]
+#[path = "undo.test.rs"]
+mod test;
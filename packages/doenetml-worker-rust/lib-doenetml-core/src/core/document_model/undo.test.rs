@@ -0,0 +1,149 @@
+use crate::{
+    Core,
+    components::{
+        ActionsEnum,
+        doenet::text_input::{TextInputActionArgs, TextInputActions},
+        types::{Action, ActionBody},
+    },
+    extract_text,
+    test_utils::*,
+};
+
+use super::*;
+
+const SOURCE: &str = r#"<textInput name="ti"/><text>$ti</text>"#;
+
+fn type_text(core: &mut Core, text: &str) {
+    core.dispatch_action(Action {
+        component_idx: ComponentIdx::new(1),
+        action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+            args: TextInputActionArgs {
+                text: text.to_string(),
+            },
+        })),
+    })
+    .unwrap();
+    core.dispatch_action(Action {
+        component_idx: ComponentIdx::new(1),
+        action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+    })
+    .unwrap();
+}
+
+#[test]
+fn undo_and_redo_revert_and_reapply_actions() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "");
+    assert!(!core.can_undo());
+
+    type_text(&mut core, "a");
+    type_text(&mut core, "b");
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "b b");
+
+    // Each action is undone separately, so undo both actions that typed `b`.
+    core.undo().unwrap();
+    core.undo().unwrap();
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "a a");
+    assert!(core.can_redo());
+
+    core.undo().unwrap();
+    let updates = core.undo().unwrap();
+    assert!(!updates.is_empty());
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "");
+    assert!(!core.can_undo());
+    assert!(core.undo().is_err());
+
+    // The restored state is marked as default again, so it is not saved.
+    assert!(core.document_model.export_state().values.is_empty());
+
+    core.redo().unwrap();
+    core.redo().unwrap();
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "a a");
+}
+
+#[test]
+fn new_action_discards_redo_steps() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.to_flat_dast();
+
+    type_text(&mut core, "a");
+    core.undo().unwrap();
+    assert!(core.can_redo());
+
+    type_text(&mut core, "c");
+    assert!(!core.can_redo());
+    assert!(core.redo().is_err());
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "c c");
+}
+
+#[test]
+fn actions_that_change_nothing_are_not_recorded() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.to_flat_dast();
+
+    type_text(&mut core, "a");
+    // The value is already `a`, so updating it again changes nothing.
+    core.dispatch_action(Action {
+        component_idx: ComponentIdx::new(1),
+        action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+    })
+    .unwrap();
+
+    core.undo().unwrap();
+    core.undo().unwrap();
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "");
+    assert!(!core.can_undo());
+}
+
+#[test]
+fn undo_history_is_bounded() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.to_flat_dast();
+
+    for i in 0..MAX_UNDO_STEPS {
+        type_text(&mut core, &i.to_string());
+    }
+    let mut undone = 0;
+    while core.undo().is_ok() {
+        undone += 1;
+    }
+    assert_eq!(undone, MAX_UNDO_STEPS);
+    // The oldest steps were discarded, so the first value typed remains.
+    assert_ne!(extract_text(&core.to_flat_dast()).trim(), "");
+}
+
+#[test]
+fn switching_users_discards_undo_history() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.to_flat_dast();
+
+    type_text(&mut core, "a");
+    assert!(core.can_undo());
+
+    core.set_active_user("bob");
+    assert!(!core.can_undo());
+    assert!(core.undo().is_err());
+}
+
+#[test]
+fn loading_state_discards_undo_history() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.to_flat_dast();
+
+    type_text(&mut core, "a");
+    let saved_state = core.export_state().unwrap();
+    type_text(&mut core, "b");
+    core.undo().unwrap();
+    assert!(core.can_redo());
+
+    core.load_state(&saved_state).unwrap();
+    assert!(!core.can_undo());
+    assert!(!core.can_redo());
+    assert_eq!(extract_text(&core.to_flat_dast()).trim(), "a a");
+}
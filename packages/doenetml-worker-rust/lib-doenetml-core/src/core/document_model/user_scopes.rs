@@ -0,0 +1,163 @@
+//! State that is stored separately for each user, so that a group working together in one document
+//! shares some responses while keeping others private.
+//!
+//! A component declares that its state is private to each user with `scope="user"`.
+//! Only one user is active at a time; the state of the other users is stashed until they become active again.
+//! A component declares that its state is shared but not saved with `scope="ephemeral"` (see `presence.rs`).
+
+use std::collections::BTreeMap;
+
+use crate::{
+    components::{ComponentCommon, types::ComponentIdx},
+    graph::directed_graph::Taggable,
+    graph_node::{GraphNode, GraphNodeLookup},
+    props::PropValue,
+};
+
+use super::{DocumentModel, SavedStateValue, provenance::attribute_text};
+
+/// The attribute with which a component declares the scope of its state.
+pub const SCOPE_ATTRIBUTE: &str = "scope";
+
+/// Who a component's state belongs to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StateScope {
+    /// The state is shared by all users.
+    #[default]
+    Shared,
+    /// Each user has their own copy of the state.
+    User,
+    /// The state is shared by all users but is not saved, e.g., the position of a collaborator's cursor.
+    Ephemeral,
+}
+
+/// The active user and the state of the users who are not active.
+#[derive(Debug, Default)]
+pub struct UserScopes {
+    /// The user whose state is currently in the document. The empty string is the default user.
+    active_user: String,
+    /// The state node of every user-scoped prop whose state has been created, along with the prop
+    /// that stores it and its default value.
+    created: Vec<(GraphNode, GraphNode, PropValue)>,
+    /// The state of users who are not active, keyed by user.
+    inactive_values: BTreeMap<String, Vec<SavedStateValue>>,
+}
+
+impl DocumentModel {
+    /// The scope of the state of the component `component_idx`, as given by its `scope` attribute.
+    pub fn get_state_scope(&self, component_idx: ComponentIdx) -> StateScope {
+        let component = self.get_component(component_idx);
+        let scope = component
+            .get_unrecognized_attributes()
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(SCOPE_ATTRIBUTE))
+            .map(|(_, attribute)| attribute_text(attribute).trim().to_ascii_lowercase());
+        match scope.as_deref() {
+            Some("user") => StateScope::User,
+            Some("ephemeral") => StateScope::Ephemeral,
+            _ => StateScope::Shared,
+        }
+    }
+
+    /// The user whose state is currently in the document.
+    pub fn get_active_user(&self) -> String {
+        self.user_scopes.borrow().active_user.clone()
+    }
+
+    /// Make `user` the active user. The state of the previously active user is stashed
+    /// and the state of `user`, if any, is restored. Shared state is unchanged.
+    ///
+    /// Return the components that have had one of their `for_render` props newly marked as stale.
+    pub fn set_active_user(&self, user: &str) -> Vec<ComponentIdx> {
+        if self.user_scopes.borrow().active_user == user {
+            return Vec::new();
+        }
+        self.clear_undo_history();
+
+        let mut stashed_values = Vec::new();
+
+        // Reset the state that has been created to its default, stashing any value the user gave it.
+        let mut nodes_reset = Vec::new();
+        for (prop_node, state_node, default) in self.user_scopes.borrow().created.iter() {
+            let state = self.states.peek_state(state_node);
+            if state.came_from_default {
+                continue;
+            }
+            stashed_values.push(self.saved_state_value(*prop_node, &state.value));
+            self.states.reset_state(state_node, default.clone());
+            nodes_reset.push(*state_node);
+        }
+
+        // Stash the values of user-scoped state that has not been created yet.
+        let mut shared_pending_state = GraphNodeLookup::new();
+        for (prop_node, value) in self.pending_state.borrow().iter() {
+            let component_idx = self.get_prop_pointer(prop_node).component_idx;
+            match self.get_state_scope(component_idx) {
+                StateScope::User => stashed_values.push(self.saved_state_value(prop_node, value)),
+                StateScope::Shared | StateScope::Ephemeral => {
+                    shared_pending_state.set_tag(prop_node, value.clone())
+                }
+            }
+        }
+        *self.pending_state.borrow_mut() = shared_pending_state;
+
+        let values_to_load = {
+            let mut user_scopes = self.user_scopes.borrow_mut();
+            let previous_user = std::mem::replace(&mut user_scopes.active_user, user.to_string());
+            if !stashed_values.is_empty() {
+                user_scopes
+                    .inactive_values
+                    .insert(previous_user, stashed_values);
+            }
+            user_scopes.inactive_values.remove(user).unwrap_or_default()
+        };
+
+        let mut changed_components = self.mark_dependents_stale(&nodes_reset);
+        changed_components.extend(self.load_state_values(values_to_load));
+        changed_components
+    }
+
+    /// Record that the state `state_node` of `prop_node` has been created, if it is user-scoped.
+    pub(super) fn register_state_scope(
+        &self,
+        prop_node: GraphNode,
+        state_node: GraphNode,
+        default: PropValue,
+    ) {
+        let component_idx = self.get_prop_pointer(prop_node).component_idx;
+        if self.get_state_scope(component_idx) == StateScope::User {
+            self.user_scopes
+                .borrow_mut()
+                .created
+                .push((prop_node, state_node, default));
+        }
+    }
+
+    /// The stashed state of the users who are not active.
+    pub(super) fn get_inactive_user_values(&self) -> BTreeMap<String, Vec<SavedStateValue>> {
+        self.user_scopes.borrow().inactive_values.clone()
+    }
+
+    /// Restore the state of each user in `user_values`. The values of the active user are loaded
+    /// into the document and the values of other users are stashed.
+    pub(super) fn load_user_values(&self, mut user_values: BTreeMap<String, Vec<SavedStateValue>>) {
+        let active_values = user_values
+            .remove(&self.get_active_user())
+            .unwrap_or_default();
+        self.user_scopes.borrow_mut().inactive_values = user_values;
+        self.load_state_values(active_values);
+    }
+
+    fn saved_state_value(&self, prop_node: GraphNode, value: &PropValue) -> SavedStateValue {
+        let prop_pointer = self.get_prop_pointer(prop_node);
+        SavedStateValue {
+            component_idx: prop_pointer.component_idx,
+            prop_name: self.get_prop_name(prop_pointer).to_string(),
+            value: serde_json::to_value(value).expect("Prop values should serialize to JSON"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "user_scopes.test.rs"]
+mod test;
@@ -0,0 +1,102 @@
+use crate::{
+    Core,
+    components::{
+        ActionsEnum,
+        doenet::text_input::{TextInputActionArgs, TextInputActions},
+        types::{Action, ActionBody},
+    },
+    extract_text,
+    test_utils::*,
+};
+
+use super::*;
+
+const SOURCE: &str = r#"<textInput name="private" scope="user"/><textInput name="shared"/><text>$private $shared</text>"#;
+
+/// Type `text` into the text input with index `component_idx` and submit it.
+fn enter_text(core: &mut Core, component_idx: usize, text: &str) {
+    let component_idx = ComponentIdx::new(component_idx);
+    let _ = core.dispatch_action(Action {
+        component_idx,
+        action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+            args: TextInputActionArgs {
+                text: text.to_string(),
+            },
+        })),
+    });
+    let _ = core.dispatch_action(Action {
+        component_idx,
+        action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+    });
+}
+
+#[test]
+fn scope_is_read_from_the_scope_attribute() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+
+    assert_eq!(
+        core.document_model.get_state_scope(ComponentIdx::new(1)),
+        StateScope::User
+    );
+    assert_eq!(
+        core.document_model.get_state_scope(ComponentIdx::new(2)),
+        StateScope::Shared
+    );
+}
+
+#[test]
+fn switching_users_swaps_only_user_scoped_state() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core.to_flat_dast();
+
+    core.document_model.set_active_user("alice");
+    enter_text(&mut core, 1, "a");
+    enter_text(&mut core, 2, "s");
+    assert_eq!(extract_text(&core.to_flat_dast()), "a s a s");
+
+    let changed_components = core.document_model.set_active_user("bob");
+    assert!(!changed_components.is_empty());
+    assert_eq!(extract_text(&core.to_flat_dast()), " s  s");
+    enter_text(&mut core, 1, "b");
+    assert_eq!(extract_text(&core.to_flat_dast()), "b s b s");
+
+    core.document_model.set_active_user("alice");
+    assert_eq!(core.document_model.get_active_user(), "alice");
+    assert_eq!(extract_text(&core.to_flat_dast()), "a s a s");
+}
+
+#[test]
+fn user_scoped_state_is_saved_per_user() {
+    let dast_root = dast_root_no_position(SOURCE);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+    core.document_model.set_active_user("alice");
+    enter_text(&mut core, 1, "a");
+    enter_text(&mut core, 2, "s");
+    core.document_model.set_active_user("bob");
+    enter_text(&mut core, 1, "b");
+
+    let saved_state = core.document_model.export_state();
+    assert!(
+        saved_state
+            .values
+            .iter()
+            .all(|value| value.component_idx == ComponentIdx::new(2))
+    );
+    assert_eq!(
+        saved_state.user_values.keys().collect::<Vec<_>>(),
+        vec!["alice", "bob"]
+    );
+
+    let mut restored_core = Core::new();
+    restored_core.init_from_dast_root(&dast_root);
+    restored_core.document_model.set_active_user("bob");
+    restored_core.document_model.load_state(saved_state);
+    assert_eq!(extract_text(&restored_core.to_flat_dast()), "b s b s");
+    restored_core.document_model.set_active_user("alice");
+    assert_eq!(extract_text(&restored_core.to_flat_dast()), "a s a s");
+}
@@ -0,0 +1,168 @@
+//! Analysis of content that is never rendered, so that authors can clean up their documents.
+
+use super::DocumentRenderer;
+use crate::{
+    components::{
+        ComponentActions, ComponentNode, ComponentVariantProps,
+        prelude::{ComponentIdx, PropProfile},
+        types::{LocalPropIdx, PropPointer},
+    },
+    core::document_model::DocumentModel,
+    dast::{DastWarning, DiagnosticCode, flat_dast::ErrorType},
+    graph::directed_graph::Taggable,
+    graph_node::GraphNode,
+    props::PropValue,
+};
+
+/// Why a component is never rendered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DeadContentReason {
+    /// The component is hidden and no interaction can change its `hidden` prop.
+    AlwaysHidden,
+    /// The component is not in the render tree, e.g., because its parent does not render it.
+    NeverRendered,
+}
+
+impl DocumentRenderer {
+    /// Find the components of the document that are never rendered, reported as informational warnings.
+    ///
+    /// A component is reported if it is not in the render tree, none of its props are used by other props
+    /// and no other component extends it (so it does not affect the rendered document), and it is not hidden
+    /// by a condition that depends on an interactive component or on the render mode or role
+    /// (so it is never shown). Content withheld until a division is available is not reported,
+    /// nor are the options of a `<select>` that were not selected or the arms of an `<experiment>` that were not assigned.
+    ///
+    /// Only the outermost such component is reported; its descendants are not listed separately.
+    /// Since props are calculated lazily, the analysis is based on the props needed to render the document.
+    pub fn find_dead_content(&mut self, document_model: &DocumentModel) -> Vec<DastWarning> {
+        self.mark_component_in_render_tree(ComponentIdx::new(0), document_model);
+
+        let candidates = document_model
+            .get_component_indices()
+            .filter(|component_idx| {
+                self.in_render_tree.get_tag(&component_idx.as_graph_node()) != Some(&true)
+            })
+            // Content withheld because of the role of the viewer is rendered for other roles.
+            .filter(|component_idx| !document_model.is_hidden_by_role(*component_idx))
+            // Content of a division that is not yet available is rendered once it is released.
+            .filter(|component_idx| !document_model.is_in_unavailable_division(*component_idx))
+            // Options of a `<select>` that were not selected are rendered in other variants.
+            .filter(|component_idx| !document_model.is_in_select(*component_idx))
+            // Arms of an `<experiment>` that were not assigned are rendered for other students.
+            .filter(|component_idx| !document_model.is_in_experiment(*component_idx))
+            .collect::<Vec<_>>();
+
+        // Calculate the `hidden` props of the candidates before inspecting the dependency graph,
+        // as calculating them adds their dependencies to the graph.
+        let hidden_props = candidates
+            .iter()
+            .map(|&component_idx| {
+                let component = document_model.get_component(component_idx);
+                component
+                    .get_prop_by_profile(&[PropProfile::Hidden])
+                    .map(|local_prop_idx| {
+                        let prop_node = document_model.prop_pointer_to_prop_node(PropPointer {
+                            component_idx,
+                            local_prop_idx,
+                        });
+                        let hidden = self.get_prop_for_render_untracked(prop_node, document_model);
+                        (prop_node, hidden.value == PropValue::Boolean(true))
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        let dependency_graph = document_model.get_dependency_graph();
+        let mut dead_components: Vec<(ComponentIdx, DeadContentReason)> = Vec::new();
+        for (&component_idx, hidden_prop) in candidates.iter().zip(hidden_props) {
+            let component = document_model.get_component(component_idx);
+            if component.get_component_type().starts_with('_') {
+                // Internal components are not authored, so there is nothing to report.
+                continue;
+            }
+
+            let props_are_used = (0..component.variant.get_num_props())
+                .map(|idx| {
+                    document_model.prop_pointer_to_prop_node(PropPointer {
+                        component_idx,
+                        local_prop_idx: LocalPropIdx::new(idx),
+                    })
+                })
+                .filter(|prop_node| Some(*prop_node) != hidden_prop.map(|(node, _)| node))
+                .any(|prop_node| dependency_graph.has_parents(prop_node));
+            if props_are_used || document_model.is_component_extended(component_idx) {
+                // E.g., a hidden component that is referenced elsewhere in the document.
+                continue;
+            }
+
+            match hidden_prop {
+                Some((hidden_node, true)) => {
+                    // Only actions can change the state of a document, so if `hidden` does not depend
+                    // on a prop of a component with actions, then no interaction can cause the component to be shown.
+                    // Content hidden because of the render mode or role is shown when the document is rendered
+                    // in another mode or for another role.
+                    let may_be_shown =
+                        dependency_graph
+                            .descendants_quick(hidden_node)
+                            .any(|node| match node {
+                                GraphNode::Prop(_) if *node != hidden_node => {
+                                    let component_idx =
+                                        document_model.get_prop_pointer(*node).component_idx;
+                                    !document_model
+                                        .get_component(component_idx)
+                                        .get_action_names()
+                                        .is_empty()
+                                }
+                                GraphNode::Query(_) => document_model.is_flag_query(*node),
+                                _ => false,
+                            });
+                    if !may_be_shown {
+                        dead_components.push((component_idx, DeadContentReason::AlwaysHidden));
+                    }
+                }
+                _ => dead_components.push((component_idx, DeadContentReason::NeverRendered)),
+            }
+        }
+
+        let is_dead = |component_idx: ComponentIdx| {
+            dead_components
+                .iter()
+                .any(|(dead_idx, _)| *dead_idx == component_idx)
+        };
+
+        dead_components
+            .iter()
+            .filter_map(|&(component_idx, reason)| {
+                let component = document_model.get_component(component_idx);
+                if component.common.parent.is_some_and(is_dead) {
+                    return None;
+                }
+                let component_type = component.get_component_type();
+                let (message, code) = match reason {
+                    DeadContentReason::AlwaysHidden => (
+                        format!("`<{component_type}>` is always hidden, so it is never rendered"),
+                        DiagnosticCode::AlwaysHidden,
+                    ),
+                    DeadContentReason::NeverRendered => (
+                        format!(
+                            "`<{component_type}>` is never rendered and none of its values are used"
+                        ),
+                        DiagnosticCode::NeverRendered,
+                    ),
+                };
+                Some(DastWarning {
+                    message,
+                    severity: ErrorType::Info,
+                    code: Some(code),
+                    suggestion: None,
+                    count: 1,
+                    position: component.common.position,
+                    source_doc: None,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "dead_content.test.rs"]
+mod test;
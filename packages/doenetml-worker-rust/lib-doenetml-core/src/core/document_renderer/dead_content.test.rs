@@ -0,0 +1,65 @@
+use crate::{
+    Core,
+    dast::{DiagnosticCode, flat_dast::ErrorType},
+    test_utils::dast_root_no_position,
+};
+
+fn dead_content_codes(doenetml: &str) -> Vec<(String, DiagnosticCode)> {
+    let dast_root = dast_root_no_position(doenetml);
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+
+    core.find_dead_content()
+        .into_iter()
+        .map(|warning| {
+            assert_eq!(warning.severity, ErrorType::Info);
+            (warning.message, warning.code.unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn rendered_content_is_not_dead() {
+    assert!(dead_content_codes(r#"<p>Hello <text>world</text></p>"#).is_empty());
+}
+
+#[test]
+fn content_hidden_by_a_constant_is_dead() {
+    assert_eq!(
+        dead_content_codes(r#"<p>Hello</p><text hide="true">secret</text>"#),
+        vec![(
+            "`<text>` is always hidden, so it is never rendered".to_string(),
+            DiagnosticCode::AlwaysHidden
+        )]
+    );
+}
+
+#[test]
+fn hidden_content_that_is_referenced_is_not_dead() {
+    assert!(
+        dead_content_codes(r#"<text name="t" hide="true">secret</text><text>The $t</text>"#)
+            .is_empty()
+    );
+}
+
+#[test]
+fn content_hidden_by_state_is_not_dead() {
+    assert!(
+        dead_content_codes(
+            r#"<textInput name="i" prefill="true" /><text hide="$i.value">secret</text>"#
+        )
+        .is_empty()
+    );
+}
+
+#[test]
+fn only_the_outermost_dead_component_is_reported() {
+    assert_eq!(
+        dead_content_codes(r#"<p hide="true"><text>a</text><text>b</text></p>"#),
+        vec![(
+            "`<p>` is always hidden, so it is never rendered".to_string(),
+            DiagnosticCode::AlwaysHidden
+        )]
+    );
+}
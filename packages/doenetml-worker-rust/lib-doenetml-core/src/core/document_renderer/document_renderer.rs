@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+
+use crate::components::prelude::{ComponentIdx, KeyboardShortcut};
 use crate::graph_node::{GraphNode, GraphNodeLookup};
 
 /// The `DocumentRenderer` is responsible for rendering the document tree into a flat DAST and
@@ -12,6 +15,8 @@ pub struct DocumentRenderer {
     // This graph node is used to figure out if any props have changed between renders.
     // It is a single fixed node and should always be related to the first entry of `self.queries`.
     pub(super) for_render_query_node: GraphNode,
+    /// The keyboard shortcuts of each component that has any, after conflicts between components were resolved.
+    pub(super) keyboard_shortcuts: HashMap<ComponentIdx, Vec<KeyboardShortcut>>,
 }
 
 impl Default for DocumentRenderer {
@@ -25,6 +30,7 @@ impl DocumentRenderer {
         DocumentRenderer {
             in_render_tree: GraphNodeLookup::new(),
             for_render_query_node: GraphNode::Query(0), // the DataQuery::Null added in queries, above
+            keyboard_shortcuts: HashMap::new(),
         }
     }
 }
@@ -0,0 +1,119 @@
+//! Summary statistics of a document, e.g., for platforms to display alongside an assignment.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use super::DocumentRenderer;
+use crate::{
+    components::{ComponentActions, ComponentNode},
+    core::document_model::DocumentModel,
+    dast::{FlatDastElementContent, FlatDastRoot, ForRenderPropValueOrContent},
+    graph::directed_graph::Taggable,
+    props::PropValue,
+};
+
+/// Summary statistics of a document.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+#[cfg_attr(feature = "web", tsify(into_wasm_abi))]
+pub struct DocumentStats {
+    /// The number of components of each type, keyed by the component's tag name.
+    /// Internal components (whose names begin with `_`) are not counted.
+    pub component_counts: BTreeMap<String, usize>,
+    /// The number of `<answer>` components.
+    pub num_answers: usize,
+    /// The total number of points available from answers. Each answer is worth one point.
+    pub total_points: f64,
+    /// An estimate of the number of elements a reader can interact with,
+    /// i.e., the rendered components that have actions.
+    pub num_interactive_elements: usize,
+    /// The number of words of text in the rendered document, for estimating reading time.
+    pub word_count: usize,
+}
+
+impl DocumentRenderer {
+    /// Compute summary statistics of the document.
+    ///
+    /// The document is rendered in order to determine which components are displayed
+    /// and the text they display.
+    pub fn document_stats(&mut self, document_model: &DocumentModel) -> DocumentStats {
+        let flat_dast = self.render_flat_dast(document_model);
+
+        let mut component_counts = BTreeMap::new();
+        let mut num_interactive_elements = 0;
+        for component_idx in document_model.get_component_indices() {
+            let component = document_model.get_component(component_idx);
+            let component_type = component.get_component_type();
+            if component_type.starts_with('_') {
+                continue;
+            }
+            *component_counts
+                .entry(component_type.to_string())
+                .or_insert(0) += 1;
+
+            if self.in_render_tree.get_tag(&component_idx.as_graph_node()) == Some(&true)
+                && !component.get_action_names().is_empty()
+            {
+                num_interactive_elements += 1;
+            }
+        }
+
+        let num_answers = component_counts.get("answer").copied().unwrap_or(0);
+
+        DocumentStats {
+            component_counts,
+            num_answers,
+            total_points: num_answers as f64,
+            num_interactive_elements,
+            word_count: extract_text(&flat_dast).split_whitespace().count(),
+        }
+    }
+}
+
+/// Extract the text displayed by `flat_dast`, in document order.
+///
+/// Text comes from the string children of elements and from any string-valued props that are rendered,
+/// such as the `value` of a `<text>`. Pieces of text are separated by spaces, so the result
+/// is suitable for counting words but does not preserve formatting.
+pub fn extract_text(flat_dast: &FlatDastRoot) -> String {
+    let mut pieces = Vec::new();
+    extract_text_from_content(flat_dast, &flat_dast.children, &mut pieces);
+    pieces.join(" ")
+}
+
+fn extract_text_from_content<'a>(
+    flat_dast: &'a FlatDastRoot,
+    content: &'a [FlatDastElementContent],
+    pieces: &mut Vec<&'a str>,
+) {
+    for child in content {
+        match child {
+            FlatDastElementContent::Text(text) => pieces.push(text),
+            FlatDastElementContent::Element(element_ref) => {
+                let element = &flat_dast.elements[element_ref.id];
+                if let Some(props) = &element.data.props {
+                    for prop in props.0.iter() {
+                        match &prop.value {
+                            ForRenderPropValueOrContent::PropValue(PropValue::String(text)) => {
+                                pieces.push(text)
+                            }
+                            ForRenderPropValueOrContent::Content(content) => {
+                                extract_text_from_content(flat_dast, content, pieces)
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                extract_text_from_content(flat_dast, &element.children, pieces);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "document_stats.test.rs"]
+mod test;
@@ -0,0 +1,52 @@
+use crate::{Core, test_utils::dast_root_no_position};
+
+use super::*;
+
+fn document_stats(doenetml: &str) -> DocumentStats {
+    let dast_root = dast_root_no_position(doenetml);
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    core.document_stats()
+}
+
+#[test]
+fn counts_components_by_type() {
+    let stats = document_stats(r#"<p><text>a</text><text>b</text></p><p>c</p>"#);
+    assert_eq!(stats.component_counts.get("text"), Some(&2));
+    assert_eq!(stats.component_counts.get("p"), Some(&2));
+    assert_eq!(stats.component_counts.get("document"), Some(&1));
+    assert!(
+        stats
+            .component_counts
+            .keys()
+            .all(|name| !name.starts_with('_'))
+    );
+}
+
+#[test]
+fn counts_words_of_rendered_text() {
+    let stats = document_stats(
+        r#"<p>The quick brown <text>fox jumps</text></p><text hide="true">not shown</text>"#,
+    );
+    assert_eq!(stats.word_count, 5);
+}
+
+#[test]
+fn counts_words_of_referenced_values() {
+    let stats = document_stats(r#"<text name="t">two words</text><p>$t again</p>"#);
+    assert_eq!(stats.word_count, 5);
+}
+
+#[test]
+fn counts_rendered_interactive_elements() {
+    let stats = document_stats(r#"<textInput /><textInput hide="true" /><p>Hi</p>"#);
+    assert_eq!(stats.component_counts.get("textInput"), Some(&2));
+    assert_eq!(stats.num_interactive_elements, 1);
+}
+
+#[test]
+fn documents_without_answers_have_no_points() {
+    let stats = document_stats(r#"<p>Hi</p>"#);
+    assert_eq!(stats.num_answers, 0);
+    assert_eq!(stats.total_points, 0.0);
+}
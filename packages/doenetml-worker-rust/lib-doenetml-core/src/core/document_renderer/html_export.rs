@@ -0,0 +1,158 @@
+//! Export of the rendered document as static HTML, e.g., to preview a document without a renderer.
+
+use crate::{
+    dast::{
+        FlatDastElement, FlatDastElementContent, FlatDastRoot, ForRenderPropValue,
+        ForRenderPropValueOrContent,
+    },
+    props::PropValue,
+};
+
+/// Render `flat_dast` as an HTML fragment that mirrors the structure of the render tree.
+///
+/// Each element becomes a custom element named after its component, e.g., `<doenet-text-input>`,
+/// and its rendered props are given as `data-` attributes, e.g., `data-credit-achieved="1"`.
+/// Props that are content are rendered inside `<doenet-prop name="...">` elements.
+/// An element without children displays its string-valued `text` or `value` prop, if any,
+/// so that, e.g., the value of a `<text>` is visible. Errors become `<doenet-error>` elements showing their message.
+///
+/// No renderer is attached, so the interactive elements of the export cannot be used.
+pub fn render_html(flat_dast: &FlatDastRoot) -> String {
+    let mut html = String::new();
+    render_content(flat_dast, &flat_dast.children, &mut html);
+    html
+}
+
+fn render_content(flat_dast: &FlatDastRoot, content: &[FlatDastElementContent], html: &mut String) {
+    for child in content {
+        match child {
+            FlatDastElementContent::Text(text) => html.push_str(&escape_html(text)),
+            FlatDastElementContent::Element(element_ref) => {
+                render_element(flat_dast, &flat_dast.elements[element_ref.id], html)
+            }
+        }
+    }
+}
+
+fn render_element(flat_dast: &FlatDastRoot, element: &FlatDastElement, html: &mut String) {
+    if element.name == "_error" {
+        html.push_str("<doenet-error");
+        if let Some(code) = element.data.code {
+            push_attribute(html, "data-code", code.as_str());
+        }
+        html.push('>');
+        html.push_str(&escape_html(
+            element.data.message.as_deref().unwrap_or_default(),
+        ));
+        html.push_str("</doenet-error>");
+        return;
+    }
+
+    let tag = format!("doenet-{}", to_kebab_case(&element.name));
+    let props = element
+        .data
+        .props
+        .as_ref()
+        .map(|props| props.0.as_slice())
+        .unwrap_or_default();
+
+    html.push('<');
+    html.push_str(&tag);
+    for prop in props {
+        if let ForRenderPropValueOrContent::PropValue(value) = &prop.value {
+            push_attribute(
+                html,
+                &format!("data-{}", to_kebab_case(prop.name)),
+                &prop_value_to_string(value),
+            );
+        }
+    }
+    html.push('>');
+
+    for prop in props {
+        if let ForRenderPropValueOrContent::Content(content) = &prop.value {
+            html.push_str("<doenet-prop");
+            push_attribute(html, "name", prop.name);
+            html.push('>');
+            render_content(flat_dast, content, html);
+            html.push_str("</doenet-prop>");
+        }
+    }
+
+    if element.children.is_empty() {
+        if let Some(text) = displayed_text(props) {
+            html.push_str(&escape_html(text));
+        }
+    } else {
+        render_content(flat_dast, &element.children, html);
+    }
+
+    html.push_str("</");
+    html.push_str(&tag);
+    html.push('>');
+}
+
+/// The text displayed by an element without children: its string-valued `text` or `value` prop.
+fn displayed_text(props: &[ForRenderPropValue]) -> Option<&str> {
+    ["text", "value"].iter().find_map(|name| {
+        props
+            .iter()
+            .find(|prop| prop.name == *name)
+            .and_then(|prop| match &prop.value {
+                ForRenderPropValueOrContent::PropValue(PropValue::String(text)) => {
+                    Some(text.as_str())
+                }
+                _ => None,
+            })
+    })
+}
+
+/// Strings are given as is; other values are given as JSON.
+fn prop_value_to_string(value: &PropValue) -> String {
+    match value {
+        PropValue::String(text) => text.to_string(),
+        _ => serde_json::to_string(value).unwrap_or_default(),
+    }
+}
+
+fn push_attribute(html: &mut String, name: &str, value: &str) {
+    html.push(' ');
+    html.push_str(name);
+    html.push_str("=\"");
+    html.push_str(&escape_html(value));
+    html.push('"');
+}
+
+/// Convert a camel case name such as `textInput` into a name such as `text-input`,
+/// as HTML element and attribute names are not case sensitive.
+fn to_kebab_case(name: &str) -> String {
+    let mut kebab = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_uppercase() {
+            kebab.push('-');
+            kebab.extend(c.to_lowercase());
+        } else {
+            kebab.push(c);
+        }
+    }
+    kebab
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+#[path = "html_export.test.rs"]
+mod test;
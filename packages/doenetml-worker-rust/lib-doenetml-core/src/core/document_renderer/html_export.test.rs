@@ -0,0 +1,33 @@
+use crate::{Core, test_utils::dast_root_no_position};
+
+use super::*;
+
+fn to_html(doenetml: &str) -> String {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(doenetml));
+    core.to_html()
+}
+
+#[test]
+fn elements_become_custom_elements() {
+    let html = to_html(r#"<p>Hello <text>world</text></p>"#);
+    assert!(html.starts_with("<doenet-document"));
+    assert!(html.contains(r#"Hello <doenet-text data-value="world">world</doenet-text>"#));
+}
+
+#[test]
+fn errors_show_their_message() {
+    let html = to_html(r#"<p>$t</p>"#);
+    assert!(html.contains(r#"<doenet-error data-code="DML2001">"#));
+    assert!(html.contains("No referent"));
+}
+
+#[test]
+fn names_and_text_are_escaped() {
+    assert_eq!(to_kebab_case("textInput"), "text-input");
+    assert_eq!(to_kebab_case("creditAchieved"), "credit-achieved");
+    assert_eq!(
+        escape_html(r#"<b class="x">Tom & Jerry's</b>"#),
+        "&lt;b class=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/b&gt;"
+    );
+}
@@ -0,0 +1,83 @@
+//! Resolution of the keyboard shortcuts declared by components, so that every renderer binds the same keys.
+
+use std::collections::HashMap;
+
+use super::DocumentRenderer;
+use crate::{
+    components::{
+        ComponentNode, ComponentOnAction,
+        prelude::{ComponentIdx, KeyboardShortcut, ShortcutScope},
+    },
+    core::document_model::DocumentModel,
+    dast::{DastWarning, DiagnosticCode, flat_dast::ErrorType},
+};
+
+impl DocumentRenderer {
+    /// Determine the keyboard shortcuts of each component, storing them in `self.keyboard_shortcuts`.
+    ///
+    /// Shortcuts scoped to a focused component never conflict with the shortcuts of other components.
+    /// A document-wide shortcut, however, conflicts with any document-wide shortcut with the same keys.
+    /// The component that comes first in the document keeps the shortcut; it is disabled on the others,
+    /// and a warning is returned for each of them. Likewise, if a component declares the same keys twice
+    /// in the same scope, only its first shortcut is kept.
+    ///
+    /// Components that are hidden by role or in a division that is not yet available are not rendered,
+    /// so they neither get shortcuts nor take shortcuts from other components.
+    pub(super) fn resolve_keyboard_shortcuts(
+        &mut self,
+        document_model: &DocumentModel,
+    ) -> Vec<DastWarning> {
+        let mut document_shortcuts: Vec<(KeyboardShortcut, ComponentIdx)> = Vec::new();
+        let mut warnings = Vec::new();
+
+        self.keyboard_shortcuts = document_model
+            .get_component_indices()
+            .filter(|&component_idx| {
+                !document_model.is_hidden_by_role(component_idx)
+                    && !document_model.is_in_unavailable_division(component_idx)
+            })
+            .filter_map(|component_idx| {
+                let component = document_model.get_component(component_idx);
+                let mut resolved: Vec<KeyboardShortcut> = Vec::new();
+
+                for shortcut in component.get_keyboard_shortcuts() {
+                    let duplicate = resolved.iter().any(|other| {
+                        other.scope == shortcut.scope && other.has_same_keys(&shortcut)
+                    });
+                    if duplicate {
+                        continue;
+                    }
+                    if shortcut.scope == ShortcutScope::Document {
+                        if let Some((_, owner_idx)) = document_shortcuts
+                            .iter()
+                            .find(|(other, _)| other.has_same_keys(&shortcut))
+                        {
+                            let owner = document_model.get_component(*owner_idx);
+                            warnings.push(DastWarning {
+                                message: format!(
+                                    "The keyboard shortcut `{}` of `<{}>` is already used by an earlier `<{}>`, so it was disabled",
+                                    shortcut.keys,
+                                    component.get_component_type(),
+                                    owner.get_component_type()
+                                ),
+                                severity: ErrorType::Warning,
+                                code: Some(DiagnosticCode::KeyboardShortcutConflict),
+                                suggestion: None,
+                                count: 1,
+                                position: component.common.position.clone(),
+                                source_doc: None,
+                            });
+                            continue;
+                        }
+                        document_shortcuts.push((shortcut.clone(), component_idx));
+                    }
+                    resolved.push(shortcut);
+                }
+
+                (!resolved.is_empty()).then_some((component_idx, resolved))
+            })
+            .collect::<HashMap<_, _>>();
+
+        warnings
+    }
+}
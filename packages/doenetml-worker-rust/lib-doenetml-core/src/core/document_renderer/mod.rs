@@ -1,8 +1,17 @@
 //! Functions and objects related to _rendering_ the component tree. A _rendered_ tree is one
 //! that will be sent to the UI for display.
 
+mod dead_content;
 #[allow(clippy::module_inception)]
 mod document_renderer;
+mod document_stats;
+mod html_export;
+mod keyboard_shortcuts;
+mod render_precision;
 mod to_flat_dast;
+mod validate;
 
 pub use document_renderer::*;
+pub use document_stats::{DocumentStats, extract_text};
+pub use html_export::render_html;
+pub use render_precision::{NumberPrecision, RenderPrecision};
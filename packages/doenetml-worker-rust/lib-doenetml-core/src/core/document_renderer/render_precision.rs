@@ -0,0 +1,82 @@
+//! The precision of the numbers sent to the renderers.
+//!
+//! Numbers are calculated with full `f64` precision. Renderers that draw coordinates on screen
+//! rarely need that precision, so a host may ask for rendered numbers to be rounded to `f32` precision,
+//! which shortens their serialization, e.g., `0.1` rather than `0.30000000000000004` for `0.1 + 0.2`.
+//! Only the rendered values are rounded; the values used in calculations are unchanged.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use crate::props::PropValue;
+
+/// The precision of a rendered number.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub enum NumberPrecision {
+    /// Full `f64` precision.
+    #[default]
+    Double,
+    /// Rounded to the nearest `f32`, serialized with the fewest digits that identify it.
+    Single,
+}
+
+/// The precision of the numbers in the rendered props of each type of component.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct RenderPrecision {
+    /// The precision for component types not in `by_component_type`.
+    pub default: NumberPrecision,
+    /// The precision for particular component types, keyed by their tag name, e.g., `point`.
+    pub by_component_type: HashMap<String, NumberPrecision>,
+}
+
+impl RenderPrecision {
+    /// The precision of the rendered numbers of a component of type `component_type`.
+    pub fn for_component_type(&self, component_type: &str) -> NumberPrecision {
+        self.by_component_type
+            .get(component_type)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+impl NumberPrecision {
+    /// Round the numbers in `value`, including those in nested `PropVec`s, to this precision.
+    pub fn round_prop_value(self, value: PropValue) -> PropValue {
+        match (self, value) {
+            (NumberPrecision::Double, value) => value,
+            (NumberPrecision::Single, PropValue::Number(number)) => {
+                PropValue::Number(round_to_single(number))
+            }
+            (NumberPrecision::Single, PropValue::PropVec(values)) => PropValue::PropVec(Rc::new(
+                values
+                    .iter()
+                    .map(|value| self.round_prop_value(value.clone()))
+                    .collect(),
+            )),
+            (NumberPrecision::Single, value) => value,
+        }
+    }
+}
+
+/// The `f64` with the fewest digits that rounds to the same `f32` as `number`,
+/// so that it serializes as compactly as the `f32` would.
+fn round_to_single(number: f64) -> f64 {
+    let single = number as f32;
+    if !single.is_finite() {
+        return single as f64;
+    }
+    // `f32`'s `Display` prints the fewest digits that identify it.
+    single.to_string().parse().unwrap_or(number)
+}
+
+#[cfg(test)]
+#[path = "render_precision.test.rs"]
+mod test;
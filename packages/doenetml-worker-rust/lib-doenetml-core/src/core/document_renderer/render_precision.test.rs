@@ -0,0 +1,59 @@
+use super::*;
+
+#[test]
+fn double_precision_leaves_numbers_unchanged() {
+    let value = PropValue::Number(0.1 + 0.2);
+    assert_eq!(
+        NumberPrecision::Double.round_prop_value(value.clone()),
+        value
+    );
+}
+
+#[test]
+fn single_precision_rounds_to_the_shortest_representation() {
+    let rounded = NumberPrecision::Single.round_prop_value(PropValue::Number(0.1 + 0.2));
+    assert_eq!(rounded, PropValue::Number(0.3));
+    assert_eq!(serde_json::to_string(&rounded).unwrap(), "0.3");
+
+    let rounded = NumberPrecision::Single.round_prop_value(PropValue::Number(f64::NAN));
+    assert!(matches!(rounded, PropValue::Number(number) if number.is_nan()));
+
+    // Numbers too large for an `f32` become infinite.
+    let rounded = NumberPrecision::Single.round_prop_value(PropValue::Number(1e300));
+    assert_eq!(rounded, PropValue::Number(f64::INFINITY));
+}
+
+#[test]
+fn single_precision_rounds_nested_vectors() {
+    let value = PropValue::PropVec(Rc::new(vec![
+        PropValue::PropVec(Rc::new(vec![
+            PropValue::Number(1.0 / 3.0),
+            PropValue::Number(2.0),
+        ])),
+        PropValue::Integer(3),
+    ]));
+    let rounded = PropValue::PropVec(Rc::new(vec![
+        PropValue::PropVec(Rc::new(vec![
+            PropValue::Number(0.33333334),
+            PropValue::Number(2.0),
+        ])),
+        PropValue::Integer(3),
+    ]));
+    assert_eq!(NumberPrecision::Single.round_prop_value(value), rounded);
+}
+
+#[test]
+fn precision_can_be_set_per_component_type() {
+    let precision = RenderPrecision {
+        default: NumberPrecision::Single,
+        by_component_type: HashMap::from([("slider".to_string(), NumberPrecision::Double)]),
+    };
+    assert_eq!(
+        precision.for_component_type("point"),
+        NumberPrecision::Single
+    );
+    assert_eq!(
+        precision.for_component_type("slider"),
+        NumberPrecision::Double
+    );
+}
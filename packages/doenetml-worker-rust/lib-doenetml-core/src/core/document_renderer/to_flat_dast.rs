@@ -1,23 +1,75 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use super::{super::graph_node::GraphNode, DocumentRenderer};
 use crate::{
     components::{
         ComponentActions, ComponentCommon, ComponentEnum, ComponentNode,
+        doenet::{html_block::HtmlBlockProps, side_by_side::Valign, table::Halign},
         prelude::{ComponentIdx, ElementData, FlatDastElement, FlatDastElementContent},
         types::PropPointer,
     },
     core::document_model::DocumentModel,
     dast::{
-        DastAttribute, DastText, DastTextRefElementContent, ElementRefAnnotation,
-        FlatDastElementUpdate, FlatDastRoot, ForRenderPropValue, ForRenderPropValueOrContent,
-        ForRenderProps, flat_dast::UntaggedContent,
+        DastAttribute, DastText, DastTextRefElementContent, DastWarning, DiagnosticCode,
+        ElementRefAnnotation, FlatDastElementUpdate, FlatDastRoot, ForRenderPropValue,
+        ForRenderPropValueOrContent, ForRenderProps,
+        diagnostics::{deduplicate_warnings, format_did_you_mean},
+        flat_dast::{ErrorType, UntaggedContent},
     },
+    general_prop::EnumAttributeValue,
     graph::directed_graph::Taggable,
-    props::{PropProfile, PropValue, PropValueType, cache::PropWithMeta},
-    state::types::content_refs::ContentRef,
+    props::{PropProfile, PropValue, PropValueType, cache::PropWithMeta, prop_type},
+    state::types::{
+        content_refs::ContentRef, division_type::DivisionType, list_marker::ListMarker,
+        math_expr::MathSimplify,
+    },
+    utils::closest_matches,
 };
 
+/// A warning if the attribute `attr_name` of the component `component_idx` is not a valid value of `T`.
+/// Only literal values are checked, since values from references are not known until they are calculated.
+fn invalid_enum_attribute_warning<T: EnumAttributeValue>(
+    document_model: &DocumentModel,
+    component_idx: ComponentIdx,
+    attr_name: &str,
+) -> Option<DastWarning> {
+    let value = document_model.get_literal_attribute_text(component_idx, attr_name)?;
+    if T::parse_attribute_value(&value).is_some() {
+        return None;
+    }
+    let component = document_model.get_component(component_idx);
+    let suggestion = format_did_you_mean(
+        &closest_matches(value.trim(), T::VALID_VALUES.iter().copied(), 3),
+        |option| format!("`{option}`"),
+    )
+    .unwrap_or_else(|| {
+        format!(
+            "Valid values are {}",
+            T::VALID_VALUES
+                .iter()
+                .map(|option| format!("`{option}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    });
+    Some(DastWarning {
+        message: format!(
+            "`{}` is not a valid value of the `{attr_name}` attribute of `<{}>`, so the default was used",
+            value.trim(),
+            component.get_component_type()
+        ),
+        severity: ErrorType::Warning,
+        code: Some(DiagnosticCode::InvalidAttributeValue),
+        suggestion: Some(suggestion),
+        count: 1,
+        // Point to the attribute itself, falling back to the component if its position is not known.
+        position: document_model
+            .get_attribute_position(component_idx, attr_name)
+            .or(component.common.position),
+        source_doc: None,
+    })
+}
+
 /// When to include a prop in the serialized rendered props.
 pub enum SerializeCondition {
     /// Always include the prop in the serialized rendered props.
@@ -34,38 +86,217 @@ impl DocumentRenderer {
     /// Include warnings as a separate vector (errors are embedded in the tree as elements).
     pub fn render_flat_dast(&mut self, document_model: &DocumentModel) -> FlatDastRoot {
         self.mark_component_in_render_tree(ComponentIdx::new(0), document_model);
+        let shortcut_conflicts = self.resolve_keyboard_shortcuts(document_model);
         let elements = document_model
             .get_component_indices()
-            .map(|comp_idx| self.component_to_flat_dast(comp_idx, document_model))
+            .map(|comp_idx| {
+                if document_model.is_hidden_by_role(comp_idx)
+                    || document_model.is_in_unavailable_division(comp_idx)
+                {
+                    // Content restricted to other roles or not yet released is omitted entirely,
+                    // not just hidden, so that, e.g., instructor notes never reach students.
+                    self.component_to_flat_dast_no_children(comp_idx, document_model)
+                } else {
+                    self.component_to_flat_dast(comp_idx, document_model)
+                }
+            })
             .collect();
 
         FlatDastRoot {
             children: vec![FlatDastElementContent::new_original_element(0)],
             elements,
-            warnings: vec![],
+            warnings: self.collect_warnings(document_model, shortcut_conflicts),
             position: None,
         }
     }
 
-    fn mark_component_in_render_tree(
+    /// Collect the non-fatal errors of the document as a deduplicated list of warnings.
+    /// Errors with severity `Error` are excluded, since they are rendered in the tree as elements.
+    ///
+    /// Elements that are not DoenetML components are passed through unchanged, so they are not errors.
+    /// However, a warning is included for such an element if it is not an HTML tag, since it may be a component
+    /// from a newer version of DoenetML, or if its name closely resembles a component type, since it is likely misspelled.
+    ///
+    /// The `shortcut_conflicts` found when resolving keyboard shortcuts are included as well.
+    fn collect_warnings(
+        &self,
+        document_model: &DocumentModel,
+        shortcut_conflicts: Vec<DastWarning>,
+    ) -> Vec<DastWarning> {
+        let component_types = ComponentEnum::authored_component_types();
+        let warnings = document_model
+            .get_component_indices()
+            .filter_map(|component_idx| {
+                let component = document_model.get_component(component_idx);
+                match component.variant {
+                    ComponentEnum::_Error(error) if error.error_type != ErrorType::Error => {
+                        Some(DastWarning {
+                            message: error.message,
+                            severity: error.error_type,
+                            code: error.code,
+                            suggestion: error.suggestion,
+                            count: 1,
+                            position: component.common.position,
+                            source_doc: None,
+                        })
+                    }
+                    ComponentEnum::_External(external) => {
+                        let suggestion = format_did_you_mean(
+                            &closest_matches(
+                                &external.name,
+                                component_types.iter().map(String::as_str),
+                                3,
+                            ),
+                            |name| format!("`<{name}>`"),
+                        );
+                        // HTML tags are expected to be left unchanged, unless they resemble a component type.
+                        if suggestion.is_none() && !external.is_unknown() {
+                            return None;
+                        }
+                        Some(DastWarning {
+                            message: external.unknown_message(),
+                            severity: ErrorType::Warning,
+                            code: Some(DiagnosticCode::UnknownComponentType),
+                            suggestion,
+                            count: 1,
+                            position: component.common.position,
+                            source_doc: None,
+                        })
+                    }
+                    ComponentEnum::Division(_) => invalid_enum_attribute_warning::<DivisionType>(
+                        document_model,
+                        component_idx,
+                        "type",
+                    ),
+                    ComponentEnum::Math(_) => invalid_enum_attribute_warning::<MathSimplify>(
+                        document_model,
+                        component_idx,
+                        "simplify",
+                    ),
+                    ComponentEnum::SideBySide(_) => invalid_enum_attribute_warning::<Valign>(
+                        document_model,
+                        component_idx,
+                        "valign",
+                    ),
+                    ComponentEnum::Row(_) | ComponentEnum::Cell(_) => {
+                        invalid_enum_attribute_warning::<Halign>(
+                            document_model,
+                            component_idx,
+                            "halign",
+                        )
+                    }
+                    ComponentEnum::Ol(_) | ComponentEnum::Ul(_) => {
+                        invalid_enum_attribute_warning::<ListMarker>(
+                            document_model,
+                            component_idx,
+                            "marker",
+                        )
+                    }
+                    ComponentEnum::HtmlBlock(_) => {
+                        let prop_node = document_model.prop_pointer_to_prop_node(PropPointer {
+                            component_idx,
+                            local_prop_idx: HtmlBlockProps::RemovedHtml.local_idx(),
+                        });
+                        let removed_html: prop_type::PropVec = document_model
+                            .get_prop_untracked(prop_node, self.for_render_query_node)
+                            .value
+                            .try_into()
+                            .unwrap();
+                        if removed_html.is_empty() {
+                            return None;
+                        }
+                        let removed = removed_html
+                            .iter()
+                            .filter_map(|removed| match removed {
+                                PropValue::String(removed) if removed.starts_with('<') => {
+                                    Some(format!("`{removed}`"))
+                                }
+                                PropValue::String(removed) => {
+                                    Some(format!("`{removed}` attribute"))
+                                }
+                                _ => None,
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        Some(DastWarning {
+                            message: format!(
+                                "Disallowed HTML was removed from `<htmlBlock>`: {removed}"
+                            ),
+                            severity: ErrorType::Warning,
+                            code: Some(DiagnosticCode::DisallowedHtmlRemoved),
+                            suggestion: None,
+                            count: 1,
+                            position: component.common.position,
+                            source_doc: None,
+                        })
+                    }
+                    _ => None,
+                }
+            });
+
+        let incompatible_content_warnings = document_model
+            .get_incompatible_content()
+            .into_iter()
+            .map(|incompatible| {
+                let component = document_model.get_component(incompatible.component_idx);
+                let container = document_model.get_component(incompatible.container_idx);
+                let location = match incompatible.attribute_name {
+                    Some(attribute_name) => format!(
+                        "in the `{attribute_name}` attribute of `<{}>`",
+                        container.get_component_type()
+                    ),
+                    None => format!("as a child of `<{}>`", container.get_component_type()),
+                };
+                DastWarning {
+                    message: format!(
+                        "`<{}>` cannot be used {location}, so it was ignored",
+                        component.get_component_type()
+                    ),
+                    severity: ErrorType::Warning,
+                    code: Some(DiagnosticCode::IncompatibleContent),
+                    suggestion: None,
+                    count: 1,
+                    position: component.common.position,
+                    source_doc: None,
+                }
+            });
+
+        deduplicate_warnings(
+            warnings
+                .chain(incompatible_content_warnings)
+                .chain(shortcut_conflicts),
+        )
+    }
+
+    /// Mark `component_idx` and the components that it renders as being in the render tree.
+    ///
+    /// Return the components that were newly marked.
+    pub(super) fn mark_component_in_render_tree(
         &mut self,
         component_idx: ComponentIdx,
         document_model: &DocumentModel,
-    ) {
+    ) -> Vec<ComponentIdx> {
         let component_node = component_idx.as_graph_node();
         if let Some(true) = self.in_render_tree.get_tag(&component_node) {
-            return;
+            return Vec::new();
+        }
+        if document_model.is_hidden_by_role(component_idx) {
+            return Vec::new();
         }
         self.in_render_tree.set_tag(component_node, true);
 
+        let mut newly_marked = vec![component_idx];
         for (child_node, _) in self.get_rendered_child_nodes(component_idx, document_model) {
             if let GraphNode::Component(_) = child_node {
-                self.mark_component_in_render_tree(child_node.into(), document_model);
+                newly_marked
+                    .extend(self.mark_component_in_render_tree(child_node.into(), document_model));
             }
         }
         for child_node in self.get_children_from_for_render_props(component_idx, document_model) {
-            self.mark_component_in_render_tree(child_node.into(), document_model);
+            newly_marked
+                .extend(self.mark_component_in_render_tree(child_node.into(), document_model));
         }
+        newly_marked
     }
 
     /// Convert a component to a `FlatDastElement`.
@@ -212,10 +443,19 @@ impl DocumentRenderer {
             });
 
         let component = document_model.get_component(component_idx);
-        let message = if let ComponentEnum::_Error(error) = &component.variant {
-            Some(error.message.clone())
-        } else {
-            None
+        let (message, code, suggestion) = match &component.variant {
+            ComponentEnum::_Error(error) => (
+                Some(error.message.clone()),
+                error.code,
+                error.suggestion.clone(),
+            ),
+            // Unknown components are marked so that renderers without a renderer for them can show a placeholder.
+            ComponentEnum::_External(external) if external.is_unknown() => (
+                Some(external.unknown_message()),
+                Some(DiagnosticCode::UnknownComponentType),
+                None,
+            ),
+            _ => (None, None, None),
         };
 
         // Only the unrecognized attributes remain ont he actual element. Convert them to a flat dast.
@@ -250,7 +490,7 @@ impl DocumentRenderer {
                     },
                 )
             })
-            .collect::<HashMap<_, _>>();
+            .collect::<BTreeMap<_, _>>();
 
         FlatDastElement {
             name: component.get_component_type().to_string(),
@@ -269,6 +509,10 @@ impl DocumentRenderer {
                 ),
                 props: rendered_props,
                 message,
+                code,
+                suggestion,
+                keyboard_shortcuts: self.keyboard_shortcuts.get(&component_idx).cloned(),
+                capabilities: Some(component.capabilities()).filter(|c| !c.is_default()),
             },
             position: component.common.position,
             source_doc: None,
@@ -278,6 +522,7 @@ impl DocumentRenderer {
     /// Calculate the values of the `for_render` props of `component_idx`.
     /// If `only_changed_props` is `true`, then calculate only the props that have changed
     /// since the last time they were calculated for rendering.
+    /// Numbers are rounded to the render precision of the component's type.
     ///
     /// Return: a `ForRenderProps` containing a `ForRenderPropValue` for each `for_render` prop that changed.
     fn get_rendered_props(
@@ -287,6 +532,11 @@ impl DocumentRenderer {
         document_model: &DocumentModel,
     ) -> ForRenderProps {
         let rendered_prop_pointers = document_model.get_for_render_prop_pointers(component_idx);
+        let number_precision = document_model.get_render_precision().for_component_type(
+            document_model
+                .get_component(component_idx)
+                .get_component_type(),
+        );
 
         let rendered_prop_value_vec = rendered_prop_pointers
             .filter_map(|prop_pointer| {
@@ -294,7 +544,7 @@ impl DocumentRenderer {
                 let should_serialize =
                     prop.changed || matches!(serialize_condition, SerializeCondition::Always);
                 if should_serialize {
-                    let prop_value = prop.value;
+                    let prop_value = number_precision.round_prop_value(prop.value);
                     let prop_name = document_model.get_prop_name(prop_pointer);
                     Some(self.prepare_prop_value_for_render(prop_name, prop_value, document_model))
                 } else {
@@ -350,13 +600,16 @@ impl DocumentRenderer {
         ForRenderPropValue { name, value }
     }
 
-    /// Output updates for any elements with changed for_render props
+    /// Output updates for any elements with changed for_render props,
+    /// and mark the element that should receive focus, if any.
     pub fn get_flat_dast_updates(
         &mut self,
         changed_components: Vec<ComponentIdx>,
         document_model: &DocumentModel,
-    ) -> HashMap<ComponentIdx, FlatDastElementUpdate> {
-        let mut flat_dast_updates: HashMap<ComponentIdx, FlatDastElementUpdate> = HashMap::new();
+    ) -> BTreeMap<ComponentIdx, FlatDastElementUpdate> {
+        let mut flat_dast_updates: BTreeMap<ComponentIdx, FlatDastElementUpdate> = BTreeMap::new();
+
+        let mut newly_rendered = Vec::new();
 
         for component_idx in changed_components {
             let component_node = component_idx.as_graph_node();
@@ -367,21 +620,98 @@ impl DocumentRenderer {
                     document_model,
                 );
 
-                if !rendered_props.is_empty() {
+                // If the rendered children changed, send the new children
+                // and render any components that were not rendered before.
+                let new_children = self
+                    .get_changed_rendered_children(component_idx, document_model)
+                    .inspect(|children| {
+                        for child in children.iter() {
+                            if let FlatDastElementContent::Element(element) = child {
+                                newly_rendered.extend(self.mark_component_in_render_tree(
+                                    ComponentIdx::new(element.id),
+                                    document_model,
+                                ));
+                            }
+                        }
+                    });
+
+                if !rendered_props.is_empty() || new_children.is_some() {
                     flat_dast_updates.insert(
                         component_idx,
                         FlatDastElementUpdate {
-                            new_children: None,
-                            changed_state: Some(rendered_props),
+                            new_children,
+                            changed_state: (!rendered_props.is_empty()).then_some(rendered_props),
+                            focus: false,
                         },
                     );
                 }
             }
         }
 
+        // Components that are newly rendered have not been sent with their props or children.
+        for component_idx in newly_rendered {
+            let element = self.component_to_flat_dast(component_idx, document_model);
+            flat_dast_updates.insert(
+                component_idx,
+                FlatDastElementUpdate {
+                    new_children: Some(element.children),
+                    changed_state: element.data.props,
+                    focus: false,
+                },
+            );
+        }
+
+        // Deliver a pending focus request along with the updates, provided its component is rendered.
+        if let Some(component_idx) = document_model.take_focus_request().filter(|component_idx| {
+            self.in_render_tree.get_tag(&component_idx.as_graph_node()) == Some(&true)
+        }) {
+            flat_dast_updates.entry(component_idx).or_default().focus = true;
+        }
+
         flat_dast_updates
     }
 
+    /// If the rendered children of `component_idx` changed since they were last rendered,
+    /// return them as flat dast content.
+    fn get_changed_rendered_children(
+        &mut self,
+        component_idx: ComponentIdx,
+        document_model: &DocumentModel,
+    ) -> Option<Vec<FlatDastElementContent>> {
+        let (_, local_prop_idx) = document_model
+            .get_provided_profiles(component_idx)
+            .into_iter()
+            .find(|(profile, _)| *profile == PropProfile::RenderedChildren)?;
+        let prop = self.get_prop_for_render(
+            PropPointer {
+                component_idx,
+                local_prop_idx,
+            },
+            document_model,
+        );
+        if !prop.changed {
+            return None;
+        }
+        match prop.value {
+            PropValue::AnnotatedContentRefs(content_refs) => Some(
+                content_refs
+                    .iter()
+                    .map(|(content_ref, annotation)| match content_ref {
+                        ContentRef::Component(idx) => {
+                            FlatDastElementContent::new_element(idx.as_usize(), *annotation)
+                        }
+                        ContentRef::String(_) => FlatDastElementContent::Text(
+                            document_model.get_string_value((*content_ref).into()),
+                        ),
+                    })
+                    .collect(),
+            ),
+            value => unreachable!(
+                "RenderedChildren prop must return AnnotatedContentRefs, found {value:?}"
+            ),
+        }
+    }
+
     /// Get the value of a prop for rendering. If the prop is stale or not resolved,
     /// this function will resolve the prop, calculate all its dependencies, and then
     /// return the result of `PropUpdaterUntyped::untyped_calculate` applied to those dependencies.
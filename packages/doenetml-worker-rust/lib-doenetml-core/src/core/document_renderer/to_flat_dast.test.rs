@@ -3,8 +3,8 @@ use std::rc::Rc;
 use crate::{
     Core,
     components::{
-        ComponentNode,
-        prelude::FlatDastElementContent,
+        ComponentEnum, ComponentNode,
+        prelude::{ComponentCapabilities, FlatDastElementContent},
         types::{ComponentIdx, StringIdx},
     },
     graph::directed_graph::Taggable,
@@ -112,3 +112,53 @@ fn test_extending_nodes_get_marked_as_duplicates() {
         &FlatDastElementContent::new_original_element(4)
     );
 }
+
+#[test]
+fn test_capabilities_are_sent_only_when_not_the_default() {
+    let dast_root = dast_root_no_position(r#"<text>hi</text><textInput /><graph />"#);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    let flat_root = core.to_flat_dast();
+
+    let text = &flat_root.elements[1];
+    let text_input = &flat_root.elements[2];
+    let graph = &flat_root.elements[3];
+
+    assert_eq!(text.data.capabilities, None);
+    assert_eq!(
+        text_input.data.capabilities,
+        Some(ComponentCapabilities {
+            needs_keyboard: true,
+            ..ComponentCapabilities::DEFAULT
+        })
+    );
+    assert_eq!(
+        graph.data.capabilities,
+        Some(ComponentCapabilities {
+            needs_pointer_events: true,
+            supports_ssr: false,
+            ..ComponentCapabilities::DEFAULT
+        })
+    );
+}
+
+#[test]
+fn test_capabilities_of_all_component_types_are_listed() {
+    let capabilities = ComponentEnum::authored_component_capabilities();
+
+    assert_eq!(
+        capabilities
+            .iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>(),
+        ComponentEnum::authored_component_types()
+            .iter()
+            .collect::<Vec<_>>()
+    );
+    let (_, applet) = capabilities
+        .iter()
+        .find(|(name, _)| name == "embeddedApplet")
+        .unwrap();
+    assert!(!applet.supports_print);
+}
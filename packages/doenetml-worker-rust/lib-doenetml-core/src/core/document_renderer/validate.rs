@@ -0,0 +1,61 @@
+//! Validation of a document, collecting every diagnostic an author should see before publishing it.
+
+use super::DocumentRenderer;
+use crate::{
+    components::ComponentEnum,
+    core::document_model::DocumentModel,
+    dast::{DastWarning, flat_dast::ErrorType},
+};
+
+impl DocumentRenderer {
+    /// Collect the diagnostics of the document, sorted by their position in the source:
+    /// - the errors that replaced content that could not be created, e.g., a reference without a referent,
+    /// - the warnings reported when rendering the document,
+    /// - the content that is never rendered, as informational warnings.
+    ///
+    /// Unlike the `_error` elements of the rendered document, errors are reported even if they are not rendered.
+    pub fn validate(&mut self, document_model: &DocumentModel) -> Vec<DastWarning> {
+        let warnings = self.render_flat_dast(document_model).warnings;
+
+        let errors = document_model
+            .get_component_indices()
+            .filter_map(|component_idx| {
+                let component = document_model.get_component(component_idx);
+                match component.variant {
+                    ComponentEnum::_Error(error) if error.error_type == ErrorType::Error => {
+                        Some(DastWarning {
+                            message: error.message,
+                            severity: ErrorType::Error,
+                            code: error.code,
+                            suggestion: error.suggestion,
+                            count: 1,
+                            position: component.common.position,
+                            source_doc: None,
+                        })
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut diagnostics = errors
+            .into_iter()
+            .chain(warnings)
+            .chain(self.find_dead_content(document_model))
+            .collect::<Vec<_>>();
+        // Diagnostics without a position are listed last.
+        diagnostics.sort_by_key(|diagnostic| {
+            diagnostic
+                .position
+                .as_ref()
+                .map_or((usize::MAX, usize::MAX), |position| {
+                    (position.start.line, position.start.column)
+                })
+        });
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+#[path = "validate.test.rs"]
+mod test;
@@ -0,0 +1,50 @@
+use crate::{
+    Core,
+    dast::{DiagnosticCode, flat_dast::ErrorType},
+    test_utils::{dast_root, dast_root_no_position},
+};
+
+fn validate(doenetml: &str) -> Vec<(ErrorType, Option<DiagnosticCode>)> {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(doenetml));
+
+    core.validate()
+        .into_iter()
+        .map(|diagnostic| (diagnostic.severity, diagnostic.code))
+        .collect()
+}
+
+#[test]
+fn valid_document_has_no_diagnostics() {
+    assert!(validate(r#"<p>Hello <text>world</text></p>"#).is_empty());
+}
+
+#[test]
+fn warnings_and_dead_content_are_reported() {
+    assert_eq!(
+        validate(r#"<p>$t</p><text hide="true">secret</text>"#),
+        vec![
+            (ErrorType::Warning, Some(DiagnosticCode::NoReferent)),
+            (ErrorType::Info, Some(DiagnosticCode::AlwaysHidden)),
+        ]
+    );
+}
+
+#[test]
+fn errors_are_reported() {
+    let diagnostics = validate(r#"<p><a></p>"#);
+    assert_eq!(diagnostics[0].0, ErrorType::Error);
+}
+
+#[test]
+fn diagnostics_are_sorted_by_position() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root("<text hide=\"true\">a</text>\n<p>$t</p>"));
+
+    let lines = core
+        .validate()
+        .into_iter()
+        .map(|diagnostic| diagnostic.position.unwrap().start.line)
+        .collect::<Vec<_>>();
+    assert_eq!(lines, vec![1, 2]);
+}
@@ -13,7 +13,8 @@ use crate::{
         Component, ComponentAttributes, ComponentCommon,
         types::{ComponentIdx, PropDefinitionIdx, PropPointer},
     },
-    dast::ElementRefAnnotation,
+    dast::{ElementRefAnnotation, Position as DastPosition},
+    graph::directed_graph::Taggable,
     graph_node::{GraphNode, GraphNodeLookup, StructureGraph},
     props::{PropDefinition, PropProfile, StringCache, cache::PropWithMeta},
 };
@@ -41,6 +42,10 @@ pub struct DocumentStructure {
     /// Stores whether a particular virtual node was created to house the children coming from another component
     /// because it was `extend`ing another component.
     pub children_came_from_extending_marker: GraphNodeLookup<bool>,
+    /// The component that each component extends, if any.
+    extended_from: TiVec<ComponentIdx, Option<ComponentIdx>>,
+    /// The position in the DoenetML source of each attribute that was specified, keyed by its attribute node.
+    attribute_positions: GraphNodeLookup<DastPosition>,
 }
 
 impl DocumentStructure {
@@ -53,6 +58,8 @@ impl DocumentStructure {
             virtual_node_count: 0,
             prop_definitions: TiVec::new(),
             children_came_from_extending_marker: GraphNodeLookup::new(),
+            extended_from: TiVec::new(),
+            attribute_positions: GraphNodeLookup::new(),
         }
     }
 
@@ -68,6 +75,8 @@ impl DocumentStructure {
         self.virtual_node_count = builder.virtual_node_count;
         self.prop_definitions = builder.props;
         self.children_came_from_extending_marker = builder.children_came_from_extending_marker;
+        self.extended_from = builder.extended_from;
+        self.attribute_positions = builder.attribute_positions;
     }
 
     /// Add an edge to the structure graph.
@@ -124,6 +133,18 @@ impl DocumentStructure {
             })
     }
 
+    /// Get the position in the DoenetML source of the attribute `attr_name` of the specified component.
+    /// If the attribute was not specified but is extended from another component, the position
+    /// is that of the attribute of the other component, since that is where its value came from.
+    pub fn get_attr_position(
+        &self,
+        component_idx: ComponentIdx,
+        attr_name: &str,
+    ) -> Option<&DastPosition> {
+        let attr_node = self.get_attr_node(component_idx, attr_name)?;
+        self.attribute_positions.get_tag(&attr_node)
+    }
+
     /// Get a `PropDefinition` corresponding to `pointer`. The type of `pointer` can be `GraphNode`
     /// or `PropDefinitionIdx`.
     pub fn get_prop_definition<T: Into<GraphNode>>(&self, pointer: T) -> &PropDefinition {
@@ -160,6 +181,33 @@ impl DocumentStructure {
         &self.components[component_idx]
     }
 
+    /// Returns a vector of all the components that are _content_ descendants of a component, in document order.
+    /// Each component is listed once, even if it is reached more than once because of the `extend` attribute.
+    pub fn get_component_content_descendants(&self, component_idx: ComponentIdx) -> Vec<GraphNode> {
+        let mut visited = GraphNodeLookup::new();
+        let mut descendants = Vec::new();
+        let mut stack = vec![component_idx.as_graph_node()];
+        visited.set_tag(component_idx.as_graph_node(), true);
+
+        while let Some(node) = stack.pop() {
+            let children = self
+                .get_component_content_children(node)
+                .into_iter()
+                .filter(|child| matches!(child, GraphNode::Component(_)))
+                .filter(|child| visited.get_tag(child).is_none())
+                .collect::<Vec<_>>();
+            for &child in children.iter() {
+                visited.set_tag(child, true);
+            }
+            // Push in reverse so that the first child is visited first.
+            stack.extend(children.into_iter().rev());
+            if node != component_idx.as_graph_node() {
+                descendants.push(node);
+            }
+        }
+        descendants
+    }
+
     /// Returns a vector of all the _content_ children of a component. That is,
     /// any virtual nodes that are listed in the children are expanded down to their content.
     pub fn get_component_content_children<T: Into<GraphNode>>(&self, pointer: T) -> Vec<GraphNode> {
@@ -209,6 +257,23 @@ impl DocumentStructure {
         content_children
     }
 
+    /// Whether another component extends the component `component_idx`, e.g., via `$foo` or `extend="$foo"`,
+    /// so that its children are also children of the extending component.
+    pub fn is_component_extended(&self, component_idx: ComponentIdx) -> bool {
+        let children_virtual_node = self
+            .structure_graph
+            .get_component_children_virtual_node(component_idx);
+        self.structure_graph
+            .get_parents(children_virtual_node)
+            .iter()
+            .any(|node| self.children_came_from_extending_marker.get_tag(node) == Some(&true))
+    }
+
+    /// The component that `component_idx` extends, if any, e.g., `$foo` extends the component named `foo`.
+    pub fn get_extended_from(&self, component_idx: ComponentIdx) -> Option<ComponentIdx> {
+        self.extended_from.get(component_idx).copied().flatten()
+    }
+
     pub fn get_attribute_content_children<T: Into<GraphNode>>(
         &self,
         pointer: T,
@@ -236,6 +301,11 @@ impl DocumentStructure {
         (0..self.components.len()).map(ComponentIdx::new)
     }
 
+    /// Whether there is a component with index `component_idx`.
+    pub fn has_component(&self, component_idx: ComponentIdx) -> bool {
+        component_idx.as_usize() < self.components.len()
+    }
+
     /// Get the value of a string node. `origin` affects the metadata returned,
     /// and is used to track whether the string has changed since the last time its value
     /// was requested.
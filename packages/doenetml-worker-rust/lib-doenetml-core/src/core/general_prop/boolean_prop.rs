@@ -58,10 +58,7 @@ impl BooleanProp {
         BooleanProp {
             data_query: DataQuery::PickProp {
                 source: PickPropSource::Children,
-                prop_specifier: PropSpecifier::Matching(vec![
-                    PropProfile::String,
-                    PropProfile::Boolean,
-                ]),
+                prop_specifier: PropSpecifier::Matching(PropProfile::Boolean.coercible_profiles()),
             },
             default_value,
             propagate_came_from_default: true,
@@ -107,7 +104,7 @@ impl PropFromAttribute<bool> for BooleanProp {
         BooleanProp {
             data_query: DataQuery::Attribute {
                 attribute_name: attr_name,
-                match_profiles: vec![PropProfile::String, PropProfile::Boolean],
+                match_profiles: PropProfile::Boolean.coercible_profiles(),
             },
             default_value,
             propagate_came_from_default: true,
@@ -0,0 +1,50 @@
+use std::rc::Rc;
+
+use crate::{components::prelude::*, props::ContentFilter, utils::usize_to_integer};
+
+/// A prop that counts the children of the component that are of a given type, e.g., the items of a list.
+///
+/// Constructor:
+/// - `new(component_type)`: create a prop that counts the children of type `component_type`.
+#[derive(Debug)]
+pub struct ChildCountProp {
+    component_type: &'static str,
+}
+
+impl ChildCountProp {
+    /// Create a prop that counts the children of type `component_type`.
+    pub fn new(component_type: &'static str) -> Self {
+        ChildCountProp { component_type }
+    }
+}
+
+#[derive(TryFromDataQueryResults, Debug)]
+#[data_query(query_trait = DataQueries, pass_data = &ChildCountProp)]
+struct RequiredData {
+    children: PropView<prop_type::ContentRefs>,
+}
+
+impl DataQueries for RequiredData {
+    fn children_query(prop: &ChildCountProp) -> DataQuery {
+        DataQuery::ContentRefs {
+            container: PropSource::Me,
+            filter: Rc::new(ContentFilter::IsType(prop.component_type)),
+        }
+    }
+}
+
+impl PropUpdater for ChildCountProp {
+    type PropType = prop_type::Integer;
+
+    fn data_queries(&self) -> Vec<DataQuery> {
+        RequiredData::data_queries_vec(self)
+    }
+
+    fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+        let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+        PropCalcResult::Calculated(
+            usize_to_integer(required_data.children.value.as_slice().len())
+                .expect("Number of children must fit in an integer"),
+        )
+    }
+}
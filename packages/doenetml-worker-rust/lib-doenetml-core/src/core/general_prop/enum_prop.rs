@@ -2,8 +2,18 @@ use std::{marker::PhantomData, rc::Rc};
 
 use crate::{components::prelude::*, props::UpdaterObject};
 
-/// A prop that represents a value from an enum. The enum must implement `TryFrom<&str>`.
-/// If the `TryFrom` fails, the default value of the enum is used.
+/// An enum that is the value of an attribute, e.g., the `type` of a `<division>`.
+pub trait EnumAttributeValue: Sized {
+    /// The values of the attribute that are accepted, as listed to authors.
+    const VALID_VALUES: &'static [&'static str];
+
+    /// Parse the value of the attribute, returning `None` if `value` is not a valid value.
+    fn parse_attribute_value(value: &str) -> Option<Self>;
+}
+
+/// A prop that represents a value from an enum. The enum must implement `EnumAttributeValue`.
+/// If the attribute is not a valid value, the default value of the enum is used
+/// (and a warning is reported to the author).
 #[derive(Debug)]
 pub struct EnumProp<T: Default + Clone> {
     /// The data query that indicates how the dependencies of this prop will be created.
@@ -13,8 +23,7 @@ pub struct EnumProp<T: Default + Clone> {
 
 impl<T> From<EnumProp<T>> for UpdaterObject
 where
-    for<'a> T: From<&'a str>,
-    T: Default + Clone + TryFrom<PropValue> + std::fmt::Debug + 'static,
+    T: EnumAttributeValue + Default + Clone + TryFrom<PropValue> + std::fmt::Debug + 'static,
     PropValue: From<T>,
     <T as TryFrom<PropValue>>::Error: std::fmt::Debug + std::fmt::Display,
 {
@@ -48,8 +57,7 @@ impl DataQueries for RequiredData {
 
 impl<T> PropUpdater for EnumProp<T>
 where
-    for<'a> T: TryFrom<&'a str>,
-    T: Default + Clone + TryFrom<PropValue> + std::fmt::Debug,
+    T: EnumAttributeValue + Default + Clone + TryFrom<PropValue> + std::fmt::Debug,
     PropValue: From<T>,
     <T as TryFrom<PropValue>>::Error: std::fmt::Display + std::fmt::Debug,
 {
@@ -68,7 +76,7 @@ where
                     .iter()
                     .map(|s| (*s.value).clone())
                     .collect::<String>();
-                let ret = T::try_from(full_string.as_str()).unwrap_or_default();
+                let ret = T::parse_attribute_value(&full_string).unwrap_or_default();
                 PropCalcResult::Calculated(ret)
             }
         }
@@ -88,16 +88,12 @@ impl MathProp {
         MathProp {
             math_number_strings_data_query: DataQuery::PickProp {
                 source: PickPropSource::Children,
-                prop_specifier: PropSpecifier::Matching(vec![
-                    PropProfile::String,
-                    PropProfile::Math,
-                    PropProfile::Number,
-                ]),
+                prop_specifier: PropSpecifier::Matching(PropProfile::Math.coercible_profiles()),
             },
             data_query_with_fixed: DataQuery::PickProp {
                 source: PickPropSource::Children,
                 prop_specifier: PropSpecifier::MatchingPair(
-                    vec![PropProfile::String, PropProfile::Math, PropProfile::Number],
+                    PropProfile::Math.coercible_profiles(),
                     vec![PropProfile::Fixed],
                 ),
             },
@@ -155,14 +151,14 @@ impl PropFromAttribute<MathExpr> for MathProp {
         MathProp {
             math_number_strings_data_query: DataQuery::Attribute {
                 attribute_name: attr_name,
-                match_profiles: vec![PropProfile::String, PropProfile::Math, PropProfile::Number],
+                match_profiles: PropProfile::Math.coercible_profiles(),
             },
             data_query_with_fixed: DataQuery::PickProp {
                 source: PickPropSource::Attribute {
                     attribute_name: attr_name,
                 },
                 prop_specifier: PropSpecifier::MatchingPair(
-                    vec![PropProfile::String, PropProfile::Math, PropProfile::Number],
+                    PropProfile::Math.coercible_profiles(),
                     vec![PropProfile::Fixed],
                 ),
             },
@@ -0,0 +1,80 @@
+use std::rc::Rc;
+
+use crate::{
+    components::prelude::*,
+    props::UpdaterObject,
+    state::types::math_expr::{MathExpr, ToTextParams},
+};
+
+/// A math to text prop converts a math expression into a string using the text format of `math-expressions`,
+/// e.g., `x^2 + 1`.
+///
+/// Constructor:
+/// - `new(math_expression_local_idx)`: create a prop that converts
+///   the math prop of the index `math_expression_local_idx`.
+#[derive(Debug)]
+pub struct MathToTextProp {
+    /// Local index for the math expression to be converted to text
+    math_expression_local_idx: LocalPropIdx,
+}
+
+impl MathToTextProp {
+    pub fn new(math_expression_local_idx: LocalPropIdx) -> Self {
+        MathToTextProp {
+            math_expression_local_idx,
+        }
+    }
+}
+
+impl From<MathToTextProp> for UpdaterObject {
+    fn from(prop: MathToTextProp) -> UpdaterObject {
+        Rc::new(prop)
+    }
+}
+
+#[derive(TryFromDataQueryResults, IntoDataQueryResults)]
+#[data_query(query_trait = DataQueries, pass_data = &LocalPropIdx)]
+struct RequiredData {
+    math_expression: PropView<MathExpr>,
+}
+impl DataQueries for RequiredData {
+    fn math_expression_query(math_expression_local_idx: &LocalPropIdx) -> DataQuery {
+        DataQuery::Prop {
+            source: PropSource::Me,
+            prop_specifier: (*math_expression_local_idx).into(),
+        }
+    }
+}
+
+impl PropUpdater for MathToTextProp {
+    type PropType = prop_type::String;
+
+    fn data_queries(&self) -> Vec<DataQuery> {
+        RequiredData::data_queries_vec(&self.math_expression_local_idx)
+    }
+
+    fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+        let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+        let math_expression = required_data.math_expression;
+
+        PropCalcResult::Calculated(Rc::new(
+            math_expression.value.to_text(ToTextParams::default()),
+        ))
+    }
+
+    /// Parse the requested string with the text parser when inverting
+    fn invert(
+        &self,
+        data: DataQueryResults,
+        requested_value: Self::PropType,
+        _is_direct_change_from_action: bool,
+    ) -> Result<DataQueryResults, InvertError> {
+        let mut desired = RequiredData::try_new_desired(&data).unwrap();
+
+        let desired_math = MathExpr::from_text((*requested_value).clone(), true, &["f", "g"]);
+
+        desired.math_expression.change_to(desired_math);
+
+        Ok(desired.into_data_query_results())
+    }
+}
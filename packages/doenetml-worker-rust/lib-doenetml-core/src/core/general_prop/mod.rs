@@ -1,15 +1,21 @@
 mod alias_prop;
 mod boolean_prop;
 mod boolean_to_string_prop;
+mod child_count_prop;
 mod component_ref_prop;
 mod component_refs_prop;
 mod enum_prop;
 mod independent_prop;
 mod latex_prop;
 mod math_prop;
+mod math_to_text_prop;
 mod number_prop;
 mod number_to_string_prop;
+mod numerical_points_prop;
+mod point_list_prop;
+mod render_mode_hidden_prop;
 mod rendered_children_passthrough_prop;
+mod role_hidden_prop;
 mod string_prop;
 mod string_to_integer_prop;
 mod util;
@@ -21,14 +27,22 @@ pub mod test_utils;
 pub use self::alias_prop::PropAlias;
 pub use self::boolean_prop::BooleanProp;
 pub use self::boolean_to_string_prop::BooleanToStringProp;
+pub use self::child_count_prop::ChildCountProp;
 pub use self::component_ref_prop::ComponentRefProp;
 pub use self::component_refs_prop::ComponentRefsProp;
-pub use self::enum_prop::EnumProp;
+pub use self::enum_prop::{EnumAttributeValue, EnumProp};
 pub use self::independent_prop::IndependentProp;
 pub use self::latex_prop::LatexProp;
 pub use self::math_prop::MathProp;
+pub use self::math_to_text_prop::MathToTextProp;
 pub use self::number_prop::NumberProp;
 pub use self::number_to_string_prop::NumberToStringProp;
-pub use self::rendered_children_passthrough_prop::RenderedChildrenPassthroughProp;
+pub use self::numerical_points_prop::NumericalPointsProp;
+pub use self::point_list_prop::PointListProp;
+pub use self::render_mode_hidden_prop::RenderModeHiddenProp;
+pub use self::rendered_children_passthrough_prop::{
+    RenderedChildrenPassthroughProp, not_hidden_filter,
+};
+pub use self::role_hidden_prop::RoleHiddenProp;
 pub use self::string_prop::StringProp;
 pub use self::string_to_integer_prop::StringToIntegerProp;
@@ -59,11 +59,7 @@ impl NumberProp {
         NumberProp {
             data_query: DataQuery::PickProp {
                 source: PickPropSource::Children,
-                prop_specifier: PropSpecifier::Matching(vec![
-                    PropProfile::Number,
-                    PropProfile::Math,
-                    PropProfile::String,
-                ]),
+                prop_specifier: PropSpecifier::Matching(PropProfile::Number.coercible_profiles()),
             },
             default_value,
             propagate_came_from_default: true,
@@ -109,7 +105,7 @@ impl PropFromAttribute<prop_type::Number> for NumberProp {
         NumberProp {
             data_query: DataQuery::Attribute {
                 attribute_name: attr_name,
-                match_profiles: vec![PropProfile::Number, PropProfile::Math, PropProfile::String],
+                match_profiles: PropProfile::Number.coercible_profiles(),
             },
             default_value,
             propagate_came_from_default: true,
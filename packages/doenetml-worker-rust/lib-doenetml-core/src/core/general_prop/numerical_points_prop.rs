@@ -0,0 +1,74 @@
+use std::rc::Rc;
+
+use crate::{components::prelude::*, props::UpdaterObject};
+
+/// A prop that converts a list of points, given as math vectors by another prop of the component,
+/// into the numerical `[x, y]` coordinates of each point, e.g., for a renderer.
+/// Coordinates that are not numerical are `NaN`.
+#[derive(Debug)]
+pub struct NumericalPointsProp {
+    /// Local index of the prop with the list of points
+    points_local_idx: LocalPropIdx,
+}
+
+impl NumericalPointsProp {
+    pub fn new(points_local_idx: LocalPropIdx) -> Self {
+        NumericalPointsProp { points_local_idx }
+    }
+}
+
+impl From<NumericalPointsProp> for UpdaterObject {
+    fn from(prop: NumericalPointsProp) -> UpdaterObject {
+        Rc::new(prop)
+    }
+}
+
+#[derive(TryFromDataQueryResults, Debug)]
+#[data_query(query_trait = DataQueries, pass_data = &LocalPropIdx)]
+struct RequiredData {
+    points: PropView<prop_type::PropVec>,
+}
+impl DataQueries for RequiredData {
+    fn points_query(points_local_idx: &LocalPropIdx) -> DataQuery {
+        DataQuery::Prop {
+            source: PropSource::Me,
+            prop_specifier: (*points_local_idx).into(),
+        }
+    }
+}
+
+impl PropUpdater for NumericalPointsProp {
+    type PropType = prop_type::PropVec;
+
+    fn data_queries(&self) -> Vec<DataQuery> {
+        RequiredData::data_queries_vec(&self.points_local_idx)
+    }
+
+    fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+        let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+
+        let numerical_points = required_data
+            .points
+            .value
+            .iter()
+            .map(|point| {
+                let components = match point {
+                    PropValue::Math(coords) => coords.to_vector_components().unwrap_or_default(),
+                    _ => Vec::new(),
+                };
+                let coord = |i: usize| {
+                    components
+                        .get(i)
+                        .map(|component| component.to_number())
+                        .unwrap_or(prop_type::Number::NAN)
+                };
+                PropValue::PropVec(Rc::new(vec![
+                    PropValue::Number(coord(0)),
+                    PropValue::Number(coord(1)),
+                ]))
+            })
+            .collect();
+
+        PropCalcResult::Calculated(Rc::new(numerical_points))
+    }
+}
@@ -0,0 +1,88 @@
+use std::rc::Rc;
+
+use crate::{components::prelude::*, props::UpdaterObject};
+
+/// A prop that lists the coordinates, as math vectors, of the `<point>`s referenced by an attribute,
+/// e.g., the vertices in `vertices="$A $B $C"`.
+///
+/// Requesting a new list changes the coordinates of the referenced points.
+#[derive(Debug)]
+pub struct PointListProp {
+    /// The data query that picks the coordinates of the points.
+    data_query: DataQuery,
+}
+
+impl PropFromAttribute<prop_type::PropVec> for PointListProp {
+    fn new_from_attribute(attr_name: AttributeName, _default: prop_type::PropVec) -> Self {
+        PointListProp {
+            data_query: DataQuery::PickProp {
+                source: PickPropSource::Attribute {
+                    attribute_name: attr_name,
+                },
+                prop_specifier: PropSpecifier::Matching(vec![PropProfile::Point]),
+            },
+        }
+    }
+}
+
+impl From<PointListProp> for UpdaterObject {
+    fn from(prop: PointListProp) -> UpdaterObject {
+        Rc::new(prop)
+    }
+}
+
+#[derive(TryFromDataQueryResults, IntoDataQueryResults, Debug)]
+#[data_query(query_trait = DataQueries, pass_data = &DataQuery)]
+struct RequiredData {
+    points: Vec<PropView<prop_type::Math>>,
+}
+impl DataQueries for RequiredData {
+    fn points_query(query: &DataQuery) -> DataQuery {
+        query.clone()
+    }
+}
+
+impl PropUpdater for PointListProp {
+    type PropType = prop_type::PropVec;
+
+    fn data_queries(&self) -> Vec<DataQuery> {
+        RequiredData::data_queries_vec(&self.data_query)
+    }
+
+    fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+        let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+        PropCalcResult::Calculated(Rc::new(
+            required_data
+                .points
+                .into_iter()
+                .map(|point| PropValue::Math(point.value))
+                .collect(),
+        ))
+    }
+
+    fn invert(
+        &self,
+        data: DataQueryResults,
+        requested_value: Self::PropType,
+        _is_direct_change_from_action: bool,
+    ) -> Result<DataQueryResults, InvertError> {
+        let mut desired = RequiredData::try_new_desired(&data).unwrap();
+
+        if requested_value.len() != desired.points.len() {
+            return Err(InvertError::CouldNotUpdate);
+        }
+        for (point, requested_coords) in desired.points.iter_mut().zip(requested_value.iter()) {
+            match requested_coords {
+                // Only the points whose coordinates differ are moved.
+                PropValue::Math(coords) => {
+                    if point.value != *coords {
+                        point.change_to(coords.clone());
+                    }
+                }
+                _ => return Err(InvertError::CouldNotUpdate),
+            }
+        }
+
+        Ok(desired.into_data_query_results())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{components::prelude::*, props::RenderMode};
+
+/// A `hidden` prop for content that is shown only when the document is rendered in a particular mode.
+///
+/// Constructor:
+/// - `new(shown_in)`: create a prop that is `true` unless the document is rendered in the mode `shown_in`.
+#[derive(Debug)]
+pub struct RenderModeHiddenProp {
+    shown_in: RenderMode,
+}
+
+impl RenderModeHiddenProp {
+    /// Create a prop that is `true` unless the document is rendered in the mode `shown_in`.
+    pub fn new(shown_in: RenderMode) -> Self {
+        RenderModeHiddenProp { shown_in }
+    }
+}
+
+/// Structure to hold data generated from the data queries
+#[derive(TryFromDataQueryResults, Debug)]
+#[data_query(query_trait = DataQueries)]
+struct RequiredData {
+    render_mode: PropView<prop_type::RenderMode>,
+}
+
+impl DataQueries for RequiredData {
+    fn render_mode_query() -> DataQuery {
+        DataQuery::RenderMode
+    }
+}
+
+impl PropUpdater for RenderModeHiddenProp {
+    type PropType = prop_type::Boolean;
+
+    fn data_queries(&self) -> Vec<DataQuery> {
+        RequiredData::to_data_queries()
+    }
+
+    fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+        let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+        PropCalcResult::Calculated(required_data.render_mode.value != self.shown_in)
+    }
+}
+
+#[cfg(test)]
+#[path = "render_mode_hidden_prop.test.rs"]
+mod tests;
@@ -0,0 +1,50 @@
+use crate::props::{DataQueryResult, DataQueryResults, cache::PropWithMeta};
+
+use super::*;
+use crate::general_prop::test_utils::*;
+
+fn render_mode_data(render_mode: RenderMode) -> DataQueryResults {
+    DataQueryResults::from_vec(vec![DataQueryResult {
+        values: vec![PropWithMeta {
+            value: PropValue::RenderMode(render_mode),
+            came_from_default: false,
+            changed: true,
+            origin: None,
+        }],
+    }])
+}
+
+#[test]
+fn render_mode_hidden_prop_queries_the_render_mode() {
+    let prop =
+        as_updater_object::<_, prop_type::Boolean>(RenderModeHiddenProp::new(RenderMode::Print));
+    let queries = prop.data_queries();
+    assert_eq!(queries.len(), 1);
+    assert!(matches!(queries[0], DataQuery::RenderMode));
+}
+
+#[test]
+fn render_mode_hidden_prop_is_hidden_outside_its_mode() {
+    let prop =
+        as_updater_object::<_, prop_type::Boolean>(RenderModeHiddenProp::new(RenderMode::Print));
+    assert_boolean_calculated_value(
+        prop.calculate_untyped(render_mode_data(RenderMode::Print)),
+        false,
+    );
+    assert_boolean_calculated_value(
+        prop.calculate_untyped(render_mode_data(RenderMode::Interactive)),
+        true,
+    );
+
+    let prop = as_updater_object::<_, prop_type::Boolean>(RenderModeHiddenProp::new(
+        RenderMode::Interactive,
+    ));
+    assert_boolean_calculated_value(
+        prop.calculate_untyped(render_mode_data(RenderMode::Print)),
+        true,
+    );
+    assert_boolean_calculated_value(
+        prop.calculate_untyped(render_mode_data(RenderMode::Interactive)),
+        false,
+    );
+}
@@ -10,20 +10,35 @@ pub struct RenderedChildrenPassthroughProp {
     data_query: DataQuery,
 }
 
+/// The filter that keeps the content that is not hidden.
+pub fn not_hidden_filter() -> Op<OpNot<ContentFilter>, ContentFilter> {
+    Op::Or(
+        // Keep things without a "hidden" prop
+        OpNot(ContentFilter::HasPropMatchingProfile(PropProfile::Hidden)),
+        // Keep things with a "hidden != true" prop
+        ContentFilter::HasPropMatchingProfileAndCondition(
+            PropProfile::Hidden,
+            Cond::Eq(PropValue::Boolean(false)),
+        ),
+    )
+}
+
 impl RenderedChildrenPassthroughProp {
     pub fn new() -> Self {
         RenderedChildrenPassthroughProp {
             data_query: DataQuery::AnnotatedContentRefs {
                 container: PropSource::Me,
-                filter: Rc::new(Op::Or(
-                    // Keep things without a "hidden" prop
-                    OpNot(ContentFilter::HasPropMatchingProfile(PropProfile::Hidden)),
-                    // Keep things with a "hidden != true" prop
-                    ContentFilter::HasPropMatchingProfileAndCondition(
-                        PropProfile::Hidden,
-                        Cond::Eq(PropValue::Boolean(false)),
-                    ),
-                )),
+                filter: Rc::new(not_hidden_filter()),
+            },
+        }
+    }
+
+    /// Like `new`, but only child components are rendered; text children (e.g., whitespace between components) are skipped.
+    pub fn new_components_only() -> Self {
+        RenderedChildrenPassthroughProp {
+            data_query: DataQuery::AnnotatedContentRefs {
+                container: PropSource::Me,
+                filter: Rc::new(Op::And(ContentFilter::IsComponent, not_hidden_filter())),
             },
         }
     }
@@ -0,0 +1,47 @@
+use crate::{components::prelude::*, props::Role};
+
+/// A `hidden` prop for content that is shown only to people with particular roles.
+///
+/// Constructor:
+/// - `new(shown_to)`: create a prop that is `true` unless the role of the person viewing the document is in `shown_to`.
+#[derive(Debug)]
+pub struct RoleHiddenProp {
+    shown_to: &'static [Role],
+}
+
+impl RoleHiddenProp {
+    /// Create a prop that is `true` unless the role of the person viewing the document is in `shown_to`.
+    pub fn new(shown_to: &'static [Role]) -> Self {
+        RoleHiddenProp { shown_to }
+    }
+}
+
+/// Structure to hold data generated from the data queries
+#[derive(TryFromDataQueryResults, Debug)]
+#[data_query(query_trait = DataQueries)]
+struct RequiredData {
+    role: PropView<prop_type::Role>,
+}
+
+impl DataQueries for RequiredData {
+    fn role_query() -> DataQuery {
+        DataQuery::Role
+    }
+}
+
+impl PropUpdater for RoleHiddenProp {
+    type PropType = prop_type::Boolean;
+
+    fn data_queries(&self) -> Vec<DataQuery> {
+        RequiredData::to_data_queries()
+    }
+
+    fn calculate(&self, data: DataQueryResults) -> PropCalcResult<Self::PropType> {
+        let required_data = RequiredData::try_from_data_query_results(data).unwrap();
+        PropCalcResult::Calculated(!self.shown_to.contains(&required_data.role.value))
+    }
+}
+
+#[cfg(test)]
+#[path = "role_hidden_prop.test.rs"]
+mod tests;
@@ -0,0 +1,34 @@
+use crate::props::{DataQueryResult, DataQueryResults, cache::PropWithMeta};
+
+use super::*;
+use crate::general_prop::test_utils::*;
+
+fn role_data(role: Role) -> DataQueryResults {
+    DataQueryResults::from_vec(vec![DataQueryResult {
+        values: vec![PropWithMeta {
+            value: PropValue::Role(role),
+            came_from_default: false,
+            changed: true,
+            origin: None,
+        }],
+    }])
+}
+
+#[test]
+fn role_hidden_prop_queries_the_role() {
+    let prop = as_updater_object::<_, prop_type::Boolean>(RoleHiddenProp::new(&[Role::Instructor]));
+    let queries = prop.data_queries();
+    assert_eq!(queries.len(), 1);
+    assert!(matches!(queries[0], DataQuery::Role));
+}
+
+#[test]
+fn role_hidden_prop_is_hidden_from_other_roles() {
+    let prop = as_updater_object::<_, prop_type::Boolean>(RoleHiddenProp::new(&[
+        Role::Instructor,
+        Role::Grader,
+    ]));
+    assert_boolean_calculated_value(prop.calculate_untyped(role_data(Role::Student)), true);
+    assert_boolean_calculated_value(prop.calculate_untyped(role_data(Role::Instructor)), false);
+    assert_boolean_calculated_value(prop.calculate_untyped(role_data(Role::Grader)), false);
+}
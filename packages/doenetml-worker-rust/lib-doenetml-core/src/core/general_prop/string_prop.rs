@@ -44,7 +44,7 @@ impl StringProp {
         StringProp {
             data_query: DataQuery::PickProp {
                 source: PickPropSource::Children,
-                prop_specifier: PropSpecifier::Matching(vec![PropProfile::String]),
+                prop_specifier: PropSpecifier::Matching(PropProfile::String.coercible_profiles()),
             },
             default_value: default_value.into(),
             propagate_came_from_default: true,
@@ -90,7 +90,7 @@ impl<S: Into<String>> PropFromAttribute<S> for StringProp {
         StringProp {
             data_query: DataQuery::Attribute {
                 attribute_name: attr_name,
-                match_profiles: vec![PropProfile::String],
+                match_profiles: PropProfile::String.coercible_profiles(),
             },
             default_value: default_value.into(),
             propagate_came_from_default: true,
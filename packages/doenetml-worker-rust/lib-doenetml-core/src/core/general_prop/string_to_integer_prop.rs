@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use crate::components::prelude::*;
+use crate::{components::prelude::*, utils::f64_to_integer};
 
 /// A prop that computes an integer from a string value.
 #[derive(Debug)]
@@ -53,15 +53,20 @@ impl PropUpdater for StringToIntegerProp {
                         }
 
                         // Float parsing is slightly more permissive. We may lose accuracy, but JS cannot handle i64s anyway.
-                        let float_val = required_data
+                        // Values that cannot be represented as an integer (e.g., `NaN` or `1e400`)
+                        // fall back to the default rather than saturating.
+                        let ret = required_data
                             .string
                             .iter()
                             .map(|s| (*s.value).clone())
                             .join("")
                             .parse::<f64>()
-                            .unwrap_or(self.default as f64);
-                        let ret = float_val.trunc() as i64;
-                        PropCalcResult::Calculated(ret)
+                            .ok()
+                            .and_then(|float_val| f64_to_integer(float_val).ok());
+                        match ret {
+                            Some(ret) => PropCalcResult::Calculated(ret),
+                            None => PropCalcResult::FromDefault(self.default),
+                        }
                     }
                 }
             }
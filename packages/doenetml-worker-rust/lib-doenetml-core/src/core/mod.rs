@@ -4,6 +4,7 @@
 //! props as appropriate and delivers updates to the UI when values change. It is responsible for maintaining (and
 //! updating) relationships between _DoenetML_ components and their props.
 
+mod compiled_program;
 pub mod component_builder;
 pub mod dispatch_action;
 mod document_model;
@@ -13,8 +14,26 @@ pub mod graph_node;
 mod graph_node_lookup;
 pub mod math_via_wasm;
 pub mod props;
+mod render_encoding;
+mod snapshot;
+mod state_blob;
 
-pub use document_model::DocumentModel;
+pub use compiled_program::{CompiledProgram, ContentHash, ProgramBlobError};
+pub use document_model::{
+    Annotation, AnnotationKind, ComponentMetadata, ComponentProvenance, ComponentStateSummary,
+    DebugStateSummary, DependencyPathNode, DeviceClass, DocumentEnvironment, DocumentFlags,
+    DocumentModel, DocumentScore, EnvironmentProperty, EssentialData, EssentialDataEvent,
+    ExperimentAssignment, IncompatibleContent, NewAnnotation, ObjectiveCredit, ObjectiveReport,
+    PropBreakpointEvent, PropBreakpointTrigger, PropOverride, PropStateSummary,
+    ResolutionTraceEntry, SavedState, SavedStateValue, SelectionDescriptor, StateChange, StateDiff,
+    Telemetry, TelemetryEvent, TelemetryEventKind, TextRange,
+};
+pub use document_renderer::{
+    DocumentStats, NumberPrecision, RenderPrecision, extract_text, render_html,
+};
+pub use render_encoding::{RenderEncodingError, RenderOutputFormat, encode_render_output};
+pub use snapshot::DocumentSnapshot;
+pub use state_blob::{StateBlobError, StateEncryption, decode_state_blob, encode_state_blob};
 
 // Because of the use of #[enum_dispatch], the `state` module must be declared before the `general_prop` module.
 pub mod state;
@@ -15,11 +15,13 @@ use crate::{
 /// - `Unresolved`: the dependencies for this prop have not yet been created
 /// - `Resolved`: the dependencies for this prop have been created,
 ///   but the value has not yet been calculated
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
 pub enum PropStatus {
     /// The value has been computed and doesn't need recomputing.
     Fresh,
     /// The value has been computed, but needs recomputing.
+    /// The previous value is kept so that it can be reused if none of the prop's dependencies changed.
     Stale,
     /// The prop has not been "resolved" yet, meaning it and (possibly) the other props it depends on for computing
     /// its value have not yet been added to the dependency graph.
@@ -41,10 +43,13 @@ struct CachedPropMeta {
     /// when calculating another prop, as it indicates that the document author
     /// did not explicitly set the value
     pub came_from_default: bool,
-    /// A counter that is incremented every time the value is set.
+    /// A counter that is incremented every time the value is set to a different value.
     /// Used to compare with another counter to determine if the variable has been changed
     /// since the other counter was last set.
     pub change_counter: u32,
+    /// True if the prop must be recalculated even if none of its dependencies changed,
+    /// e.g., because an override of its value was removed.
+    pub must_recalculate: bool,
 }
 
 /// Store a prop's and other meta information needed to operate a cache
@@ -64,18 +69,30 @@ impl CachedProp {
                 status: PropStatus::Unresolved,
                 came_from_default: false,
                 change_counter: 0,
+                must_recalculate: false,
             }),
         }
     }
 
-    /// Sets the value and marks it as fresh using internal mutability.
+    /// Sets the value and `came_from_default` and marks the prop as fresh using internal mutability.
+    ///
+    /// The change counter is incremented only if the value or `came_from_default` differs from what was cached.
+    /// Recalculating a prop to the same value is therefore not reported as a change,
+    /// so the props that depend on it can return `PropCalcResult::NoChange` rather than cascading recalculations.
     /// **For internal use only.**
-    fn _set_value(&self, value: PropValue) {
+    fn _set_value(&self, value: PropValue, came_from_default: bool) {
+        let unchanged = self.meta.borrow().came_from_default == came_from_default
+            && self.value.borrow().as_ref() == Some(&value);
+
         *self.value.borrow_mut() = Some(value);
         self.meta.borrow_mut().status = PropStatus::Fresh;
-        let change_counter = self.meta.borrow().change_counter;
-        // A little bit of safety in case someone wiggles their mouse 4 billion times
-        self.meta.borrow_mut().change_counter = change_counter.wrapping_add(1);
+        self.meta.borrow_mut().must_recalculate = false;
+        self._set_came_from_default(came_from_default);
+        if !unchanged {
+            let change_counter = self.meta.borrow().change_counter;
+            // A little bit of safety in case someone wiggles their mouse 4 billion times
+            self.meta.borrow_mut().change_counter = change_counter.wrapping_add(1);
+        }
     }
 
     /// Sets the prop as stale using interior mutability.
@@ -84,6 +101,14 @@ impl CachedProp {
         self.meta.borrow_mut().status = PropStatus::Stale;
     }
 
+    /// Sets the prop as stale and requires it to be recalculated, even if none of its dependencies changed,
+    /// using interior mutability.
+    fn invalidate(&self) {
+        let mut meta = self.meta.borrow_mut();
+        meta.status = PropStatus::Stale;
+        meta.must_recalculate = true;
+    }
+
     /// Set `came_from_default` using interior mutability.
     /// **For internal use only.**
     fn _set_came_from_default(&self, came_from_default: bool) {
@@ -125,12 +150,10 @@ impl CachedProp {
     fn set_value(&self, result: PropCalcResult<PropValue>) {
         match result {
             PropCalcResult::Calculated(value) => {
-                self._set_value(value);
-                self._set_came_from_default(false);
+                self._set_value(value, false);
             }
             PropCalcResult::FromDefault(value) => {
-                self._set_value(value);
-                self._set_came_from_default(true);
+                self._set_value(value, true);
             }
             PropCalcResult::NoChange => {
                 if self.get_status() == PropStatus::Resolved {
@@ -139,6 +162,7 @@ impl CachedProp {
                     )
                 }
                 self.set_status(PropStatus::Fresh);
+                self.meta.borrow_mut().must_recalculate = false;
             }
         }
     }
@@ -152,6 +176,11 @@ impl CachedProp {
     pub fn get_status(&self) -> PropStatus {
         self.meta.borrow().status
     }
+    /// Get whether the prop must be recalculated even if none of its dependencies changed.
+    pub fn get_must_recalculate(&self) -> bool {
+        self.meta.borrow().must_recalculate
+    }
+
     /// Get the change counter of the prop.
     pub fn get_change_counter(&self) -> u32 {
         self.meta.borrow().change_counter
@@ -204,6 +233,20 @@ impl PropCache {
             })
     }
 
+    /// Get the status of a prop along with its last cached value, without computing anything
+    /// or updating the change tracker. The value is `None` if the prop has never been computed.
+    pub fn peek_prop<A: borrow::Borrow<GraphNode>>(
+        &self,
+        prop_node: A,
+    ) -> (PropStatus, Option<PropValue>) {
+        self.store
+            .borrow()
+            .get_tag(prop_node.borrow())
+            .map_or((PropStatus::Unresolved, None), |cached_prop| {
+                (cached_prop.get_status(), cached_prop.get_cached_value())
+            })
+    }
+
     /// Set the status of a prop.
     pub fn set_prop_status<A: borrow::Borrow<GraphNode>>(&self, prop_node: A, status: PropStatus) {
         let prop_node = prop_node.borrow();
@@ -213,6 +256,25 @@ impl PropCache {
         cached_prop.set_status(status);
     }
 
+    /// Mark a prop as stale and require it to be recalculated, even if none of its dependencies changed.
+    /// The cached value is kept, but it will not be reused.
+    pub fn invalidate_prop<A: borrow::Borrow<GraphNode>>(&self, prop_node: A) {
+        let prop_node = prop_node.borrow();
+        self.ensure_prop_exists(prop_node);
+        let store = self.store.borrow();
+        let cached_prop = store.get_tag(prop_node).unwrap();
+        cached_prop.invalidate();
+    }
+
+    /// Whether a prop must be recalculated even if none of its dependencies changed.
+    /// See `invalidate_prop`.
+    pub fn must_recalculate<A: borrow::Borrow<GraphNode>>(&self, prop_node: A) -> bool {
+        self.store
+            .borrow()
+            .get_tag(prop_node.borrow())
+            .is_some_and(|cached_prop| cached_prop.get_must_recalculate())
+    }
+
     /// Ensures that cached data associated with `prop_node` exists.
     /// If no cached data exists, a new `CachedProp` is created and associated with `prop_node`.
     fn ensure_prop_exists(&self, prop_node: &GraphNode) {
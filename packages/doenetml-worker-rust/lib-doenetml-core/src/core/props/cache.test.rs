@@ -108,14 +108,8 @@ fn test_a_data_query_can_keep_track_of_changes_to_multiple_props() {
 
     // Set the prop values multiple times to make sure they have different change counters.
     cache.set_prop_status(prop_node, PropStatus::Resolved);
-    cache.set_prop(
-        prop_node,
-        PropCalcResult::Calculated(PropValue::Integer(10)),
-    );
-    cache.set_prop(
-        prop_node,
-        PropCalcResult::Calculated(PropValue::Integer(10)),
-    );
+    cache.set_prop(prop_node, PropCalcResult::Calculated(PropValue::Integer(8)));
+    cache.set_prop(prop_node, PropCalcResult::Calculated(PropValue::Integer(9)));
     cache.set_prop(
         prop_node,
         PropCalcResult::Calculated(PropValue::Integer(10)),
@@ -208,3 +202,60 @@ fn test_cache_origin_uses_whole_graph_node() {
     let val3 = cache.get_prop_unchecked(prop_node, origin_node2);
     assert_eq!(val3.changed, true);
 }
+
+#[test]
+fn test_recalculating_to_the_same_value_is_not_a_change() {
+    let cache = PropCache::new();
+
+    let prop_node = GraphNode::Prop(0);
+    let query_node = GraphNode::Query(0);
+
+    cache.set_prop_status(prop_node, PropStatus::Resolved);
+    let val = cache.get_prop(prop_node, query_node, || {
+        PropCalcResult::Calculated(PropValue::Integer(10))
+    });
+    assert_eq!(val.changed, true);
+
+    // Recalculating to an equal value is not reported as a change
+    cache.set_prop_status(prop_node, PropStatus::Stale);
+    let val = cache.get_prop(prop_node, query_node, || {
+        PropCalcResult::Calculated(PropValue::Integer(10))
+    });
+    assert_eq!(val.changed, false);
+
+    // The same value coming from a default is a change
+    cache.set_prop_status(prop_node, PropStatus::Stale);
+    let val = cache.get_prop(prop_node, query_node, || {
+        PropCalcResult::FromDefault(PropValue::Integer(10))
+    });
+    assert_eq!(val.changed, true);
+    assert_eq!(val.came_from_default, true);
+
+    cache.set_prop_status(prop_node, PropStatus::Stale);
+    let val = cache.get_prop(prop_node, query_node, || {
+        PropCalcResult::FromDefault(PropValue::Integer(11))
+    });
+    assert_eq!(val.changed, true);
+}
+
+#[test]
+fn test_can_peek_without_calculating() {
+    let cache = PropCache::new();
+
+    let prop_node = GraphNode::Prop(0);
+    let query_node = GraphNode::Query(0);
+
+    assert_eq!(cache.peek_prop(prop_node), (PropStatus::Unresolved, None));
+
+    cache.set_prop_status(prop_node, PropStatus::Resolved);
+    assert_eq!(cache.peek_prop(prop_node), (PropStatus::Resolved, None));
+
+    let _val = cache.get_prop(prop_node, query_node, || {
+        PropCalcResult::Calculated(PropValue::Integer(10))
+    });
+    cache.set_prop_status(prop_node, PropStatus::Stale);
+    assert_eq!(
+        cache.peek_prop(prop_node),
+        (PropStatus::Stale, Some(PropValue::Integer(10)))
+    );
+}
@@ -2,13 +2,21 @@
 //! filtering/querying a component and its props.
 
 use super::{ApplyTest, Cond, PropValue};
-use crate::{DocumentModel, graph_node::GraphNode, props::PropProfile};
+use crate::{
+    DocumentModel,
+    components::types::{LocalPropIdx, PropPointer},
+    graph_node::GraphNode,
+    props::PropProfile,
+};
 
 /// Filters used to filter content (props/components/strings) from the structure graph.
 #[derive(Debug, Clone)]
 pub enum ContentFilter {
     /// Match a component by type name.
     IsType(&'static str),
+    /// Match a component whose type is one of the whitespace-separated names (ignoring case)
+    /// in a string prop of the component making the query, e.g., the `componentTypes` of a `<collect>`.
+    IsTypeListedIn(LocalPropIdx),
     /// Match components that have a prop matching a profile;
     /// The value of the matched prop is not considered.
     HasPropMatchingProfile(PropProfile),
@@ -47,6 +55,21 @@ impl ApplyTest<FilterData<'_>, GraphNode> for ContentFilter {
                 }
                 _ => false,
             },
+            ContentFilter::IsTypeListedIn(local_prop_idx) => match node {
+                GraphNode::Component(_) => {
+                    let Some(list_node) = type_list_prop_node(*local_prop_idx, data) else {
+                        return false;
+                    };
+                    let component_type = document_model.get_component_type(node);
+                    match document_model._get_prop_unchecked(list_node, origin).value {
+                        PropValue::String(type_list) => type_list
+                            .split_whitespace()
+                            .any(|type_name| type_name.eq_ignore_ascii_case(&component_type)),
+                        _ => false,
+                    }
+                }
+                _ => false,
+            },
             ContentFilter::HasPropMatchingProfile(profile) => match node {
                 GraphNode::Component(component_idx) => {
                     let prop =
@@ -133,6 +156,11 @@ impl ApplyTest<FilterData<'_>, GraphNode> for ContentFilter {
 
         match self {
             ContentFilter::IsType(_) => vec![],
+            ContentFilter::IsTypeListedIn(local_prop_idx) => {
+                type_list_prop_node(*local_prop_idx, data)
+                    .into_iter()
+                    .collect()
+            }
             ContentFilter::HasPropMatchingProfile(_) => {
                 // This search merely returns the presence of a prop matching the profile, not its value.
                 // Since prop profiles are static, we can safely return no deps.
@@ -151,3 +179,14 @@ impl ApplyTest<FilterData<'_>, GraphNode> for ContentFilter {
         }
     }
 }
+
+/// The node of the prop `local_prop_idx` of the component whose data query is being filtered.
+fn type_list_prop_node(local_prop_idx: LocalPropIdx, data: &FilterData) -> Option<GraphNode> {
+    let document_model = data.document_model;
+    let prop_node = document_model.get_nearest_prop_ancestor_of_query(data.origin)?;
+    let component_idx = document_model.get_prop_pointer(prop_node).component_idx;
+    Some(document_model.prop_pointer_to_prop_node(PropPointer {
+        component_idx,
+        local_prop_idx,
+    }))
+}
@@ -5,6 +5,7 @@ use crate::{
         prelude::{ComponentIdx, LocalPropIdx},
         types::AttributeName,
     },
+    core::document_model::EnvironmentProperty,
     graph_node::GraphNode,
 };
 
@@ -100,6 +101,8 @@ pub enum PickPropSource {
     /// Search for the prop on the ancestors of the querying component.
     /// Only return the prop from the first matching ancestor (if one exists).
     NearestMatchingAncestor,
+    /// Search for the props on all the descendants of the querying component, in document order.
+    Descendants,
 }
 
 impl From<LocalPropIdx> for PropSpecifier {
@@ -157,6 +160,21 @@ pub enum DataQuery {
         filter: Rc<dyn for<'a> ApplyTest<FilterData<'a>, GraphNode>>,
     },
 
+    /// Query for the components rendered inside of `container`, in document order, filtering them
+    /// based on the supplied `filter`. Results in a `prop_type::ContentRefs` with the matching component refs.
+    ///
+    /// Unlike [`DataQuery::ContentRefs`], the search continues into the rendered children of each
+    /// descendant, so content that is not rendered, e.g., an unselected `<case>` of a `<conditionalContent>`,
+    /// is skipped, and the result changes when the rendered children change.
+    /// Only descendants of `container` in the document structure are searched.
+    DescendantContentRefs {
+        /// Descendants of this component will be searched
+        container: PropSource,
+        /// How to filter the descendants. This should be a [`ContentFilter`] or a
+        /// composition of [`ContentFilter`]s.
+        filter: Rc<dyn for<'a> ApplyTest<FilterData<'a>, GraphNode>>,
+    },
+
     /// Query for a particular prop of a component
     Prop {
         /// Where to look for the desired prop.
@@ -191,6 +209,32 @@ pub enum DataQuery {
     /// Query for a reference to "self", the component making the query.
     SelfRef,
 
+    /// Query for the mode the document is rendered in, e.g., for print.
+    /// The render mode is set by the host before the document is rendered,
+    /// so props that depend on it are not recalculated if it changes.
+    RenderMode,
+
+    /// Query for the role of the person viewing the document, e.g., instructor.
+    /// Like the render mode, the role is set by the host before the document is rendered.
+    Role,
+
+    /// Query for the seed from which selections are made, e.g., which option a `<select>` shows.
+    /// Like the render mode, the seed is set by the host before the document is rendered.
+    VariantSeed,
+
+    /// Query for the seed assigned to the student viewing the document, e.g., from which an `<experiment>` assigns an arm.
+    /// Like the render mode, the seed is set by the host before the document is rendered.
+    StudentSeed,
+
+    /// Query for the options the querying component selected on previous attempts, so that it can avoid repeating them.
+    /// Like the render mode, these are set by the host before the document is rendered.
+    SeenSelections,
+
+    /// Query for a property of the environment in which the document is displayed, e.g., the width of the viewport.
+    /// Unlike flags, the environment may change after the document is rendered,
+    /// in which case props that depend on the changed property are recalculated.
+    Environment(EnvironmentProperty),
+
     #[default]
     /// A data query that cannot be resolved. This is used as a dependency of other data queries.
     Null,
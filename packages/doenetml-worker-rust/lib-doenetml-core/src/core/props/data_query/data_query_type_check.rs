@@ -1,5 +1,6 @@
 use crate::{
     components::ComponentVariantPropTypes,
+    core::document_model::EnvironmentProperty,
     props::{PropProfile, prop_profile_to_type},
 };
 
@@ -20,6 +21,7 @@ impl DataQuery {
         match self {
             DataQuery::ContentRefs { .. } => Ok(vec![PropValueType::ContentRefs]),
             DataQuery::AnnotatedContentRefs { .. } => Ok(vec![PropValueType::AnnotatedContentRefs]),
+            DataQuery::DescendantContentRefs { .. } => Ok(vec![PropValueType::ContentRefs]),
             DataQuery::Prop { prop_specifier, .. } | DataQuery::PickProp { prop_specifier, .. } => {
                 // This is not completely correct. There are non-allowed cases where `PropSpecifier::LocalIdx` is used
                 // with a `source != PropSource:Me`. These are hard runtime errors. Technically, we should return `Err` in those
@@ -38,6 +40,16 @@ impl DataQuery {
             }
             DataQuery::State => Err(()),
             DataQuery::SelfRef => Ok(vec![PropValueType::ComponentRef]),
+            DataQuery::RenderMode => Ok(vec![PropValueType::RenderMode]),
+            DataQuery::Role => Ok(vec![PropValueType::Role]),
+            DataQuery::VariantSeed => Ok(vec![PropValueType::Integer]),
+            DataQuery::StudentSeed => Ok(vec![PropValueType::Integer]),
+            DataQuery::SeenSelections => Ok(vec![PropValueType::Integer]),
+            DataQuery::Environment(property) => Ok(vec![match property {
+                EnvironmentProperty::ViewportWidth => PropValueType::Number,
+                EnvironmentProperty::DeviceClass => PropValueType::String,
+                EnvironmentProperty::PrefersReducedMotion => PropValueType::Boolean,
+            }]),
             DataQuery::Null => Err(()),
         }
     }
@@ -4,6 +4,9 @@ use crate::components::{_Fragment, Boolean, Math, Number, Text, types::PropPoint
 
 use super::{PropProfile, PropUpdaterUntyped, PropValueType};
 
+pub use crate::state::types::render_mode::RenderMode;
+pub use crate::state::types::role::Role;
+
 /// Data associated with a prop that is "owned" by a component.
 #[derive(Debug, Clone)]
 pub struct PropDefinitionMeta {
@@ -27,6 +30,19 @@ pub type UpdaterObject = Rc<dyn PropUpdaterUntyped>;
 pub struct ForRenderOutputs {
     pub in_graph: bool,
     pub in_text: bool,
+    /// If set, the prop is sent to the UI only when the document is rendered in this mode.
+    pub only_in_mode: Option<RenderMode>,
+}
+
+impl ForRenderOutputs {
+    /// Whether the prop is sent to the UI in `render_context` when the document is rendered in `render_mode`.
+    pub fn is_rendered(&self, render_context: RenderContext, render_mode: RenderMode) -> bool {
+        let rendered_in_context = match render_context {
+            RenderContext::InGraph => self.in_graph,
+            RenderContext::InText => self.in_text,
+        };
+        rendered_in_context && self.only_in_mode.is_none_or(|mode| mode == render_mode)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -31,10 +31,14 @@ pub enum PropProfile {
     Integer,
     /// Matches Boolean props
     Boolean,
+    /// Matches a prop that stores the coordinates of a point. It can also be used where math is expected.
+    Point,
     /// Matches the hidden prop
     Hidden,
     /// Matches the fixed prop
     Fixed,
+    /// Matches a prop that stores whether a component, such as a `<choice>`, cannot be interacted with.
+    Disabled,
     /// Matches the SerialNumber prop
     SerialNumber,
     /// Matches the DivisionCodeNumber prop
@@ -68,6 +72,53 @@ pub enum PropProfile {
     XrefLabel,
     /// Matches a prop that stores `ContentRefs` to any content that should be "expanded" when an xref is clicked.
     XrefDisplayContent,
+    /// Matches a prop that stores the title shown on the button that selects a `<tab>`.
+    TabTitle,
+    /// Matches a prop that stores whether an interactive component, such as a `<textInput>`, has been answered.
+    /// It is used to measure the progress through a document.
+    Answered,
+    /// Matches a prop that stores the fraction of a document that has been completed, from 0 to 1.
+    CompletionFraction,
+    /// Matches a prop that stores the response a user entered into an interactive component, such as a `<textInput>`.
+    /// An `<answer>` collects the responses of its descendants.
+    Response,
+    /// Matches a prop that stores the credit, from 0 to 1, that an `<award>` gives for the current response.
+    CreditAchieved,
+    /// Matches a prop that stores whether a `<solution>` has been revealed.
+    /// The `<document>` counts the revealed solutions, e.g., for scoring policies that penalize them.
+    SolutionRevealed,
+    /// Matches a prop that stores the number of `<cell>`s in a `<row>`.
+    /// A `<table>` uses it to count its rows and columns.
+    RowNumCells,
+    /// Matches a prop that stores the `halign` attribute of a `<row>`, which aligns all of its cells.
+    RowHalign,
+    /// Matches a prop that stores the `halign` attribute of a `<table>`, which gives the alignment of each column.
+    TableHalign,
+    /// Matches a prop that stores the values of the `<number>`s of a `<numberList>`.
+    /// A `<matrix>` uses it to read each of its rows.
+    NumberListValues,
+}
+
+impl PropProfile {
+    /// The profiles of the props that can be used where a prop of this profile is expected,
+    /// e.g., a `Number` prop or a string can be used where `Math` is expected.
+    ///
+    /// This is the coercion matrix used when props are calculated from children or attributes.
+    pub fn coercible_profiles(self) -> Vec<PropProfile> {
+        match self {
+            PropProfile::Number => {
+                vec![PropProfile::Number, PropProfile::Math, PropProfile::String]
+            }
+            PropProfile::Math => vec![
+                PropProfile::String,
+                PropProfile::Math,
+                PropProfile::Number,
+                PropProfile::Point,
+            ],
+            PropProfile::Boolean => vec![PropProfile::String, PropProfile::Boolean],
+            profile => vec![profile],
+        }
+    }
 }
 
 /// Returns the value type that corresponds to each `PropProfile`.
@@ -80,8 +131,10 @@ pub const fn prop_profile_to_type(profile: PropProfile) -> PropValueType {
         PropProfile::Math => PropValueType::Math,
         PropProfile::Integer => PropValueType::Integer,
         PropProfile::Boolean => PropValueType::Boolean,
+        PropProfile::Point => PropValueType::Math,
         PropProfile::Hidden => PropValueType::Boolean,
         PropProfile::Fixed => PropValueType::Boolean,
+        PropProfile::Disabled => PropValueType::Boolean,
         PropProfile::SerialNumber => PropValueType::Integer,
         PropProfile::DivisionCodeNumber => PropValueType::String,
         PropProfile::DivisionDepth => PropValueType::Integer,
@@ -96,5 +149,15 @@ pub const fn prop_profile_to_type(profile: PropProfile) -> PropValueType {
         PropProfile::_Ref => PropValueType::ComponentRef,
         PropProfile::XrefLabel => PropValueType::XrefLabel,
         PropProfile::XrefDisplayContent => PropValueType::AnnotatedContentRefs,
+        PropProfile::TabTitle => PropValueType::String,
+        PropProfile::Answered => PropValueType::Boolean,
+        PropProfile::CompletionFraction => PropValueType::Number,
+        PropProfile::Response => PropValueType::String,
+        PropProfile::CreditAchieved => PropValueType::Number,
+        PropProfile::SolutionRevealed => PropValueType::Boolean,
+        PropProfile::RowNumCells => PropValueType::Integer,
+        PropProfile::RowHalign => PropValueType::String,
+        PropProfile::TableHalign => PropValueType::String,
+        PropProfile::NumberListValues => PropValueType::PropVec,
     }
 }
@@ -54,12 +54,17 @@ pub enum PropValue {
     ContentRefs(prop_type::ContentRefs),
     ContentRef(prop_type::ContentRef),
     None(()),
+    #[serde(with = "rc_serde")]
     PropVec(prop_type::PropVec),
     #[serde(with = "rc_serde")]
     XrefLabel(prop_type::XrefLabel),
     ListDepth(prop_type::ListDepth),
     ListMarker(prop_type::ListMarker),
     DivisionType(prop_type::DivisionType),
+    RenderMode(prop_type::RenderMode),
+    Role(prop_type::Role),
+    #[serde(with = "rc_serde")]
+    NumberMatrix(prop_type::NumberMatrix),
 }
 
 /// The discriminating type of a `PropValue`.
@@ -72,7 +77,8 @@ pub mod prop_type {
 
     use super::*;
     use crate::state::types::{
-        component_refs, content_refs, division_type, list_depth, list_marker, xref_label,
+        component_refs, content_refs, division_type, list_depth, list_marker, number_matrix,
+        render_mode, role, xref_label,
     };
 
     /// A macro that declares a type and implements `TypeDiscriminant`.
@@ -130,8 +136,12 @@ pub mod prop_type {
     define_type!(ListDepth, list_depth::ListDepth);
     define_type!(ListMarker, list_marker::ListMarker);
     define_type!(DivisionType, division_type::DivisionType);
+    define_type!(RenderMode, render_mode::RenderMode);
+    define_type!(Role, role::Role);
+    define_type!(NumberMatrix, Rc<number_matrix::NumberMatrix>);
 
-    pub type PropVec = Vec<PropValue>;
+    /// A vector of values, shared rather than copied when the prop is read.
+    pub type PropVec = Rc<Vec<PropValue>>;
 
     /// By default, wasm-bindgen won't pick up this module as containing types to export
     /// to Typescript. We force wasm-bindgen to export types in this module by providing a
@@ -208,6 +218,12 @@ mod conversions {
         }
     }
 
+    impl From<Vec<PropValue>> for PropValue {
+        fn from(v: Vec<PropValue>) -> Self {
+            PropValue::PropVec(Rc::new(v))
+        }
+    }
+
     impl From<MathExpr> for PropValue {
         fn from(v: MathExpr) -> Self {
             PropValue::Math(Rc::new(v))
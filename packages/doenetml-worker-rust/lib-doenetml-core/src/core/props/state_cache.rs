@@ -57,6 +57,14 @@ impl StateCache {
         })
     }
 
+    /// Get the value of a state prop without tracking that it has been queried,
+    /// e.g., in order to save it.
+    pub fn peek_state<A: borrow::Borrow<GraphNode>>(&self, state_node: A) -> PropWithMeta {
+        // The origin does not matter when the query is not tracked.
+        self.prop_cache
+            .get_prop_unchecked_untracked(state_node, GraphNode::Query(0))
+    }
+
     /// Set the value of a state prop. `origin` is the `GraphNode::DataQuery` that requested the state prop.
     /// The store tracks and reports if the value has changed since the last time it was queried.
     pub fn set_state<A: borrow::Borrow<GraphNode>>(&self, state_node: A, value: PropValue) {
@@ -64,6 +72,13 @@ impl StateCache {
         self.prop_cache
             .set_prop(state_node, PropCalcResult::Calculated(value));
     }
+
+    /// Reset a state prop to `value`, which is its default value, so that it is marked as `came_from_default`.
+    pub fn reset_state<A: borrow::Borrow<GraphNode>>(&self, state_node: A, value: PropValue) {
+        let state_node = state_node.borrow();
+        self.prop_cache
+            .set_prop(state_node, PropCalcResult::FromDefault(value));
+    }
 }
 
 impl Default for StateCache {
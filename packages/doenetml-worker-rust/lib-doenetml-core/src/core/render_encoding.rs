@@ -0,0 +1,53 @@
+//! Binary encodings of the rendered document (the `FlatDast` and its updates) that a host can select
+//! instead of receiving the rendered document as a Javascript object.
+//!
+//! Large render trees are expensive to convert into Javascript objects one value at a time.
+//! Instead, the typed render nodes are serialized directly into a single buffer,
+//! without building an intermediate `serde_json::Value`, which the host decodes in one pass.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+/// The encoding of a rendered document returned to the host.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+#[cfg_attr(feature = "web", tsify(from_wasm_abi))]
+pub enum RenderOutputFormat {
+    /// UTF-8 encoded JSON.
+    #[default]
+    Json,
+    /// MessagePack, with structs encoded as maps keyed by their field names,
+    /// so that it decodes to the same objects as the JSON encoding.
+    MessagePack,
+}
+
+/// An error encoding a rendered document.
+#[derive(Debug, Error, PartialEq)]
+pub enum RenderEncodingError {
+    #[error("the rendered document could not be encoded as {format:?}: {message}")]
+    Encoding {
+        format: RenderOutputFormat,
+        message: String,
+    },
+}
+
+/// Serialize `value`, e.g., a `FlatDastRoot`, in the encoding given by `format`.
+pub fn encode_render_output<T: Serialize + ?Sized>(
+    value: &T,
+    format: RenderOutputFormat,
+) -> Result<Vec<u8>, RenderEncodingError> {
+    let encoded = match format {
+        RenderOutputFormat::Json => serde_json::to_vec(value).map_err(|err| err.to_string()),
+        RenderOutputFormat::MessagePack => {
+            rmp_serde::to_vec_named(value).map_err(|err| err.to_string())
+        }
+    };
+    encoded.map_err(|message| RenderEncodingError::Encoding { format, message })
+}
+
+#[cfg(test)]
+#[path = "render_encoding.test.rs"]
+mod test;
@@ -0,0 +1,30 @@
+use super::*;
+use crate::{Core, test_utils::dast_root_no_position};
+
+fn rendered_document() -> crate::dast::FlatDastRoot {
+    let dast_root = dast_root_no_position(
+        r#"<p>Hello <text name="t">world</text></p><boolean name="b">true</boolean>"#,
+    );
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast()
+}
+
+#[test]
+fn json_encoding_matches_serde_json() {
+    let flat_dast = rendered_document();
+    let encoded = encode_render_output(&flat_dast, RenderOutputFormat::Json).unwrap();
+    assert_eq!(encoded, serde_json::to_vec(&flat_dast).unwrap());
+}
+
+#[test]
+fn message_pack_encoding_decodes_to_the_json_objects() {
+    let flat_dast = rendered_document();
+    let encoded = encode_render_output(&flat_dast, RenderOutputFormat::MessagePack).unwrap();
+    let decoded: serde_json::Value = rmp_serde::from_slice(&encoded).unwrap();
+    assert_eq!(decoded, serde_json::to_value(&flat_dast).unwrap());
+
+    // The binary encoding is more compact than JSON.
+    let json = encode_render_output(&flat_dast, RenderOutputFormat::Json).unwrap();
+    assert!(encoded.len() < json.len());
+}
@@ -0,0 +1,49 @@
+//! An immutable snapshot of a resolved document, so that a server can share one document across threads.
+//!
+//! `Core` tracks its state with `RefCell`s and `Rc`s, so it can be neither sent to nor shared with another thread.
+//! Rather than converting all of its interior mutability to locks, a `DocumentSnapshot` resolves everything
+//! that read-only render and score requests need up front and stores it as plain data,
+//! so that it is `Send + Sync` and can be shared, e.g., in an `Arc`.
+//! In particular, the values of props hold `Rc`s, so the rendered document is stored as JSON rather than as `FlatDastRoot`.
+
+use serde::Serialize;
+
+use super::{Core, DocumentStats, ObjectiveReport};
+
+/// The rendered document and its scores at the time the snapshot was taken.
+/// Later changes to the `Core` it was taken from are not reflected in the snapshot.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSnapshot {
+    /// The document rendered as `FlatDast`, as returned by `Core::to_flat_dast`, serialized to JSON.
+    pub flat_dast: serde_json::Value,
+    /// The credit earned on each learning objective, as returned by `Core::get_objective_report`.
+    pub objective_report: ObjectiveReport,
+    /// Summary statistics of the document, as returned by `Core::document_stats`.
+    pub stats: DocumentStats,
+}
+
+// A snapshot is useless to a server unless it can be shared across threads.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<DocumentSnapshot>();
+};
+
+impl Core {
+    /// Resolve the document and take an immutable snapshot of it that can be shared across threads.
+    ///
+    /// Since this renders the document, any pending updates for the renderer are consumed,
+    /// just as with `to_flat_dast`.
+    pub fn snapshot(&mut self) -> DocumentSnapshot {
+        DocumentSnapshot {
+            flat_dast: serde_json::to_value(self.to_flat_dast())
+                .expect("FlatDast can always be serialized to JSON"),
+            objective_report: self.get_objective_report(),
+            stats: self.document_stats(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "snapshot.test.rs"]
+mod test;
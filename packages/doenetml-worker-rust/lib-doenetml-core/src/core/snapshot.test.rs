@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use serde_json::json;
+
+use crate::test_utils::dast_root_no_position;
+
+use super::*;
+
+#[test]
+fn snapshot_can_be_shared_across_threads() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(
+        r#"<p>Hello</p><number objective="add">1</number>"#,
+    ));
+    let snapshot = Arc::new(core.snapshot());
+
+    let renders = std::thread::scope(|scope| {
+        let handles = (0..4)
+            .map(|_| {
+                let snapshot = Arc::clone(&snapshot);
+                scope.spawn(move || {
+                    (
+                        snapshot.flat_dast["elements"][1]["children"].clone(),
+                        snapshot.objective_report.objectives.len(),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    for (children, num_objectives) in renders {
+        assert_eq!(children, json!(["Hello"]));
+        assert_eq!(num_objectives, 1);
+    }
+}
+
+#[test]
+fn snapshot_does_not_change_with_core() {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(r#"<p>Hello</p>"#));
+    let snapshot = core.snapshot();
+
+    core.init_from_dast_root(&dast_root_no_position(r#"<p>Bye</p>"#));
+
+    assert_eq!(
+        snapshot.flat_dast["elements"][1]["children"],
+        json!(["Hello"])
+    );
+}
@@ -2,6 +2,8 @@ use std::fmt::{Display, Formatter};
 
 use strum_macros::EnumString;
 
+use crate::general_prop::EnumAttributeValue;
+
 /// A `<division>` is a stand in for multiple other elements, e.g. `<section>`, `<chapter>`, etc.
 /// This enum distinguishes between different types of divisions.
 #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, EnumString)]
@@ -39,6 +41,21 @@ impl DivisionType {
     }
 }
 
+impl EnumAttributeValue for DivisionType {
+    const VALID_VALUES: &'static [&'static str] = &[
+        "part",
+        "chapter",
+        "section",
+        "subsection",
+        "subsubsection",
+        "paragraphs",
+    ];
+
+    fn parse_attribute_value(value: &str) -> Option<Self> {
+        value.trim().parse().ok()
+    }
+}
+
 impl Display for DivisionType {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         let display_name = match self {
@@ -1,3 +1,8 @@
+use crate::general_prop::EnumAttributeValue;
+
+/// The largest index that is written using roman numerals. (Classical roman numerals stop at `MMMCMXCIX`.)
+pub const MAX_ROMAN_NUMERAL: u64 = 3999;
+
 /// A list marker. This is used by both `<ol>` and `<ul>` elements.
 /// They are named similarly to their [HTML equivalents](https://developer.mozilla.org/en-US/docs/Web/CSS/list-style-type).
 #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -45,10 +50,15 @@ impl ListMarker {
     /// If `0` or negative numbers are used for any method other than `ListMarker::Decimal`,
     /// the function will not error, but results may vary. (`ListMarker::*Alpha` will return an empty string,
     /// `ListMarker::*Roman` will prefix the result with `-`.)
+    ///
+    /// Roman numerals are only used up to `MAX_ROMAN_NUMERAL`; larger indices are written as decimals.
     pub fn index_to_string(&self, index: i64) -> String {
         match self {
             ListMarker::Decimal { .. } => index.to_string(),
             ListMarker::LowerAlpha => {
+                if index < 1 {
+                    return String::new();
+                }
                 let mut index = index - 1;
                 let mut result = String::new();
                 while index >= 0 {
@@ -69,7 +79,10 @@ impl ListMarker {
                 } else {
                     "-".to_string()
                 };
-                let mut index = index.abs();
+                if index.unsigned_abs() > MAX_ROMAN_NUMERAL {
+                    return index.to_string();
+                }
+                let mut index = index.unsigned_abs();
                 if index == 0 {
                     // No Roman numeral for 0, but we make do.
                     return "o".to_string();
@@ -150,22 +163,45 @@ impl Default for ListMarker {
 
 impl From<&str> for ListMarker {
     fn from(s: &str) -> Self {
-        match s {
-            "1" => Self::Decimal { start: 1 },
-            "0" => Self::Decimal { start: 0 },
-            "a" => Self::LowerAlpha,
-            "i" => Self::LowerRoman,
-            "A" => Self::UpperAlpha,
-            "I" => Self::UpperRoman,
-            "decimal" => Self::Decimal { start: 1 },
-            "lower-alpha" => Self::LowerAlpha,
-            "lower-roman" => Self::LowerRoman,
-            "upper-alpha" => Self::UpperAlpha,
-            "upper-roman" => Self::UpperRoman,
-            "disc" => Self::Disc,
-            "circle" => Self::Circle,
-            "square" => Self::Square,
-            _ => Self::Decimal { start: 1 },
+        Self::parse_attribute_value(s).unwrap_or_default()
+    }
+}
+
+impl EnumAttributeValue for ListMarker {
+    const VALID_VALUES: &'static [&'static str] = &[
+        "1",
+        "0",
+        "a",
+        "i",
+        "A",
+        "I",
+        "decimal",
+        "lower-alpha",
+        "lower-roman",
+        "upper-alpha",
+        "upper-roman",
+        "disc",
+        "circle",
+        "square",
+    ];
+
+    fn parse_attribute_value(value: &str) -> Option<Self> {
+        match value.trim() {
+            "1" => Some(Self::Decimal { start: 1 }),
+            "0" => Some(Self::Decimal { start: 0 }),
+            "a" => Some(Self::LowerAlpha),
+            "i" => Some(Self::LowerRoman),
+            "A" => Some(Self::UpperAlpha),
+            "I" => Some(Self::UpperRoman),
+            "decimal" => Some(Self::Decimal { start: 1 }),
+            "lower-alpha" => Some(Self::LowerAlpha),
+            "lower-roman" => Some(Self::LowerRoman),
+            "upper-alpha" => Some(Self::UpperAlpha),
+            "upper-roman" => Some(Self::UpperRoman),
+            "disc" => Some(Self::Disc),
+            "circle" => Some(Self::Circle),
+            "square" => Some(Self::Square),
+            _ => None,
         }
     }
 }
@@ -214,5 +250,12 @@ mod tests {
         assert_eq!(ListMarker::LowerRoman.index_to_string(9), "ix");
         assert_eq!(ListMarker::LowerRoman.index_to_string(24), "xxiv");
         assert_eq!(ListMarker::LowerRoman.index_to_string(-4), "-iv");
+        assert_eq!(ListMarker::UpperRoman.index_to_string(3999), "MMMCMXCIX");
+        assert_eq!(ListMarker::UpperRoman.index_to_string(4000), "4000");
+        assert_eq!(
+            ListMarker::UpperRoman.index_to_string(i64::MIN),
+            i64::MIN.to_string()
+        );
+        assert_eq!(ListMarker::LowerAlpha.index_to_string(i64::MIN), "");
     }
 }
@@ -4,4 +4,7 @@ pub mod division_type;
 pub mod list_depth;
 pub mod list_marker;
 pub mod math_expr;
+pub mod number_matrix;
+pub mod render_mode;
+pub mod role;
 pub mod xref_label;
@@ -0,0 +1,93 @@
+/// A rectangular array of numbers, stored row by row.
+/// Every row has the same number of entries; a missing entry is `NaN`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+pub struct NumberMatrix {
+    rows: Vec<Vec<f64>>,
+}
+
+impl NumberMatrix {
+    /// Create a matrix from `rows`. Rows shorter than the longest row are padded with `NaN`.
+    pub fn new(mut rows: Vec<Vec<f64>>) -> Self {
+        let num_columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in rows.iter_mut() {
+            row.resize(num_columns, f64::NAN);
+        }
+        NumberMatrix { rows }
+    }
+
+    /// The number of rows.
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The number of columns, i.e., the number of entries in each row.
+    pub fn num_columns(&self) -> usize {
+        self.rows.first().map_or(0, Vec::len)
+    }
+
+    /// The entry in row `row` and column `column`, starting from 0.
+    /// Returns `None` if either is out of range.
+    pub fn get(&self, row: usize, column: usize) -> Option<f64> {
+        self.rows.get(row)?.get(column).copied()
+    }
+
+    /// The rows of the matrix.
+    pub fn rows(&self) -> &[Vec<f64>] {
+        &self.rows
+    }
+
+    /// A Latex representation of the matrix, e.g., `\begin{bmatrix} 1 & 2 \\ 3 & 4 \end{bmatrix}`.
+    /// Missing entries are left blank.
+    pub fn to_latex(&self) -> String {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|entry| {
+                        if entry.is_nan() {
+                            String::new()
+                        } else {
+                            entry.to_string()
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" & ")
+            })
+            .collect::<Vec<_>>()
+            .join(r" \\ ");
+        format!(r"\begin{{bmatrix}} {rows} \end{{bmatrix}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_rows_are_padded() {
+        let matrix = NumberMatrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0]]);
+        assert_eq!(matrix.num_rows(), 2);
+        assert_eq!(matrix.num_columns(), 3);
+        assert_eq!(matrix.get(0, 2), Some(3.0));
+        assert!(matrix.get(1, 2).unwrap().is_nan());
+        assert_eq!(matrix.get(2, 0), None);
+    }
+
+    #[test]
+    fn empty_matrix_has_no_rows_or_columns() {
+        let matrix = NumberMatrix::new(vec![]);
+        assert_eq!(matrix.num_rows(), 0);
+        assert_eq!(matrix.num_columns(), 0);
+    }
+
+    #[test]
+    fn latex_leaves_missing_entries_blank() {
+        let matrix = NumberMatrix::new(vec![vec![1.0, 2.5], vec![-3.0]]);
+        assert_eq!(
+            matrix.to_latex(),
+            r"\begin{bmatrix} 1 & 2.5 \\ -3 &  \end{bmatrix}"
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// The medium the document is rendered for. Interactive components render different props
+/// depending on the mode, e.g., in print a `<textInput>` renders its current value (or a blank)
+/// rather than an input box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+pub enum RenderMode {
+    /// Rendered in a browser, where readers can interact with the document.
+    #[default]
+    Interactive,
+    /// Rendered for print or PDF, where interaction is impossible.
+    Print,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// The role of the person viewing the document. Some content is shown only to certain roles,
+/// e.g., the contents of a `<forInstructor>` are never rendered for students.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(tsify_next::Tsify))]
+pub enum Role {
+    #[default]
+    Student,
+    Instructor,
+    /// Someone who assesses student work, e.g., a teaching assistant.
+    Grader,
+}
@@ -0,0 +1,103 @@
+//! The binary format in which a document's saved state is handed to a platform for storage.
+//!
+//! A blob consists of a header followed by the payload:
+//! - 4 bytes: the magic bytes `DMLS`,
+//! - 1 byte: the version of the format (currently `1`),
+//! - 1 byte: flags; bit `0` is set if the payload is encrypted,
+//! - the payload: the [`SavedState`] serialized as JSON, encrypted if a [`StateEncryption`] was supplied.
+//!
+//! Core handles the framing and serialization, so that a platform with privacy requirements only needs to supply
+//! the encryption itself.
+
+use std::fmt::Debug;
+
+use thiserror::Error;
+
+use super::SavedState;
+
+const MAGIC: &[u8; 4] = b"DMLS";
+const FORMAT_VERSION: u8 = 1;
+const HEADER_LEN: usize = 6;
+const FLAG_ENCRYPTED: u8 = 0b1;
+
+/// Encryption supplied by a platform that is applied to the saved state of a document,
+/// e.g., to encrypt student work at rest.
+pub trait StateEncryption: Debug {
+    /// Encrypt the serialized state `plaintext`.
+    fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, String>;
+    /// Decrypt `ciphertext` that was produced by `encrypt`.
+    fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, String>;
+}
+
+/// An error encoding or decoding a state blob.
+#[derive(Debug, Error, PartialEq)]
+pub enum StateBlobError {
+    #[error("the data is not a saved DoenetML state")]
+    NotAStateBlob,
+    #[error("saved state version {0} is not supported")]
+    UnsupportedVersion(u8),
+    #[error("the saved state is encrypted, but no encryption was supplied to decrypt it")]
+    MissingEncryption,
+    #[error("encryption failed: {0}")]
+    Encryption(String),
+    #[error("the saved state is invalid: {0}")]
+    InvalidState(String),
+}
+
+/// Serialize `saved_state` into a blob, encrypting the payload with `encryption` if supplied.
+pub fn encode_state_blob(
+    saved_state: &SavedState,
+    encryption: Option<&dyn StateEncryption>,
+) -> Result<Vec<u8>, StateBlobError> {
+    let payload = serde_json::to_vec(saved_state)
+        .map_err(|err| StateBlobError::InvalidState(err.to_string()))?;
+    let (flags, payload) = match encryption {
+        Some(encryption) => (
+            FLAG_ENCRYPTED,
+            encryption
+                .encrypt(&payload)
+                .map_err(StateBlobError::Encryption)?,
+        ),
+        None => (0, payload),
+    };
+
+    let mut blob = Vec::with_capacity(HEADER_LEN + payload.len());
+    blob.extend_from_slice(MAGIC);
+    blob.push(FORMAT_VERSION);
+    blob.push(flags);
+    blob.extend_from_slice(&payload);
+    Ok(blob)
+}
+
+/// Deserialize a blob created by [`encode_state_blob`], decrypting the payload with `encryption` if it is encrypted.
+pub fn decode_state_blob(
+    blob: &[u8],
+    encryption: Option<&dyn StateEncryption>,
+) -> Result<SavedState, StateBlobError> {
+    if blob.len() < HEADER_LEN || &blob[..4] != MAGIC {
+        return Err(StateBlobError::NotAStateBlob);
+    }
+    let version = blob[4];
+    if version != FORMAT_VERSION {
+        return Err(StateBlobError::UnsupportedVersion(version));
+    }
+    let flags = blob[5];
+    let payload = &blob[HEADER_LEN..];
+
+    let decrypted;
+    let payload = if flags & FLAG_ENCRYPTED != 0 {
+        let encryption = encryption.ok_or(StateBlobError::MissingEncryption)?;
+        decrypted = encryption
+            .decrypt(payload)
+            .map_err(StateBlobError::Encryption)?;
+        &decrypted[..]
+    } else {
+        payload
+    };
+
+    serde_json::from_slice(payload).map_err(|err| StateBlobError::InvalidState(err.to_string()))
+}
+
+#[cfg(test)]
+#[path = "state_blob.test.rs"]
+mod test;
@@ -0,0 +1,96 @@
+use crate::components::types::ComponentIdx;
+
+use super::*;
+use crate::core::SavedStateValue;
+
+/// A reversible stand-in for encryption that flips every bit.
+#[derive(Debug)]
+struct FlipBits;
+
+impl StateEncryption for FlipBits {
+    fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, String> {
+        Ok(plaintext.iter().map(|byte| !byte).collect())
+    }
+    fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, String> {
+        Ok(ciphertext.iter().map(|byte| !byte).collect())
+    }
+}
+
+#[derive(Debug)]
+struct FailingEncryption;
+
+impl StateEncryption for FailingEncryption {
+    fn encrypt(&self, _plaintext: &[u8]) -> Result<Vec<u8>, String> {
+        Err("no key".to_string())
+    }
+    fn decrypt(&self, _ciphertext: &[u8]) -> Result<Vec<u8>, String> {
+        Err("no key".to_string())
+    }
+}
+
+fn saved_state() -> SavedState {
+    SavedState {
+        values: vec![SavedStateValue {
+            component_idx: ComponentIdx::new(1),
+            prop_name: "value".to_string(),
+            value: serde_json::json!("a secret answer"),
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn unencrypted_state_round_trips() {
+    let blob = encode_state_blob(&saved_state(), None).unwrap();
+    assert_eq!(&blob[..6], b"DMLS\x01\x00");
+    assert_eq!(decode_state_blob(&blob, None).unwrap(), saved_state());
+}
+
+#[test]
+fn encrypted_state_round_trips() {
+    let blob = encode_state_blob(&saved_state(), Some(&FlipBits)).unwrap();
+    assert_eq!(&blob[..6], b"DMLS\x01\x01");
+    assert!(!String::from_utf8_lossy(&blob).contains("secret"));
+    assert_eq!(
+        decode_state_blob(&blob, Some(&FlipBits)).unwrap(),
+        saved_state()
+    );
+
+    assert_eq!(
+        decode_state_blob(&blob, None),
+        Err(StateBlobError::MissingEncryption)
+    );
+}
+
+#[test]
+fn unencrypted_state_can_be_loaded_when_encryption_is_set() {
+    let blob = encode_state_blob(&saved_state(), None).unwrap();
+    assert_eq!(
+        decode_state_blob(&blob, Some(&FlipBits)).unwrap(),
+        saved_state()
+    );
+}
+
+#[test]
+fn encryption_errors_are_reported() {
+    assert_eq!(
+        encode_state_blob(&saved_state(), Some(&FailingEncryption)),
+        Err(StateBlobError::Encryption("no key".to_string()))
+    );
+}
+
+#[test]
+fn invalid_blobs_are_rejected() {
+    assert_eq!(
+        decode_state_blob(b"{}", None),
+        Err(StateBlobError::NotAStateBlob)
+    );
+    assert_eq!(
+        decode_state_blob(b"DMLS\x02\x00{}", None),
+        Err(StateBlobError::UnsupportedVersion(2))
+    );
+    assert!(matches!(
+        decode_state_blob(b"DMLS\x01\x00not json", None),
+        Err(StateBlobError::InvalidState(_))
+    ));
+}
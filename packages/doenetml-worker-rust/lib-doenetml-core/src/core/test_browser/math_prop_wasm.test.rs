@@ -1,14 +1,15 @@
 use std::rc::Rc;
 
 use crate::{
+    components::types::LocalPropIdx,
     embed_test,
     general_prop::{
         self,
         test_utils::{
             assert_math_calculated_value, assert_math_default_result,
-            return_empty_data_query_result, return_single_boolean_data_query_result,
-            return_single_math_data_query_result, return_single_number_data_query_result,
-            return_single_string_data_query_result,
+            assert_string_calculated_value, return_empty_data_query_result,
+            return_single_boolean_data_query_result, return_single_math_data_query_result,
+            return_single_number_data_query_result, return_single_string_data_query_result,
         },
     },
     props::{
@@ -487,4 +488,22 @@ pub fn add_math_prop_wasm_tests(all_tests: &mut Vec<String>, test_name: &str) {
             );
         }
     );
+
+    embed_test!(
+        all_tests,
+        test_name,
+        fn math_to_text_prop_from_math() {
+            let prop = as_updater_object::<_, prop_type::String>(
+                general_prop::MathToTextProp::new(LocalPropIdx::new(0)),
+            );
+
+            let math = return_single_math_data_query_result(
+                Rc::new(MathExpr::from_text("xy", true, &["f"])),
+                false,
+            );
+            let data = DataQueryResults::from_vec(vec![math]);
+
+            assert_string_calculated_value(prop.calculate_untyped(data), "x y");
+        }
+    );
 }
@@ -3,7 +3,7 @@ use serde::{
     ser::{SerializeMap, SerializeStruct},
 };
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 #[cfg(feature = "web")]
 use tsify_next::Tsify;
 #[cfg(feature = "web")]
@@ -13,7 +13,12 @@ use thiserror::Error;
 
 use crate::{dast::flat_dast::SourceDoc, props::PropValue};
 
-use super::flat_dast::ErrorType;
+use super::{
+    diagnostics::{
+        DiagnosticCode, default_warning_count, default_warning_severity, is_single_occurrence,
+    },
+    flat_dast::ErrorType,
+};
 
 /// Dast root node
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,7 +88,7 @@ pub enum DastTextRefElementContent {
 pub struct DastElement {
     pub name: String,
 
-    pub attributes: HashMap<String, DastAttribute>,
+    pub attributes: BTreeMap<String, DastAttribute>,
 
     pub children: Vec<DastElementContent>,
 
@@ -101,7 +106,7 @@ impl DastElement {
     pub fn with_name(name: &str) -> Self {
         DastElement {
             name: name.to_string(),
-            attributes: HashMap::new(),
+            attributes: BTreeMap::new(),
             children: Vec::new(),
             data: None,
             position: None,
@@ -123,8 +128,129 @@ pub struct ElementData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 
+    /// For errors, the code identifying the kind of error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub code: Option<DiagnosticCode>,
+
+    /// For errors, a suggested fix.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub suggestion: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none", skip_deserializing)]
     pub props: Option<ForRenderProps>,
+
+    /// The keyboard shortcuts that renderers should bind to the actions of the element.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub keyboard_shortcuts: Option<Vec<KeyboardShortcut>>,
+
+    /// What a renderer of the element must be able to do. Omitted if the element has the default capabilities.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub capabilities: Option<ComponentCapabilities>,
+}
+
+/// What a renderer must be able to do to render a component type as intended,
+/// so that hosts lacking a capability can substitute a fallback, e.g., a static image of a `<graph>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct ComponentCapabilities {
+    /// The component is interacted with by clicking or dragging, e.g., a `<slider>`.
+    pub needs_pointer_events: bool,
+    /// The component is interacted with by typing, e.g., a `<textInput>`.
+    pub needs_keyboard: bool,
+    /// The component can be rendered in a printed document.
+    pub supports_print: bool,
+    /// The component can be rendered on a server, without running scripts in a browser.
+    pub supports_ssr: bool,
+}
+
+impl ComponentCapabilities {
+    /// The capabilities of a component that needs no input and can be rendered anywhere.
+    pub const DEFAULT: ComponentCapabilities = ComponentCapabilities {
+        needs_pointer_events: false,
+        needs_keyboard: false,
+        supports_print: true,
+        supports_ssr: true,
+    };
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::DEFAULT
+    }
+}
+
+impl Default for ComponentCapabilities {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Where a keyboard shortcut is active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub enum ShortcutScope {
+    /// The shortcut is active only while the element has focus, e.g., `Enter` in a `<textInput>`.
+    Focused,
+    /// The shortcut is active anywhere in the document.
+    Document,
+}
+
+/// A keyboard shortcut that calls an action of an element.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub struct KeyboardShortcut {
+    /// The keys to press, e.g., `Enter` or `Alt+PageDown`. Modifiers come first, in the order
+    /// `Ctrl`, `Alt`, `Shift`, `Meta`, and are joined to the key with `+`.
+    pub keys: String,
+    /// The name of the action to call when the keys are pressed.
+    pub action_name: String,
+    pub scope: ShortcutScope,
+}
+
+impl KeyboardShortcut {
+    /// Create a shortcut calling `action_name` when `keys` are pressed.
+    /// The modifiers in `keys` may be in any order and case, e.g., `shift+ctrl+k` becomes `Ctrl+Shift+k`.
+    pub fn new(keys: &str, action_name: &str, scope: ShortcutScope) -> Self {
+        const MODIFIERS: [(&str, &[&str]); 4] = [
+            ("Ctrl", &["ctrl", "control"]),
+            ("Alt", &["alt", "option"]),
+            ("Shift", &["shift"]),
+            ("Meta", &["meta", "cmd", "command"]),
+        ];
+
+        let parts = keys.split('+').map(str::trim).collect::<Vec<_>>();
+        let (key, modifiers) = parts.split_last().unwrap_or((&"", &[]));
+        let mut normalized = MODIFIERS
+            .iter()
+            .filter(|(_, aliases)| {
+                modifiers
+                    .iter()
+                    .any(|modifier| aliases.contains(&modifier.to_lowercase().as_str()))
+            })
+            .map(|(name, _)| name.to_string())
+            .collect::<Vec<_>>();
+        normalized.push(if key.chars().count() == 1 {
+            key.to_lowercase()
+        } else {
+            key.to_string()
+        });
+
+        KeyboardShortcut {
+            keys: normalized.join("+"),
+            action_name: action_name.to_string(),
+            scope,
+        }
+    }
+
+    /// Whether this shortcut and `other` are triggered by the same keys.
+    pub fn has_same_keys(&self, other: &KeyboardShortcut) -> bool {
+        self.keys.eq_ignore_ascii_case(&other.keys)
+    }
 }
 
 /// A partial implementation of PartialEq for ElementData
@@ -281,7 +407,7 @@ impl DastAttribute {
 #[cfg_attr(feature = "web", derive(Tsify))]
 pub struct DastRef {
     pub path: Vec<PathPart>,
-    pub attributes: HashMap<String, DastAttribute>,
+    pub attributes: BTreeMap<String, DastAttribute>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub position: Option<Position>,
@@ -353,6 +479,15 @@ pub struct DastError {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_type: Option<ErrorType>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub code: Option<DiagnosticCode>,
+
+    /// A suggested fix, e.g., the correct spelling of a misspelled name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub suggestion: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub position: Option<Position>,
 
@@ -462,7 +597,7 @@ pub enum ElementRefAnnotation {
 pub struct FlatDastElement {
     pub name: String,
 
-    pub attributes: HashMap<String, DastAttribute>,
+    pub attributes: BTreeMap<String, DastAttribute>,
 
     pub children: Vec<FlatDastElementContent>,
 
@@ -536,6 +671,25 @@ impl Serialize for FlatDastElement {
 pub struct DastWarning {
     pub message: String,
 
+    /// How serious the warning is. Warnings never prevent a document from rendering,
+    /// but an `Error` severity indicates that content is missing from the rendered document.
+    #[serde(default = "default_warning_severity")]
+    pub severity: ErrorType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub code: Option<DiagnosticCode>,
+
+    /// A suggested fix, e.g., the correct spelling of a misspelled name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub suggestion: Option<String>,
+
+    /// The number of times this warning occurred in the document.
+    #[serde(default = "default_warning_count")]
+    #[serde(skip_serializing_if = "is_single_occurrence")]
+    pub count: usize,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub position: Option<Position>,
 
@@ -545,7 +699,7 @@ pub struct DastWarning {
 
 /// An update to a single element in the Dast tree.
 /// It may contain changes to the element's attributes, children, or `data.state`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[serde(rename = "elementUpdate")]
 #[serde(rename_all = "camelCase")]
@@ -556,6 +710,10 @@ pub struct FlatDastElementUpdate {
 
     #[serde(skip_serializing_if = "Option::is_none", skip_deserializing)]
     pub changed_state: Option<ForRenderProps>,
+
+    /// Whether the element should receive keyboard focus once the update is rendered.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub focus: bool,
 }
 
 #[derive(Debug, Error)]
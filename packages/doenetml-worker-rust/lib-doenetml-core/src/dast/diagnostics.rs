@@ -0,0 +1,190 @@
+//! Stable identifiers for the errors and warnings produced while processing a DoenetML document,
+//! and utilities for presenting warnings to authors.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "web")]
+use tsify_next::Tsify;
+
+use super::{DastWarning, FlatDastRoot, flat_dast::ErrorType};
+
+/// A stable code identifying the kind of an error or warning. Unlike the message,
+/// which may change wording, the code may be relied upon by authoring tools.
+///
+/// Codes are serialized as strings of the form `DMLxxxx`, where the first digit indicates the stage
+/// of processing that produced the diagnostic:
+/// - `DML1xxx`: parsing the DoenetML source,
+/// - `DML2xxx`: resolving references,
+/// - `DML3xxx`: extending and copying components,
+/// - `DML4xxx`: looking up component types and props by name,
+/// - `DML5xxx`: analyzing the content of a document for authors.
+///
+/// Once assigned, a code is never reused for a different kind of diagnostic.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "web", derive(Tsify))]
+pub enum DiagnosticCode {
+    /// The DoenetML source could not be parsed.
+    #[serde(rename = "DML1001")]
+    InvalidDoenetML,
+    /// A reference such as `$foo` did not match any component.
+    #[serde(rename = "DML2001")]
+    NoReferent,
+    /// A reference such as `$foo` matched more than one component.
+    #[serde(rename = "DML2002")]
+    NonUniqueReferent,
+    /// A reference to a prop, such as `$p.x`, was given where only a reference to a component is allowed,
+    /// e.g., in the `ref` attribute of an `<xref>`.
+    #[serde(rename = "DML2003")]
+    ComponentReferenceExpected,
+    /// An `extend` or `copy` attribute was not a single reference to a component.
+    #[serde(rename = "DML3001")]
+    InvalidExtendAttribute,
+    /// An element had more than one `extend` or `copy` attribute.
+    #[serde(rename = "DML3002")]
+    DuplicateExtendAttribute,
+    /// A component extended something it cannot extend, e.g., an external component or an error.
+    #[serde(rename = "DML3003")]
+    InvalidExtendSource,
+    /// An element's tag name is not a DoenetML component type, so it was passed through unchanged,
+    /// yet the name closely resembles a component type.
+    #[serde(rename = "DML4001")]
+    UnknownComponentType,
+    /// A reference such as `$p.foo` named a prop that does not exist on the referenced component.
+    #[serde(rename = "DML4002")]
+    PropDoesNotExist,
+    /// An attribute that accepts only certain values, such as the `type` of a `<division>`, had another value.
+    #[serde(rename = "DML4003")]
+    InvalidAttributeValue,
+    /// A component was used where its value cannot be converted to the expected type, e.g., a `<boolean>`
+    /// in the `x` attribute of a `<point>`, so it was ignored.
+    #[serde(rename = "DML4004")]
+    IncompatibleContent,
+    /// An experimental component was used without being enabled by the `experimentalComponents` flag.
+    #[serde(rename = "DML4005")]
+    ExperimentalComponentNotEnabled,
+    /// A component is hidden by a condition that cannot change, so it is never rendered.
+    #[serde(rename = "DML5001")]
+    AlwaysHidden,
+    /// A component is not rendered and none of its values are used by rendered content.
+    #[serde(rename = "DML5002")]
+    NeverRendered,
+    /// Tags or attributes that are not allowed in raw HTML were removed.
+    #[serde(rename = "DML5003")]
+    DisallowedHtmlRemoved,
+    /// Two components declared the same document-wide keyboard shortcut, so the later one was disabled.
+    #[serde(rename = "DML5004")]
+    KeyboardShortcutConflict,
+}
+
+impl DiagnosticCode {
+    /// The stable `DMLxxxx` string for this code.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiagnosticCode::InvalidDoenetML => "DML1001",
+            DiagnosticCode::NoReferent => "DML2001",
+            DiagnosticCode::NonUniqueReferent => "DML2002",
+            DiagnosticCode::ComponentReferenceExpected => "DML2003",
+            DiagnosticCode::InvalidExtendAttribute => "DML3001",
+            DiagnosticCode::DuplicateExtendAttribute => "DML3002",
+            DiagnosticCode::InvalidExtendSource => "DML3003",
+            DiagnosticCode::UnknownComponentType => "DML4001",
+            DiagnosticCode::PropDoesNotExist => "DML4002",
+            DiagnosticCode::InvalidAttributeValue => "DML4003",
+            DiagnosticCode::IncompatibleContent => "DML4004",
+            DiagnosticCode::ExperimentalComponentNotEnabled => "DML4005",
+            DiagnosticCode::AlwaysHidden => "DML5001",
+            DiagnosticCode::NeverRendered => "DML5002",
+            DiagnosticCode::DisallowedHtmlRemoved => "DML5003",
+            DiagnosticCode::KeyboardShortcutConflict => "DML5004",
+        }
+    }
+}
+
+impl fmt::Display for DiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Format a suggestion offering `options` as replacements, e.g., "Did you mean `a` or `b`?".
+/// Each option is formatted with `format_option`. Returns `None` if there are no options.
+pub fn format_did_you_mean<T>(
+    options: &[T],
+    format_option: impl Fn(&T) -> String,
+) -> Option<String> {
+    let options = options.iter().map(format_option).collect::<Vec<_>>();
+    match options.as_slice() {
+        [] => None,
+        [option] => Some(format!("Did you mean {option}?")),
+        [first, second] => Some(format!("Did you mean {first} or {second}?")),
+        [rest @ .., last] => Some(format!("Did you mean {}, or {last}?", rest.join(", "))),
+    }
+}
+
+/// The default severity of a `DastWarning`.
+pub(super) fn default_warning_severity() -> ErrorType {
+    ErrorType::Warning
+}
+
+/// The default number of occurrences of a `DastWarning`.
+pub(super) fn default_warning_count() -> usize {
+    1
+}
+
+pub(super) fn is_single_occurrence(count: &usize) -> bool {
+    *count == 1
+}
+
+/// Combine identical warnings, preserving the order in which they first appear.
+///
+/// Two warnings are identical if they have the same severity, code, message, and location.
+/// (This happens, for example, when the same invalid content is repeated by multiple copies of a component.)
+/// The `count` of the combined warning is the sum of the counts of the identical warnings.
+pub fn deduplicate_warnings(warnings: impl IntoIterator<Item = DastWarning>) -> Vec<DastWarning> {
+    let mut deduplicated: Vec<DastWarning> = Vec::new();
+    for warning in warnings {
+        match deduplicated
+            .iter_mut()
+            .find(|existing| existing.is_same_warning_as(&warning))
+        {
+            Some(existing) => existing.count += warning.count,
+            None => deduplicated.push(warning),
+        }
+    }
+    deduplicated
+}
+
+impl DastWarning {
+    /// Whether `self` and `other` describe the same warning, ignoring how many times each occurred.
+    pub fn is_same_warning_as(&self, other: &DastWarning) -> bool {
+        self.severity == other.severity
+            && self.code == other.code
+            && self.message == other.message
+            && self.position == other.position
+            && self.source_doc == other.source_doc
+    }
+}
+
+impl FlatDastRoot {
+    /// The warnings that have the code `code`.
+    pub fn warnings_with_code(&self, code: DiagnosticCode) -> impl Iterator<Item = &DastWarning> {
+        self.warnings
+            .iter()
+            .filter(move |warning| warning.code == Some(code))
+    }
+
+    /// The warnings that have the severity `severity`.
+    pub fn warnings_with_severity(
+        &self,
+        severity: ErrorType,
+    ) -> impl Iterator<Item = &DastWarning> {
+        self.warnings
+            .iter()
+            .filter(move |warning| warning.severity == severity)
+    }
+}
+
+#[cfg(test)]
+#[path = "diagnostics.test.rs"]
+mod test;
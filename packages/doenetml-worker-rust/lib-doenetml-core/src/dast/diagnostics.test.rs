@@ -0,0 +1,135 @@
+use super::*;
+
+fn warning(message: &str, code: Option<DiagnosticCode>) -> DastWarning {
+    DastWarning {
+        message: message.to_string(),
+        severity: ErrorType::Warning,
+        code,
+        suggestion: None,
+        count: 1,
+        position: None,
+        source_doc: None,
+    }
+}
+
+#[test]
+fn identical_warnings_are_combined() {
+    let warnings = deduplicate_warnings(vec![
+        warning(
+            "No referent found for reference: $t",
+            Some(DiagnosticCode::NoReferent),
+        ),
+        warning(
+            "Multiple referents found for reference: $s",
+            Some(DiagnosticCode::NonUniqueReferent),
+        ),
+        warning(
+            "No referent found for reference: $t",
+            Some(DiagnosticCode::NoReferent),
+        ),
+    ]);
+
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].message, "No referent found for reference: $t");
+    assert_eq!(warnings[0].count, 2);
+    assert_eq!(warnings[1].code, Some(DiagnosticCode::NonUniqueReferent));
+    assert_eq!(warnings[1].count, 1);
+}
+
+#[test]
+fn warnings_differing_in_severity_are_not_combined() {
+    let mut info = warning("Something happened", None);
+    info.severity = ErrorType::Info;
+
+    let warnings = deduplicate_warnings(vec![warning("Something happened", None), info]);
+
+    assert_eq!(warnings.len(), 2);
+}
+
+#[test]
+fn count_is_serialized_only_for_repeated_warnings() {
+    let mut repeated = warning("Repeated", Some(DiagnosticCode::NoReferent));
+    repeated.count = 3;
+
+    let once = serde_json::to_value(warning("Once", None)).unwrap();
+    assert!(once.get("count").is_none());
+    assert!(once.get("code").is_none());
+
+    let repeated = serde_json::to_value(repeated).unwrap();
+    assert_eq!(repeated["count"], 3);
+    assert_eq!(repeated["code"], "DML2001");
+}
+
+#[test]
+fn can_filter_warnings_by_code_and_severity() {
+    let mut info = warning("Just so you know", None);
+    info.severity = ErrorType::Info;
+    let root = FlatDastRoot {
+        children: vec![],
+        elements: vec![],
+        warnings: vec![
+            warning(
+                "No referent found for reference: $t",
+                Some(DiagnosticCode::NoReferent),
+            ),
+            info,
+        ],
+        position: None,
+    };
+
+    assert_eq!(
+        root.warnings_with_code(DiagnosticCode::NoReferent).count(),
+        1
+    );
+    assert_eq!(
+        root.warnings_with_code(DiagnosticCode::NonUniqueReferent)
+            .count(),
+        0
+    );
+    assert_eq!(root.warnings_with_severity(ErrorType::Info).count(), 1);
+    assert_eq!(root.warnings_with_severity(ErrorType::Error).count(), 0);
+}
+
+#[test]
+fn diagnostic_codes_serialize_to_their_stable_strings() {
+    for code in [
+        DiagnosticCode::InvalidDoenetML,
+        DiagnosticCode::NoReferent,
+        DiagnosticCode::NonUniqueReferent,
+        DiagnosticCode::ComponentReferenceExpected,
+        DiagnosticCode::InvalidExtendAttribute,
+        DiagnosticCode::DuplicateExtendAttribute,
+        DiagnosticCode::InvalidExtendSource,
+        DiagnosticCode::UnknownComponentType,
+        DiagnosticCode::PropDoesNotExist,
+        DiagnosticCode::InvalidAttributeValue,
+        DiagnosticCode::IncompatibleContent,
+        DiagnosticCode::ExperimentalComponentNotEnabled,
+        DiagnosticCode::AlwaysHidden,
+        DiagnosticCode::NeverRendered,
+        DiagnosticCode::DisallowedHtmlRemoved,
+        DiagnosticCode::KeyboardShortcutConflict,
+    ] {
+        assert_eq!(serde_json::to_value(code).unwrap(), code.as_str());
+        assert_eq!(code.to_string(), code.as_str());
+    }
+    assert_eq!(DiagnosticCode::NoReferent.as_str(), "DML2001");
+}
+
+#[test]
+fn did_you_mean_lists_all_options() {
+    let format = |name: &&str| format!("`{name}`");
+    assert_eq!(format_did_you_mean::<&str>(&[], format), None);
+    assert_eq!(
+        format_did_you_mean(&["a"], format).as_deref(),
+        Some("Did you mean `a`?")
+    );
+    assert_eq!(
+        format_did_you_mean(&["a", "b"], format).as_deref(),
+        Some("Did you mean `a` or `b`?")
+    );
+    assert_eq!(
+        format_did_you_mean(&["a", "b", "c"], format).as_deref(),
+        Some("Did you mean `a`, `b`, or `c`?")
+    );
+}
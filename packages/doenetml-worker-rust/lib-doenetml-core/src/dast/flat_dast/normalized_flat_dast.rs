@@ -1,14 +1,14 @@
 //! `NormalizedFlatDast` is a variant of a flat DAST where only `Text`, `Element`, and `Error` children are allowed.
 //! To get to normalized form, all refs and function refs must be expanded.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 #[cfg(feature = "web")]
 use tsify_next::Tsify;
 
 use super::{ErrorType, FlatElement, FlatError, FlatNode, FlatRoot, Index, UntaggedContent};
 
 /// Objects that can be stored in the main `nodes` array of a `NormalizedRoot`.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 #[cfg_attr(feature = "web", derive(Tsify))]
 pub enum NormalizedNode {
@@ -22,6 +22,8 @@ impl Default for NormalizedNode {
             parent: None,
             message: "DEFAULT NODE".to_string(),
             error_type: ErrorType::Error,
+            code: None,
+            suggestion: None,
             unresolved_path: None,
             position: None,
             source_doc: None,
@@ -81,7 +83,7 @@ impl NormalizedNode {
 /// positions in the `nodes` vec.
 ///
 /// These references are untagged, so the type of each node may be mutated and the reference remains valid.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[serde(rename = "normalizedRoot")]
 #[cfg_attr(feature = "web", derive(Tsify))]
@@ -7,7 +7,7 @@ use std::{collections::HashMap, fmt::Display, num::ParseIntError};
 use serde::{Deserialize, Serialize};
 use tsify_next::{Tsify, declare};
 
-use crate::dast::DastRoot;
+use crate::dast::{DastRoot, DiagnosticCode};
 
 use super::{
     super::{Position, ref_resolve::RefResolution},
@@ -214,6 +214,13 @@ pub struct FlatError {
     pub message: String,
     pub error_type: ErrorType,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub code: Option<DiagnosticCode>,
+    /// A suggested fix, e.g., the correct spelling of a misspelled name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub suggestion: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unresolved_path: Option<Vec<FlatPathPart>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub position: Option<Position>,
@@ -228,6 +235,8 @@ impl FlatError {
             parent: None,
             message,
             error_type: ErrorType::Error,
+            code: None,
+            suggestion: None,
             unresolved_path: None,
             position: None,
             source_doc: None,
@@ -268,6 +277,22 @@ pub struct FlatIndex {
     pub source_doc: Option<SourceDoc>,
 }
 
+impl FlatIndex {
+    /// The index as a number, starting from 1, if it is given by literal text, e.g., `2` in `$l[2]`.
+    /// Returns `None` if the index contains a reference or is not a positive integer.
+    pub fn as_literal_index(&self) -> Option<usize> {
+        let text = self
+            .value
+            .iter()
+            .map(|content| match content {
+                UntaggedContent::Text(text) => Some(text.as_str()),
+                UntaggedContent::Ref(_) => None,
+            })
+            .collect::<Option<String>>()?;
+        text.trim().parse().ok().filter(|index| *index > 0)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "web", derive(Tsify))]
 #[serde(tag = "type")]
@@ -318,6 +343,8 @@ impl Default for FlatNode {
             parent: None,
             message: "DEFAULT NODE".to_string(),
             error_type: ErrorType::Error,
+            code: None,
+            suggestion: None,
             unresolved_path: None,
             position: None,
             source_doc: None,
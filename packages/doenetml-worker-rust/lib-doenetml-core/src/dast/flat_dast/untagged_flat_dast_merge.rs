@@ -203,6 +203,8 @@ impl FlatRoot {
             message: node.message.clone(),
             unresolved_path: None,
             error_type: node.error_type.unwrap_or_default(),
+            code: node.code,
+            suggestion: node.suggestion.clone(),
             position: node.position.clone(),
             source_doc: node.source_doc,
             parent,
@@ -12,8 +12,10 @@
 //! where the node is stored.
 
 pub mod dast_structure;
+pub mod diagnostics;
 pub mod flat_dast;
 pub mod ref_expand;
 pub mod ref_resolve;
 
 pub use dast_structure::*;
+pub use diagnostics::DiagnosticCode;
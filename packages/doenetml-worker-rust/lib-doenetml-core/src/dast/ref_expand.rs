@@ -1,13 +1,15 @@
-use std::{collections::HashMap, mem};
+use std::{collections::BTreeMap, mem};
 
 use anyhow::anyhow;
 
 use super::{
-    DastElement, DastElementContent, DastError,
+    DastElement, DastElementContent, DastError, DiagnosticCode,
     flat_dast::{
         ErrorType, FlatElement, FlatError, FlatNode, FlatRoot, Index, Source, UntaggedContent,
     },
-    ref_resolve::{RefResolution, ResolutionError, Resolver, format_error_message},
+    ref_resolve::{
+        RefResolution, ResolutionError, Resolver, format_error_message, format_name_suggestion,
+    },
 };
 
 /// An `Expander` replaces all refs with their `DastElement`-equivalent forms. For example
@@ -71,6 +73,10 @@ impl Expander {
                             parent: ref_.parent,
                             message: format_error_message(err, &ref_.path),
                             error_type: ErrorType::Warning,
+                            code: Some(err.diagnostic_code()),
+                            suggestion: resolver
+                                .suggest_name(&ref_.path, ref_.idx)
+                                .map(|name| format_name_suggestion(&name)),
                             unresolved_path: if let ResolutionError::NoReferent = err {
                                 Some(ref_.path.clone())
                             } else {
@@ -106,7 +112,7 @@ impl Expander {
                                     // inputs to the function.
                                     let dast_ol = DastElement {
                                         name: "ol".to_string(),
-                                        attributes: HashMap::new(),
+                                        attributes: BTreeMap::new(),
                                         children: inputs
                                             .iter()
                                             .map(|_| DastElementContent::element_with_name("li"))
@@ -154,6 +160,10 @@ impl Expander {
                                 parent: function_ref.parent,
                                 message: format!("Ref resolution error: {err}"),
                                 error_type: ErrorType::Warning,
+                                code: Some(err.diagnostic_code()),
+                                suggestion: resolver
+                                    .suggest_name(&function_ref.path, function_ref.idx)
+                                    .map(|name| format_name_suggestion(&name)),
                                 unresolved_path: if let ResolutionError::NoReferent = err {
                                     Some(function_ref.path.clone())
                                 } else {
@@ -218,6 +228,8 @@ impl Expander {
                 element.children.push(flat_root.merge_content(
                     &DastElementContent::Error(DastError {
                         error_type: Some(ErrorType::Error),
+                        code: Some(DiagnosticCode::DuplicateExtendAttribute),
+                        suggestion: None,
                         message: "Duplicate `extend` or `copy` attributes".to_string(),
                         position: element.position.clone(),
                         source_doc: element.source_doc,
@@ -293,6 +305,11 @@ impl Expander {
                     ErrorType::Error
                 };
 
+                let (code, suggestion) = match error_found {
+                    Some(err) => (err.code, err.suggestion.clone()),
+                    None => (Some(DiagnosticCode::InvalidExtendAttribute), None),
+                };
+
                 let message = if let Some(err) = error_found {
                     format!("In '{}' attribute: {}", extend_or_copy.name, err.message)
                 } else {
@@ -303,6 +320,8 @@ impl Expander {
                     &DastElementContent::Error(DastError {
                         message,
                         error_type: Some(error_type),
+                        code,
+                        suggestion,
                         position: extend_or_copy.position.clone(),
                         source_doc: extend_or_copy.source_doc,
                     }),
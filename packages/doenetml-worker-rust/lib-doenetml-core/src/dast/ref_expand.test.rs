@@ -539,6 +539,7 @@ fn initial_ref_skips_unqualified_option_children() {
                     "type": "error",
                     "message": "No referent found for reference: `$n2`",
                     "errorType": "warning",
+                    "code": "DML2001",
                     "parent": 0,
                     "idx": 3,
                     "unresolvedPath": [{ "type": "flatPathPart", "name": "n2", "index": [] }]
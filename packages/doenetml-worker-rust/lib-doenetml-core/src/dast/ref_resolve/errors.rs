@@ -2,7 +2,7 @@ use serde::Serialize;
 use thiserror::Error;
 use tsify_next::Tsify;
 
-use crate::dast::flat_dast::FlatPathPart;
+use crate::dast::{DiagnosticCode, flat_dast::FlatPathPart};
 
 #[derive(Clone, Debug, Serialize, Error, PartialEq, Copy)]
 #[cfg_attr(feature = "web", derive(Tsify))]
@@ -14,6 +14,22 @@ pub enum ResolutionError {
     NonUniqueReferent,
 }
 
+impl ResolutionError {
+    /// The `DiagnosticCode` used when reporting this error to the author.
+    pub fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            ResolutionError::NoReferent => DiagnosticCode::NoReferent,
+            ResolutionError::NonUniqueReferent => DiagnosticCode::NonUniqueReferent,
+        }
+    }
+}
+
+/// Format the suggestion offered when a reference has no referent
+/// but `name` is a likely correction of its first name.
+pub fn format_name_suggestion(name: &str) -> String {
+    format!("Did you mean `${name}`?")
+}
+
 /// Format an error message given that `err` was produced when trying to resolve `path`.
 /// Since the resolve algorithm stops when any index is found,
 /// this message includes the path up to any index.
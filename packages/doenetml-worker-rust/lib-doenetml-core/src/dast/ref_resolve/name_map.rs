@@ -1,7 +1,7 @@
 use std::ops::{Deref, DerefMut};
 
 use rustc_hash::FxHashMap;
-use serde::{Serialize, ser::SerializeMap};
+use serde::{Deserialize, Serialize, de::Error as _, ser::SerializeMap};
 
 use crate::dast::{flat_dast::SourceDoc, ref_resolve::Ref};
 
@@ -73,3 +73,30 @@ impl Serialize for NameMap {
         map.end()
     }
 }
+
+impl<'de> Deserialize<'de> for NameMap {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let map = FxHashMap::<String, Ref>::deserialize(deserializer)?;
+        map.into_iter()
+            .map(|(key, ref_)| {
+                let (name, source_doc) = key.rsplit_once(':').ok_or_else(|| {
+                    D::Error::custom(format!(
+                        "expected a key of the form `name:source_doc`, found `{key}`"
+                    ))
+                })?;
+                let source_doc = SourceDoc::try_from(source_doc).map_err(D::Error::custom)?;
+                Ok((
+                    NameWithSource {
+                        name: name.to_string(),
+                        source_doc,
+                    },
+                    ref_,
+                ))
+            })
+            .collect::<Result<_, _>>()
+            .map(NameMap)
+    }
+}
@@ -3,9 +3,12 @@ use std::iter;
 use tsify_next::Tsify;
 
 use super::{NameMap, ResolutionError};
-use crate::dast::{
-    flat_dast::{FlatElement, FlatNode, FlatPathPart, Index, SourceDoc, UntaggedContent},
-    ref_resolve::NameWithSource,
+use crate::{
+    dast::{
+        flat_dast::{FlatElement, FlatNode, FlatPathPart, Index, SourceDoc, UntaggedContent},
+        ref_resolve::NameWithSource,
+    },
+    utils::closest_match,
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -87,14 +90,14 @@ impl Visibility {
 }
 
 /// The possibilities for the parent of a node in the resolver
-#[derive(Debug, Serialize, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
 pub enum NodeParent {
     None,
     FlatRoot,
     Node(Index),
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct NodeResolverData {
     /// The parent of the node. Options are:
     /// - `NodeParent:None` corresponds to no parent
@@ -127,7 +130,7 @@ pub struct NodeResolverData {
 /// A `Resolver` is used to lookup elements by path/name. It constructs a search index
 /// upon construction. If the underlying `FlatRoot` changes, a new `Resolver` should be
 /// recreated.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Resolver {
     /// List of the node resolver data for a node at a given index shifted by `1`
     /// so that `node_resolver_data[i+1]` gives the data for node `i`
@@ -526,6 +529,56 @@ impl Resolver {
         }
         Err(ResolutionError::NoReferent)
     }
+
+    /// If the first part of `path` does not match any name accessible from `origin`,
+    /// return the accessible name closest to it, which is a likely correction of a misspelling.
+    ///
+    /// For example, given `<point name="point1" />$piont1`, the suggestion for `$piont1` is `point1`.
+    pub fn suggest_name(&self, path: &[FlatPathPart], origin: Index) -> Option<String> {
+        if origin + 1 >= self.node_resolver_data.len() {
+            return None;
+        }
+        let first_path_part = path.first()?;
+        let source_doc: SourceDoc = first_path_part.source_doc.into();
+        let first_resolution = self.search_parents(
+            &NameWithSource {
+                name: first_path_part.name.clone(),
+                source_doc,
+            },
+            origin,
+        );
+        if !matches!(first_resolution, Err(ResolutionError::NoReferent)) {
+            return None;
+        }
+
+        // The names accessible from `origin` are those in the name maps of `origin` and its ancestors.
+        let mut name_maps = vec![&self.node_resolver_data[origin + 1].name_map];
+        let mut node_data = &self.node_resolver_data[origin + 1];
+        loop {
+            let parent_plus_1 = match node_data.node_parent {
+                NodeParent::None => break,
+                NodeParent::FlatRoot => 0,
+                NodeParent::Node(idx) => idx + 1,
+            };
+            node_data = &self.node_resolver_data[parent_plus_1];
+            name_maps.push(&node_data.name_map);
+            if parent_plus_1 == 0 {
+                break;
+            }
+        }
+
+        let mut candidates = name_maps
+            .into_iter()
+            .flat_map(|name_map| name_map.keys())
+            .filter(|name_with_source| name_with_source.source_doc == source_doc)
+            .map(|name_with_source| name_with_source.name.as_str())
+            .collect::<Vec<_>>();
+        // Sort so that the suggestion does not depend on the iteration order of the name maps.
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        closest_match(&first_path_part.name, candidates).map(|name| name.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -832,3 +832,44 @@ fn references_can_span_three_source_documents() {
         })
     );
 }
+
+#[test]
+fn suggest_names_close_to_misspelled_names() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <a name="point1">
+            <b name="inner">
+                <c />
+            </b>
+        </a>
+        <d name="graph" />
+        <e />"#,
+    );
+    let flat_root = FlatRoot::from_dast(&dast_root);
+    let c_idx = find(&flat_root, "c").unwrap();
+    let e_idx = find(&flat_root, "e").unwrap();
+
+    let resolver = Resolver::from_flat_root(&flat_root);
+
+    assert_eq!(
+        resolver.suggest_name(&make_path(["piont1"], None), e_idx),
+        Some("point1".to_string())
+    );
+    assert_eq!(
+        resolver.suggest_name(&make_path(["Graph", "x"], None), c_idx),
+        Some("graph".to_string())
+    );
+    assert_eq!(
+        resolver.suggest_name(&make_path(["innr"], None), c_idx),
+        Some("inner".to_string())
+    );
+    // No suggestion when the name resolves or when nothing is close
+    assert_eq!(
+        resolver.suggest_name(&make_path(["graph"], None), e_idx),
+        None
+    );
+    assert_eq!(
+        resolver.suggest_name(&make_path(["circle"], None), e_idx),
+        None
+    );
+}
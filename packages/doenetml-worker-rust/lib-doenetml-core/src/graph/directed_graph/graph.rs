@@ -124,6 +124,29 @@ impl<Node: Clone + Debug, IndexLookup: Taggable<Node, usize>> DirectedGraph<Node
             .collect()
     }
 
+    /// Returns the immediate parents of `node`.
+    pub fn get_parents<A: Borrow<Node>>(&self, node: A) -> Vec<Node> {
+        let node = node.borrow();
+        let &index = self.index_lookup.get_tag(node).unwrap();
+        self.reverse_edges[index]
+            .iter()
+            .map(|&i| self.nodes[i].clone())
+            .collect()
+    }
+
+    /// Returns whether `node` has any parents, without collecting them.
+    /// `node` need not have been added to the graph.
+    pub fn has_parents<A: Borrow<Node>>(&self, node: A) -> bool {
+        self.index_lookup
+            .get_tag(node.borrow())
+            .is_some_and(|&index| !self.reverse_edges[index].is_empty())
+    }
+
+    /// Returns whether `node` has been added to the graph.
+    pub fn contains_node<A: Borrow<Node>>(&self, node: A) -> bool {
+        self.index_lookup.get_tag(node.borrow()).is_some()
+    }
+
     /// Returns the `n`th child of `node`. `n` is 0-indexed.
     pub fn get_nth_child<A: Borrow<Node>>(&self, node: A, n: usize) -> Option<Node> {
         let node = node.borrow();
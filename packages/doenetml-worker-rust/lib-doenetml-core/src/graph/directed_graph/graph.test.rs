@@ -12,6 +12,24 @@ fn test_indexable() {
     assert_eq!(*indexable.get_tag(&"test2".into()).unwrap(), 1);
 }
 
+#[test]
+fn can_get_parents_of_nodes() {
+    // a -> c
+    // b -> c
+    let mut graph = DirectedGraph::<String, HashMap<_, _>>::new();
+    graph.add_edge("a".to_string(), "c".to_string());
+    graph.add_edge("b".to_string(), "c".to_string());
+
+    assert_eq!(graph.get_parents("c".to_string()), vec!["a", "b"]);
+    assert!(graph.get_parents("a".to_string()).is_empty());
+    assert!(graph.contains_node("a".to_string()));
+    assert!(!graph.contains_node("d".to_string()));
+
+    assert!(graph.has_parents("c".to_string()));
+    assert!(!graph.has_parents("a".to_string()));
+    assert!(!graph.has_parents("d".to_string()));
+}
+
 #[test]
 fn can_walk_in_topological_order() {
     // Set up the graph
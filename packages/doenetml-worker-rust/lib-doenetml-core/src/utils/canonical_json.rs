@@ -0,0 +1,75 @@
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+
+/// The number of significant digits that numbers are rounded to by `to_canonical_json`.
+const SIGNIFICANT_DIGITS: usize = 10;
+
+/// Serialize `value` as pretty-printed JSON in a canonical form, so that equal values always give the same text:
+/// - the keys of every object are sorted,
+/// - numbers are rounded to `SIGNIFICANT_DIGITS` significant digits, so that floating point error
+///   (e.g., `0.30000000000000004`) does not show,
+/// - numbers that are whole, including `-0`, are written as integers.
+pub fn to_canonical_json<T: Serialize>(value: &T) -> String {
+    let value = serde_json::to_value(value).unwrap_or(Value::Null);
+    serde_json::to_string_pretty(&canonicalize(value)).unwrap()
+}
+
+fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries = map.into_iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, canonicalize(value)))
+                    .collect::<Map<_, _>>(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(canonicalize).collect()),
+        Value::Number(number) => Value::Number(canonicalize_number(number)),
+        value => value,
+    }
+}
+
+fn canonicalize_number(number: Number) -> Number {
+    if number.is_i64() || number.is_u64() {
+        return number;
+    }
+    let Some(x) = number.as_f64() else {
+        return number;
+    };
+    let rounded = format!("{x:.prec$e}", prec = SIGNIFICANT_DIGITS - 1)
+        .parse::<f64>()
+        .unwrap_or(x);
+    // Whole numbers that can be represented exactly are written as integers.
+    if rounded.fract() == 0.0 && rounded.abs() < 2f64.powi(53) {
+        Number::from(rounded as i64)
+    } else {
+        Number::from_f64(rounded).unwrap_or(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_keys_are_sorted() {
+        assert_eq!(
+            to_canonical_json(&json!({"b": 1, "a": {"d": [], "c": "x"}})),
+            "{\n  \"a\": {\n    \"c\": \"x\",\n    \"d\": []\n  },\n  \"b\": 1\n}"
+        );
+    }
+
+    #[test]
+    fn test_numbers_are_normalized() {
+        assert_eq!(
+            to_canonical_json(&json!([0.1 + 0.2, 2.0, -0.0, 1e300, 12345.678])),
+            to_canonical_json(&json!([0.3, 2, 0, 1e300, 12345.678]))
+        );
+        assert_eq!(to_canonical_json(&json!(-0.0)), "0");
+        assert_eq!(to_canonical_json(&json!(1.0 / 3.0)), "0.3333333333");
+    }
+}
@@ -0,0 +1,109 @@
+//! Fuzzy matching of names, used to suggest corrections for misspelled names in DoenetML.
+
+/// The number of single-character insertions, deletions, or substitutions
+/// needed to turn `a` into `b` (the Levenshtein distance).
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    let mut current_row = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current_row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution_cost = if a_char == *b_char { 0 } else { 1 };
+            current_row[j + 1] = (previous_row[j] + substitution_cost)
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[b.len()]
+}
+
+/// The largest edit distance for which a candidate is considered a plausible misspelling of a name
+/// with `name_length` characters. Very short names only match candidates that differ in case,
+/// since almost any other short name would be within a single edit.
+fn max_distance_for_length(name_length: usize) -> usize {
+    name_length / 3
+}
+
+/// Find the candidate closest to `name`, if any candidate is close enough to be a plausible misspelling.
+///
+/// Comparison ignores case, so a candidate that differs from `name` only in case is always a match.
+/// Candidates exactly equal to `name` are ignored. Ties are broken in favor of the earliest candidate.
+pub fn closest_match<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    closest_matches(name, candidates, 1).pop()
+}
+
+/// Find up to `limit` candidates that are close enough to `name` to be plausible misspellings,
+/// closest first. Matching follows the same rules as [`closest_match`].
+pub fn closest_matches<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+    limit: usize,
+) -> Vec<&'a str> {
+    let lowercase_name = name.to_lowercase();
+    let max_distance = max_distance_for_length(name.chars().count());
+
+    let mut matches = candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| {
+            (
+                edit_distance(&lowercase_name, &candidate.to_lowercase()),
+                candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect::<Vec<_>>();
+    // A stable sort keeps candidates with equal distances in their original order.
+    matches.sort_by_key(|(distance, _)| *distance);
+
+    matches
+        .into_iter()
+        .take(limit)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("point", "point"), 0);
+        assert_eq!(edit_distance("point", "piont"), 2);
+        assert_eq!(edit_distance("point1", "point"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_closest_match() {
+        let candidates = ["point1", "point2", "text1", "graph"];
+        assert_eq!(closest_match("point", candidates), Some("point1"));
+        assert_eq!(closest_match("txt1", candidates), Some("text1"));
+        assert_eq!(closest_match("Graph", candidates), Some("graph"));
+        assert_eq!(closest_match("circle", candidates), None);
+        assert_eq!(closest_match("graph", candidates), None);
+        assert_eq!(closest_match("t1", ["t2", "T1"]), Some("T1"));
+        assert_eq!(closest_match("t1", ["t2"]), None);
+    }
+
+    #[test]
+    fn test_closest_matches() {
+        let candidates = ["vals", "value", "valve", "x"];
+        assert_eq!(
+            closest_matches("values", candidates, 3),
+            vec!["value", "vals", "valve"]
+        );
+        assert_eq!(closest_matches("values", candidates, 1), vec!["value"]);
+        assert!(closest_matches("xyz", candidates, 3).is_empty());
+    }
+}
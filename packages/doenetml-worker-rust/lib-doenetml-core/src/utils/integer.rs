@@ -0,0 +1,89 @@
+//! Checked conversions into and out of the `i64` used by `Integer` props.
+//!
+//! DoenetML integers ultimately travel to JavaScript, so we never silently saturate
+//! or wrap when converting. Callers get an `IntegerConversionError` and decide how to recover
+//! (usually by falling back to the prop's default).
+
+use thiserror::Error;
+
+/// The largest integer that JavaScript can represent exactly (`Number.MAX_SAFE_INTEGER`).
+pub const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/// The smallest integer that JavaScript can represent exactly (`Number.MIN_SAFE_INTEGER`).
+pub const MIN_SAFE_INTEGER: i64 = -MAX_SAFE_INTEGER;
+
+#[derive(Debug, Error, Clone, Copy, PartialEq)]
+pub enum IntegerConversionError {
+    #[error("`{0}` is not a number")]
+    NotANumber(f64),
+    #[error("`{0}` is outside the range of representable integers")]
+    OutOfRange(f64),
+    #[error("`{0}` cannot be used as an index or size")]
+    InvalidIndex(i64),
+}
+
+/// Convert `value` to an integer by truncating towards zero.
+///
+/// Fails if `value` is `NaN` or if the truncated value lies outside of
+/// `MIN_SAFE_INTEGER..=MAX_SAFE_INTEGER`.
+pub fn f64_to_integer(value: f64) -> Result<i64, IntegerConversionError> {
+    if value.is_nan() {
+        return Err(IntegerConversionError::NotANumber(value));
+    }
+    let truncated = value.trunc();
+    if truncated < MIN_SAFE_INTEGER as f64 || truncated > MAX_SAFE_INTEGER as f64 {
+        return Err(IntegerConversionError::OutOfRange(value));
+    }
+    Ok(truncated as i64)
+}
+
+/// Convert a size or index (e.g., a position among siblings) into an integer.
+pub fn usize_to_integer(value: usize) -> Result<i64, IntegerConversionError> {
+    i64::try_from(value)
+        .ok()
+        .filter(|v| *v <= MAX_SAFE_INTEGER)
+        .ok_or(IntegerConversionError::OutOfRange(value as f64))
+}
+
+/// Convert an integer into a size or index. Fails for negative integers.
+pub fn integer_to_usize(value: i64) -> Result<usize, IntegerConversionError> {
+    usize::try_from(value).map_err(|_| IntegerConversionError::InvalidIndex(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f64_to_integer() {
+        assert_eq!(f64_to_integer(3.7), Ok(3));
+        assert_eq!(f64_to_integer(-3.7), Ok(-3));
+        assert_eq!(
+            f64_to_integer(MAX_SAFE_INTEGER as f64),
+            Ok(MAX_SAFE_INTEGER)
+        );
+        assert!(matches!(
+            f64_to_integer(f64::NAN),
+            Err(IntegerConversionError::NotANumber(_))
+        ));
+        assert_eq!(
+            f64_to_integer(f64::INFINITY),
+            Err(IntegerConversionError::OutOfRange(f64::INFINITY))
+        );
+        assert_eq!(
+            f64_to_integer(1e300),
+            Err(IntegerConversionError::OutOfRange(1e300))
+        );
+    }
+
+    #[test]
+    fn test_usize_integer_round_trip() {
+        assert_eq!(usize_to_integer(5), Ok(5));
+        assert!(usize_to_integer(usize::MAX).is_err());
+        assert_eq!(integer_to_usize(5), Ok(5));
+        assert_eq!(
+            integer_to_usize(-1),
+            Err(IntegerConversionError::InvalidIndex(-1))
+        );
+    }
+}
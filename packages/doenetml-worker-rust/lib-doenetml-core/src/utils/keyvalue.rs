@@ -1,16 +1,16 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 pub trait KeyValueIgnoreCase<K, V> {
     fn get_key_value_ignore_case<'a>(&'a self, key: &str) -> Option<(&'a K, &'a V)>;
     fn remove_ignore_case(&mut self, key: &str) -> Option<V>;
 }
 
-impl<K, V> KeyValueIgnoreCase<K, V> for HashMap<K, V>
+impl<K, V> KeyValueIgnoreCase<K, V> for BTreeMap<K, V>
 where
-    K: ToString + std::cmp::Eq + std::hash::Hash + Clone,
+    K: ToString + Ord + Clone,
 {
-    /// Match key to the HashMap keys, ignoring case.
-    /// Return the original key-value pair from the HashMap.
+    /// Match key to the BTreeMap keys, ignoring case.
+    /// Return the original key-value pair from the BTreeMap.
     ///
     /// Warning: This function returns the first match if multiple keys match. It is the
     /// caller's responsibility to ensure that the keys are unique up to case.
@@ -19,8 +19,8 @@ where
             .find(|(k, _)| k.to_string().eq_ignore_ascii_case(key))
     }
 
-    /// Match key to the HashMap keys, ignoring case.
-    /// If found, remove the original value from the HashMap.
+    /// Match key to the BTreeMap keys, ignoring case.
+    /// If found, remove the original value from the BTreeMap.
     ///
     /// Warning: This function deletes the first match if multiple keys match. It is the
     /// caller's responsibility to ensure that the keys are unique up to case.
@@ -38,7 +38,7 @@ mod tests {
 
     #[test]
     fn test_get_key_value_ignore_case() {
-        let mut map = HashMap::new();
+        let mut map = BTreeMap::new();
         map.insert("Key1".to_string(), "Value1");
         map.insert("KEY2".to_string(), "Value2");
 
@@ -55,7 +55,7 @@ mod tests {
 
     #[test]
     fn test_remove_ignore_case() {
-        let mut map = HashMap::new();
+        let mut map = BTreeMap::new();
         map.insert("Key1".to_string(), "Value1");
         map.insert("KEY2".to_string(), "Value2");
 
@@ -1,8 +1,18 @@
+pub mod canonical_json;
+pub mod fuzzy_match;
+pub mod integer;
 pub mod keyvalue;
 pub mod logging;
 pub mod parse_json;
 pub mod rc_serde;
+pub mod sanitize_html;
+pub mod seeded_rng;
 
+pub use canonical_json::*;
+pub use fuzzy_match::*;
+pub use integer::*;
 pub use keyvalue::*;
 #[allow(unused)]
 pub use logging::*;
+pub use sanitize_html::*;
+pub use seeded_rng::*;
@@ -0,0 +1,419 @@
+//! Sanitization of raw HTML supplied by authors, e.g., in an `<htmlBlock>`.
+//!
+//! Only an allow-list of formatting tags and attributes is kept. Everything else is stripped,
+//! and the names of what was stripped are reported so that authors can be warned.
+
+/// Tags that are kept. Other tags are removed, but their contents are kept.
+const ALLOWED_TAGS: &[&str] = &[
+    "a",
+    "abbr",
+    "b",
+    "blockquote",
+    "br",
+    "caption",
+    "code",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "em",
+    "figcaption",
+    "figure",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "i",
+    "img",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "s",
+    "small",
+    "span",
+    "strong",
+    "sub",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "tr",
+    "u",
+    "ul",
+];
+
+/// Tags that are removed along with their contents, since their contents are not meant to be displayed.
+const REMOVED_WITH_CONTENTS_TAGS: &[&str] = &[
+    "iframe", "noscript", "object", "script", "style", "template", "textarea",
+];
+
+/// Attributes that are kept on any allowed tag.
+const GLOBAL_ATTRIBUTES: &[&str] = &["class", "title", "lang", "dir"];
+
+/// Attributes that are kept on specific tags.
+const TAG_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("a", &["href"]),
+    ("img", &["src", "alt", "width", "height"]),
+    ("ol", &["start", "type"]),
+    ("td", &["colspan", "rowspan"]),
+    ("th", &["colspan", "rowspan", "scope"]),
+];
+
+/// Attributes whose values are URLs, which are kept only if they use an allowed scheme.
+const URL_ATTRIBUTES: &[&str] = &["href", "src"];
+
+/// The URL schemes that are allowed. URLs without a scheme (relative URLs) are also allowed.
+const ALLOWED_URL_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// The result of sanitizing HTML.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SanitizedHtml {
+    /// The sanitized HTML.
+    pub html: String,
+    /// The names of the tags that were removed, in the order they first appear.
+    pub removed_tags: Vec<String>,
+    /// The names of the attributes that were removed, in the order they first appear.
+    pub removed_attributes: Vec<String>,
+}
+
+impl SanitizedHtml {
+    /// Whether anything was removed from the HTML.
+    pub fn removed_anything(&self) -> bool {
+        !self.removed_tags.is_empty() || !self.removed_attributes.is_empty()
+    }
+
+    fn record_removed_tag(&mut self, name: &str) {
+        if !self.removed_tags.iter().any(|tag| tag == name) {
+            self.removed_tags.push(name.to_string());
+        }
+    }
+
+    fn record_removed_attribute(&mut self, name: &str) {
+        if !self.removed_attributes.iter().any(|attr| attr == name) {
+            self.removed_attributes.push(name.to_string());
+        }
+    }
+}
+
+/// Whether `name` is an HTML tag that is kept by `sanitize_html`, e.g., `span`.
+pub fn is_allowed_html_tag(name: &str) -> bool {
+    ALLOWED_TAGS.contains(&name)
+}
+
+/// Sanitize `html`, keeping only allow-listed tags and attributes.
+///
+/// - Disallowed tags are removed, but their contents are kept, except for tags such as `<script>`
+///   whose contents are removed as well.
+/// - Disallowed attributes, including all event handlers (e.g., `onclick`), are removed.
+///   URLs are kept only if they are relative or use `http`, `https`, or `mailto`.
+/// - Comments and declarations (e.g., `<!DOCTYPE html>`) are removed without being reported.
+/// - A `<` that does not begin a tag is escaped.
+///
+/// The sanitizer does not balance tags; unclosed tags are left for the browser to close.
+pub fn sanitize_html(html: &str) -> SanitizedHtml {
+    let mut result = SanitizedHtml::default();
+    let mut rest = html;
+
+    while let Some(tag_start) = rest.find('<') {
+        result.html.push_str(&rest[..tag_start]);
+        rest = &rest[tag_start..];
+
+        if let Some(after_comment) = rest.strip_prefix("<!--") {
+            rest = match after_comment.find("-->") {
+                Some(end) => &after_comment[end + 3..],
+                None => "",
+            };
+            continue;
+        }
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = match rest.find('>') {
+                Some(end) => &rest[end + 1..],
+                None => "",
+            };
+            continue;
+        }
+
+        match parse_tag(rest) {
+            None => {
+                result.html.push_str("&lt;");
+                rest = &rest[1..];
+            }
+            Some((tag, remaining)) => {
+                rest = remaining;
+                let name = tag.name.to_ascii_lowercase();
+
+                if REMOVED_WITH_CONTENTS_TAGS.contains(&name.as_str()) {
+                    result.record_removed_tag(&name);
+                    if !tag.is_closing && !tag.is_self_closing {
+                        rest = skip_past_closing_tag(rest, &name);
+                    }
+                    continue;
+                }
+                if !ALLOWED_TAGS.contains(&name.as_str()) {
+                    result.record_removed_tag(&name);
+                    continue;
+                }
+
+                if tag.is_closing {
+                    result.html.push_str(&format!("</{name}>"));
+                    continue;
+                }
+
+                result.html.push('<');
+                result.html.push_str(&name);
+                for (attr_name, attr_value) in tag.attributes {
+                    let attr_name = attr_name.to_ascii_lowercase();
+                    if !is_allowed_attribute(&name, &attr_name, attr_value) {
+                        result.record_removed_attribute(&attr_name);
+                        continue;
+                    }
+                    result.html.push(' ');
+                    result.html.push_str(&attr_name);
+                    if let Some(value) = attr_value {
+                        result.html.push_str("=\"");
+                        result.html.push_str(&value.replace('"', "&quot;"));
+                        result.html.push('"');
+                    }
+                }
+                if tag.is_self_closing {
+                    result.html.push_str(" /");
+                }
+                result.html.push('>');
+            }
+        }
+    }
+    result.html.push_str(rest);
+
+    result
+}
+
+/// A tag parsed from the source.
+struct Tag<'a> {
+    name: &'a str,
+    attributes: Vec<(&'a str, Option<&'a str>)>,
+    is_closing: bool,
+    is_self_closing: bool,
+}
+
+/// Parse the tag at the start of `source` (which begins with `<`), returning the tag
+/// and the source following it. Returns `None` if `source` does not begin with a tag.
+fn parse_tag(source: &str) -> Option<(Tag<'_>, &str)> {
+    let mut rest = &source[1..];
+    let is_closing = rest.starts_with('/');
+    if is_closing {
+        rest = &rest[1..];
+    }
+
+    let name_len = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .unwrap_or(rest.len());
+    if name_len == 0 || !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let name = &rest[..name_len];
+    rest = &rest[name_len..];
+
+    let mut attributes = Vec::new();
+    let mut is_self_closing = false;
+    loop {
+        rest = rest.trim_start();
+        if let Some(remaining) = rest.strip_prefix('>') {
+            rest = remaining;
+            break;
+        }
+        if let Some(remaining) = rest.strip_prefix("/>") {
+            is_self_closing = true;
+            rest = remaining;
+            break;
+        }
+        if rest.is_empty() {
+            // An unterminated tag is treated as text.
+            return None;
+        }
+
+        let attr_name_len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(rest.len());
+        if attr_name_len == 0 {
+            // A stray `/` or `=`
+            rest = &rest[1..];
+            continue;
+        }
+        let attr_name = &rest[..attr_name_len];
+        rest = rest[attr_name_len..].trim_start();
+
+        let attr_value = match rest.strip_prefix('=') {
+            None => None,
+            Some(remaining) => {
+                let remaining = remaining.trim_start();
+                let (value, remaining) = match remaining.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let remaining = &remaining[1..];
+                        let end = remaining.find(quote)?;
+                        (&remaining[..end], &remaining[end + 1..])
+                    }
+                    _ => {
+                        let end = remaining
+                            .find(|c: char| c.is_whitespace() || c == '>')
+                            .unwrap_or(remaining.len());
+                        remaining.split_at(end)
+                    }
+                };
+                rest = remaining;
+                Some(value)
+            }
+        };
+        attributes.push((attr_name, attr_value));
+    }
+
+    Some((
+        Tag {
+            name,
+            attributes,
+            is_closing,
+            is_self_closing,
+        },
+        rest,
+    ))
+}
+
+/// Return the source following the closing tag `</name>`, or the empty string if there is none.
+fn skip_past_closing_tag<'a>(source: &'a str, name: &str) -> &'a str {
+    let closing_tag = format!("</{name}");
+    match source.to_ascii_lowercase().find(&closing_tag) {
+        Some(start) => match source[start..].find('>') {
+            Some(end) => &source[start + end + 1..],
+            None => "",
+        },
+        None => "",
+    }
+}
+
+fn is_allowed_attribute(tag_name: &str, attr_name: &str, value: Option<&str>) -> bool {
+    let allowed = GLOBAL_ATTRIBUTES.contains(&attr_name)
+        || TAG_ATTRIBUTES
+            .iter()
+            .any(|(tag, attrs)| *tag == tag_name && attrs.contains(&attr_name));
+    if !allowed {
+        return false;
+    }
+    if URL_ATTRIBUTES.contains(&attr_name) {
+        return value.is_some_and(is_allowed_url);
+    }
+    true
+}
+
+/// Whether `url` is relative or uses an allowed scheme.
+///
+/// The browser decodes character references in attribute values before interpreting the URL,
+/// so references could be used to disguise a scheme, e.g., `javascript&colon;x` or `java&#115;cript:x`.
+/// Hence, URLs containing any character reference other than `&amp;` are rejected.
+/// A `&` that does not begin a reference, as in `page?a=1&b=2`, is allowed.
+fn is_allowed_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect();
+    if url
+        .match_indices('&')
+        .any(|(idx, _)| is_disallowed_character_reference(&url[idx + 1..]))
+    {
+        return false;
+    }
+    match url.find([':', '/', '?', '#']) {
+        Some(idx) if url[idx..].starts_with(':') => ALLOWED_URL_SCHEMES
+            .iter()
+            .any(|scheme| url[..idx].eq_ignore_ascii_case(scheme)),
+        _ => true,
+    }
+}
+
+/// Whether `rest`, the text following a `&`, begins a character reference other than `&amp;`.
+/// Numeric references (`&#...`) and named references (`&name;`) are both detected.
+fn is_disallowed_character_reference(rest: &str) -> bool {
+    if rest.starts_with('#') {
+        return true;
+    }
+    let name_len = rest
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(rest.len());
+    name_len > 0 && rest[name_len..].starts_with(';') && &rest[..name_len] != "amp"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowed_html_is_unchanged() {
+        let html = r#"<p class="note">Some <b>bold</b> and <a href="https://doenet.org">a link</a>.<br /></p>"#;
+        let sanitized = sanitize_html(html);
+        assert_eq!(sanitized.html, html);
+        assert!(!sanitized.removed_anything());
+    }
+
+    #[test]
+    fn test_disallowed_tags_are_removed() {
+        let sanitized =
+            sanitize_html(r#"<p>a<script>alert("hi")</script>b<font color="red">c</font></p>"#);
+        assert_eq!(sanitized.html, "<p>abc</p>");
+        assert_eq!(sanitized.removed_tags, vec!["script", "font"]);
+    }
+
+    #[test]
+    fn test_disallowed_attributes_are_removed() {
+        let sanitized = sanitize_html(
+            r#"<p onclick="steal()" style='color: red'>a</p><a href="javascript:steal()">b</a><a href="page.html">c</a>"#,
+        );
+        assert_eq!(
+            sanitized.html,
+            r#"<p>a</p><a>b</a><a href="page.html">c</a>"#
+        );
+        assert_eq!(
+            sanitized.removed_attributes,
+            vec!["onclick", "style", "href"]
+        );
+        assert!(sanitized.removed_tags.is_empty());
+    }
+
+    #[test]
+    fn test_disguised_urls_are_removed() {
+        let sanitized =
+            sanitize_html(r#"<a href="java&#115;cript:x">a</a><img src=" JavaScript:x">"#);
+        assert_eq!(sanitized.html, "<a>a</a><img>");
+
+        for url in [
+            "javascript&colon;alert(1)",
+            "java&Tab;script:alert(1)",
+            "java&NewLine;script:alert(1)",
+            "java&#x73;cript:alert(1)",
+        ] {
+            let sanitized = sanitize_html(&format!(r#"<a href="{url}">a</a>"#));
+            assert_eq!(sanitized.html, "<a>a</a>", "{url} should be removed");
+            assert_eq!(sanitized.removed_attributes, vec!["href"]);
+        }
+    }
+
+    #[test]
+    fn test_ampersands_in_urls_are_kept() {
+        let html =
+            r#"<a href="page.html?a=1&amp;b=2">a</a><a href="https://doenet.org/?a=1&b=2">b</a>"#;
+        let sanitized = sanitize_html(html);
+        assert_eq!(sanitized.html, html);
+        assert!(!sanitized.removed_anything());
+    }
+
+    #[test]
+    fn test_comments_and_stray_brackets() {
+        let sanitized = sanitize_html("<!-- note -->1 < 2 <3");
+        assert_eq!(sanitized.html, "1 &lt; 2 &lt;3");
+        assert!(!sanitized.removed_anything());
+    }
+}
@@ -0,0 +1,95 @@
+//! A deterministic source of random numbers for choices made from a seed, e.g., by a `<select>`.
+//!
+//! The same seed always gives the same numbers, so reloading a document with the same seed
+//! reproduces the same choices. The numbers are not suitable for cryptography.
+
+use crate::components::types::ComponentIdx;
+
+/// The increment of the SplitMix64 generator, also used to spread component indices across the seed.
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// A SplitMix64 generator seeded from a document seed and a component index,
+/// so that each component makes choices independent of the others.
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    /// Create a generator for the component `component_idx` from the document seed `seed`.
+    pub fn new(seed: u64, component_idx: ComponentIdx) -> Self {
+        SeededRng {
+            state: seed ^ (component_idx.as_usize() as u64).wrapping_mul(GOLDEN_GAMMA),
+        }
+    }
+
+    /// The next random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        let mut z = self.state;
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A random index less than `n`, which must be positive.
+    pub fn next_index(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Choose `k` of the indices less than `n` without replacement, in the order they were chosen.
+    /// If `k` is larger than `n`, all `n` indices are chosen.
+    pub fn choose_indices(&mut self, n: usize, k: usize) -> Vec<usize> {
+        // A partial Fisher-Yates shuffle.
+        let mut indices = (0..n).collect::<Vec<_>>();
+        let k = k.min(n);
+        for i in 0..k {
+            let j = i + self.next_index(n - i);
+            indices.swap(i, j);
+        }
+        indices.truncate(k);
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_numbers() {
+        let mut rng1 = SeededRng::new(42, ComponentIdx::new(3));
+        let mut rng2 = SeededRng::new(42, ComponentIdx::new(3));
+        for _ in 0..10 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn components_get_different_numbers() {
+        let mut rng1 = SeededRng::new(42, ComponentIdx::new(3));
+        let mut rng2 = SeededRng::new(42, ComponentIdx::new(4));
+        assert_ne!(rng1.next_u64(), rng2.next_u64());
+    }
+
+    #[test]
+    fn chosen_indices_are_distinct_and_in_range() {
+        for seed in 0..20 {
+            let mut rng = SeededRng::new(seed, ComponentIdx::new(1));
+            let mut chosen = rng.choose_indices(10, 4);
+            assert_eq!(chosen.len(), 4);
+            chosen.sort();
+            chosen.dedup();
+            assert_eq!(chosen.len(), 4);
+            assert!(chosen.iter().all(|&idx| idx < 10));
+        }
+    }
+
+    #[test]
+    fn choosing_more_indices_than_exist_chooses_all() {
+        let mut rng = SeededRng::new(7, ComponentIdx::new(1));
+        let mut chosen = rng.choose_indices(3, 5);
+        chosen.sort();
+        assert_eq!(chosen, vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,92 @@
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::accordion::{AccordionActions, AccordionProps},
+        types::{Action, ActionBody},
+    },
+    dast::FlatDastElementContent,
+    props::PropValue,
+};
+
+use super::*;
+
+const OPEN_LOCAL_IDX: LocalPropIdx = AccordionProps::Open.local_idx();
+
+#[test]
+fn accordion_renders_content_only_when_open() {
+    let dast_root = dast_root_no_position(
+        r#"<accordion name="a" title="More"><text name="t">hi</text></accordion>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let a_idx = core.get_component_index_by_name("a");
+    let t_idx = core.get_component_index_by_name("t");
+
+    assert_eq!(
+        core.get_prop(a_idx, OPEN_LOCAL_IDX).value,
+        PropValue::Boolean(false)
+    );
+    let flat_dast = core.to_flat_dast();
+    assert!(flat_dast.elements[a_idx].children.is_empty());
+    assert!(flat_dast.elements[t_idx].data.props.is_none());
+
+    let toggle = || Action {
+        component_idx: a_idx.into(),
+        action: ActionsEnum::Accordion(AccordionActions::ToggleOpen),
+    };
+
+    // Opening the accordion renders its content.
+    let updates = core.dispatch_action(toggle()).unwrap();
+    assert_eq!(
+        core.get_prop(a_idx, OPEN_LOCAL_IDX).value,
+        PropValue::Boolean(true)
+    );
+    assert_eq!(
+        updates[&ComponentIdx::from(a_idx)].new_children,
+        Some(vec![FlatDastElementContent::new_original_element(t_idx)])
+    );
+    assert!(updates[&ComponentIdx::from(t_idx)].changed_state.is_some());
+
+    // Closing it removes the content again.
+    let updates = core.dispatch_action(toggle()).unwrap();
+    assert_eq!(
+        core.get_prop(a_idx, OPEN_LOCAL_IDX).value,
+        PropValue::Boolean(false)
+    );
+    assert_eq!(
+        updates[&ComponentIdx::from(a_idx)].new_children,
+        Some(vec![])
+    );
+}
+
+#[test]
+fn accordion_can_start_open() {
+    let dast_root =
+        dast_root_no_position(r#"<accordion name="a" open><text name="t">hi</text></accordion>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let a_idx = core.get_component_index_by_name("a");
+    let t_idx = core.get_component_index_by_name("t");
+
+    let flat_dast = core.to_flat_dast();
+    assert_eq!(
+        flat_dast.elements[a_idx].children,
+        vec![FlatDastElementContent::new_original_element(t_idx)]
+    );
+
+    // The initial state from the attribute can be changed by an action.
+    let _ = core
+        .dispatch_action(Action {
+            component_idx: a_idx.into(),
+            action: ActionsEnum::Accordion(AccordionActions::ToggleOpen),
+        })
+        .unwrap();
+    assert_eq!(
+        core.get_prop(a_idx, OPEN_LOCAL_IDX).value,
+        PropValue::Boolean(false)
+    );
+}
@@ -0,0 +1,220 @@
+use super::*;
+
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::{
+            answer::{AnswerActions, AnswerProps},
+            number::NumberProps,
+            text_input::{TextInputActionArgs, TextInputActions},
+        },
+        types::{Action, ActionBody},
+    },
+    dast::FlatDastElementContent,
+    props::PropValue,
+};
+
+const CREDIT_ACHIEVED_IDX: LocalPropIdx = AnswerProps::CreditAchieved.local_idx();
+const RESPONSE_HAS_BEEN_SUBMITTED_IDX: LocalPropIdx =
+    AnswerProps::ResponseHasBeenSubmitted.local_idx();
+const SUBMITTED_RESPONSES_IDX: LocalPropIdx = AnswerProps::SubmittedResponses.local_idx();
+const JUST_SUBMITTED_IDX: LocalPropIdx = AnswerProps::JustSubmitted.local_idx();
+
+/// Return the actions for typing `text` into a text input and pressing enter.
+fn enter_response_actions(text: &str, text_input_idx: usize) -> Vec<Action> {
+    vec![
+        Action {
+            component_idx: text_input_idx.into(),
+            action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+                args: TextInputActionArgs {
+                    text: text.to_string(),
+                },
+            })),
+        },
+        Action {
+            component_idx: text_input_idx.into(),
+            action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+        },
+    ]
+}
+
+/// Return the action for submitting an answer.
+fn submit_answer_action(answer_idx: usize) -> Action {
+    Action {
+        component_idx: answer_idx.into(),
+        action: ActionsEnum::Answer(AnswerActions::SubmitAnswer),
+    }
+}
+
+fn enter_response(core: &mut TestCore, text: &str, text_input_idx: usize) {
+    for action in enter_response_actions(text, text_input_idx) {
+        let _ = core.dispatch_action(action);
+    }
+}
+
+fn strings(values: &[&str]) -> PropValue {
+    PropValue::from(
+        values
+            .iter()
+            .map(|&s| PropValue::from(s))
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[test]
+fn awards_are_not_rendered() {
+    let dast_root = dast_root_no_position(
+        r#"<answer name="a"><textInput name="ti" /><award>hello</award></answer>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let a_idx = core.get_component_index_by_name("a");
+    let ti_idx = core.get_component_index_by_name("ti");
+
+    let flat_dast = core.to_flat_dast();
+    assert_eq!(
+        flat_dast.elements[a_idx].children,
+        vec![FlatDastElementContent::new_original_element(ti_idx)]
+    );
+}
+
+#[test]
+fn credit_is_achieved_only_when_submitted() {
+    let dast_root = dast_root_no_position(
+        r#"<answer name="a"><textInput name="ti" /><award>hello</award></answer>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let a_idx = core.get_component_index_by_name("a");
+    let ti_idx = core.get_component_index_by_name("ti");
+
+    let credit: f64 = core.get_prop_value_typed(a_idx, CREDIT_ACHIEVED_IDX);
+    assert_eq!(credit, 0.0);
+    let submitted: bool = core.get_prop_value_typed(a_idx, RESPONSE_HAS_BEEN_SUBMITTED_IDX);
+    assert!(!submitted);
+
+    // Entering the correct response does not change the credit until it is submitted
+    enter_response(&mut core, " hello ", ti_idx);
+    let credit: f64 = core.get_prop_value_typed(a_idx, CREDIT_ACHIEVED_IDX);
+    assert_eq!(credit, 0.0);
+
+    let _ = core.dispatch_action(submit_answer_action(a_idx));
+    let credit: f64 = core.get_prop_value_typed(a_idx, CREDIT_ACHIEVED_IDX);
+    assert_eq!(credit, 1.0);
+    let submitted: bool = core.get_prop_value_typed(a_idx, RESPONSE_HAS_BEEN_SUBMITTED_IDX);
+    assert!(submitted);
+    assert_eq!(
+        core.get_prop_value(a_idx, SUBMITTED_RESPONSES_IDX),
+        strings(&[" hello "])
+    );
+    let just_submitted: bool = core.get_prop_value_typed(a_idx, JUST_SUBMITTED_IDX);
+    assert!(just_submitted);
+
+    // Changing the response keeps the submitted credit
+    enter_response(&mut core, "bye", ti_idx);
+    let credit: f64 = core.get_prop_value_typed(a_idx, CREDIT_ACHIEVED_IDX);
+    assert_eq!(credit, 1.0);
+    let just_submitted: bool = core.get_prop_value_typed(a_idx, JUST_SUBMITTED_IDX);
+    assert!(!just_submitted);
+
+    let _ = core.dispatch_action(submit_answer_action(a_idx));
+    let credit: f64 = core.get_prop_value_typed(a_idx, CREDIT_ACHIEVED_IDX);
+    assert_eq!(credit, 0.0);
+    assert_eq!(
+        core.get_prop_value(a_idx, SUBMITTED_RESPONSES_IDX),
+        strings(&["bye"])
+    );
+}
+
+#[test]
+fn best_award_gives_the_credit() {
+    let dast_root = dast_root_no_position(
+        r#"<answer name="a">
+  <textInput name="ti" />
+  <award>hello</award>
+  <award credit="0.5">hi</award>
+  <award credit="0.25">hi</award>
+</answer>
+<number name="n">$a.creditAchieved</number>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let a_idx = core.get_component_index_by_name("a");
+    let ti_idx = core.get_component_index_by_name("ti");
+    let n_idx = core.get_component_index_by_name("n");
+
+    enter_response(&mut core, "hi", ti_idx);
+    let _ = core.dispatch_action(submit_answer_action(a_idx));
+
+    let credit: f64 = core.get_prop_value_typed(a_idx, CREDIT_ACHIEVED_IDX);
+    assert_eq!(credit, 0.5);
+
+    // The credit can be referenced by other components
+    let value: f64 = core.get_prop_value_typed(n_idx, NumberProps::Value.local_idx());
+    assert_eq!(value, 0.5);
+}
+
+#[test]
+fn submitted_answer_is_saved() {
+    let dast_root = dast_root_no_position(
+        r#"<answer name="a"><textInput name="ti" /><award>hello</award></answer>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let a_idx = core.get_component_index_by_name("a");
+    let ti_idx = core.get_component_index_by_name("ti");
+
+    enter_response(&mut core, "hello", ti_idx);
+    let _ = core.dispatch_action(submit_answer_action(a_idx));
+    let state_blob = core.core.export_state().unwrap();
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+    core.core.load_state(&state_blob).unwrap();
+
+    let credit: f64 = core.get_prop_value_typed(a_idx, CREDIT_ACHIEVED_IDX);
+    assert_eq!(credit, 1.0);
+    assert_eq!(
+        core.get_prop_value(a_idx, SUBMITTED_RESPONSES_IDX),
+        strings(&["hello"])
+    );
+}
+
+#[test]
+fn score_counts_each_answer_as_one_point() {
+    let dast_root = dast_root_no_position(
+        r#"<answer name="a"><textInput name="ti" /><award>hello</award><award credit="0.5">hi</award></answer>
+<answer name="b"><textInput /><award>bye</award></answer>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let a_idx = core.get_component_index_by_name("a");
+    let b_idx = core.get_component_index_by_name("b");
+    let ti_idx = core.get_component_index_by_name("ti");
+
+    let score = core.core.get_score();
+    assert_eq!(score.credits, vec![0.0, 0.0]);
+    assert_eq!(score.score, 0.0);
+    assert_eq!(score.total_points, 2.0);
+
+    enter_response(&mut core, "hi", ti_idx);
+    let _ = core.dispatch_action(submit_answer_action(a_idx));
+
+    let score = core.core.get_score();
+    assert_eq!(
+        score.component_indices,
+        vec![ComponentIdx::new(a_idx), ComponentIdx::new(b_idx)]
+    );
+    assert_eq!(score.credits, vec![0.5, 0.0]);
+    assert_eq!(score.score, 0.5);
+}
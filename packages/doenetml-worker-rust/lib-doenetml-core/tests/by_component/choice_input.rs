@@ -0,0 +1,154 @@
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::choice_input::{ChoiceInputActionArgs, ChoiceInputActions, ChoiceInputProps},
+        types::{Action, ActionBody},
+    },
+    dast::FlatDastElementContent,
+    props::PropValue,
+};
+
+use super::*;
+
+const SELECTED_INDICES_LOCAL_IDX: LocalPropIdx = ChoiceInputProps::SelectedIndices.local_idx();
+
+fn select_action(choice_input_idx: usize, selected_indices: Vec<i64>) -> Action {
+    Action {
+        component_idx: choice_input_idx.into(),
+        action: ActionsEnum::ChoiceInput(ChoiceInputActions::UpdateSelectedIndices(ActionBody {
+            args: ChoiceInputActionArgs { selected_indices },
+        })),
+    }
+}
+
+fn integers(values: &[i64]) -> PropValue {
+    PropValue::from(
+        values
+            .iter()
+            .copied()
+            .map(PropValue::Integer)
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[test]
+fn choices_are_rendered_as_children() {
+    let dast_root = dast_root_no_position(
+        r#"<choiceInput name="ci">
+  <choice name="c1">cat</choice>
+  <choice name="c2" hide>dog</choice>
+  <choice name="c3" disabled>mouse</choice>
+</choiceInput>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let ci_idx = core.get_component_index_by_name("ci");
+    let c1_idx = core.get_component_index_by_name("c1");
+    let c2_idx = core.get_component_index_by_name("c2");
+    let c3_idx = core.get_component_index_by_name("c3");
+
+    let flat_dast = core.to_flat_dast();
+    // Hidden choices are rendered too, so that the children line up with `choiceOrder`.
+    assert_eq!(
+        flat_dast.elements[ci_idx].children,
+        vec![
+            FlatDastElementContent::new_original_element(c1_idx),
+            FlatDastElementContent::new_original_element(c2_idx),
+            FlatDastElementContent::new_original_element(c3_idx),
+        ]
+    );
+
+    assert_eq!(
+        core.get_prop(ci_idx, SELECTED_INDICES_LOCAL_IDX).value,
+        integers(&[])
+    );
+    assert_eq!(
+        core.get_prop(ci_idx, ChoiceInputProps::ChoiceOrder.local_idx())
+            .value,
+        integers(&[1, 2, 3])
+    );
+    assert_eq!(
+        core.get_prop(ci_idx, ChoiceInputProps::ChoicesHidden.local_idx())
+            .value,
+        PropValue::from(vec![false.into(), true.into(), false.into()])
+    );
+    assert_eq!(
+        core.get_prop(ci_idx, ChoiceInputProps::ChoicesDisabled.local_idx())
+            .value,
+        PropValue::from(vec![false.into(), false.into(), true.into()])
+    );
+}
+
+#[test]
+fn selecting_a_single_choice() {
+    let dast_root = dast_root_no_position(
+        r#"<choiceInput name="ci"><choice>cat</choice><choice>dog</choice><choice disabled>mouse</choice></choiceInput>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+    let ci_idx = core.get_component_index_by_name("ci");
+
+    core.dispatch_action(select_action(ci_idx, vec![2]))
+        .unwrap();
+    assert_eq!(
+        core.get_prop(ci_idx, SELECTED_INDICES_LOCAL_IDX).value,
+        integers(&[2])
+    );
+
+    // Only one choice may be selected without `selectMultiple`.
+    core.dispatch_action(select_action(ci_idx, vec![2, 1]))
+        .unwrap();
+    assert_eq!(
+        core.get_prop(ci_idx, SELECTED_INDICES_LOCAL_IDX).value,
+        integers(&[1])
+    );
+
+    // Disabled choices and indices out of range cannot be selected.
+    core.dispatch_action(select_action(ci_idx, vec![3, 0, 4]))
+        .unwrap();
+    assert_eq!(
+        core.get_prop(ci_idx, SELECTED_INDICES_LOCAL_IDX).value,
+        integers(&[])
+    );
+}
+
+#[test]
+fn selecting_multiple_choices() {
+    let dast_root = dast_root_no_position(
+        r#"<choiceInput name="ci" selectMultiple><choice>cat</choice><choice>dog</choice><choice>mouse</choice></choiceInput>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+    let ci_idx = core.get_component_index_by_name("ci");
+
+    core.dispatch_action(select_action(ci_idx, vec![3, 1, 3]))
+        .unwrap();
+    assert_eq!(
+        core.get_prop(ci_idx, SELECTED_INDICES_LOCAL_IDX).value,
+        integers(&[1, 3])
+    );
+}
+
+#[test]
+fn selection_is_saved_and_restored() {
+    let source = r#"<choiceInput name="ci" selectMultiple><choice>cat</choice><choice>dog</choice></choiceInput>"#;
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root_no_position(source));
+    let ci_idx = core.get_component_index_by_name("ci");
+    core.dispatch_action(select_action(ci_idx, vec![1, 2]))
+        .unwrap();
+    let state_blob = core.core.export_state().unwrap();
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root_no_position(source));
+    core.core.load_state(&state_blob).unwrap();
+    assert_eq!(
+        core.get_prop(ci_idx, SELECTED_INDICES_LOCAL_IDX).value,
+        integers(&[1, 2])
+    );
+}
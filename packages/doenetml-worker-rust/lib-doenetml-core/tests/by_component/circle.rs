@@ -0,0 +1,157 @@
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::{
+            circle::{CircleActions, CircleMoveActionArgs, CircleProps, CircleResizeActionArgs},
+            number::NumberProps,
+        },
+        types::{Action, ActionBody},
+    },
+    props::{PropValue, prop_type},
+    state::types::math_expr::{JsMathExpr, MathExpr},
+};
+
+use super::*;
+
+// Note: we can only test circles with numerical centers, as otherwise it requires wasm to call out to math-expressions
+
+const CENTER_LOCAL_IDX: LocalPropIdx = CircleProps::Center.local_idx();
+const NUMERICAL_CENTER_LOCAL_IDX: LocalPropIdx = CircleProps::NumericalCenter.local_idx();
+const RADIUS_LOCAL_IDX: LocalPropIdx = CircleProps::Radius.local_idx();
+
+fn numerical_center(x: f64, y: f64) -> prop_type::PropVec {
+    vec![PropValue::Number(x), PropValue::Number(y)].into()
+}
+
+fn move_circle(core: &mut TestCore, circle_idx: usize, center: [f64; 2]) -> Result<(), String> {
+    core.dispatch_action(Action {
+        component_idx: circle_idx.into(),
+        action: ActionsEnum::Circle(CircleActions::MoveCircle(ActionBody {
+            args: CircleMoveActionArgs { center },
+        })),
+    })
+    .map(|_| ())
+}
+
+fn resize_circle(core: &mut TestCore, circle_idx: usize, radius: f64) -> Result<(), String> {
+    core.dispatch_action(Action {
+        component_idx: circle_idx.into(),
+        action: ActionsEnum::Circle(CircleActions::ResizeCircle(ActionBody {
+            args: CircleResizeActionArgs { radius },
+        })),
+    })
+    .map(|_| ())
+}
+
+#[test]
+fn unit_circle_at_origin_by_default() {
+    let dast_root = dast_root_no_position(r#"<circle name="c"/>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let circle_idx = core.get_component_index_by_name("c");
+
+    let center = MathExpr {
+        math_object: JsMathExpr("[\"vector\",0,0]".to_string()),
+    };
+    assert_eq!(
+        core.get_prop(circle_idx, CENTER_LOCAL_IDX).value,
+        center.into()
+    );
+    let center: prop_type::PropVec =
+        core.get_prop_value_typed(circle_idx, NUMERICAL_CENTER_LOCAL_IDX);
+    assert_eq!(center, numerical_center(0.0, 0.0));
+    let radius: f64 = core.get_prop_value_typed(circle_idx, RADIUS_LOCAL_IDX);
+    assert_eq!(radius, 1.0);
+}
+
+#[test]
+fn circle_centered_at_a_point() {
+    let dast_root = dast_root_no_position(
+        r#"<point name="P" x="3" y="-2"/><graph><circle name="c" center="$P" radius="2.5"/></graph>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let circle_idx = core.get_component_index_by_name("c");
+
+    let center: prop_type::PropVec =
+        core.get_prop_value_typed(circle_idx, NUMERICAL_CENTER_LOCAL_IDX);
+    assert_eq!(center, numerical_center(3.0, -2.0));
+    let radius: f64 = core.get_prop_value_typed(circle_idx, RADIUS_LOCAL_IDX);
+    assert_eq!(radius, 2.5);
+}
+
+#[test]
+fn move_and_resize_default_circle() {
+    let dast_root = dast_root_no_position(r#"<graph><circle name="c"/></graph>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let circle_idx = core.get_component_index_by_name("c");
+
+    move_circle(&mut core, circle_idx, [-1.0, 4.5]).unwrap();
+    resize_circle(&mut core, circle_idx, 3.0).unwrap();
+
+    let center: prop_type::PropVec =
+        core.get_prop_value_typed(circle_idx, NUMERICAL_CENTER_LOCAL_IDX);
+    assert_eq!(center, numerical_center(-1.0, 4.5));
+    let radius: f64 = core.get_prop_value_typed(circle_idx, RADIUS_LOCAL_IDX);
+    assert_eq!(radius, 3.0);
+}
+
+#[test]
+fn move_and_resize_circle_update_the_referenced_values() {
+    let dast_root = dast_root_no_position(
+        r#"<number name="a">1</number><number name="b">2</number><number name="r">5</number>
+<point name="P" x="$a" y="$b"/>
+<graph><circle name="c" center="$P" radius="$r"/></graph>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let circle_idx = core.get_component_index_by_name("c");
+
+    move_circle(&mut core, circle_idx, [-3.0, 0.5]).unwrap();
+    resize_circle(&mut core, circle_idx, 0.25).unwrap();
+
+    // The changes are propagated to the `<number>`s that the center and radius come from.
+    for (name, value) in [("a", -3.0), ("b", 0.5), ("r", 0.25)] {
+        let number_idx = core.get_component_index_by_name(name);
+        let number: f64 = core.get_prop_value_typed(number_idx, NumberProps::Value.local_idx());
+        assert_eq!(number, value);
+    }
+}
+
+#[test]
+fn invalid_radius_is_rejected() {
+    let dast_root = dast_root_no_position(r#"<circle name="c"/>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let circle_idx = core.get_component_index_by_name("c");
+
+    assert!(resize_circle(&mut core, circle_idx, -1.0).is_err());
+    assert!(resize_circle(&mut core, circle_idx, f64::NAN).is_err());
+
+    let center: prop_type::PropVec =
+        core.get_prop_value_typed(circle_idx, NUMERICAL_CENTER_LOCAL_IDX);
+    assert_eq!(center, numerical_center(0.0, 0.0));
+    let radius: f64 = core.get_prop_value_typed(circle_idx, RADIUS_LOCAL_IDX);
+    assert_eq!(radius, 1.0);
+}
+
+#[test]
+fn move_circle_requires_two_coordinates() {
+    let args = serde_json::from_str::<CircleMoveActionArgs>(r#"{"center": [1, -2.5]}"#).unwrap();
+    assert_eq!(args.center, [1.0, -2.5]);
+
+    for json in [r#"{"center": [1]}"#, r#"{"center": [1, 2, 3]}"#] {
+        assert!(serde_json::from_str::<CircleMoveActionArgs>(json).is_err());
+    }
+}
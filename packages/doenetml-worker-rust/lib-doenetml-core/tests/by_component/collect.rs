@@ -0,0 +1,120 @@
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::text_input::{TextInputActionArgs, TextInputActions},
+        types::{Action, ActionBody},
+    },
+    dast::FlatDastElementContent,
+};
+
+use super::*;
+
+/// Type `text` into the text input named `name` and press enter.
+fn enter_text(core: &mut TestCore, name: &str, text: &str) {
+    let component_idx = core.get_component_index_by_name(name).into();
+    core.dispatch_actions(vec![
+        Action {
+            component_idx,
+            action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+                args: TextInputActionArgs {
+                    text: text.to_string(),
+                },
+            })),
+        },
+        Action {
+            component_idx,
+            action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+        },
+    ])
+    .unwrap();
+}
+
+/// The content rendered by the component named `name`.
+fn rendered_children(core: &mut TestCore, name: &str) -> Vec<FlatDastElementContent> {
+    let component_idx = core.get_component_index_by_name(name);
+    core.to_flat_dast().elements[component_idx].children.clone()
+}
+
+/// The content of a `<collect>` that renders the component named `name`.
+fn collected(core: &mut TestCore, name: &str) -> FlatDastElementContent {
+    FlatDastElementContent::new_duplicate_element(core.get_component_index_by_name(name))
+}
+
+#[test]
+fn collects_components_of_the_given_type() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <graph name="g">
+            <point name="A" />
+            <line name="l" />
+            <point name="B" />
+        </graph>
+        <collect name="c" source="$g" componentTypes="point" />
+        "#,
+    );
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let a = collected(&mut core, "A");
+    let b = collected(&mut core, "B");
+    assert_eq!(rendered_children(&mut core, "c"), vec![a, b]);
+}
+
+#[test]
+fn collects_several_types_in_document_order() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <section name="s">
+            <p name="p1"><text name="t1">one</text></p>
+            <p name="p2"><text name="t2">two</text></p>
+        </section>
+        <collect name="c" source="$s" componentTypes="p text" />
+        "#,
+    );
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let expected = ["p1", "t1", "p2", "t2"]
+        .iter()
+        .map(|name| collected(&mut core, name))
+        .collect::<Vec<_>>();
+    assert_eq!(rendered_children(&mut core, "c"), expected);
+}
+
+#[test]
+fn collected_components_follow_conditional_content() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <textInput name="ti">$b</textInput><boolean name="b">false</boolean>
+        <graph name="g">
+            <point name="A" />
+            <conditionalContent>
+                <case condition="$b"><point name="B" /></case>
+            </conditionalContent>
+        </graph>
+        <collect name="c" source="$g" componentTypes="point" />
+        "#,
+    );
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let a = collected(&mut core, "A");
+    assert_eq!(rendered_children(&mut core, "c"), vec![a.clone()]);
+
+    enter_text(&mut core, "ti", "true");
+    let b = collected(&mut core, "B");
+    assert_eq!(rendered_children(&mut core, "c"), vec![a.clone(), b]);
+
+    enter_text(&mut core, "ti", "false");
+    assert_eq!(rendered_children(&mut core, "c"), vec![a]);
+}
+
+#[test]
+fn collects_nothing_without_source() {
+    let dast_root =
+        dast_root_no_position(r#"<point name="A" /><collect name="c" componentTypes="point" />"#);
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    assert!(rendered_children(&mut core, "c").is_empty());
+}
@@ -0,0 +1,103 @@
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::text_input::{TextInputActionArgs, TextInputActions},
+        types::{Action, ActionBody},
+    },
+    dast::FlatDastElementContent,
+};
+
+use super::*;
+
+/// Type `text` into the text input named `name` and press enter.
+fn enter_text(core: &mut TestCore, name: &str, text: &str) {
+    let component_idx = core.get_component_index_by_name(name).into();
+    core.dispatch_actions(vec![
+        Action {
+            component_idx,
+            action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+                args: TextInputActionArgs {
+                    text: text.to_string(),
+                },
+            })),
+        },
+        Action {
+            component_idx,
+            action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+        },
+    ])
+    .unwrap();
+}
+
+/// The content rendered by the component named `name`.
+fn rendered_children(core: &mut TestCore, name: &str) -> Vec<FlatDastElementContent> {
+    let component_idx = core.get_component_index_by_name(name);
+    core.to_flat_dast().elements[component_idx].children.clone()
+}
+
+/// The content of a parent that renders the component named `name`.
+fn rendered_component(core: &mut TestCore, name: &str) -> FlatDastElementContent {
+    FlatDastElementContent::new_original_element(core.get_component_index_by_name(name))
+}
+
+#[test]
+fn shows_first_true_case_or_else() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <textInput name="ti1">$b1</textInput><boolean name="b1">true</boolean>
+        <textInput name="ti2">$b2</textInput><boolean name="b2">true</boolean>
+        <conditionalContent name="cc">
+            <case name="first" condition="$b1"><p>First</p></case>
+            <case name="second" condition="$b2"><p>Second</p></case>
+            <else name="otherwise"><p>Otherwise</p></else>
+        </conditionalContent>
+        "#,
+    );
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    // Both cases are true, so only the first is shown.
+    let first = rendered_component(&mut core, "first");
+    assert_eq!(rendered_children(&mut core, "cc"), vec![first]);
+
+    enter_text(&mut core, "ti1", "false");
+    let second = rendered_component(&mut core, "second");
+    assert_eq!(rendered_children(&mut core, "cc"), vec![second]);
+
+    enter_text(&mut core, "ti2", "false");
+    let otherwise = rendered_component(&mut core, "otherwise");
+    assert_eq!(rendered_children(&mut core, "cc"), vec![otherwise]);
+
+    enter_text(&mut core, "ti1", "true");
+    let first = rendered_component(&mut core, "first");
+    assert_eq!(rendered_children(&mut core, "cc"), vec![first]);
+}
+
+#[test]
+fn shows_nothing_without_true_case_or_else() {
+    let dast_root = dast_root_no_position(
+        r#"<conditionalContent name="cc"><case condition="false"><p>Hidden</p></case></conditionalContent>"#,
+    );
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    assert!(rendered_children(&mut core, "cc").is_empty());
+}
+
+#[test]
+fn condition_without_cases_shows_children() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <textInput name="ti">$b</textInput><boolean name="b">false</boolean>
+        <conditionalContent name="cc" condition="$b"><p name="p">Shown</p></conditionalContent>
+        "#,
+    );
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    assert!(rendered_children(&mut core, "cc").is_empty());
+
+    enter_text(&mut core, "ti", "true");
+    let p = rendered_component(&mut core, "p");
+    assert_eq!(rendered_children(&mut core, "cc"), vec![p]);
+}
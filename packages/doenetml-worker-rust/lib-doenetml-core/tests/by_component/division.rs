@@ -1,4 +1,14 @@
-use doenetml_core::{components::doenet::division::DivisionProps, props::prop_type};
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::{
+            division::DivisionProps,
+            text_input::{TextInputActionArgs, TextInputActions},
+        },
+        types::{Action, ActionBody},
+    },
+    props::prop_type,
+};
 
 use super::*;
 
@@ -64,3 +74,103 @@ fn division_report_proper_label() {
     );
     assert_eq!(label.label, "Chapter");
 }
+
+// <document>: idx 0
+//  <textInput name="credit">: idx 1
+//  <section name="s">: idx 2
+//   <title>: idx 3
+//   <p>: idx 4
+//    <textInput>: idx 5
+const RELEASE_SOURCE: &str = r#"<textInput name="credit" /><section name="s" availableAfterCredit="$credit >= 0.8"><title>Next</title><p>Released <textInput /></p></section>"#;
+
+fn section_is_available(core: &mut TestCore) -> bool {
+    core.get_prop_value_typed(
+        core.get_component_index_by_name("s"),
+        DivisionProps::Available.local_idx(),
+    )
+}
+
+fn type_in_text_input(component_idx: usize, text: &str) -> Action {
+    Action {
+        component_idx: component_idx.into(),
+        action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+            args: TextInputActionArgs {
+                text: text.to_string(),
+            },
+        })),
+    }
+}
+
+#[test]
+fn division_without_condition_is_available() {
+    let dast_root = dast_root_no_position(r#"<section name="s"><p>Hi</p></section>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    assert!(section_is_available(&mut core));
+}
+
+#[test]
+fn division_is_available_when_credit_is_sufficient() {
+    let dast_root = dast_root_no_position(
+        r#"<number name="a">1</number><number name="b">0.2</number><section name="s1" availableAfterCredit="$a >= 0.8" /><section name="s2" availableAfterCredit="$a = 1 and $b > 0.5" />"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let s1: bool = core.get_prop_value_typed(
+        core.get_component_index_by_name("s1"),
+        DivisionProps::Available.local_idx(),
+    );
+    assert!(s1);
+    let s2: bool = core.get_prop_value_typed(
+        core.get_component_index_by_name("s2"),
+        DivisionProps::Available.local_idx(),
+    );
+    assert!(!s2);
+}
+
+#[test]
+fn division_is_released_after_credit() {
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root_no_position(RELEASE_SOURCE));
+
+    assert!(!section_is_available(&mut core));
+    let flat_dast = core.to_flat_dast();
+    // The title is rendered via the `title` prop, not as a child.
+    assert!(flat_dast.elements[2].children.is_empty());
+    // The locked content is not sent to the renderer.
+    assert!(
+        !serde_json::to_string(&flat_dast)
+            .unwrap()
+            .contains("Released")
+    );
+
+    core.dispatch_action(type_in_text_input(1, "0.9")).unwrap();
+    core.dispatch_action(Action {
+        component_idx: 1.into(),
+        action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+    })
+    .unwrap();
+    assert!(section_is_available(&mut core));
+    let flat_dast = core.to_flat_dast();
+    assert!(
+        serde_json::to_string(&flat_dast)
+            .unwrap()
+            .contains("Released")
+    );
+}
+
+#[test]
+fn actions_in_unavailable_division_are_rejected() {
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root_no_position(RELEASE_SOURCE));
+    core.to_flat_dast();
+
+    assert!(
+        core.dispatch_action(type_in_text_input(5, "hello"))
+            .is_err()
+    );
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::{
+            embedded_applet::{
+                AppletValueChangedActionArgs, EmbeddedAppletActions, EmbeddedAppletProps,
+            },
+            number::NumberProps,
+        },
+        types::{Action, ActionBody},
+    },
+    dast::{ForRenderPropValue, ForRenderProps},
+    props::PropValue,
+};
+
+const APPLET_VALUE_IDX: LocalPropIdx = EmbeddedAppletProps::Value.local_idx();
+const NUMBER_VALUE_IDX: LocalPropIdx = NumberProps::Value.local_idx();
+
+/// Return the action reporting that the applet's value changed to `value`.
+fn applet_value_changed_action(value: f64, component_idx: usize) -> Action {
+    Action {
+        component_idx: component_idx.into(),
+        action: ActionsEnum::EmbeddedApplet(EmbeddedAppletActions::AppletValueChanged(
+            ActionBody {
+                args: AppletValueChangedActionArgs { value },
+            },
+        )),
+    }
+}
+
+#[test]
+fn embedded_applet_renders_set_applet_instructions() {
+    let dast_root = dast_root_no_position(
+        r#"<number name="n">3</number><embeddedApplet src="https://example.com/applet" appletType="geogebra" input="$n" />"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+    assert_eq!(
+        flat_dast.elements[2].data.props,
+        Some(ForRenderProps(vec![
+            ForRenderPropValue {
+                name: "setApplet",
+                value: PropValue::Number(3.0).into()
+            },
+            ForRenderPropValue {
+                name: "src",
+                value: "https://example.com/applet".into()
+            },
+            ForRenderPropValue {
+                name: "appletType",
+                value: "geogebra".into()
+            },
+        ]))
+    );
+}
+
+#[test]
+fn applet_has_no_value_until_it_reports_one() {
+    let dast_root = dast_root_no_position(r#"<embeddedApplet name="a" />"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let applet_idx = core.get_component_index_by_name("a");
+    let value: f64 = core.get_prop_value_typed(applet_idx, APPLET_VALUE_IDX);
+    assert!(value.is_nan());
+}
+
+#[test]
+fn applet_value_changed_updates_dependent_values() {
+    let dast_root = dast_root_no_position(
+        r#"<embeddedApplet name="a" /><number name="n">$a.value</number><number name="m">$a</number>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let applet_idx = core.get_component_index_by_name("a");
+    let n_idx = core.get_component_index_by_name("n");
+    let m_idx = core.get_component_index_by_name("m");
+
+    let _ = core.dispatch_action(applet_value_changed_action(5.5, applet_idx));
+
+    let value: f64 = core.get_prop_value_typed(applet_idx, APPLET_VALUE_IDX);
+    assert_eq!(value, 5.5);
+    let value: f64 = core.get_prop_value_typed(n_idx, NUMBER_VALUE_IDX);
+    assert_eq!(value, 5.5);
+    let value: f64 = core.get_prop_value_typed(m_idx, NUMBER_VALUE_IDX);
+    assert_eq!(value, 5.5);
+}
@@ -0,0 +1,118 @@
+use doenetml_core::{
+    components::doenet::experiment::ExperimentProps,
+    core::{DocumentFlags, ExperimentAssignment, decode_state_blob},
+    dast::FlatDastElementContent,
+    props::prop_type,
+};
+
+use super::*;
+
+// <document>: idx 0
+//  <experiment name="e">: idx 1
+//   <p>: idx 2, 3
+const SOURCE: &str = r#"<experiment name="e"><p>A</p><p>B</p></experiment>"#;
+
+fn core_with_student_seed(student_seed: u64) -> TestCore {
+    let mut core = Core::new();
+    core.set_flags(DocumentFlags {
+        student_seed,
+        ..Default::default()
+    });
+    let mut core = TestCore::new_from(core);
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core
+}
+
+fn arm_index(core: &mut TestCore) -> prop_type::Integer {
+    core.get_prop_value_typed(
+        core.get_component_index_by_name("e"),
+        ExperimentProps::ArmIndex.local_idx(),
+    )
+}
+
+#[test]
+fn experiment_is_deterministic_for_a_student_seed() {
+    let mut arms = std::collections::BTreeSet::new();
+    for seed in 0..10 {
+        let first = arm_index(&mut core_with_student_seed(seed));
+        let second = arm_index(&mut core_with_student_seed(seed));
+        assert_eq!(first, second);
+        assert!((0..2).contains(&first));
+        arms.insert(first);
+    }
+    // Different students are assigned to different arms.
+    assert_eq!(arms.len(), 2);
+}
+
+#[test]
+fn experiment_ignores_the_variant_seed() {
+    let arm = arm_index(&mut core_with_student_seed(4));
+    for variant_seed in 0..10 {
+        let mut core = Core::new();
+        core.set_flags(DocumentFlags {
+            student_seed: 4,
+            variant_seed,
+            ..Default::default()
+        });
+        let mut core = TestCore::new_from(core);
+        core.init_from_dast_root(&dast_root_no_position(SOURCE));
+        assert_eq!(arm_index(&mut core), arm);
+    }
+}
+
+#[test]
+fn experiment_renders_only_the_assigned_arm() {
+    let mut core = core_with_student_seed(7);
+    let arm = arm_index(&mut core) as usize;
+    let flat_dast = core.to_flat_dast();
+
+    assert_eq!(
+        flat_dast.elements[1].children,
+        vec![FlatDastElementContent::new_original_element(2 + arm)]
+    );
+}
+
+#[test]
+fn experiment_assignment_is_reported_for_analytics() {
+    let mut core = core_with_student_seed(3);
+    let arm = arm_index(&mut core) as usize;
+
+    assert_eq!(
+        core.core.get_experiment_assignments(),
+        vec![ExperimentAssignment {
+            component_idx: 1.into(),
+            arm_index: arm,
+        }]
+    );
+}
+
+#[test]
+fn experiment_assignment_is_saved_and_restored() {
+    let mut core = core_with_student_seed(3);
+    let arm = arm_index(&mut core);
+    let state_blob = core.core.export_state().unwrap();
+
+    let saved_state = decode_state_blob(&state_blob, None).unwrap();
+    let saved_arm = saved_state
+        .values
+        .iter()
+        .find(|value| value.prop_name == "armIndex")
+        .unwrap();
+    assert_eq!(saved_arm.value, serde_json::json!(arm));
+
+    // A student whose seed gives the other arm keeps the saved arm.
+    let other_seed = (0..10)
+        .find(|&seed| arm_index(&mut core_with_student_seed(seed)) != arm)
+        .unwrap();
+    let mut core = core_with_student_seed(other_seed);
+    core.core.load_state(&state_blob).unwrap();
+    assert_eq!(arm_index(&mut core), arm);
+}
+
+#[test]
+fn unassigned_arms_are_not_dead_content() {
+    let mut core = core_with_student_seed(0);
+    core.to_flat_dast();
+
+    assert!(core.core.find_dead_content().is_empty());
+}
@@ -0,0 +1,124 @@
+use super::*;
+
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::text_input::{TextInputActionArgs, TextInputActions},
+        types::{Action, ActionBody},
+    },
+    core::DocumentFlags,
+    dast::{FlatDastElementContent, FlatDastRoot},
+    props::{PropValue, Role},
+};
+
+// <document>: idx 0
+//  <p>: idx 1
+//   <forInstructor>: idx 2
+//    <p name="note">: idx 3
+//    <textInput>: idx 4
+//  $note: idx 5
+const SOURCE: &str = r#"<p>Solve it.<forInstructor><p name="note">Answer: 42</p><textInput /></forInstructor></p>$note"#;
+
+fn core_for_role(role: Role) -> Core {
+    let mut core = Core::new();
+    core.set_flags(DocumentFlags {
+        role,
+        ..Default::default()
+    });
+    core.init_from_dast_root(&dast_root_no_position(SOURCE));
+    core
+}
+
+fn flat_dast_contains(flat_dast: &FlatDastRoot, text: &str) -> bool {
+    serde_json::to_string(flat_dast).unwrap().contains(text)
+}
+
+fn type_in_text_input() -> Action {
+    Action {
+        component_idx: 4.into(),
+        action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+            args: TextInputActionArgs {
+                text: "hello".to_string(),
+            },
+        })),
+    }
+}
+
+#[test]
+fn instructor_content_never_reaches_students() {
+    let mut core = core_for_role(Role::Student);
+    let flat_dast = core.to_flat_dast();
+
+    assert_eq!(
+        flat_dast.elements[1].children,
+        vec![FlatDastElementContent::Text("Solve it.".to_string())]
+    );
+    // Neither the note nor its copy outside the `<forInstructor>` is sent to the renderer.
+    assert!(!flat_dast_contains(&flat_dast, "Answer"));
+    assert!(flat_dast.elements[5].children.is_empty());
+}
+
+#[test]
+fn instructor_content_is_rendered_for_instructors_and_graders() {
+    for role in [Role::Instructor, Role::Grader] {
+        let mut core = core_for_role(role);
+        let flat_dast = core.to_flat_dast();
+
+        assert_eq!(
+            flat_dast.elements[1].children,
+            vec![
+                FlatDastElementContent::Text("Solve it.".to_string()),
+                FlatDastElementContent::new_original_element(2),
+            ]
+        );
+        assert!(flat_dast_contains(&flat_dast, "Answer: 42"));
+    }
+}
+
+#[test]
+fn students_cannot_act_on_instructor_content() {
+    let mut core = core_for_role(Role::Student);
+    core.to_flat_dast();
+    assert!(core.dispatch_action(type_in_text_input()).is_err());
+
+    let mut core = core_for_role(Role::Instructor);
+    core.to_flat_dast();
+    assert!(core.dispatch_action(type_in_text_input()).is_ok());
+}
+
+#[test]
+fn instructor_content_is_not_dead_content() {
+    let mut core = core_for_role(Role::Student);
+    core.to_flat_dast();
+
+    assert!(core.find_dead_content().is_empty());
+}
+
+#[test]
+fn props_of_instructor_content_never_reach_students() {
+    let source = r#"<forInstructor><text name="ans">x+1</text></forInstructor><text name="t">Hint: $ans.value</text>"#;
+    let core_for_role = |role| {
+        let mut core = Core::new();
+        core.set_flags(DocumentFlags {
+            role,
+            ..Default::default()
+        });
+        core.init_from_dast_root(&dast_root_no_position(source));
+        core
+    };
+
+    let mut core = core_for_role(Role::Student);
+    let flat_dast = core.to_flat_dast();
+    assert!(!flat_dast_contains(&flat_dast, "x+1"));
+    assert_eq!(
+        core.get_prop_value("t", "value"),
+        Some(PropValue::from("Hint: "))
+    );
+
+    let mut core = core_for_role(Role::Instructor);
+    core.to_flat_dast();
+    assert_eq!(
+        core.get_prop_value("t", "value"),
+        Some(PropValue::from("Hint: x+1"))
+    );
+}
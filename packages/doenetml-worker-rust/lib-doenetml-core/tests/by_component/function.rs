@@ -0,0 +1,78 @@
+use doenetml_core::{
+    components::doenet::function::FunctionProps, props::prop_type,
+    state::types::math_expr::MathExpr,
+};
+
+use super::*;
+
+// Note: we can only test functions with numerical formulas, as otherwise it requires wasm to call out to math-expressions
+
+const FORMULA_LOCAL_IDX: LocalPropIdx = FunctionProps::Formula.local_idx();
+const VARIABLE_LOCAL_IDX: LocalPropIdx = FunctionProps::Variable.local_idx();
+
+#[test]
+fn formula_from_children() {
+    let dast_root = dast_root_no_position(r#"<function name="f">3</function>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let function_idx = core.get_component_index_by_name("f");
+
+    let formula = core.get_prop(function_idx, FORMULA_LOCAL_IDX);
+    assert_eq!(formula.value, MathExpr::from(3.0).into());
+    assert_eq!(formula.came_from_default, false);
+}
+
+#[test]
+fn formula_attribute_takes_precedence_over_children() {
+    let dast_root = dast_root_no_position(
+        r#"<function name="f" formula="5">3</function><function name="g" formula="7"/>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let f_idx = core.get_component_index_by_name("f");
+    let g_idx = core.get_component_index_by_name("g");
+
+    let formula = core.get_prop(f_idx, FORMULA_LOCAL_IDX);
+    assert_eq!(formula.value, MathExpr::from(5.0).into());
+    assert_eq!(formula.came_from_default, false);
+
+    let formula = core.get_prop(g_idx, FORMULA_LOCAL_IDX);
+    assert_eq!(formula.value, MathExpr::from(7.0).into());
+    assert_eq!(formula.came_from_default, false);
+}
+
+#[test]
+fn formula_is_blank_by_default() {
+    let dast_root = dast_root_no_position(r#"<function name="f"/>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let function_idx = core.get_component_index_by_name("f");
+
+    let formula = core.get_prop(function_idx, FORMULA_LOCAL_IDX);
+    assert_eq!(formula.value, MathExpr::default().into());
+    assert_eq!(formula.came_from_default, true);
+}
+
+#[test]
+fn variable_defaults_to_x() {
+    let dast_root = dast_root_no_position(
+        r#"<function name="f">3</function><function name="g" variable="t">3</function>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let variable: prop_type::String =
+        core.get_prop_value_typed(core.get_component_index_by_name("f"), VARIABLE_LOCAL_IDX);
+    assert_eq!(*variable, "x");
+
+    let variable: prop_type::String =
+        core.get_prop_value_typed(core.get_component_index_by_name("g"), VARIABLE_LOCAL_IDX);
+    assert_eq!(*variable, "t");
+}
@@ -1,7 +1,15 @@
 use doenetml_core::{
-    components::doenet::graph::GraphProps,
+    components::{
+        ActionsEnum,
+        doenet::{
+            graph::{GraphActions, GraphChangeAxisLimitsActionArgs, GraphProps},
+            number::NumberProps,
+        },
+        types::{Action, ActionBody},
+    },
+    core::{DocumentEnvironment, DocumentFlags, NumberPrecision, RenderPrecision},
     dast::{ForRenderPropValue, ForRenderPropValueOrContent, ForRenderProps},
-    props::cache::PropWithMeta,
+    props::{cache::PropWithMeta, prop_type},
 };
 
 use super::*;
@@ -12,6 +20,9 @@ const X_MIN_LOCAL_IDX: LocalPropIdx = GraphProps::XMin.local_idx();
 const X_MAX_LOCAL_IDX: LocalPropIdx = GraphProps::XMax.local_idx();
 const Y_MIN_LOCAL_IDX: LocalPropIdx = GraphProps::YMin.local_idx();
 const Y_MAX_LOCAL_IDX: LocalPropIdx = GraphProps::YMax.local_idx();
+const WIDTH_LOCAL_IDX: LocalPropIdx = GraphProps::Width.local_idx();
+const ASPECT_RATIO_LOCAL_IDX: LocalPropIdx = GraphProps::AspectRatio.local_idx();
+const GRID_LOCAL_IDX: LocalPropIdx = GraphProps::Grid.local_idx();
 
 #[test]
 fn graph_with_default_axis_limits() {
@@ -116,6 +127,18 @@ fn graph_with_default_axis_limits() {
             ForRenderPropValue {
                 name: "yMax",
                 value: ForRenderPropValueOrContent::PropValue((10.0).into())
+            },
+            ForRenderPropValue {
+                name: "width",
+                value: ForRenderPropValueOrContent::PropValue(288.0.into())
+            },
+            ForRenderPropValue {
+                name: "aspectRatio",
+                value: ForRenderPropValueOrContent::PropValue(1.0.into())
+            },
+            ForRenderPropValue {
+                name: "grid",
+                value: ForRenderPropValueOrContent::PropValue("none".into())
             }
         ]))
     );
@@ -225,7 +248,252 @@ fn graph_with_specified_axis_limits() {
             ForRenderPropValue {
                 name: "yMax",
                 value: ForRenderPropValueOrContent::PropValue((30.0).into())
+            },
+            ForRenderPropValue {
+                name: "width",
+                value: ForRenderPropValueOrContent::PropValue(288.0.into())
+            },
+            ForRenderPropValue {
+                name: "aspectRatio",
+                value: ForRenderPropValueOrContent::PropValue(1.0.into())
+            },
+            ForRenderPropValue {
+                name: "grid",
+                value: ForRenderPropValueOrContent::PropValue("none".into())
             }
         ]))
     );
 }
+
+#[test]
+fn graph_width_fits_in_viewport() {
+    let dast_root = dast_root_no_position(r#"<graph name="g" /><graph name="g2" width="150" />"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let graph_idx = core.get_component_index_by_name("g");
+    let graph2_idx = core.get_component_index_by_name("g2");
+    let _ = core.to_flat_dast();
+
+    // Without a viewport width, the specified width is used.
+    assert_eq!(
+        core.get_prop(graph_idx, WIDTH_LOCAL_IDX).value,
+        288.0.into()
+    );
+    assert_eq!(
+        core.get_prop(graph2_idx, WIDTH_LOCAL_IDX).value,
+        150.0.into()
+    );
+
+    // Both graphs depend on the viewport width, so both are updated,
+    // but a narrow viewport shrinks only the graph that does not fit.
+    let updates = core.core.set_environment(DocumentEnvironment {
+        viewport_width: Some(200.0),
+        ..Default::default()
+    });
+    let mut updated_components = updates.keys().copied().collect::<Vec<_>>();
+    updated_components.sort_by_key(|idx| idx.as_usize());
+    assert_eq!(
+        updated_components,
+        vec![
+            ComponentIdx::from(graph_idx),
+            ComponentIdx::from(graph2_idx)
+        ]
+    );
+    assert_eq!(
+        core.get_prop(graph_idx, WIDTH_LOCAL_IDX).value,
+        200.0.into()
+    );
+    assert_eq!(
+        core.get_prop(graph2_idx, WIDTH_LOCAL_IDX).value,
+        150.0.into()
+    );
+
+    // Changing a property that the width does not depend on causes no updates.
+    let updates = core.core.set_environment(DocumentEnvironment {
+        viewport_width: Some(200.0),
+        prefers_reduced_motion: true,
+        ..Default::default()
+    });
+    assert!(updates.is_empty());
+
+    // Widening the viewport restores the specified width.
+    core.core.set_environment(DocumentEnvironment {
+        viewport_width: Some(1000.0),
+        ..Default::default()
+    });
+    assert_eq!(
+        core.get_prop(graph_idx, WIDTH_LOCAL_IDX).value,
+        288.0.into()
+    );
+}
+
+#[test]
+fn graph_width_restored_when_viewport_width_becomes_unknown() {
+    let dast_root = dast_root_no_position(r#"<graph name="g" />"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let graph_idx = core.get_component_index_by_name("g");
+    let _ = core.to_flat_dast();
+
+    core.core.set_environment(DocumentEnvironment {
+        viewport_width: Some(200.0),
+        ..Default::default()
+    });
+    assert_eq!(
+        core.get_prop(graph_idx, WIDTH_LOCAL_IDX).value,
+        200.0.into()
+    );
+
+    // The viewport width query now has no value, so there is no changed value to trigger
+    // the recalculation, but the width must still be recalculated.
+    let updates = core.core.set_environment(DocumentEnvironment::default());
+    assert_eq!(
+        updates.keys().copied().collect::<Vec<_>>(),
+        vec![ComponentIdx::from(graph_idx)]
+    );
+    assert_eq!(
+        core.get_prop(graph_idx, WIDTH_LOCAL_IDX).value,
+        288.0.into()
+    );
+}
+
+#[test]
+fn graph_aspect_ratio_and_grid() {
+    let dast_root = dast_root_no_position(
+        r#"<graph name="g1" aspectRatio="2" grid />
+<graph name="g2" grid="Dense" />
+<graph name="g3" grid="false" />
+<graph name="g4" grid="sparse" />"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let g1_idx = core.get_component_index_by_name("g1");
+    let g2_idx = core.get_component_index_by_name("g2");
+    let g3_idx = core.get_component_index_by_name("g3");
+    let g4_idx = core.get_component_index_by_name("g4");
+
+    let aspect_ratio: f64 = core.get_prop_value_typed(g1_idx, ASPECT_RATIO_LOCAL_IDX);
+    assert_eq!(aspect_ratio, 2.0);
+    let aspect_ratio: f64 = core.get_prop_value_typed(g2_idx, ASPECT_RATIO_LOCAL_IDX);
+    assert_eq!(aspect_ratio, 1.0);
+
+    // A blank `grid` attribute gives a medium grid and unrecognized values give no grid.
+    let grid: prop_type::String = core.get_prop_value_typed(g1_idx, GRID_LOCAL_IDX);
+    assert_eq!(grid.as_str(), "medium");
+    let grid: prop_type::String = core.get_prop_value_typed(g2_idx, GRID_LOCAL_IDX);
+    assert_eq!(grid.as_str(), "dense");
+    let grid: prop_type::String = core.get_prop_value_typed(g3_idx, GRID_LOCAL_IDX);
+    assert_eq!(grid.as_str(), "none");
+    let grid: prop_type::String = core.get_prop_value_typed(g4_idx, GRID_LOCAL_IDX);
+    assert_eq!(grid.as_str(), "none");
+}
+
+#[test]
+fn change_axis_limits_updates_bound_numbers() {
+    let dast_root = dast_root_no_position(
+        r#"<number name="xmax">10</number>
+<graph name="g" xmax="$xmax" />"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let graph_idx = core.get_component_index_by_name("g");
+    let xmax_idx = core.get_component_index_by_name("xmax");
+
+    let _ = core.dispatch_action(Action {
+        component_idx: graph_idx.into(),
+        action: ActionsEnum::Graph(GraphActions::ChangeAxisLimits(ActionBody {
+            args: GraphChangeAxisLimitsActionArgs {
+                x_min: -5.0,
+                x_max: 25.0,
+                y_min: -6.0,
+                y_max: 7.0,
+            },
+        })),
+    });
+
+    let x_min: f64 = core.get_prop_value_typed(graph_idx, X_MIN_LOCAL_IDX);
+    assert_eq!(x_min, -5.0);
+    let x_max: f64 = core.get_prop_value_typed(graph_idx, X_MAX_LOCAL_IDX);
+    assert_eq!(x_max, 25.0);
+    let y_min: f64 = core.get_prop_value_typed(graph_idx, Y_MIN_LOCAL_IDX);
+    assert_eq!(y_min, -6.0);
+    let y_max: f64 = core.get_prop_value_typed(graph_idx, Y_MAX_LOCAL_IDX);
+    assert_eq!(y_max, 7.0);
+
+    // The new limit is propagated to the `<number>` that the graph's `xMax` comes from.
+    let x_max: f64 = core.get_prop_value_typed(xmax_idx, NumberProps::Value.local_idx());
+    assert_eq!(x_max, 25.0);
+}
+
+#[test]
+fn rendered_axis_limits_are_rounded_to_the_render_precision() {
+    let dast_root =
+        dast_root_no_position(r#"<graph name="g" xmin="-0.30000000000000004" xmax="0.1" />"#);
+
+    let rendered_x_limits = |precision: RenderPrecision| {
+        let mut core = Core::new();
+        core.set_flags(DocumentFlags {
+            render_precision: precision,
+            ..Default::default()
+        });
+        let mut core = TestCore::new_from(core);
+        core.init_from_dast_root(&dast_root);
+
+        let graph_idx = core.get_component_index_by_name("g");
+        let flat_dast = core.to_flat_dast();
+        let props = flat_dast.elements[graph_idx].data.props.clone().unwrap();
+        (props.0[0].clone(), props.0[1].clone())
+    };
+
+    // By default, numbers are rendered with full precision.
+    assert_eq!(
+        rendered_x_limits(RenderPrecision::default()),
+        (
+            ForRenderPropValue {
+                name: "xMin",
+                value: ForRenderPropValueOrContent::PropValue((-0.30000000000000004).into())
+            },
+            ForRenderPropValue {
+                name: "xMax",
+                value: ForRenderPropValueOrContent::PropValue(0.1.into())
+            },
+        )
+    );
+
+    // Single precision for graphs rounds their rendered numbers.
+    let single_for_graphs = RenderPrecision {
+        by_component_type: [("graph".to_string(), NumberPrecision::Single)].into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        rendered_x_limits(single_for_graphs),
+        (
+            ForRenderPropValue {
+                name: "xMin",
+                value: ForRenderPropValueOrContent::PropValue((-0.3).into())
+            },
+            ForRenderPropValue {
+                name: "xMax",
+                value: ForRenderPropValueOrContent::PropValue(0.1.into())
+            },
+        )
+    );
+
+    // Single precision for other component types leaves graphs unchanged.
+    let single_for_points = RenderPrecision {
+        by_component_type: [("point".to_string(), NumberPrecision::Single)].into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        rendered_x_limits(single_for_points).0.value,
+        ForRenderPropValueOrContent::PropValue((-0.30000000000000004).into())
+    );
+}
@@ -0,0 +1,80 @@
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::hint::{HintActions, HintProps},
+        types::Action,
+    },
+    dast::FlatDastElementContent,
+    props::PropValue,
+};
+
+use super::*;
+
+const OPEN_LOCAL_IDX: LocalPropIdx = HintProps::Open.local_idx();
+
+#[test]
+fn hint_is_revealed_and_closed_by_actions() {
+    let dast_root =
+        dast_root_no_position(r#"<hint name="h"><text name="t">Try factoring.</text></hint>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let h_idx = core.get_component_index_by_name("h");
+    let t_idx = core.get_component_index_by_name("t");
+
+    assert_eq!(
+        core.get_prop(h_idx, OPEN_LOCAL_IDX).value,
+        PropValue::Boolean(false)
+    );
+    assert!(core.to_flat_dast().elements[h_idx].children.is_empty());
+
+    let dispatch = |core: &mut TestCore, action: HintActions| {
+        core.dispatch_action(Action {
+            component_idx: h_idx.into(),
+            action: ActionsEnum::Hint(action),
+        })
+        .unwrap()
+    };
+
+    // Revealing twice leaves the hint open.
+    for _ in 0..2 {
+        let _ = dispatch(&mut core, HintActions::RevealHint);
+        assert_eq!(
+            core.get_prop(h_idx, OPEN_LOCAL_IDX).value,
+            PropValue::Boolean(true)
+        );
+    }
+    assert_eq!(
+        core.to_flat_dast().elements[h_idx].children,
+        vec![FlatDastElementContent::new_original_element(t_idx)]
+    );
+
+    let _ = dispatch(&mut core, HintActions::CloseHint);
+    assert_eq!(
+        core.get_prop(h_idx, OPEN_LOCAL_IDX).value,
+        PropValue::Boolean(false)
+    );
+    assert!(core.to_flat_dast().elements[h_idx].children.is_empty());
+}
+
+#[test]
+fn hint_has_a_default_title() {
+    let dast_root = dast_root_no_position(
+        r#"<hint name="h1"><p>a</p></hint><hint name="h2" title="Need help?"><p>b</p></hint>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let title: String = core.get_prop_value_typed(
+        core.get_component_index_by_name("h1"),
+        HintProps::Title.local_idx(),
+    );
+    assert_eq!(title, "Hint");
+    let title: String = core.get_prop_value_typed(
+        core.get_component_index_by_name("h2"),
+        HintProps::Title.local_idx(),
+    );
+    assert_eq!(title, "Need help?");
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+use doenetml_core::{
+    dast::{DiagnosticCode, ForRenderPropValue, ForRenderProps},
+    props::PropValue,
+};
+
+#[test]
+fn html_block_renders_sanitized_html() {
+    let dast_root = dast_root_no_position(
+        r#"<htmlBlock><![CDATA[<p onclick="steal()">Hi <b>there</b><script>steal()</script></p>]]></htmlBlock>"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+    assert_eq!(
+        flat_dast.elements[1].data.props,
+        Some(ForRenderProps(vec![ForRenderPropValue {
+            name: "html",
+            value: PropValue::String("<p>Hi <b>there</b></p>".to_string().into()).into()
+        }]))
+    );
+    // The html is rendered as an opaque string, not as child elements
+    assert!(flat_dast.elements[1].children.is_empty());
+}
+
+#[test]
+fn stripping_disallowed_html_produces_warning() {
+    let dast_root = dast_root_no_position(
+        r#"<htmlBlock><![CDATA[<p onclick="steal()">Hi<script>steal()</script></p>]]></htmlBlock>"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+    let warnings = flat_dast
+        .warnings_with_code(DiagnosticCode::DisallowedHtmlRemoved)
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "Disallowed HTML was removed from `<htmlBlock>`: `<script>`, `onclick` attribute"
+    );
+}
+
+#[test]
+fn allowed_html_produces_no_warning() {
+    let dast_root = dast_root_no_position(
+        r#"<htmlBlock><![CDATA[<p class="legacy">Hi <a href="https://doenet.org">there</a></p>]]></htmlBlock>"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+    assert!(flat_dast.warnings.is_empty());
+}
+
+#[test]
+fn removed_html_is_a_prop() {
+    let dast_root = dast_root_no_position(
+        r#"<htmlBlock name="h"><![CDATA[<p onclick="steal()">Hi<script>steal()</script></p>]]></htmlBlock>"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    assert_eq!(
+        core.get_prop_value("h", "removedHtml"),
+        Some(PropValue::from(vec![
+            PropValue::from("<script>"),
+            PropValue::from("onclick"),
+        ]))
+    );
+}
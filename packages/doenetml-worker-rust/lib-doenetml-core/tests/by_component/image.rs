@@ -0,0 +1,72 @@
+use super::*;
+
+use doenetml_core::{
+    components::doenet::image::{ImageProps, parse_size},
+    dast::{ForRenderPropValue, ForRenderProps},
+    props::PropValue,
+};
+
+const WIDTH_LOCAL_IDX: LocalPropIdx = ImageProps::Width.local_idx();
+const HEIGHT_LOCAL_IDX: LocalPropIdx = ImageProps::Height.local_idx();
+
+#[test]
+fn image_renders_source_size_and_description() {
+    let dast_root = dast_root_no_position(
+        r#"<image source="cat.png" width="300" height="50%" description="A cat asleep on a keyboard" />"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+    assert_eq!(flat_dast.elements[1].name, "image");
+    assert_eq!(
+        flat_dast.elements[1].data.props,
+        Some(ForRenderProps(vec![
+            ForRenderPropValue {
+                name: "source",
+                value: "cat.png".into()
+            },
+            ForRenderPropValue {
+                name: "width",
+                value: "300px".into()
+            },
+            ForRenderPropValue {
+                name: "height",
+                value: "50%".into()
+            },
+            ForRenderPropValue {
+                name: "description",
+                value: "A cat asleep on a keyboard".into()
+            },
+        ]))
+    );
+}
+
+#[test]
+fn unspecified_or_invalid_sizes_are_empty() {
+    let dast_root = dast_root_no_position(r#"<image name="i" source="cat.png" width="auto" />"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let image_idx = core.get_component_index_by_name("i");
+    assert_eq!(
+        core.get_prop(image_idx, WIDTH_LOCAL_IDX).value,
+        PropValue::String(String::new().into())
+    );
+    assert_eq!(
+        core.get_prop(image_idx, HEIGHT_LOCAL_IDX).value,
+        PropValue::String(String::new().into())
+    );
+}
+
+#[test]
+fn sizes_are_pixels_or_percentages() {
+    assert_eq!(parse_size("300"), Some("300px".to_string()));
+    assert_eq!(parse_size(" 300px "), Some("300px".to_string()));
+    assert_eq!(parse_size("12.5%"), Some("12.5%".to_string()));
+    assert_eq!(parse_size("auto"), None);
+    assert_eq!(parse_size("-5px"), None);
+    assert_eq!(parse_size(""), None);
+}
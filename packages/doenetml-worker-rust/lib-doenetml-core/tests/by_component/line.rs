@@ -0,0 +1,140 @@
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::{
+            line::{LineActions, LineMoveActionArgs, LineProps},
+            number::NumberProps,
+        },
+        types::{Action, ActionBody},
+    },
+    props::{PropValue, prop_type},
+    state::types::math_expr::{JsMathExpr, MathExpr},
+};
+
+use super::*;
+
+// Note: we can only test lines through points with numerical values, as otherwise it requires wasm to call out to math-expressions
+
+const NUMERICAL_POINTS_LOCAL_IDX: LocalPropIdx = LineProps::NumericalPoints.local_idx();
+const SLOPE_LOCAL_IDX: LocalPropIdx = LineProps::Slope.local_idx();
+const INTERCEPT_LOCAL_IDX: LocalPropIdx = LineProps::Intercept.local_idx();
+const EQUATION_LOCAL_IDX: LocalPropIdx = LineProps::Equation.local_idx();
+
+fn numerical_points(points: &[[f64; 2]]) -> prop_type::PropVec {
+    points
+        .iter()
+        .map(|&[x, y]| PropValue::from(vec![PropValue::Number(x), PropValue::Number(y)]))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+#[test]
+fn line_through_two_points() {
+    let dast_root = dast_root_no_position(
+        r#"<point name="P" x="1" y="5"/><point name="Q" x="3" y="9"/>
+<graph><line name="l" through="$P $Q"/></graph>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let line_idx = core.get_component_index_by_name("l");
+
+    let points: prop_type::PropVec =
+        core.get_prop_value_typed(line_idx, NUMERICAL_POINTS_LOCAL_IDX);
+    assert_eq!(points, numerical_points(&[[1.0, 5.0], [3.0, 9.0]]));
+
+    let slope: f64 = core.get_prop_value_typed(line_idx, SLOPE_LOCAL_IDX);
+    assert_eq!(slope, 2.0);
+    let intercept: f64 = core.get_prop_value_typed(line_idx, INTERCEPT_LOCAL_IDX);
+    assert_eq!(intercept, 3.0);
+
+    let equation = MathExpr {
+        math_object: JsMathExpr(r#"["=","y",["+",["*",2,"x"],3]]"#.to_string()),
+    };
+    assert_eq!(
+        core.get_prop(line_idx, EQUATION_LOCAL_IDX).value,
+        equation.into()
+    );
+}
+
+#[test]
+fn vertical_line() {
+    let dast_root = dast_root_no_position(
+        r#"<point name="P" x="2" y="5"/><point name="Q" x="2" y="-1"/>
+<line name="l" through="$P $Q"/>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let line_idx = core.get_component_index_by_name("l");
+
+    let slope: f64 = core.get_prop_value_typed(line_idx, SLOPE_LOCAL_IDX);
+    assert_eq!(slope, f64::INFINITY);
+    let intercept: f64 = core.get_prop_value_typed(line_idx, INTERCEPT_LOCAL_IDX);
+    assert!(intercept.is_nan());
+
+    let equation = MathExpr {
+        math_object: JsMathExpr(r#"["=","x",2]"#.to_string()),
+    };
+    assert_eq!(
+        core.get_prop(line_idx, EQUATION_LOCAL_IDX).value,
+        equation.into()
+    );
+}
+
+#[test]
+fn move_line_moves_its_points() {
+    let dast_root = dast_root_no_position(
+        r#"<number name="a">0</number><number name="b">0</number>
+<number name="c">1</number><number name="d">1</number>
+<point name="P" x="$a" y="$b"/><point name="Q" x="$c" y="$d"/>
+<graph><line name="l" through="$P $Q"/></graph>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let line_idx = core.get_component_index_by_name("l");
+
+    let _ = core.dispatch_action(Action {
+        component_idx: line_idx.into(),
+        action: ActionsEnum::Line(LineActions::MoveLine(ActionBody {
+            args: LineMoveActionArgs {
+                point1_coords: [-2.0, 4.0],
+                point2_coords: [2.0, 2.0],
+            },
+        })),
+    });
+
+    let points: prop_type::PropVec =
+        core.get_prop_value_typed(line_idx, NUMERICAL_POINTS_LOCAL_IDX);
+    assert_eq!(points, numerical_points(&[[-2.0, 4.0], [2.0, 2.0]]));
+    let slope: f64 = core.get_prop_value_typed(line_idx, SLOPE_LOCAL_IDX);
+    assert_eq!(slope, -0.5);
+
+    // The new coordinates are propagated to the `<number>`s that the points come from.
+    for (name, value) in [("a", -2.0), ("b", 4.0), ("c", 2.0), ("d", 2.0)] {
+        let number_idx = core.get_component_index_by_name(name);
+        let number: f64 = core.get_prop_value_typed(number_idx, NumberProps::Value.local_idx());
+        assert_eq!(number, value);
+    }
+}
+
+#[test]
+fn move_line_requires_two_coordinates_per_point() {
+    let args = serde_json::from_str::<LineMoveActionArgs>(
+        r#"{"point1coords": [-2.5, 4], "point2coords": [2, 2]}"#,
+    )
+    .unwrap();
+    assert_eq!(args.point1_coords, [-2.5, 4.0]);
+    assert_eq!(args.point2_coords, [2.0, 2.0]);
+
+    for json in [
+        r#"{"point1coords": [-2], "point2coords": [2, 2]}"#,
+        r#"{"point1coords": [-2, 4, 1], "point2coords": [2, 2]}"#,
+    ] {
+        assert!(serde_json::from_str::<LineMoveActionArgs>(json).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
 
-use doenetml_core::components::doenet::math::MathProps;
+use doenetml_core::{components::doenet::math::MathProps, dast::DiagnosticCode, props::PropValue};
 
 // Note: we cannot do much with math here, as it requires wasm to call out to math-expressions
 
@@ -8,6 +8,8 @@ const VALUE_LOCAL_IDX: LocalPropIdx = MathProps::Value.local_idx();
 const HIDDEN_LOCAL_IDX: LocalPropIdx = MathProps::Hidden.local_idx();
 const FIXED_LOCAL_IDX: LocalPropIdx = MathProps::Fixed.local_idx();
 const SPLIT_SYMBOLS_LOCAL_IDX: LocalPropIdx = MathProps::SplitSymbols.local_idx();
+const SIMPLIFY_LOCAL_IDX: LocalPropIdx = MathProps::Simplify.local_idx();
+const EXPAND_LOCAL_IDX: LocalPropIdx = MathProps::Expand.local_idx();
 
 #[test]
 fn value_prop_from_string_child() {
@@ -123,3 +125,52 @@ fn split_symbols_attribute() {
     assert_eq!(split_symbols4, false);
     assert_eq!(split_symbols_prop4.came_from_default, false);
 }
+
+#[test]
+fn simplify_and_expand_attributes() {
+    let dast_root = dast_root_no_position(
+        r#"<math></math><math simplify></math><math simplify="numbersPreserveOrder"></math><math simplify="false" expand></math>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    for (math_idx, simplify, expand) in [
+        (1, "none", false),
+        (2, "full", false),
+        (3, "numbersPreserveOrder", false),
+        (4, "none", true),
+    ] {
+        assert_eq!(
+            core.get_prop(math_idx, SIMPLIFY_LOCAL_IDX).value,
+            PropValue::from(simplify)
+        );
+        assert_eq!(
+            core.get_prop(math_idx, EXPAND_LOCAL_IDX).value,
+            PropValue::Boolean(expand)
+        );
+    }
+}
+
+#[test]
+fn invalid_simplify_is_ignored_with_a_warning() {
+    let dast_root = dast_root_no_position(r#"<math simplify="ful"></math>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    assert_eq!(
+        core.get_prop(1, SIMPLIFY_LOCAL_IDX).value,
+        PropValue::from("none")
+    );
+
+    let flat_dast = core.to_flat_dast();
+    let warnings = flat_dast
+        .warnings_with_code(DiagnosticCode::InvalidAttributeValue)
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "`ful` is not a valid value of the `simplify` attribute of `<math>`, so the default was used"
+    );
+}
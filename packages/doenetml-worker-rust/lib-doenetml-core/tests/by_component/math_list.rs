@@ -0,0 +1,38 @@
+use super::*;
+
+use doenetml_core::{components::doenet::math_list::MathListProps, props::PropValue};
+
+// Note: we cannot calculate the values of the maths here, as it requires wasm to call out to math-expressions
+
+const COUNT_LOCAL_IDX: LocalPropIdx = MathListProps::Count.local_idx();
+
+#[test]
+fn count_includes_text_items_and_math_children() {
+    let dast_root = dast_root_no_position(
+        r#"<math name="m">y</math><mathList name="l">x+1, $m <math>z</math> (1, 2)</mathList>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    // The comma inside the parentheses does not separate items.
+    let list_idx = core.get_component_index_by_name("l");
+    assert_eq!(
+        core.get_prop(list_idx, COUNT_LOCAL_IDX).value,
+        PropValue::Integer(4)
+    );
+}
+
+#[test]
+fn empty_math_list_has_no_items() {
+    let dast_root = dast_root_no_position(r#"<mathList name="l"> </mathList>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let list_idx = core.get_component_index_by_name("l");
+    assert_eq!(
+        core.get_prop(list_idx, COUNT_LOCAL_IDX).value,
+        PropValue::Integer(0)
+    );
+}
@@ -0,0 +1,119 @@
+use std::rc::Rc;
+
+use super::*;
+
+use doenetml_core::{
+    components::doenet::{matrix::MatrixProps, number::NumberProps},
+    dast::{DiagnosticCode, FlatDastElementContent},
+    props::PropValue,
+};
+
+const NUM_ROWS_LOCAL_IDX: LocalPropIdx = MatrixProps::NumRows.local_idx();
+const NUM_COLUMNS_LOCAL_IDX: LocalPropIdx = MatrixProps::NumColumns.local_idx();
+const LATEX_LOCAL_IDX: LocalPropIdx = MatrixProps::Latex.local_idx();
+const NUMBER_VALUE_LOCAL_IDX: LocalPropIdx = NumberProps::Value.local_idx();
+
+#[test]
+fn matrix_reads_rows_from_number_lists() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <number name="n">5</number>
+        <matrix name="m">
+            <numberList>1 2 3</numberList>
+            <numberList>4 $n</numberList>
+        </matrix>
+        "#,
+    );
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let m_idx = core.get_component_index_by_name("m");
+    assert_eq!(
+        core.get_prop(m_idx, NUM_ROWS_LOCAL_IDX).value,
+        PropValue::Integer(2)
+    );
+    assert_eq!(
+        core.get_prop(m_idx, NUM_COLUMNS_LOCAL_IDX).value,
+        PropValue::Integer(3)
+    );
+    // The short row is padded with a blank entry.
+    assert_eq!(
+        core.get_prop(m_idx, LATEX_LOCAL_IDX).value,
+        PropValue::String(Rc::new(
+            r"\begin{bmatrix} 1 & 2 & 3 \\ 4 & 5 &  \end{bmatrix}".to_string()
+        ))
+    );
+}
+
+#[test]
+fn empty_matrix_has_no_rows_or_columns() {
+    let dast_root = dast_root_no_position(r#"<matrix name="m" />"#);
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let m_idx = core.get_component_index_by_name("m");
+    assert_eq!(
+        core.get_prop(m_idx, NUM_ROWS_LOCAL_IDX).value,
+        PropValue::Integer(0)
+    );
+    assert_eq!(
+        core.get_prop(m_idx, NUM_COLUMNS_LOCAL_IDX).value,
+        PropValue::Integer(0)
+    );
+}
+
+#[test]
+fn entries_can_be_referenced_by_row_and_column() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <matrix name="m">
+            <numberList>1 2</numberList>
+            <numberList>3 4</numberList>
+        </matrix>
+        <number name="e" extend="$m.entry[2][1]" />
+        <p name="p">$m.entry[1][2]</p>
+        "#,
+    );
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let e_idx = core.get_component_index_by_name("e");
+    assert_eq!(
+        core.get_prop(e_idx, NUMBER_VALUE_LOCAL_IDX).value,
+        PropValue::Number(3.0)
+    );
+
+    // A direct reference to an entry becomes a `<number>`.
+    let p_idx = core.get_component_index_by_name("p");
+    let flat_dast = core.to_flat_dast();
+    let entry_idx = match flat_dast.elements[p_idx].children.as_slice() {
+        [FlatDastElementContent::Element(entry)] => entry.id,
+        other => panic!("Expected a single element child, found {other:?}"),
+    };
+    assert_eq!(flat_dast.elements[entry_idx].name, "number");
+    assert_eq!(
+        core.get_prop(entry_idx, NUMBER_VALUE_LOCAL_IDX).value,
+        PropValue::Number(2.0)
+    );
+}
+
+#[test]
+fn entry_out_of_range_is_an_error() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <matrix name="m"><numberList>1 2</numberList></matrix>
+        <p>$m.entry[2][1]</p>
+        "#,
+    );
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+    let errors = flat_dast
+        .elements
+        .iter()
+        .filter(|element| element.name == "_error")
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].data.code, Some(DiagnosticCode::NoReferent));
+}
@@ -1,14 +1,44 @@
+mod accordion;
+mod answer;
 mod boolean;
+mod choice_input;
+mod circle;
+mod collect;
+mod conditional_content;
 mod division;
+mod embedded_applet;
+mod experiment;
+mod for_instructor;
+mod function;
 mod graph;
+mod hint;
+mod html_block;
+mod image;
+mod line;
 mod math;
+mod math_list;
+mod matrix;
+mod number_list;
 mod ol;
 mod p;
 mod point;
+mod polygon;
+mod progress_bar;
+mod render_mode_alternatives;
 mod section;
+mod select;
+mod select_from_sequence;
+mod sequence;
+mod side_by_side;
+mod slider;
+mod solution;
+mod table;
+mod tabs;
 mod text;
 mod text_input;
+mod text_list;
 mod ul;
+mod video;
 mod xref;
 
 use super::*;
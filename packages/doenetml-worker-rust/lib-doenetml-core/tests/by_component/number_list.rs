@@ -0,0 +1,195 @@
+use super::*;
+
+use doenetml_core::{
+    components::{doenet::number::NumberProps, doenet::number_list::NumberListProps},
+    dast::{DiagnosticCode, FlatDastElementContent},
+    props::PropValue,
+    state::types::content_refs::ContentRef,
+};
+
+const COUNT_LOCAL_IDX: LocalPropIdx = NumberListProps::Count.local_idx();
+const RENDERED_CHILDREN_LOCAL_IDX: LocalPropIdx = NumberListProps::RenderedChildren.local_idx();
+const NUMBER_VALUE_LOCAL_IDX: LocalPropIdx = NumberProps::Value.local_idx();
+
+/// The component indices of the rendered children of the component `component_idx`,
+/// panicking if any of the children is not a component.
+fn get_rendered_child_components(component_idx: usize, core: &mut TestCore) -> Vec<usize> {
+    match core
+        .get_prop(component_idx, RENDERED_CHILDREN_LOCAL_IDX)
+        .value
+    {
+        PropValue::AnnotatedContentRefs(content_refs) => content_refs
+            .as_slice()
+            .iter()
+            .map(|(content_ref, _)| match content_ref {
+                ContentRef::Component(idx) => idx.as_usize(),
+                other => panic!("Expected a component child, found {other:?}"),
+            })
+            .collect(),
+        other => panic!("Expected rendered children, found {other:?}"),
+    }
+}
+
+#[test]
+fn text_children_become_numbers() {
+    let dast_root = dast_root_no_position(r#"<numberList name="l">1, 2  3.5</numberList>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let list_idx = core.get_component_index_by_name("l");
+    let children = get_rendered_child_components(list_idx, &mut core);
+    assert_eq!(children.len(), 3);
+
+    let values = children
+        .iter()
+        .map(|idx| core.get_prop(*idx, NUMBER_VALUE_LOCAL_IDX).value)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![
+            PropValue::Number(1.0),
+            PropValue::Number(2.0),
+            PropValue::Number(3.5)
+        ]
+    );
+
+    // The new numbers are rendered as `<number>` elements
+    let flat_dast = core.to_flat_dast();
+    let list_children = &flat_dast.elements[list_idx].children;
+    assert_eq!(
+        *list_children,
+        children
+            .iter()
+            .map(|idx| FlatDastElementContent::new_original_element(*idx))
+            .collect::<Vec<_>>()
+    );
+    for idx in children {
+        assert_eq!(flat_dast.elements[idx].name, "number");
+    }
+}
+
+#[test]
+fn text_children_mixed_with_references() {
+    let dast_root = dast_root_no_position(
+        r#"<number name="n">5</number><numberList name="l">1 $n <number>7</number> 9</numberList>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let list_idx = core.get_component_index_by_name("l");
+    let values = get_rendered_child_components(list_idx, &mut core)
+        .iter()
+        .map(|idx| core.get_prop(*idx, NUMBER_VALUE_LOCAL_IDX).value)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![
+            PropValue::Number(1.0),
+            PropValue::Number(5.0),
+            PropValue::Number(7.0),
+            PropValue::Number(9.0)
+        ]
+    );
+}
+
+#[test]
+fn extending_number_list_shares_its_numbers() {
+    let dast_root = dast_root_no_position(
+        r#"<numberList name="l">1 2</numberList><numberList name="l2" extend="$l">3</numberList>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let list_idx = core.get_component_index_by_name("l2");
+    let values = get_rendered_child_components(list_idx, &mut core)
+        .iter()
+        .map(|idx| core.get_prop(*idx, NUMBER_VALUE_LOCAL_IDX).value)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![
+            PropValue::Number(1.0),
+            PropValue::Number(2.0),
+            PropValue::Number(3.0)
+        ]
+    );
+}
+
+#[test]
+fn count_includes_text_items_and_number_children() {
+    let dast_root = dast_root_no_position(
+        r#"<number name="n">5</number><numberList name="l">1 $n <number>7</number> 9</numberList><numberList name="empty" />"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let list_idx = core.get_component_index_by_name("l");
+    assert_eq!(
+        core.get_prop(list_idx, COUNT_LOCAL_IDX).value,
+        PropValue::Integer(4)
+    );
+    let empty_idx = core.get_component_index_by_name("empty");
+    assert_eq!(
+        core.get_prop(empty_idx, COUNT_LOCAL_IDX).value,
+        PropValue::Integer(0)
+    );
+}
+
+#[test]
+fn members_can_be_referenced_by_index() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <numberList name="l">1 2 3</numberList>
+        <number name="second" extend="$l[2]" />
+        <p name="p">$l[3]</p>
+        "#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let second_idx = core.get_component_index_by_name("second");
+    assert_eq!(
+        core.get_prop(second_idx, NUMBER_VALUE_LOCAL_IDX).value,
+        PropValue::Number(2.0)
+    );
+
+    // A direct reference to a member becomes a `<number>`.
+    let p_idx = core.get_component_index_by_name("p");
+    let flat_dast = core.to_flat_dast();
+    let member_idx = match flat_dast.elements[p_idx].children.as_slice() {
+        [FlatDastElementContent::Element(member)] => member.id,
+        other => panic!("Expected a single element child, found {other:?}"),
+    };
+    assert_eq!(flat_dast.elements[member_idx].name, "number");
+    assert_eq!(
+        core.get_prop(member_idx, NUMBER_VALUE_LOCAL_IDX).value,
+        PropValue::Number(3.0)
+    );
+}
+
+#[test]
+fn member_index_out_of_range_is_an_error() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <numberList name="l">1 2</numberList>
+        <p>$l[3]</p>
+        "#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+    let errors = flat_dast
+        .elements
+        .iter()
+        .filter(|element| element.name == "_error")
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].data.code, Some(DiagnosticCode::NoReferent));
+}
@@ -35,6 +35,20 @@ fn ol_can_compute_their_start_index() {
     assert_eq!(idx, 3);
 }
 
+#[test]
+fn ol_start_index_falls_back_to_default_when_out_of_range() {
+    let dast_root = dast_root_no_position(r#"<ol start="1e400" /><ol start="-2.5" />"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let idx: prop_type::Integer = core.get_prop_value_typed(1, OlProps::StartIndex.local_idx());
+    assert_eq!(idx, 1);
+
+    let idx: prop_type::Integer = core.get_prop_value_typed(2, OlProps::StartIndex.local_idx());
+    assert_eq!(idx, -2);
+}
+
 #[test]
 fn ol_can_compute_marker() {
     let dast_root =
@@ -3,10 +3,12 @@ use super::*;
 use doenetml_core::{
     components::{
         ActionsEnum,
+        doenet::math::MathProps,
         doenet::point::{PointActions, PointMoveActionArgs, PointProps},
         types::{Action, ActionBody},
     },
     dast::{ForRenderPropValue, ForRenderPropValueOrContent, ForRenderProps},
+    props::PropValue,
     state::types::math_expr::{JsMathExpr, MathExpr},
 };
 
@@ -249,6 +251,109 @@ fn move_point_with_x_and_y() {
     assert_eq!(coords_prop.value, math_coords.into());
 }
 
+#[test]
+fn drag_point_with_batch_of_moves() {
+    let dast_root =
+        dast_root_no_position(r#"<number name="x">-5.2</number><point name="P" x="$x"></point>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+
+    let point_idx = core.get_component_index_by_name("P");
+    let x_idx = core.get_component_index_by_name("x");
+
+    let move_to = |x: f64, y: f64| Action {
+        component_idx: point_idx.into(),
+        action: ActionsEnum::Point(PointActions::Move(ActionBody {
+            args: PointMoveActionArgs { x, y },
+        })),
+    };
+
+    let staleness_passes = core.core.document_model.get_staleness_pass_count();
+    let updates = core
+        .dispatch_actions(vec![
+            move_to(1.0, 3.2),
+            move_to(2.0, 4.0),
+            move_to(3.0, 5.0),
+        ])
+        .unwrap();
+    assert!(updates.contains_key(&ComponentIdx::from(point_idx)));
+
+    // The changes of all the moves are made together, so props are marked stale only once.
+    assert_eq!(
+        core.core.document_model.get_staleness_pass_count(),
+        staleness_passes + 1
+    );
+
+    // The last move determines the position of the point.
+    let math_x: MathExpr = 3.0.into();
+    let math_y: MathExpr = 5.0.into();
+    assert_eq!(core.get_prop(point_idx, X_LOCAL_IDX).value, math_x.into());
+    assert_eq!(core.get_prop(point_idx, Y_LOCAL_IDX).value, math_y.into());
+
+    // If any action of a batch fails, none of the moves are made.
+    let invalid_action = Action {
+        component_idx: x_idx.into(),
+        action: ActionsEnum::Point(PointActions::Move(ActionBody {
+            args: PointMoveActionArgs { x: 0.0, y: 0.0 },
+        })),
+    };
+    assert!(
+        core.dispatch_actions(vec![move_to(7.0, 7.0), invalid_action])
+            .is_err()
+    );
+    let math_x: MathExpr = 3.0.into();
+    assert_eq!(core.get_prop(point_idx, X_LOCAL_IDX).value, math_x.into());
+
+    // The batch is undone as a single step.
+    core.core.undo().unwrap();
+    let math_x: MathExpr = (-5.2).into();
+    let math_y: MathExpr = 0.0.into();
+    assert_eq!(core.get_prop(point_idx, X_LOCAL_IDX).value, math_x.into());
+    assert_eq!(core.get_prop(point_idx, Y_LOCAL_IDX).value, math_y.into());
+    assert!(!core.core.can_undo());
+}
+
+#[test]
+fn move_point_recalculates_dependents_once() {
+    let dast_root = dast_root_no_position(
+        r#"<number name="x">3.7</number><number name="y">4.8</number><point name="P" x="$x" y="$y"></point>
+<point name="Q" x="$P.x" y="$P.y"></point>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+
+    let point_idx = core.get_component_index_by_name("P");
+    let q_idx = core.get_component_index_by_name("Q");
+
+    core.core.enable_resolution_trace(1000);
+    let _ = core.dispatch_action(Action {
+        component_idx: point_idx.into(),
+        action: ActionsEnum::Point(PointActions::Move(ActionBody {
+            args: PointMoveActionArgs { x: 1.0, y: -3.2 },
+        })),
+    });
+
+    // Both coordinates are changed before anything is recalculated,
+    // so the coordinates of `Q` are calculated once, from both new values.
+    let math_coords: PropValue = MathExpr {
+        math_object: JsMathExpr("[\"vector\",1,-3.2]".to_string()),
+    }
+    .into();
+    let coords_calculations = core
+        .core
+        .get_resolution_trace()
+        .into_iter()
+        .filter(|e| e.component_idx == ComponentIdx::from(q_idx) && e.prop_name == "coords")
+        .map(|e| e.value)
+        .collect::<Vec<_>>();
+    assert_eq!(coords_calculations, vec![Some(math_coords.clone())]);
+    assert_eq!(core.get_prop(q_idx, COORDS_LOCAL_IDX).value, math_coords);
+}
+
 #[test]
 fn dast_of_point_in_graph_returns_x_and_y() {
     let dast_root = dast_root_no_position(r#"<graph><point name="P" x="8.9" y="6.2"/></graph>"#);
@@ -391,3 +496,29 @@ fn rendered_props_with_references() {
         }
     ));
 }
+
+#[test]
+fn point_can_be_used_as_math_and_number_as_coordinate() {
+    let dast_root = dast_root_no_position(
+        r#"<number name="n">3</number><point name="P" x="$n" y="1" /><math name="m">$P</math>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let point_idx = core.get_component_index_by_name("P");
+    let math_idx = core.get_component_index_by_name("m");
+
+    let math_three: MathExpr = 3.0.into();
+    let math_three_one = MathExpr {
+        math_object: JsMathExpr("[\"vector\",3,1]".to_string()),
+    };
+
+    // The number is coerced into the math of the `x` attribute
+    let x_prop = core.get_prop(point_idx, X_LOCAL_IDX);
+    assert_eq!(x_prop.value, math_three.into());
+
+    // The coordinates of the point are used as the value of the math
+    let math_prop = core.get_prop(math_idx, MathProps::Value.local_idx());
+    assert_eq!(math_prop.value, math_three_one.into());
+}
@@ -0,0 +1,177 @@
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::{
+            number::NumberProps,
+            polygon::{
+                PolygonActions, PolygonMoveActionArgs, PolygonMoveVertexActionArgs, PolygonProps,
+            },
+        },
+        types::{Action, ActionBody},
+    },
+    props::{PropValue, prop_type},
+};
+
+use super::*;
+
+// Note: we can only test polygons with numerical vertices, as otherwise it requires wasm to call out to math-expressions
+
+const VERTICES_LOCAL_IDX: LocalPropIdx = PolygonProps::Vertices.local_idx();
+const NUMERICAL_VERTICES_LOCAL_IDX: LocalPropIdx = PolygonProps::NumericalVertices.local_idx();
+
+fn numerical_vertices(vertices: &[[f64; 2]]) -> prop_type::PropVec {
+    vertices
+        .iter()
+        .map(|&[x, y]| PropValue::from(vec![PropValue::Number(x), PropValue::Number(y)]))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+fn dispatch_polygon_action(
+    core: &mut TestCore,
+    polygon_idx: usize,
+    action: PolygonActions,
+) -> Result<(), String> {
+    core.dispatch_action(Action {
+        component_idx: polygon_idx.into(),
+        action: ActionsEnum::Polygon(action),
+    })
+    .map(|_| ())
+}
+
+const TRIANGLE: &str = r#"<number name="a">0</number><number name="b">0</number>
+<number name="c">4</number><number name="d">0</number>
+<number name="e">0</number><number name="f">3</number>
+<point name="A" x="$a" y="$b"/><point name="B" x="$c" y="$d"/><point name="C" x="$e" y="$f"/>
+<graph><polygon name="p" vertices="$A $B $C"/></graph>"#;
+
+#[test]
+fn polygon_vertices_come_from_points() {
+    let dast_root = dast_root_no_position(TRIANGLE);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let polygon_idx = core.get_component_index_by_name("p");
+
+    let vertices: prop_type::PropVec = core.get_prop_value_typed(polygon_idx, VERTICES_LOCAL_IDX);
+    assert_eq!(vertices.len(), 3);
+    let vertices: prop_type::PropVec =
+        core.get_prop_value_typed(polygon_idx, NUMERICAL_VERTICES_LOCAL_IDX);
+    assert_eq!(
+        vertices,
+        numerical_vertices(&[[0.0, 0.0], [4.0, 0.0], [0.0, 3.0]])
+    );
+}
+
+#[test]
+fn polygon_without_vertices_is_empty() {
+    let dast_root = dast_root_no_position(r#"<polygon name="p"/>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let polygon_idx = core.get_component_index_by_name("p");
+
+    let vertices: prop_type::PropVec =
+        core.get_prop_value_typed(polygon_idx, NUMERICAL_VERTICES_LOCAL_IDX);
+    assert!(vertices.is_empty());
+}
+
+#[test]
+fn move_polygon_moves_every_vertex() {
+    let dast_root = dast_root_no_position(TRIANGLE);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let polygon_idx = core.get_component_index_by_name("p");
+
+    dispatch_polygon_action(
+        &mut core,
+        polygon_idx,
+        PolygonActions::MovePolygon(ActionBody {
+            args: PolygonMoveActionArgs {
+                vertex_coords: vec![[1.0, 1.0], [5.0, 1.0], [1.0, 4.0]],
+            },
+        }),
+    )
+    .unwrap();
+
+    let vertices: prop_type::PropVec =
+        core.get_prop_value_typed(polygon_idx, NUMERICAL_VERTICES_LOCAL_IDX);
+    assert_eq!(
+        vertices,
+        numerical_vertices(&[[1.0, 1.0], [5.0, 1.0], [1.0, 4.0]])
+    );
+
+    // The vertices are propagated to the `<number>`s that they come from.
+    for (name, value) in [
+        ("a", 1.0),
+        ("b", 1.0),
+        ("c", 5.0),
+        ("d", 1.0),
+        ("e", 1.0),
+        ("f", 4.0),
+    ] {
+        let number_idx = core.get_component_index_by_name(name);
+        let number: f64 = core.get_prop_value_typed(number_idx, NumberProps::Value.local_idx());
+        assert_eq!(number, value);
+    }
+
+    // A move must give every vertex.
+    let result = dispatch_polygon_action(
+        &mut core,
+        polygon_idx,
+        PolygonActions::MovePolygon(ActionBody {
+            args: PolygonMoveActionArgs {
+                vertex_coords: vec![[0.0, 0.0]],
+            },
+        }),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn move_polygon_vertex_moves_one_vertex() {
+    let dast_root = dast_root_no_position(TRIANGLE);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let polygon_idx = core.get_component_index_by_name("p");
+
+    dispatch_polygon_action(
+        &mut core,
+        polygon_idx,
+        PolygonActions::MovePolygonVertex(ActionBody {
+            args: PolygonMoveVertexActionArgs {
+                vertex_index: 0,
+                coords: [-2.0, -1.5],
+            },
+        }),
+    )
+    .unwrap();
+
+    let vertices: prop_type::PropVec =
+        core.get_prop_value_typed(polygon_idx, NUMERICAL_VERTICES_LOCAL_IDX);
+    assert_eq!(
+        vertices,
+        numerical_vertices(&[[-2.0, -1.5], [4.0, 0.0], [0.0, 3.0]])
+    );
+    let b_idx = core.get_component_index_by_name("b");
+    let b: f64 = core.get_prop_value_typed(b_idx, NumberProps::Value.local_idx());
+    assert_eq!(b, -1.5);
+
+    let result = dispatch_polygon_action(
+        &mut core,
+        polygon_idx,
+        PolygonActions::MovePolygonVertex(ActionBody {
+            args: PolygonMoveVertexActionArgs {
+                vertex_index: 3,
+                coords: [0.0, 0.0],
+            },
+        }),
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,106 @@
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::{
+            progress_bar::ProgressBarProps,
+            text_input::{TextInputActionArgs, TextInputActions},
+        },
+        types::{Action, ActionBody},
+    },
+    props::PropValue,
+};
+
+use super::*;
+
+const FRACTION_LOCAL_IDX: LocalPropIdx = ProgressBarProps::Fraction.local_idx();
+
+/// Type `text` into the text input `component_idx` and submit it.
+fn answer(core: &mut TestCore, component_idx: usize, text: &str) {
+    core.dispatch_action(Action {
+        component_idx: component_idx.into(),
+        action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+            args: TextInputActionArgs {
+                text: text.to_string(),
+            },
+        })),
+    })
+    .unwrap();
+    core.dispatch_action(Action {
+        component_idx: component_idx.into(),
+        action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+    })
+    .unwrap();
+}
+
+#[test]
+fn progress_bar_shows_fraction_of_inputs_answered() {
+    let dast_root = dast_root_no_position(
+        r#"<progressBar name="pb" />
+<section><textInput name="a" /><p><textInput name="b" /></p></section>
+<textInput name="c" prefill="given" />
+<textInput name="d" />"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let pb_idx = core.get_component_index_by_name("pb");
+    let a_idx = core.get_component_index_by_name("a");
+    let b_idx = core.get_component_index_by_name("b");
+
+    // A prefilled input has not been answered.
+    core.to_flat_dast();
+    assert_eq!(
+        core.get_prop(pb_idx, FRACTION_LOCAL_IDX).value,
+        PropValue::Number(0.0)
+    );
+
+    answer(&mut core, a_idx, "x");
+    assert_eq!(
+        core.get_prop(pb_idx, FRACTION_LOCAL_IDX).value,
+        PropValue::Number(0.25)
+    );
+
+    // A blank response does not count.
+    answer(&mut core, b_idx, "  ");
+    assert_eq!(
+        core.get_prop(pb_idx, FRACTION_LOCAL_IDX).value,
+        PropValue::Number(0.25)
+    );
+
+    // Answering updates the rendered progress bar.
+    core.dispatch_action(Action {
+        component_idx: b_idx.into(),
+        action: ActionsEnum::TextInput(TextInputActions::UpdateImmediateValue(ActionBody {
+            args: TextInputActionArgs {
+                text: "y".to_string(),
+            },
+        })),
+    })
+    .unwrap();
+    let updates = core
+        .dispatch_action(Action {
+            component_idx: b_idx.into(),
+            action: ActionsEnum::TextInput(TextInputActions::UpdateValue),
+        })
+        .unwrap();
+    assert!(updates[&ComponentIdx::from(pb_idx)].changed_state.is_some());
+    assert_eq!(
+        core.get_prop(pb_idx, FRACTION_LOCAL_IDX).value,
+        PropValue::Number(0.5)
+    );
+}
+
+#[test]
+fn document_without_inputs_is_complete() {
+    let dast_root = dast_root_no_position(r#"<progressBar name="pb" /><p>Read this.</p>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let pb_idx = core.get_component_index_by_name("pb");
+    assert_eq!(
+        core.get_prop(pb_idx, FRACTION_LOCAL_IDX).value,
+        PropValue::Number(1.0)
+    );
+}
@@ -0,0 +1,48 @@
+use super::*;
+
+use doenetml_core::{core::DocumentFlags, dast::FlatDastElementContent, props::RenderMode};
+
+const SOURCE: &str = r#"<p><interactiveOnly><textInput /></interactiveOnly><printFallback>Write your answer here: ____</printFallback></p>"#;
+
+/// Get the flat dast children of the `<p>` in `SOURCE` when rendered in `render_mode`.
+fn p_children_in_mode(render_mode: RenderMode) -> Vec<FlatDastElementContent> {
+    let dast_root = dast_root_no_position(SOURCE);
+
+    let mut core = Core::new();
+    core.set_flags(DocumentFlags {
+        render_mode,
+        ..Default::default()
+    });
+    core.init_from_dast_root(&dast_root);
+
+    // the document tag will be index 0.
+    let flat_dast = core.to_flat_dast();
+    flat_dast.elements[1].children.clone()
+}
+
+#[test]
+fn interactive_only_content_is_rendered_when_interactive() {
+    assert_eq!(
+        p_children_in_mode(RenderMode::Interactive),
+        vec![FlatDastElementContent::new_original_element(2)]
+    );
+}
+
+#[test]
+fn print_fallback_content_is_rendered_when_printed() {
+    assert_eq!(
+        p_children_in_mode(RenderMode::Print),
+        vec![FlatDastElementContent::new_original_element(4)]
+    );
+}
+
+#[test]
+fn render_mode_alternatives_are_not_dead_content() {
+    let dast_root = dast_root_no_position(SOURCE);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+
+    assert!(core.find_dead_content().is_empty());
+}
@@ -1,7 +1,11 @@
+use std::rc::Rc;
+
 use super::*;
 
 use doenetml_core::{
-    components::doenet::division::DivisionProps, dast::FlatDastElementContent, props::prop_type,
+    components::doenet::division::DivisionProps,
+    dast::FlatDastElementContent,
+    props::{PropValue, prop_type},
     state::types::content_refs::ContentRef,
 };
 use test_helpers::*;
@@ -267,6 +271,38 @@ fn section_gets_division_depth() {
     assert_eq!(get_division_depth_prop(section_idx, &mut core), 0);
 }
 
+#[test]
+fn sections_have_public_section_number_and_level() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <section name="s1">
+            <title>First</title>
+            <subsection name="s11"><title>Inner</title></subsection>
+            <subsection name="s12"><title>Inner</title></subsection>
+        </section>
+        <section name="s2"><title>Second</title></section>"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    for (name, section_number, level) in [
+        ("s1", "1", 1),
+        ("s11", "1.1", 2),
+        ("s12", "1.2", 2),
+        ("s2", "2", 1),
+    ] {
+        assert_eq!(
+            core.get_prop_value(name, "sectionNumber"),
+            Some(PropValue::String(Rc::new(section_number.to_string())))
+        );
+        assert_eq!(
+            core.get_prop_value(name, "level"),
+            Some(PropValue::Integer(level))
+        );
+    }
+}
+
 #[test]
 fn section_title_reference_works() {
     // Items with idx 1 and 5 are <section> elements
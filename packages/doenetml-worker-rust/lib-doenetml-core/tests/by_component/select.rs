@@ -0,0 +1,193 @@
+use doenetml_core::{
+    components::doenet::select::{SelectProps, select_option, select_options},
+    core::{DocumentFlags, SelectionDescriptor},
+    dast::FlatDastElementContent,
+    props::{PropValue, prop_type},
+};
+
+use super::*;
+
+// <document>: idx 0
+//  <select name="s">: idx 1
+//   <p>: idx 2, 3, 4
+const SOURCE: &str = r#"<select name="s"><p>A</p><p>B</p><p>C</p></select>"#;
+
+// <document>: idx 0
+//  <select name="s" numToSelect="2">: idx 1
+//   <p>: idx 2, 3, 4, 5
+const SOURCE_SELECT_TWO: &str =
+    r#"<select name="s" numToSelect="2"><p>A</p><p>B</p><p>C</p><p>D</p></select>"#;
+
+fn core_with_flags(variant_seed: u64, seen_selections: Vec<SelectionDescriptor>) -> TestCore {
+    core_with_source(SOURCE, variant_seed, seen_selections)
+}
+
+fn core_with_source(
+    source: &str,
+    variant_seed: u64,
+    seen_selections: Vec<SelectionDescriptor>,
+) -> TestCore {
+    let mut core = Core::new();
+    core.set_flags(DocumentFlags {
+        variant_seed,
+        seen_selections,
+        ..Default::default()
+    });
+    let mut core = TestCore::new_from(core);
+    core.init_from_dast_root(&dast_root_no_position(source));
+    core
+}
+
+fn selected_index(core: &mut TestCore) -> prop_type::Integer {
+    core.get_prop_value_typed(
+        core.get_component_index_by_name("s"),
+        SelectProps::SelectedIndex.local_idx(),
+    )
+}
+
+fn selected_indices(core: &mut TestCore) -> Vec<usize> {
+    let component_idx = core.get_component_index_by_name("s");
+    match core
+        .get_prop(component_idx, SelectProps::SelectedIndices.local_idx())
+        .value
+    {
+        PropValue::PropVec(indices) => indices
+            .iter()
+            .map(|idx| match idx {
+                PropValue::Integer(idx) => *idx as usize,
+                other => panic!("Expected an integer, found {other:?}"),
+            })
+            .collect(),
+        other => panic!("Expected a vector, found {other:?}"),
+    }
+}
+
+fn seen(option_indices: &[usize]) -> Vec<SelectionDescriptor> {
+    option_indices
+        .iter()
+        .map(|&option_index| SelectionDescriptor {
+            component_idx: 1.into(),
+            option_index,
+        })
+        .collect()
+}
+
+#[test]
+fn select_is_deterministic_for_a_seed() {
+    for seed in 0..10 {
+        let first = selected_index(&mut core_with_flags(seed, vec![]));
+        let second = selected_index(&mut core_with_flags(seed, vec![]));
+        assert_eq!(first, second);
+        assert!((0..3).contains(&first));
+    }
+}
+
+#[test]
+fn select_renders_only_the_selected_option() {
+    let mut core = core_with_flags(7, vec![]);
+    let selected = selected_index(&mut core) as usize;
+    let flat_dast = core.to_flat_dast();
+
+    assert_eq!(
+        flat_dast.elements[1].children,
+        vec![FlatDastElementContent::new_original_element(2 + selected)]
+    );
+}
+
+#[test]
+fn select_avoids_options_seen_on_previous_attempts() {
+    for seed in 0..10 {
+        let first = selected_index(&mut core_with_flags(seed, vec![])) as usize;
+        let second = selected_index(&mut core_with_flags(seed, seen(&[first]))) as usize;
+        assert_ne!(first, second);
+        let third = selected_index(&mut core_with_flags(seed, seen(&[first, second]))) as usize;
+        assert_eq!(
+            [first, second, third]
+                .iter()
+                .collect::<std::collections::BTreeSet<_>>()
+                .len(),
+            3
+        );
+    }
+}
+
+#[test]
+fn select_prefers_least_seen_options_once_all_are_seen() {
+    // Every option has been seen, but the second option only once.
+    assert_eq!(select_option(3, 1.into(), 3, &[0, 1, 2, 0, 2]), 1);
+
+    let selected = selected_index(&mut core_with_flags(3, seen(&[0, 1, 2, 0, 2])));
+    assert_eq!(selected, 1);
+}
+
+#[test]
+fn selections_round_trip_through_flags() {
+    let mut core = core_with_flags(5, vec![]);
+    let selected = selected_index(&mut core) as usize;
+
+    assert_eq!(core.core.get_selections(), seen(&[selected]));
+
+    let mut core = core_with_flags(5, core.core.get_selections());
+    assert_ne!(selected_index(&mut core) as usize, selected);
+}
+
+#[test]
+fn unselected_options_are_not_dead_content() {
+    let mut core = core_with_flags(0, vec![]);
+    core.to_flat_dast();
+
+    assert!(core.core.find_dead_content().is_empty());
+}
+
+#[test]
+fn select_renders_num_to_select_distinct_options() {
+    for seed in 0..10 {
+        let mut core = core_with_source(SOURCE_SELECT_TWO, seed, vec![]);
+        let selected = selected_indices(&mut core);
+        assert_eq!(selected.len(), 2);
+        assert_ne!(selected[0], selected[1]);
+        assert!(selected.iter().all(|&idx| idx < 4));
+        assert_eq!(selected_index(&mut core), selected[0] as prop_type::Integer);
+
+        let flat_dast = core.to_flat_dast();
+        assert_eq!(
+            flat_dast.elements[1].children,
+            selected
+                .iter()
+                .map(|&idx| FlatDastElementContent::new_original_element(2 + idx))
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn selecting_more_options_than_exist_selects_all() {
+    let mut selected = select_options(4, 1.into(), 3, 5, &[]);
+    selected.sort();
+    assert_eq!(selected, vec![0, 1, 2]);
+}
+
+#[test]
+fn selecting_one_option_matches_select_option() {
+    for seed in 0..10 {
+        assert_eq!(
+            select_options(seed, 1.into(), 5, 1, &[0, 3]),
+            vec![select_option(seed, 1.into(), 5, &[0, 3])]
+        );
+    }
+}
+
+#[test]
+fn multiple_selections_avoid_seen_options_and_round_trip_through_flags() {
+    for seed in 0..10 {
+        let mut core = core_with_source(SOURCE_SELECT_TWO, seed, vec![]);
+        let first = selected_indices(&mut core);
+        let selections = core.core.get_selections();
+        assert_eq!(selections, seen(&first));
+
+        // The two options not seen on the first attempt are selected on the second.
+        let mut core = core_with_source(SOURCE_SELECT_TWO, seed, selections);
+        let second = selected_indices(&mut core);
+        assert!(second.iter().all(|idx| !first.contains(idx)));
+    }
+}
@@ -0,0 +1,104 @@
+use doenetml_core::{
+    components::doenet::select_from_sequence::SelectFromSequenceProps, core::DocumentFlags,
+    props::PropValue,
+};
+
+use super::*;
+
+fn core_with_seed(variant_seed: u64, doenetml: &str) -> TestCore {
+    let mut core = Core::new();
+    core.set_flags(DocumentFlags {
+        variant_seed,
+        ..Default::default()
+    });
+    let mut core = TestCore::new_from(core);
+    core.init_from_dast_root(&dast_root_no_position(doenetml));
+    core
+}
+
+fn selected_values(core: &mut TestCore, name: &str) -> Vec<f64> {
+    let component_idx = core.get_component_index_by_name(name);
+    match core
+        .get_prop(
+            component_idx,
+            SelectFromSequenceProps::SelectedValues.local_idx(),
+        )
+        .value
+    {
+        PropValue::PropVec(values) => values
+            .iter()
+            .map(|value| match value {
+                PropValue::Number(number) => *number,
+                other => panic!("Expected a number, found {other:?}"),
+            })
+            .collect(),
+        other => panic!("Expected a vector, found {other:?}"),
+    }
+}
+
+#[test]
+fn selects_distinct_members_of_the_sequence() {
+    for variant_seed in 0..20 {
+        let mut core = core_with_seed(
+            variant_seed,
+            r#"<selectFromSequence name="s" from="2" to="20" step="2" numToSelect="4"/>"#,
+        );
+        let mut values = selected_values(&mut core, "s");
+        assert_eq!(values.len(), 4);
+        assert!(
+            values
+                .iter()
+                .all(|&value| (2.0..=20.0).contains(&value) && value % 2.0 == 0.0)
+        );
+        values.sort_by(f64::total_cmp);
+        values.dedup();
+        assert_eq!(values.len(), 4);
+    }
+}
+
+#[test]
+fn same_seed_gives_same_selection() {
+    let source = r#"<selectFromSequence name="s" to="100" numToSelect="5"/>"#;
+    let first = selected_values(&mut core_with_seed(12345, source), "s");
+    let second = selected_values(&mut core_with_seed(12345, source), "s");
+    assert_eq!(first, second);
+
+    // Some other seed gives a different selection.
+    assert!(
+        (0..10).any(
+            |variant_seed| selected_values(&mut core_with_seed(variant_seed, source), "s") != first
+        )
+    );
+}
+
+#[test]
+fn cannot_select_more_members_than_the_sequence_has() {
+    let mut core = core_with_seed(
+        3,
+        r#"<selectFromSequence name="s" from="1" to="3" numToSelect="5"/>"#,
+    );
+    let mut values = selected_values(&mut core, "s");
+    values.sort_by(f64::total_cmp);
+    assert_eq!(values, vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn text_lists_the_selected_values() {
+    let mut core = core_with_seed(
+        7,
+        r#"<selectFromSequence name="s" from="1" to="9" numToSelect="3"/>"#,
+    );
+    let values = selected_values(&mut core, "s");
+    let text: String = core.get_prop_value_typed(
+        core.get_component_index_by_name("s"),
+        SelectFromSequenceProps::Text.local_idx(),
+    );
+    assert_eq!(
+        text,
+        values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+}
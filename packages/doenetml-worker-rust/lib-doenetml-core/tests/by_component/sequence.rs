@@ -0,0 +1,113 @@
+use super::*;
+
+use doenetml_core::{
+    components::doenet::{
+        number::NumberProps,
+        sequence::{SequenceProps, sequence_members},
+    },
+    props::PropValue,
+    state::types::content_refs::ContentRef,
+};
+
+const RENDERED_CHILDREN_LOCAL_IDX: LocalPropIdx = SequenceProps::RenderedChildren.local_idx();
+const NUMBER_VALUE_LOCAL_IDX: LocalPropIdx = NumberProps::Value.local_idx();
+
+/// The values of the `<number>` members of the sequence named `name` in `doenetml`.
+fn member_values(doenetml: &str, name: &str) -> Vec<f64> {
+    let dast_root = dast_root_no_position(doenetml);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let sequence_idx = core.get_component_index_by_name(name);
+    let children = match core
+        .get_prop(sequence_idx, RENDERED_CHILDREN_LOCAL_IDX)
+        .value
+    {
+        PropValue::AnnotatedContentRefs(content_refs) => content_refs
+            .as_slice()
+            .iter()
+            .map(|(content_ref, _)| match content_ref {
+                ContentRef::Component(idx) => idx.as_usize(),
+                other => panic!("Expected a component child, found {other:?}"),
+            })
+            .collect::<Vec<_>>(),
+        other => panic!("Expected rendered children, found {other:?}"),
+    };
+
+    children
+        .into_iter()
+        .map(|idx| core.get_prop_value_typed(idx, NUMBER_VALUE_LOCAL_IDX))
+        .collect()
+}
+
+#[test]
+fn sequence_from_to() {
+    assert_eq!(
+        member_values(r#"<sequence name="s" from="3" to="7"/>"#, "s"),
+        vec![3.0, 4.0, 5.0, 6.0, 7.0]
+    );
+}
+
+#[test]
+fn sequence_with_step() {
+    assert_eq!(
+        member_values(r#"<sequence name="s" from="2" to="11" step="3"/>"#, "s"),
+        vec![2.0, 5.0, 8.0, 11.0]
+    );
+    assert_eq!(
+        member_values(r#"<sequence name="s" from="5" to="1" step="-2"/>"#, "s"),
+        vec![5.0, 3.0, 1.0]
+    );
+}
+
+#[test]
+fn length_takes_precedence_over_to() {
+    assert_eq!(
+        member_values(
+            r#"<sequence name="s" from="0" to="100" step="5" length="3"/>"#,
+            "s"
+        ),
+        vec![0.0, 5.0, 10.0]
+    );
+}
+
+#[test]
+fn sequence_defaults_to_one_through_ten() {
+    assert_eq!(
+        member_values(r#"<sequence name="s"/>"#, "s"),
+        (1..=10).map(f64::from).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn sequence_that_does_not_reach_to_is_empty() {
+    assert_eq!(
+        member_values(r#"<sequence name="s" from="5" to="1"/>"#, "s"),
+        Vec::<f64>::new()
+    );
+}
+
+#[test]
+fn sequence_with_referenced_attribute_has_no_members() {
+    assert_eq!(
+        member_values(
+            r#"<number name="n">4</number><sequence name="s" to="$n"/>"#,
+            "s"
+        ),
+        Vec::<f64>::new()
+    );
+}
+
+#[test]
+fn sequence_members_allow_for_rounding_error() {
+    assert_eq!(sequence_members(0.0, Some(0.3), 0.1, None).len(), 4);
+    assert_eq!(
+        sequence_members(1.0, None, 0.0, Some(3.0)),
+        Vec::<f64>::new()
+    );
+    assert_eq!(
+        sequence_members(0.0, None, 1.0, Some(1e12)).len(),
+        doenetml_core::components::doenet::sequence::MAX_SEQUENCE_LENGTH
+    );
+}
@@ -0,0 +1,126 @@
+use doenetml_core::{
+    components::doenet::side_by_side::SideBySideProps,
+    core::{DeviceClass, DocumentEnvironment},
+    dast::DiagnosticCode,
+    props::PropValue,
+};
+
+use super::*;
+
+const WIDTHS_LOCAL_IDX: LocalPropIdx = SideBySideProps::Widths.local_idx();
+const STACKED_LOCAL_IDX: LocalPropIdx = SideBySideProps::Stacked.local_idx();
+const VALIGN_LOCAL_IDX: LocalPropIdx = SideBySideProps::Valign.local_idx();
+
+/// The widths of the children of the `<sideBySide>` `component_idx`.
+fn get_widths(component_idx: usize, core: &mut TestCore) -> Vec<f64> {
+    match core.get_prop(component_idx, WIDTHS_LOCAL_IDX).value {
+        PropValue::PropVec(widths) => widths
+            .iter()
+            .map(|width| match width {
+                PropValue::Number(width) => *width,
+                other => panic!("Expected a number, found {other:?}"),
+            })
+            .collect(),
+        other => panic!("Expected a vector of widths, found {other:?}"),
+    }
+}
+
+#[test]
+fn children_share_width_equally_by_default() {
+    let dast_root = dast_root_no_position(
+        r#"<sideBySide name="s"><p>a</p> text is ignored <p>b</p><spacer /><p>c</p></sideBySide>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let s_idx = core.get_component_index_by_name("s");
+    assert_eq!(get_widths(s_idx, &mut core), vec![25.0, 25.0, 25.0, 25.0]);
+    assert_eq!(
+        core.get_prop(s_idx, STACKED_LOCAL_IDX).value,
+        PropValue::Boolean(false)
+    );
+    assert_eq!(
+        core.get_prop(s_idx, VALIGN_LOCAL_IDX).value,
+        PropValue::String("top".to_string().into())
+    );
+}
+
+#[test]
+fn unspecified_widths_share_the_remaining_width() {
+    let dast_root = dast_root_no_position(
+        r#"<sideBySide name="s" widths="40% auto" valign="Middle"><p>a</p><p>b</p><p>c</p></sideBySide>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let s_idx = core.get_component_index_by_name("s");
+    assert_eq!(get_widths(s_idx, &mut core), vec![40.0, 30.0, 30.0]);
+    assert_eq!(
+        core.get_prop(s_idx, VALIGN_LOCAL_IDX).value,
+        PropValue::String("middle".to_string().into())
+    );
+}
+
+#[test]
+fn widths_that_do_not_fit_are_scaled() {
+    let dast_root = dast_root_no_position(
+        r#"<sideBySide name="s" widths="75% 125%"><p>a</p><p>b</p><p>c</p></sideBySide>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let s_idx = core.get_component_index_by_name("s");
+    assert_eq!(get_widths(s_idx, &mut core), vec![37.5, 62.5, 0.0]);
+}
+
+#[test]
+fn children_are_stacked_on_a_phone() {
+    let dast_root = dast_root_no_position(
+        r#"<sideBySide name="s" widths="40% 60%"><p>a</p><p>b</p></sideBySide>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let s_idx = core.get_component_index_by_name("s");
+    let _ = core.to_flat_dast();
+    assert_eq!(get_widths(s_idx, &mut core), vec![40.0, 60.0]);
+
+    let updates = core.core.set_environment(DocumentEnvironment {
+        device_class: DeviceClass::Phone,
+        ..Default::default()
+    });
+    assert!(updates.contains_key(&ComponentIdx::from(s_idx)));
+    assert_eq!(
+        core.get_prop(s_idx, STACKED_LOCAL_IDX).value,
+        PropValue::Boolean(true)
+    );
+    assert_eq!(get_widths(s_idx, &mut core), vec![100.0, 100.0]);
+}
+
+#[test]
+fn invalid_valign_produces_warning() {
+    let dast_root =
+        dast_root_no_position(r#"<sideBySide name="s" valign="bottm"><p>a</p></sideBySide>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let s_idx = core.get_component_index_by_name("s");
+    let flat_dast = core.to_flat_dast();
+    let warnings = flat_dast
+        .warnings_with_code(DiagnosticCode::InvalidAttributeValue)
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "`bottm` is not a valid value of the `valign` attribute of `<sideBySide>`, so the default was used"
+    );
+    assert_eq!(
+        core.get_prop(s_idx, VALIGN_LOCAL_IDX).value,
+        PropValue::String("top".to_string().into())
+    );
+}
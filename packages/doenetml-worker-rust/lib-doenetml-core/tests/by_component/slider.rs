@@ -0,0 +1,123 @@
+use super::*;
+
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::{
+            number::NumberProps,
+            slider::{SliderActions, SliderChangeValueActionArgs, SliderProps},
+        },
+        types::{Action, ActionBody},
+    },
+    props::PropValue,
+};
+
+const SLIDER_VALUE_IDX: LocalPropIdx = SliderProps::Value.local_idx();
+const NUMBER_VALUE_IDX: LocalPropIdx = NumberProps::Value.local_idx();
+
+/// Return the action moving the slider to `value`.
+fn change_value_action(value: f64, component_idx: usize) -> Action {
+    Action {
+        component_idx: component_idx.into(),
+        action: ActionsEnum::Slider(SliderActions::ChangeValue(ActionBody {
+            args: SliderChangeValueActionArgs { value },
+        })),
+    }
+}
+
+#[test]
+fn slider_starts_at_from_or_initial_value() {
+    let dast_root = dast_root_no_position(
+        r#"<slider name="s1" from="2" to="8" /><slider name="s2" initialValue="5" />"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let s1_idx = core.get_component_index_by_name("s1");
+    let s2_idx = core.get_component_index_by_name("s2");
+
+    let value: f64 = core.get_prop_value_typed(s1_idx, SLIDER_VALUE_IDX);
+    assert_eq!(value, 2.0);
+    let value: f64 = core.get_prop_value_typed(s2_idx, SLIDER_VALUE_IDX);
+    assert_eq!(value, 5.0);
+}
+
+#[test]
+fn change_value_snaps_to_step_within_range() {
+    let dast_root = dast_root_no_position(
+        r#"<slider name="s" from="1" to="9" step="2" /><number name="n">$s</number>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let s_idx = core.get_component_index_by_name("s");
+    let n_idx = core.get_component_index_by_name("n");
+
+    let _ = core.dispatch_action(change_value_action(4.2, s_idx));
+    let value: f64 = core.get_prop_value_typed(s_idx, SLIDER_VALUE_IDX);
+    assert_eq!(value, 5.0);
+    let value: f64 = core.get_prop_value_typed(n_idx, NUMBER_VALUE_IDX);
+    assert_eq!(value, 5.0);
+
+    let _ = core.dispatch_action(change_value_action(20.0, s_idx));
+    let value: f64 = core.get_prop_value_typed(s_idx, SLIDER_VALUE_IDX);
+    assert_eq!(value, 9.0);
+
+    let _ = core.dispatch_action(change_value_action(-3.0, s_idx));
+    let value: f64 = core.get_prop_value_typed(s_idx, SLIDER_VALUE_IDX);
+    assert_eq!(value, 1.0);
+}
+
+#[test]
+fn slider_bound_to_number_changes_the_number() {
+    let dast_root =
+        dast_root_no_position(r#"<number name="n">3</number><slider name="s" bindValueTo="$n" />"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let s_idx = core.get_component_index_by_name("s");
+    let n_idx = core.get_component_index_by_name("n");
+
+    let value: f64 = core.get_prop_value_typed(s_idx, SLIDER_VALUE_IDX);
+    assert_eq!(value, 3.0);
+
+    let _ = core.dispatch_action(change_value_action(7.0, s_idx));
+    let value: f64 = core.get_prop_value_typed(n_idx, NUMBER_VALUE_IDX);
+    assert_eq!(value, 7.0);
+    let value: f64 = core.get_prop_value_typed(s_idx, SLIDER_VALUE_IDX);
+    assert_eq!(value, 7.0);
+}
+
+#[test]
+fn markers_are_every_step_from_from_to_to() {
+    let dast_root = dast_root_no_position(
+        r#"<slider name="s1" from="0" to="1" step="0.25" /><slider name="s2" to="1000" step="1" />"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let s1_idx = core.get_component_index_by_name("s1");
+    let s2_idx = core.get_component_index_by_name("s2");
+
+    assert_eq!(
+        core.get_prop(s1_idx, SliderProps::Markers.local_idx())
+            .value,
+        PropValue::from(vec![
+            PropValue::Number(0.0),
+            PropValue::Number(0.25),
+            PropValue::Number(0.5),
+            PropValue::Number(0.75),
+            PropValue::Number(1.0),
+        ])
+    );
+    // Too many markers to show
+    assert_eq!(
+        core.get_prop(s2_idx, SliderProps::Markers.local_idx())
+            .value,
+        PropValue::from(Vec::new())
+    );
+}
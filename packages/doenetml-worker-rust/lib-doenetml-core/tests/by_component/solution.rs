@@ -0,0 +1,87 @@
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::{
+            document::DocumentProps,
+            solution::{SolutionActions, SolutionProps},
+        },
+        types::Action,
+    },
+    dast::FlatDastElementContent,
+    props::PropValue,
+};
+
+use super::*;
+
+const REVEALED_LOCAL_IDX: LocalPropIdx = SolutionProps::Revealed.local_idx();
+const NUM_SOLUTIONS_REVEALED_LOCAL_IDX: LocalPropIdx =
+    DocumentProps::NumSolutionsRevealed.local_idx();
+
+fn reveal(core: &mut TestCore, name: &str) {
+    let component_idx = core.get_component_index_by_name(name).into();
+    core.dispatch_action(Action {
+        component_idx,
+        action: ActionsEnum::Solution(SolutionActions::RevealSolution),
+    })
+    .unwrap();
+}
+
+#[test]
+fn solution_content_is_rendered_once_revealed() {
+    let dast_root =
+        dast_root_no_position(r#"<solution name="s"><text name="t">It is 4.</text></solution>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let s_idx = core.get_component_index_by_name("s");
+    let t_idx = core.get_component_index_by_name("t");
+
+    assert_eq!(
+        core.get_prop(s_idx, REVEALED_LOCAL_IDX).value,
+        PropValue::Boolean(false)
+    );
+    assert!(core.to_flat_dast().elements[s_idx].children.is_empty());
+
+    reveal(&mut core, "s");
+    assert_eq!(
+        core.get_prop(s_idx, REVEALED_LOCAL_IDX).value,
+        PropValue::Boolean(true)
+    );
+    assert_eq!(
+        core.to_flat_dast().elements[s_idx].children,
+        vec![FlatDastElementContent::new_original_element(t_idx)]
+    );
+}
+
+#[test]
+fn document_counts_revealed_solutions() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <solution name="s1"><p>one</p></solution>
+        <section><solution name="s2"><p>two</p></solution></section>
+        <solution name="s3" revealed><p>three</p></solution>
+        "#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    // The document is component 0.
+    let num_revealed = |core: &mut TestCore| {
+        core.get_prop(ComponentIdx::new(0), NUM_SOLUTIONS_REVEALED_LOCAL_IDX)
+            .value
+    };
+
+    assert_eq!(num_revealed(&mut core), PropValue::Integer(1));
+
+    reveal(&mut core, "s2");
+    assert_eq!(num_revealed(&mut core), PropValue::Integer(2));
+
+    // Revealing a solution again does not count it twice.
+    reveal(&mut core, "s2");
+    assert_eq!(num_revealed(&mut core), PropValue::Integer(2));
+
+    reveal(&mut core, "s1");
+    assert_eq!(num_revealed(&mut core), PropValue::Integer(3));
+}
@@ -0,0 +1,105 @@
+use doenetml_core::{
+    components::doenet::{cell::CellProps, table::TableProps},
+    dast::DiagnosticCode,
+    props::PropValue,
+};
+
+use super::*;
+
+const NUM_ROWS_LOCAL_IDX: LocalPropIdx = TableProps::NumRows.local_idx();
+const NUM_COLUMNS_LOCAL_IDX: LocalPropIdx = TableProps::NumColumns.local_idx();
+const HALIGN_LOCAL_IDX: LocalPropIdx = CellProps::Halign.local_idx();
+
+/// The alignment of the cell named `name`.
+fn get_halign(core: &mut TestCore, name: &str) -> PropValue {
+    let cell_idx = core.get_component_index_by_name(name);
+    core.get_prop(cell_idx, HALIGN_LOCAL_IDX).value
+}
+
+fn halign(value: &str) -> PropValue {
+    PropValue::String(value.to_string().into())
+}
+
+#[test]
+fn table_counts_rows_and_columns() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <table name="t">
+            <row><cell>1</cell><cell>2</cell></row>
+            <row><cell>a</cell><cell>b</cell><cell><p>c</p></cell></row>
+            <row><cell>x</cell></row>
+        </table>
+        "#,
+    );
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let t_idx = core.get_component_index_by_name("t");
+    assert_eq!(
+        core.get_prop(t_idx, NUM_ROWS_LOCAL_IDX).value,
+        PropValue::Integer(3)
+    );
+    assert_eq!(
+        core.get_prop(t_idx, NUM_COLUMNS_LOCAL_IDX).value,
+        PropValue::Integer(3)
+    );
+}
+
+#[test]
+fn empty_table_has_no_rows_or_columns() {
+    let dast_root = dast_root_no_position(r#"<table name="t" />"#);
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let t_idx = core.get_component_index_by_name("t");
+    assert_eq!(
+        core.get_prop(t_idx, NUM_ROWS_LOCAL_IDX).value,
+        PropValue::Integer(0)
+    );
+    assert_eq!(
+        core.get_prop(t_idx, NUM_COLUMNS_LOCAL_IDX).value,
+        PropValue::Integer(0)
+    );
+}
+
+#[test]
+fn cells_are_aligned_by_cell_then_row_then_column() {
+    let dast_root = dast_root_no_position(
+        r#"
+        <table halign="right center">
+            <row><cell name="a">a</cell><cell name="b">b</cell><cell name="c">c</cell></row>
+            <row halign="justify"><cell name="d">d</cell><cell name="e" halign="Center">e</cell></row>
+        </table>
+        "#,
+    );
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    assert_eq!(get_halign(&mut core, "a"), halign("right"));
+    assert_eq!(get_halign(&mut core, "b"), halign("center"));
+    // The third column has no alignment, so the default is used.
+    assert_eq!(get_halign(&mut core, "c"), halign("left"));
+    assert_eq!(get_halign(&mut core, "d"), halign("justify"));
+    assert_eq!(get_halign(&mut core, "e"), halign("center"));
+}
+
+#[test]
+fn invalid_halign_produces_warning() {
+    let dast_root = dast_root_no_position(
+        r#"<table halign="right"><row><cell name="a" halign="centre">a</cell></row></table>"#,
+    );
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+    let warnings = flat_dast
+        .warnings_with_code(DiagnosticCode::InvalidAttributeValue)
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "`centre` is not a valid value of the `halign` attribute of `<cell>`, so the default was used"
+    );
+    // The alignment of the column is used instead.
+    assert_eq!(get_halign(&mut core, "a"), halign("right"));
+}
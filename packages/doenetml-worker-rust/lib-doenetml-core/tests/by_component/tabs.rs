@@ -0,0 +1,192 @@
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::tabs::{TabsActions, TabsProps, TabsSelectTabActionArgs},
+        types::{Action, ActionBody},
+    },
+    dast::{DiagnosticCode, FlatDastElementContent, KeyboardShortcut, ShortcutScope},
+    props::PropValue,
+};
+
+use super::*;
+
+const SELECTED_INDEX_LOCAL_IDX: LocalPropIdx = TabsProps::SelectedIndex.local_idx();
+const TAB_TITLES_LOCAL_IDX: LocalPropIdx = TabsProps::TabTitles.local_idx();
+
+#[test]
+fn only_selected_tab_is_rendered() {
+    let dast_root = dast_root_no_position(
+        r#"<tabs name="tabs">
+  <tab name="t1" title="First"><text name="x">one</text></tab>
+  <tab name="t2" title="Second"><text name="y">two</text></tab>
+</tabs>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let tabs_idx = core.get_component_index_by_name("tabs");
+    let t1_idx = core.get_component_index_by_name("t1");
+    let t2_idx = core.get_component_index_by_name("t2");
+    let x_idx = core.get_component_index_by_name("x");
+    let y_idx = core.get_component_index_by_name("y");
+
+    assert_eq!(
+        core.get_prop(tabs_idx, SELECTED_INDEX_LOCAL_IDX).value,
+        PropValue::Integer(1)
+    );
+    assert_eq!(
+        core.get_prop(tabs_idx, TAB_TITLES_LOCAL_IDX).value,
+        PropValue::PropVec(vec!["First".into(), "Second".into()].into())
+    );
+
+    let flat_dast = core.to_flat_dast();
+    assert_eq!(
+        flat_dast.elements[tabs_idx].children,
+        vec![FlatDastElementContent::new_original_element(t1_idx)]
+    );
+    // The props of the content of the tab that is not selected are not calculated for rendering.
+    assert!(flat_dast.elements[x_idx].data.props.is_some());
+    assert!(flat_dast.elements[y_idx].data.props.is_none());
+
+    // Selecting the second tab renders it and its content.
+    let updates = core
+        .dispatch_action(Action {
+            component_idx: tabs_idx.into(),
+            action: ActionsEnum::Tabs(TabsActions::SelectTab(ActionBody {
+                args: TabsSelectTabActionArgs { index: 2 },
+            })),
+        })
+        .unwrap();
+    assert_eq!(
+        core.get_prop(tabs_idx, SELECTED_INDEX_LOCAL_IDX).value,
+        PropValue::Integer(2)
+    );
+    assert_eq!(
+        updates[&ComponentIdx::from(tabs_idx)].new_children,
+        Some(vec![FlatDastElementContent::new_original_element(t2_idx)])
+    );
+    assert_eq!(
+        updates[&ComponentIdx::from(t2_idx)].new_children,
+        Some(vec![FlatDastElementContent::new_original_element(y_idx)])
+    );
+    assert!(updates[&ComponentIdx::from(y_idx)].changed_state.is_some());
+}
+
+#[test]
+fn selected_index_from_attribute() {
+    let dast_root = dast_root_no_position(
+        r#"<tabs name="tabs" selectedIndex="2"><tab name="t1" /><tab name="t2" /><tab name="t3" /></tabs>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let tabs_idx = core.get_component_index_by_name("tabs");
+    let t2_idx = core.get_component_index_by_name("t2");
+
+    assert_eq!(
+        core.get_prop(tabs_idx, SELECTED_INDEX_LOCAL_IDX).value,
+        PropValue::Integer(2)
+    );
+    let flat_dast = core.to_flat_dast();
+    assert_eq!(
+        flat_dast.elements[tabs_idx].children,
+        vec![FlatDastElementContent::new_original_element(t2_idx)]
+    );
+}
+
+#[test]
+fn next_and_previous_tab_wrap_around() {
+    let dast_root = dast_root_no_position(
+        r#"<tabs name="tabs"><tab name="t1" /><tab name="t2" /><tab name="t3" /></tabs>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let tabs_idx = core.get_component_index_by_name("tabs");
+    let mut select = |action: TabsActions| {
+        core.dispatch_action(Action {
+            component_idx: tabs_idx.into(),
+            action: ActionsEnum::Tabs(action),
+        })
+        .unwrap();
+        core.get_prop(tabs_idx, SELECTED_INDEX_LOCAL_IDX).value
+    };
+
+    assert_eq!(
+        select(TabsActions::SelectPreviousTab),
+        PropValue::Integer(3)
+    );
+    assert_eq!(select(TabsActions::SelectNextTab), PropValue::Integer(1));
+    assert_eq!(select(TabsActions::SelectNextTab), PropValue::Integer(2));
+}
+
+#[test]
+fn keyboard_shortcuts_of_later_tabs_are_disabled() {
+    let dast_root = dast_root_no_position(
+        r#"<tabs name="first"><tab /></tabs><tabs name="second"><tab /></tabs>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let first_idx = core.get_component_index_by_name("first");
+    let second_idx = core.get_component_index_by_name("second");
+
+    let flat_dast = core.to_flat_dast();
+    assert_eq!(
+        flat_dast.elements[first_idx].data.keyboard_shortcuts,
+        Some(vec![
+            KeyboardShortcut::new("alt+PageDown", "selectNextTab", ShortcutScope::Document),
+            KeyboardShortcut::new(
+                "option+PageUp",
+                "selectPreviousTab",
+                ShortcutScope::Document
+            ),
+        ])
+    );
+    assert_eq!(flat_dast.elements[second_idx].data.keyboard_shortcuts, None);
+
+    let conflicts = flat_dast
+        .warnings
+        .iter()
+        .filter(|warning| warning.code == Some(DiagnosticCode::KeyboardShortcutConflict))
+        .collect::<Vec<_>>();
+    assert_eq!(conflicts.len(), 2);
+    assert!(conflicts[0].message.contains("`Alt+PageDown`"));
+}
+
+#[test]
+fn selecting_tab_from_keyboard_moves_focus() {
+    let dast_root =
+        dast_root_no_position(r#"<tabs name="tabs"><tab name="t1" /><tab name="t2" /></tabs>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+    core.to_flat_dast();
+
+    let tabs_idx = core.get_component_index_by_name("tabs");
+
+    // Clicking a tab leaves focus on the button that was clicked.
+    let updates = core
+        .dispatch_action(Action {
+            component_idx: tabs_idx.into(),
+            action: ActionsEnum::Tabs(TabsActions::SelectTab(ActionBody {
+                args: TabsSelectTabActionArgs { index: 2 },
+            })),
+        })
+        .unwrap();
+    assert!(updates.values().all(|update| !update.focus));
+
+    // Selecting a tab with a keyboard shortcut moves focus to the tabs.
+    let updates = core
+        .dispatch_action(Action {
+            component_idx: tabs_idx.into(),
+            action: ActionsEnum::Tabs(TabsActions::SelectNextTab),
+        })
+        .unwrap();
+    assert!(updates[&ComponentIdx::from(tabs_idx)].focus);
+    assert_eq!(updates.values().filter(|update| update.focus).count(), 1);
+}
@@ -1,4 +1,4 @@
-use doenetml_core::dast::{ForRenderPropValue, ForRenderProps};
+use doenetml_core::dast::{ForRenderPropValue, ForRenderProps, KeyboardShortcut, ShortcutScope};
 
 use super::*;
 
@@ -38,6 +38,78 @@ fn value_and_immediate_value_respond_to_actions() {
     assert_eq!(get_value(text_input_idx, &mut core), "hello");
 }
 
+/// An action that sets a value to what it already is does not update any components.
+#[test]
+fn repeating_an_action_does_not_update_components() {
+    let dast_root = dast_root_no_position(r#"<textInput/>"#);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    let _ = core.to_flat_dast();
+
+    // the text input will be index 1, as the document tag will be index 0.
+    let text_input_idx = ComponentIdx::new(1);
+
+    let updates = core
+        .dispatch_action(update_immediate_value_action(
+            String::from("hello"),
+            text_input_idx,
+        ))
+        .unwrap();
+    assert!(!updates.is_empty());
+
+    let updates = core
+        .dispatch_action(update_immediate_value_action(
+            String::from("hello"),
+            text_input_idx,
+        ))
+        .unwrap();
+    assert!(updates.is_empty());
+    assert_eq!(get_immediate_value(text_input_idx, &mut core), "hello");
+}
+
+/// In a batch, each action sees the values requested by the actions before it,
+/// so `UpdateValue` uses the immediate value set by `UpdateImmediateValue` earlier in the batch.
+#[test]
+fn batched_actions_build_on_each_other() {
+    let dast_root = dast_root_no_position(r#"<textInput/>"#);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    let _ = core.to_flat_dast();
+
+    // the text input will be index 1, as the document tag will be index 0.
+    let text_input_idx = ComponentIdx::new(1);
+
+    let updates = core
+        .dispatch_actions(vec![
+            update_immediate_value_action(String::from("hello"), text_input_idx),
+            update_value_action(text_input_idx),
+        ])
+        .unwrap();
+    assert!(updates.contains_key(&text_input_idx));
+    assert_eq!(get_immediate_value(text_input_idx, &mut core), "hello");
+    assert_eq!(get_value(text_input_idx, &mut core), "hello");
+
+    // If an action of a batch fails, none of the changes of the batch are made.
+    assert!(
+        core.dispatch_actions(vec![
+            update_immediate_value_action(String::from("bye"), text_input_idx),
+            update_value_action(text_input_idx),
+            update_value_action(ComponentIdx::new(0)),
+        ])
+        .is_err()
+    );
+    assert_eq!(get_immediate_value(text_input_idx, &mut core), "hello");
+    assert_eq!(get_value(text_input_idx, &mut core), "hello");
+
+    // The batch is undone as a single step.
+    core.undo().unwrap();
+    assert_eq!(get_immediate_value(text_input_idx, &mut core), "");
+    assert_eq!(get_value(text_input_idx, &mut core), "");
+    assert!(!core.can_undo());
+}
+
 /// The UpdateImmediateValue and UpdateValue actions work the same with a prefill,
 /// only their initial values are from prefill.
 #[test]
@@ -368,3 +440,26 @@ fn flat_dast_updates_from_actions() {
         }])
     );
 }
+
+/// Pressing `Enter` while a text input is focused calls its `UpdateValue` action.
+/// Shortcuts scoped to a focused component do not conflict, so every text input gets one.
+#[test]
+fn enter_updates_value() {
+    let dast_root = dast_root_no_position(r#"<textInput/><textInput/>"#);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+    for text_input_idx in [1, 2] {
+        assert_eq!(
+            flat_dast.elements[text_input_idx].data.keyboard_shortcuts,
+            Some(vec![KeyboardShortcut::new(
+                "Enter",
+                "updateValue",
+                ShortcutScope::Focused
+            )])
+        );
+    }
+    assert!(flat_dast.warnings.is_empty());
+}
@@ -52,6 +52,75 @@ fn text_input_extending_text_input() {
     assert_eq!(get_value(extending_idx, &mut core), "hello");
 }
 
+/// A text input extending a text input with `independentState` keeps its own value
+#[test]
+fn text_input_extending_text_input_with_independent_state() {
+    let dast_root = dast_root_no_position(
+        r#"<textInput name="ti"/> <textInput extend="$ti" independentState="true" />"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let text_input_idx = ComponentIdx::new(1);
+    let extending_idx = ComponentIdx::new(2);
+
+    assert_eq!(get_value(text_input_idx, &mut core), "");
+    assert_eq!(get_value(extending_idx, &mut core), "");
+
+    let _ = core.dispatch_action(update_immediate_value_action(
+        String::from("hello"),
+        text_input_idx,
+    ));
+    let _ = core.dispatch_action(update_value_action(text_input_idx));
+
+    assert_eq!(get_value(text_input_idx, &mut core), "hello");
+    assert_eq!(get_immediate_value(extending_idx, &mut core), "");
+    assert_eq!(get_value(extending_idx, &mut core), "");
+
+    let _ = core.dispatch_action(update_immediate_value_action(
+        String::from("bye"),
+        extending_idx,
+    ));
+    let _ = core.dispatch_action(update_value_action(extending_idx));
+
+    assert_eq!(get_value(text_input_idx, &mut core), "hello");
+    assert_eq!(get_value(extending_idx, &mut core), "bye");
+}
+
+/// The state of a copy with `independentState` starts from the state of its source
+#[test]
+fn independent_state_is_initialized_from_source() {
+    let dast_root = dast_root_no_position(
+        r#"<textInput name="ti"/> <textInput extend="$ti" independentState="true" />"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let text_input_idx = ComponentIdx::new(1);
+    let extending_idx = ComponentIdx::new(2);
+
+    // Change the source before the state of the copy is created.
+    let _ = core.dispatch_action(update_immediate_value_action(
+        String::from("example"),
+        text_input_idx,
+    ));
+    let _ = core.dispatch_action(update_value_action(text_input_idx));
+
+    assert_eq!(get_value(extending_idx, &mut core), "example");
+
+    // Modifying the copy leaves the source unchanged.
+    let _ = core.dispatch_action(update_immediate_value_action(
+        String::from("example, modified"),
+        extending_idx,
+    ));
+    let _ = core.dispatch_action(update_value_action(extending_idx));
+
+    assert_eq!(get_value(text_input_idx, &mut core), "example");
+    assert_eq!(get_value(extending_idx, &mut core), "example, modified");
+}
+
 /// A text extending a text input mirrors `value`
 #[test]
 fn references_to_value_and_immediate_value_respond_to_actions() {
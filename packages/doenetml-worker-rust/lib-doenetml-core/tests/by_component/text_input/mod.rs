@@ -1,5 +1,6 @@
 mod basic_actions;
 mod extending;
+mod print_mode;
 mod test_helpers;
 mod with_references;
 mod with_references_to_boolean;
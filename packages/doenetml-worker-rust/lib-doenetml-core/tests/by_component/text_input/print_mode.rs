@@ -0,0 +1,94 @@
+use doenetml_core::{
+    core::DocumentFlags,
+    dast::{ForRenderPropValue, ForRenderProps},
+    props::RenderMode,
+};
+
+use super::*;
+
+fn print_flags() -> DocumentFlags {
+    DocumentFlags {
+        render_mode: RenderMode::Print,
+        ..Default::default()
+    }
+}
+
+/// When rendered interactively, a text input renders the contents of its input box.
+#[test]
+fn text_input_renders_immediate_value_when_interactive() {
+    let dast_root = dast_root_no_position(r#"<textInput prefill="hello" />"#);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+    let text_input_idx = ComponentIdx::new(1);
+
+    let flat_dast = core.to_flat_dast();
+    assert_eq!(
+        flat_dast.elements[text_input_idx.as_usize()].data.props,
+        Some(ForRenderProps(vec![ForRenderPropValue {
+            name: "immediateValue",
+            value: "hello".into()
+        }]))
+    );
+}
+
+/// When rendered for print, a text input renders its value in place of the input box.
+#[test]
+fn text_input_renders_print_value_when_printed() {
+    let dast_root = dast_root_no_position(r#"<textInput prefill="hello" /><textInput />"#);
+
+    let mut core = Core::new();
+    core.set_flags(print_flags());
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+    assert_eq!(
+        flat_dast.elements[1].data.props,
+        Some(ForRenderProps(vec![ForRenderPropValue {
+            name: "printValue",
+            value: "hello".into()
+        }]))
+    );
+    // Without a response, the value is blank
+    assert_eq!(
+        flat_dast.elements[2].data.props,
+        Some(ForRenderProps(vec![ForRenderPropValue {
+            name: "printValue",
+            value: "".into()
+        }]))
+    );
+}
+
+/// The print value is the value submitted by a user, not what is being typed.
+#[test]
+fn print_value_is_submitted_value() {
+    let dast_root = dast_root_no_position(r#"<textInput/>"#);
+
+    let mut core = Core::new();
+    core.set_flags(print_flags());
+    core.init_from_dast_root(&dast_root);
+    let text_input_idx = ComponentIdx::new(1);
+
+    let _ = core.dispatch_action(update_immediate_value_action(
+        String::from("hello"),
+        text_input_idx,
+    ));
+    let flat_dast = core.to_flat_dast();
+    assert_eq!(
+        flat_dast.elements[1].data.props,
+        Some(ForRenderProps(vec![ForRenderPropValue {
+            name: "printValue",
+            value: "".into()
+        }]))
+    );
+
+    let _ = core.dispatch_action(update_value_action(text_input_idx));
+    let flat_dast = core.to_flat_dast();
+    assert_eq!(
+        flat_dast.elements[1].data.props,
+        Some(ForRenderProps(vec![ForRenderPropValue {
+            name: "printValue",
+            value: "hello".into()
+        }]))
+    );
+}
@@ -132,6 +132,49 @@ fn text_input_reference_prefill_is_not_changed_when_update_value() {
     assert_eq!(get_text_value(text_idx, &mut core), "hello");
 }
 
+/// A text component referenced by `bindValueTo` of a text input
+/// will be changed with `UpdateValue`, and it takes precedence over any children
+#[test]
+fn text_input_bind_value_to_is_changed_when_update_value() {
+    let dast_root = dast_root_no_position(
+        r#"<textInput bindValueTo="$t">ignored</textInput><text name="t">hello</text>"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    // the text input will be index 1, as the document tag will be index 0.
+    let text_input_idx = ComponentIdx::new(1);
+
+    // the original text will be right after the text input, so index 2
+    let text_idx = ComponentIdx::new(2);
+
+    // both variables are initialized from the bound text
+    assert_eq!(get_immediate_value(text_input_idx, &mut core), "hello");
+    assert_eq!(get_value(text_input_idx, &mut core), "hello");
+    assert_eq!(get_text_value(text_idx, &mut core), "hello");
+
+    // The UpdateImmediateValue action (which should be in response to typing characters)
+    // should only update the immediate_value prop
+    let type_word_action = update_immediate_value_action(String::from("bye"), text_input_idx);
+
+    let _ = core.dispatch_action(type_word_action);
+
+    assert_eq!(get_immediate_value(text_input_idx, &mut core), "bye");
+    assert_eq!(get_value(text_input_idx, &mut core), "hello");
+    assert_eq!(get_text_value(text_idx, &mut core), "hello");
+
+    // The UpdateValue action (which should corresponds to pressing enter or blurring)
+    // should update value to match immediate value, changing the bound text
+    let press_enter_action = update_value_action(text_input_idx);
+
+    let _ = core.dispatch_action(press_enter_action);
+
+    assert_eq!(get_immediate_value(text_input_idx, &mut core), "bye");
+    assert_eq!(get_value(text_input_idx, &mut core), "bye");
+    assert_eq!(get_text_value(text_idx, &mut core), "bye");
+}
+
 /// References to `value` and `immediate_value` update with actions
 #[test]
 fn references_to_value_and_immediate_value_respond_to_actions() {
@@ -240,3 +283,29 @@ fn plain_reference_to_text_input_expands_into_text() {
     assert_eq!(get_value(text_input_idx, &mut core), "hello");
     assert_eq!(get_text_value(reference_idx, &mut core), "hello");
 }
+
+/// A reference that combines an index into a group with a prop, e.g., `$g[2].value`,
+/// refers to the prop of that member of the group
+#[test]
+fn reference_to_prop_of_indexed_group_member() {
+    let dast_root = dast_root_no_position(
+        r#"<group name="g"><text>a</text><textInput/></group><text extend="$g[2].value"/>$g[2].value"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    // `<group>` is index 1, with members at indices 2 and 3.
+    let text_input_idx = ComponentIdx::new(3);
+    let extending_text_idx = ComponentIdx::new(4);
+    let reference_idx = ComponentIdx::new(5);
+
+    let _ = core.dispatch_action(update_immediate_value_action(
+        String::from("hello"),
+        text_input_idx,
+    ));
+    let _ = core.dispatch_action(update_value_action(text_input_idx));
+
+    assert_eq!(get_text_value(extending_text_idx, &mut core), "hello");
+    assert_eq!(get_text_value(reference_idx, &mut core), "hello");
+}
@@ -0,0 +1,73 @@
+use std::rc::Rc;
+
+use super::*;
+
+use doenetml_core::{
+    components::doenet::{text::TextProps, text_list::TextListProps},
+    dast::FlatDastElementContent,
+    props::PropValue,
+};
+
+const COUNT_LOCAL_IDX: LocalPropIdx = TextListProps::Count.local_idx();
+const TEXT_VALUE_LOCAL_IDX: LocalPropIdx = TextProps::Value.local_idx();
+
+#[test]
+fn text_children_become_texts() {
+    let dast_root = dast_root_no_position(
+        r#"<text name="t">green</text><textList name="l">red, blue $t</textList>"#,
+    );
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let list_idx = core.get_component_index_by_name("l");
+    assert_eq!(
+        core.get_prop(list_idx, COUNT_LOCAL_IDX).value,
+        PropValue::Integer(3)
+    );
+
+    let flat_dast = core.to_flat_dast();
+    let children = flat_dast.elements[list_idx]
+        .children
+        .iter()
+        .map(|child| match child {
+            FlatDastElementContent::Element(child) => child.id,
+            other => panic!("Expected an element child, found {other:?}"),
+        })
+        .collect::<Vec<_>>();
+    for idx in &children {
+        assert_eq!(flat_dast.elements[*idx].name, "text");
+    }
+    let values = children
+        .iter()
+        .map(|idx| core.get_prop(*idx, TEXT_VALUE_LOCAL_IDX).value)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        ["red", "blue", "green"]
+            .into_iter()
+            .map(|value| PropValue::String(Rc::new(value.to_string())))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn members_can_be_referenced_by_index() {
+    let dast_root =
+        dast_root_no_position(r#"<textList name="l">red blue</textList><p name="p">$l[2]</p>"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+
+    let p_idx = core.get_component_index_by_name("p");
+    let flat_dast = core.to_flat_dast();
+    let member_idx = match flat_dast.elements[p_idx].children.as_slice() {
+        [FlatDastElementContent::Element(member)] => member.id,
+        other => panic!("Expected a single element child, found {other:?}"),
+    };
+    assert_eq!(flat_dast.elements[member_idx].name, "text");
+    assert_eq!(
+        core.get_prop(member_idx, TEXT_VALUE_LOCAL_IDX).value,
+        PropValue::String(Rc::new("blue".to_string()))
+    );
+}
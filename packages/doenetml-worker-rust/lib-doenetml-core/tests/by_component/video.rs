@@ -0,0 +1,143 @@
+use std::rc::Rc;
+
+use doenetml_core::{
+    components::{
+        ActionsEnum,
+        doenet::video::{
+            VideoActions, VideoProps, VideoStartedActionArgs, VideoWatchedActionArgs,
+            add_watched_segment,
+        },
+        types::{Action, ActionBody},
+    },
+    props::PropValue,
+};
+
+use super::*;
+
+const STATE_LOCAL_IDX: LocalPropIdx = VideoProps::State.local_idx();
+const DURATION_LOCAL_IDX: LocalPropIdx = VideoProps::Duration.local_idx();
+const SEGMENTS_WATCHED_LOCAL_IDX: LocalPropIdx = VideoProps::SegmentsWatched.local_idx();
+
+fn video_action(action: VideoActions, component_idx: usize) -> Action {
+    Action {
+        component_idx: component_idx.into(),
+        action: ActionsEnum::Video(action),
+    }
+}
+
+fn watched_action(begin_time: f64, end_time: f64, component_idx: usize) -> Action {
+    video_action(
+        VideoActions::RecordVideoWatched(ActionBody {
+            args: VideoWatchedActionArgs {
+                begin_time,
+                end_time,
+            },
+        }),
+        component_idx,
+    )
+}
+
+/// The value of `segmentsWatched` for the pairs `segments`.
+fn segments_value(segments: &[(f64, f64)]) -> PropValue {
+    PropValue::PropVec(Rc::new(
+        segments
+            .iter()
+            .map(|(begin, end)| {
+                PropValue::PropVec(Rc::new(vec![
+                    PropValue::Number(*begin),
+                    PropValue::Number(*end),
+                ]))
+            })
+            .collect(),
+    ))
+}
+
+#[test]
+fn video_state_and_duration_track_playback() {
+    let dast_root = dast_root_no_position(r#"<video name="v" source="lecture.mp4" />"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+    let video_idx = core.get_component_index_by_name("v");
+
+    assert_eq!(
+        core.get_prop(video_idx, STATE_LOCAL_IDX).value,
+        PropValue::from("paused")
+    );
+    let duration: f64 = core.get_prop_value_typed(video_idx, DURATION_LOCAL_IDX);
+    assert!(duration.is_nan());
+
+    core.dispatch_action(video_action(
+        VideoActions::RecordVideoStarted(ActionBody {
+            args: VideoStartedActionArgs { duration: 300.0 },
+        }),
+        video_idx,
+    ))
+    .unwrap();
+    assert_eq!(
+        core.get_prop(video_idx, STATE_LOCAL_IDX).value,
+        PropValue::from("playing")
+    );
+    let duration: f64 = core.get_prop_value_typed(video_idx, DURATION_LOCAL_IDX);
+    assert_eq!(duration, 300.0);
+
+    core.dispatch_action(video_action(VideoActions::RecordVideoPaused, video_idx))
+        .unwrap();
+    assert_eq!(
+        core.get_prop(video_idx, STATE_LOCAL_IDX).value,
+        PropValue::from("paused")
+    );
+}
+
+#[test]
+fn watched_segments_are_merged_and_stored_as_essential_data() {
+    let dast_root = dast_root_no_position(r#"<video name="v" source="lecture.mp4" />"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+    let video_idx = core.get_component_index_by_name("v");
+
+    for (begin, end) in [(10.0, 20.0), (0.0, 5.0), (15.0, 30.0)] {
+        core.dispatch_action(watched_action(begin, end, video_idx))
+            .unwrap();
+    }
+    assert_eq!(
+        core.get_prop(video_idx, SEGMENTS_WATCHED_LOCAL_IDX).value,
+        segments_value(&[(0.0, 5.0), (10.0, 30.0)])
+    );
+
+    let segments_watched = core
+        .core
+        .list_essential_data(video_idx.into())
+        .unwrap()
+        .into_iter()
+        .find(|data| data.origin == "segmentsWatched")
+        .unwrap();
+    assert_eq!(
+        segments_watched.value,
+        segments_value(&[(0.0, 5.0), (10.0, 30.0)])
+    );
+    assert!(!segments_watched.came_from_default);
+}
+
+#[test]
+fn adding_watched_segments() {
+    assert_eq!(
+        add_watched_segment(vec![(0.0, 5.0), (10.0, 20.0)], 5.0, 10.0),
+        vec![(0.0, 20.0)]
+    );
+    assert_eq!(
+        add_watched_segment(vec![(10.0, 20.0)], 12.0, 15.0),
+        vec![(10.0, 20.0)]
+    );
+    // Empty and backwards segments are ignored.
+    assert_eq!(
+        add_watched_segment(vec![(10.0, 20.0)], 30.0, 30.0),
+        vec![(10.0, 20.0)]
+    );
+    assert_eq!(
+        add_watched_segment(vec![(10.0, 20.0)], 5.0, 1.0),
+        vec![(10.0, 20.0)]
+    );
+    assert_eq!(add_watched_segment(Vec::new(), 0.0, f64::NAN), Vec::new());
+}
@@ -2,6 +2,7 @@ use super::*;
 
 use doenetml_core::{
     components::{ComponentNode, doenet::xref::XrefProps},
+    dast::DiagnosticCode,
     props::{PropView, prop_type, traits::IntoPropView},
     state::types::component_refs::ComponentRef,
 };
@@ -102,3 +103,28 @@ fn xref_can_tolerate_invalid_ref_field2() {
     core.init_from_dast_root(&dast_root);
     core.to_flat_dast();
 }
+
+#[test]
+fn xref_ref_to_a_prop_becomes_a_warning() {
+    let dast_root = dast_root_no_position(r#"<section name="foo"/><xref ref="$foo.title" />"#);
+
+    let mut core = TestCore::new();
+    core.init_from_dast_root(&dast_root);
+    let flat_dast = core.to_flat_dast();
+
+    // the document tag will be index 0.
+    let xref_idx = ComponentIdx::from(2);
+
+    let prop = core.get_prop(xref_idx, XrefProps::Referent.local_idx());
+    let pv: PropView<prop_type::ComponentRef> = prop.into_prop_view();
+    assert_eq!(pv.value, None);
+
+    let warnings = flat_dast
+        .warnings_with_code(DiagnosticCode::ComponentReferenceExpected)
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "Expected a reference to a component, but the reference has the path `.title`"
+    );
+}
@@ -88,3 +88,32 @@ fn test_can_change_source_on_an_initialized_core() {
     assert_eq!(core.get_component_index_by_name("t2"), 2);
     assert_eq!(core.get_component_index_by_name("t3"), 3);
 }
+
+#[test]
+fn flat_dast_is_the_same_across_runs() {
+    let source = r#"<span zeta="1" alpha="2" mu="3" beta="4"><text name="t" /></span><p gamma="5" delta="6">$t</p>"#;
+
+    let serialized = (0..3)
+        .map(|_| {
+            let mut core = Core::new();
+            core.init_from_dast_root(&dast_root_no_position(source));
+            serde_json::to_string(&core.to_flat_dast()).unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(serialized[0], serialized[1]);
+    assert_eq!(serialized[0], serialized[2]);
+
+    // Attributes are listed in order of their names.
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(source));
+    let flat_dast = core.to_flat_dast();
+    let span = flat_dast
+        .elements
+        .iter()
+        .find(|element| element.name == "span")
+        .unwrap();
+    assert_eq!(
+        span.attributes.keys().collect::<Vec<_>>(),
+        vec!["alpha", "beta", "mu", "zeta"]
+    );
+}
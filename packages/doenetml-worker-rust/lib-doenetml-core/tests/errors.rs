@@ -1,5 +1,8 @@
 mod test_utils;
-use doenetml_core::core::core::Core;
+use doenetml_core::{
+    core::core::Core,
+    dast::{DiagnosticCode, flat_dast::ErrorType},
+};
 use test_utils::*;
 
 #[test]
@@ -116,3 +119,297 @@ fn error_referencing_external_and_referencing_error() {
             .contains("from an error component")
     );
 }
+
+#[test]
+fn unresolved_path_indices_are_reported() {
+    let dast_root = dast_root_no_position(
+        "<document><group name='g'><text>a</text></group><number name='n'>1</number>$g[$n].value $g[5].value</document>",
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let elements = core.to_flat_dast().elements;
+    let errors = elements
+        .iter()
+        .filter(|element| element.name == "_error")
+        .collect::<Vec<_>>();
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+        errors[0].data.code,
+        Some(DiagnosticCode::InvalidExtendSource)
+    );
+    assert!(
+        errors[0]
+            .data
+            .message
+            .as_ref()
+            .unwrap()
+            .contains("Indices that depend on other components")
+    );
+    assert_eq!(
+        errors[0].data.suggestion.as_deref(),
+        Some("Use a number as the index, e.g., `$g[2]`")
+    );
+    assert_eq!(errors[1].data.code, Some(DiagnosticCode::NoReferent));
+}
+
+#[test]
+fn indices_of_props_are_not_reported_as_missing_components() {
+    let dast_root = dast_root_no_position(
+        "<document><text name='t'>a</text>$t.value[2]<text extend='$e' name='f' />$f[2].value<ext name='e'/></document>",
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let elements = core.to_flat_dast().elements;
+    let errors = elements
+        .iter()
+        .filter(|element| element.name == "_error")
+        .collect::<Vec<_>>();
+
+    assert_eq!(errors.len(), 3);
+    assert_eq!(
+        errors[0].data.code,
+        Some(DiagnosticCode::InvalidExtendSource)
+    );
+    assert!(
+        errors[0]
+            .data
+            .message
+            .as_ref()
+            .unwrap()
+            .contains("Path indices not yet supported")
+    );
+    assert!(
+        errors[2]
+            .data
+            .message
+            .as_ref()
+            .unwrap()
+            .contains("from an error component")
+    );
+}
+
+#[test]
+fn repeated_warnings_are_reported_once_with_a_count() {
+    let dast_root = dast_root_no_position("<document>$t $t<text name='s'/>$s</document>");
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+
+    assert_eq!(flat_dast.warnings.len(), 1);
+    let warning = &flat_dast.warnings[0];
+    assert_eq!(warning.code, Some(DiagnosticCode::NoReferent));
+    assert_eq!(warning.severity, ErrorType::Warning);
+    assert_eq!(warning.count, 2);
+    assert!(warning.message.contains("No referent"));
+}
+
+#[test]
+fn errors_include_code_and_suggestion() {
+    let dast_root = dast_root_no_position("<document><text name='text1' />$txt1</document>");
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+    let elements = flat_dast.elements;
+
+    assert_eq!(elements[2].name, "_error");
+    assert_eq!(elements[2].data.code, Some(DiagnosticCode::NoReferent));
+    assert_eq!(
+        elements[2].data.suggestion.as_deref(),
+        Some("Did you mean `$text1`?")
+    );
+
+    let warning = &flat_dast.warnings[0];
+    assert_eq!(warning.code.unwrap().as_str(), "DML2001");
+    assert_eq!(
+        warning.suggestion.as_deref(),
+        Some("Did you mean `$text1`?")
+    );
+}
+
+#[test]
+fn missing_prop_suggests_similar_public_props() {
+    let dast_root = dast_root_no_position("<document><text name='t' />$t.valu $t.hiden</document>");
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let elements = core.to_flat_dast().elements;
+
+    assert_eq!(elements[2].name, "_error");
+    assert_eq!(
+        elements[2].data.code,
+        Some(DiagnosticCode::PropDoesNotExist)
+    );
+    assert_eq!(
+        elements[2].data.suggestion.as_deref(),
+        Some("Did you mean `.value`?")
+    );
+
+    // `hidden` is not public, so it is not suggested
+    assert_eq!(elements[3].name, "_error");
+    assert_eq!(
+        elements[3].data.code,
+        Some(DiagnosticCode::PropDoesNotExist)
+    );
+    assert_eq!(elements[3].data.suggestion, None);
+}
+
+#[test]
+fn misspelled_component_type_produces_warning() {
+    let dast_root = dast_root_no_position("<document><pont /><txt /><span /></document>");
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+
+    // The elements are still passed through unchanged
+    assert_eq!(flat_dast.elements[1].name, "pont");
+
+    let warnings = flat_dast
+        .warnings_with_code(DiagnosticCode::UnknownComponentType)
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(
+        warnings[0].message,
+        "`<pont>` is not a DoenetML component, so it was left unchanged"
+    );
+    assert_eq!(
+        warnings[0].suggestion.as_deref(),
+        Some("Did you mean `<point>`?")
+    );
+    assert_eq!(
+        warnings[1].suggestion.as_deref(),
+        Some("Did you mean `<text>`?")
+    );
+}
+
+#[test]
+fn unknown_component_keeps_its_content_and_produces_warning() {
+    let dast_root = dast_root_no_position(
+        r#"<document><matrixPlot rows="2"><text>inside</text></matrixPlot><span /></document>"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+
+    // The unknown component keeps its attributes and children, and is marked for a placeholder
+    let unknown = &flat_dast.elements[1];
+    assert_eq!(unknown.name, "matrixPlot");
+    assert!(unknown.attributes.contains_key("rows"));
+    assert_eq!(unknown.children.len(), 1);
+    assert_eq!(
+        unknown.data.code,
+        Some(DiagnosticCode::UnknownComponentType)
+    );
+
+    // HTML tags are not marked
+    assert_eq!(flat_dast.elements[3].name, "span");
+    assert_eq!(flat_dast.elements[3].data.code, None);
+
+    let warnings = flat_dast
+        .warnings_with_code(DiagnosticCode::UnknownComponentType)
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "`<matrixPlot>` is not a DoenetML component, so it was left unchanged"
+    );
+    assert_eq!(warnings[0].suggestion, None);
+}
+
+#[test]
+fn invalid_enum_attribute_values_produce_warnings() {
+    let dast_root = dast_root_no_position(
+        "<document><division type='chaptr' /><division type='Chapter' /><ol marker='roman' /><text name='t'>bogus</text><ul marker='$t' /></document>",
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+
+    // Valid values are parsed case-insensitively and values from references are not checked.
+    let warnings = flat_dast
+        .warnings_with_code(DiagnosticCode::InvalidAttributeValue)
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(
+        warnings[0].message,
+        "`chaptr` is not a valid value of the `type` attribute of `<division>`, so the default was used"
+    );
+    assert_eq!(
+        warnings[0].suggestion.as_deref(),
+        Some("Did you mean `chapter`?")
+    );
+    assert_eq!(
+        warnings[1].message,
+        "`roman` is not a valid value of the `marker` attribute of `<ol>`, so the default was used"
+    );
+    assert!(
+        warnings[1]
+            .suggestion
+            .as_deref()
+            .unwrap()
+            .contains("`lower-roman`")
+    );
+}
+
+#[test]
+fn invalid_enum_attribute_warning_points_to_the_attribute() {
+    let source = "<document>\n<division type='chaptr' />\n</document>";
+    let dast_root = dast_root(source);
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+    let warnings = flat_dast
+        .warnings_with_code(DiagnosticCode::InvalidAttributeValue)
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 1);
+
+    // The warning covers `type='chaptr'`, not the whole `<division>`.
+    let position = warnings[0].position.as_ref().unwrap();
+    assert_eq!(position.start.line, 2);
+    assert_eq!(position.start.column, 11);
+    assert_eq!(position.end.line, 2);
+    assert_eq!(position.end.column, 24);
+}
+
+#[test]
+fn incompatible_content_produces_warnings() {
+    let dast_root = dast_root_no_position(
+        r#"<document><graph name="g" /><boolean name="b">true</boolean><number name="n">3</number><point name="P" x="$b" y="$g" /><math name="m">$P</math><number name="n2"><p>4</p></number><text name="t">$n</text></document>"#,
+    );
+
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root);
+
+    let flat_dast = core.to_flat_dast();
+
+    // A number can be used as text, a point can be used as math, and a boolean can be used through its text,
+    // so only the components that provide no value are ignored.
+    let messages = flat_dast
+        .warnings_with_code(DiagnosticCode::IncompatibleContent)
+        .map(|warning| warning.message.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            "`<graph>` cannot be used in the `y` attribute of `<point>`, so it was ignored",
+            "`<p>` cannot be used as a child of `<number>`, so it was ignored",
+        ]
+    );
+}
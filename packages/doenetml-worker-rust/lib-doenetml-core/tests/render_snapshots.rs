@@ -0,0 +1,46 @@
+//! Snapshot tests of the render tree. Each test renders a document and compares its canonical render tree,
+//! as given by `Core::render_tree_canonical`, to the snapshot stored in `tests/snapshots`.
+//!
+//! To lock the render output of a new component, add a test here and run
+//! `cargo insta test --review` (or `INSTA_UPDATE=always cargo test`) to create its snapshot,
+//! then check that the snapshot is correct before committing it.
+//! If a change alters the render output, the test fails with a diff against the stored snapshot.
+
+mod test_utils;
+
+use doenetml_core::core::core::Core;
+use test_utils::*;
+
+/// Render `source` and compare its canonical render tree to the snapshot named `name`.
+fn assert_render_snapshot(name: &str, source: &str) {
+    let mut core = Core::new();
+    core.init_from_dast_root(&dast_root_no_position(source));
+    insta::assert_snapshot!(name, core.render_tree_canonical());
+}
+
+#[test]
+fn text_in_document() {
+    assert_render_snapshot(
+        "text_in_document",
+        "<document>hi there<text>you</text></document>",
+    );
+}
+
+#[test]
+fn sibling_texts() {
+    assert_render_snapshot(
+        "sibling_texts",
+        "<document><text>a</text><text>b</text></document>",
+    );
+}
+
+#[test]
+fn render_tree_is_canonical_across_runs() {
+    let source = r#"<document><span zeta="1" alpha="2"><text>a</text></span></document>"#;
+    let render = || {
+        let mut core = Core::new();
+        core.init_from_dast_root(&dast_root_no_position(source));
+        core.render_tree_canonical()
+    };
+    assert_eq!(render(), render());
+}
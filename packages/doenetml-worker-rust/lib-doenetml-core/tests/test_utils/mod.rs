@@ -9,7 +9,7 @@ use doenetml_core::props::{PropValue, PropView};
 use serde_json;
 #[allow(unused)]
 pub use serde_json::{Value, json};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::Write;
 use std::path::PathBuf;
@@ -251,7 +251,14 @@ impl TestCore {
     pub fn dispatch_action(
         &mut self,
         action: Action,
-    ) -> Result<HashMap<ComponentIdx, FlatDastElementUpdate>, String> {
+    ) -> Result<BTreeMap<ComponentIdx, FlatDastElementUpdate>, String> {
         self.core.dispatch_action(action)
     }
+
+    pub fn dispatch_actions(
+        &mut self,
+        actions: Vec<Action>,
+    ) -> Result<BTreeMap<ComponentIdx, FlatDastElementUpdate>, String> {
+        self.core.dispatch_actions(actions)
+    }
 }
@@ -2,20 +2,27 @@ mod utils;
 
 extern crate web_sys;
 
-use std::collections::HashMap;
+use std::{collections::BTreeMap, rc::Rc};
 
 use serde::{Deserialize, Serialize};
 use tsify_next::Tsify;
 use wasm_bindgen::prelude::*;
 
 use doenetml_core::{
-    components::{prelude::ComponentIdx, types::Action},
-    core::core::Core,
+    components::{ComponentEnum, prelude::ComponentIdx, types::Action},
+    core::{
+        Annotation, ComponentProvenance, DebugStateSummary, DocumentEnvironment, DocumentFlags,
+        DocumentStats, EssentialData, EssentialDataEvent, ExperimentAssignment, NewAnnotation,
+        ObjectiveReport, PropBreakpointEvent, PropOverride, RenderOutputFormat,
+        ResolutionTraceEntry, SavedStateValue, SelectionDescriptor, StateDiff, StateEncryption,
+        core::Core, encode_render_output,
+    },
     dast::{
-        DastRoot, FlatDastElementUpdate, FlatDastRoot,
+        ComponentCapabilities, DastRoot, DastWarning, FlatDastElementUpdate, FlatDastRoot,
         flat_dast::{FlatFragment, FlatNode, FlatPathPart, Index, NormalizedRoot, UntaggedContent},
         ref_resolve::{IndexResolution, RefResolution, ResolutionError},
     },
+    props::PropValue,
 };
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
@@ -31,13 +38,14 @@ pub struct PublicDoenetMLCore {
     dast_root: Option<DastRoot>,
     source: String,
     flags_json: Option<String>,
+    saved_state: Option<Vec<u8>>,
     initialized: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Tsify)]
 #[tsify(into_wasm_abi)]
 pub struct ActionResponse {
-    payload: HashMap<ComponentIdx, FlatDastElementUpdate>,
+    payload: BTreeMap<ComponentIdx, FlatDastElementUpdate>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Tsify)]
@@ -63,6 +71,136 @@ pub struct RootNames {
     names: Vec<Option<String>>,
 }
 
+#[derive(Debug, Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+pub struct ResolutionTrace {
+    entries: Vec<ResolutionTraceEntry>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+pub struct BreakpointEvents {
+    events: Vec<PropBreakpointEvent>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+pub struct EssentialDataList {
+    data: Vec<EssentialData>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+pub struct EssentialDataEvents {
+    events: Vec<EssentialDataEvent>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+pub struct DeadContent {
+    warnings: Vec<DastWarning>,
+}
+
+/// Static information about the component types that may appear in DoenetML.
+#[derive(Debug, Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+pub struct ComponentManifest {
+    capabilities: BTreeMap<String, ComponentCapabilities>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+pub struct PropOverrides {
+    overrides: Vec<PropOverride>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+pub struct Annotations {
+    annotations: Vec<Annotation>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ActionBatch {
+    actions: Vec<Action>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct EphemeralState {
+    values: Vec<SavedStateValue>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Selections {
+    selections: Vec<SelectionDescriptor>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+pub struct ExperimentAssignments {
+    assignments: Vec<ExperimentAssignment>,
+}
+
+#[derive(Debug, Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+pub struct PropValueResponse {
+    value: Option<PropValue>,
+}
+
+/// Encryption of saved state by callbacks supplied from Javascript.
+#[derive(Debug)]
+struct JsStateEncryption {
+    encrypt: js_sys::Function,
+    decrypt: js_sys::Function,
+}
+
+impl JsStateEncryption {
+    fn call(callback: &js_sys::Function, data: &[u8]) -> Result<Vec<u8>, String> {
+        let result = callback
+            .call1(&JsValue::NULL, &js_sys::Uint8Array::from(data))
+            .map_err(|err| format!("{err:?}"))?;
+        result
+            .dyn_into::<js_sys::Uint8Array>()
+            .map(|array| array.to_vec())
+            .map_err(|_| "Encryption callback must return a Uint8Array".to_string())
+    }
+}
+
+impl StateEncryption for JsStateEncryption {
+    fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, String> {
+        Self::call(&self.encrypt, plaintext)
+    }
+    fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, String> {
+        Self::call(&self.decrypt, ciphertext)
+    }
+}
+
+/// Telemetry whose clock and events are supplied by callbacks from Javascript.
+#[derive(Debug)]
+struct JsTelemetry {
+    record: js_sys::Function,
+    now: js_sys::Function,
+}
+
+impl Telemetry for JsTelemetry {
+    fn now(&self) -> f64 {
+        self.now
+            .call0(&JsValue::NULL)
+            .ok()
+            .and_then(|time| time.as_f64())
+            .unwrap_or(0.0)
+    }
+    fn record(&self, event: TelemetryEvent) {
+        if let Ok(event) = serde_wasm_bindgen::to_value(&event) {
+            // Telemetry must never break the document, so errors thrown by the callback are ignored.
+            let _ = self.record.call1(&JsValue::NULL, &event);
+        }
+    }
+}
+
 // For some reason, wasm-bindgen won't always correctly see that a module is being used
 // in an exported interface. In that case, the Typescript types generated by that module will not
 // be picked up. As a workaround we can create a dummy struct in that module and use it here, forcing
@@ -84,6 +222,7 @@ impl PublicDoenetMLCore {
             dast_root: None,
             source: "".to_string(),
             flags_json: None,
+            saved_state: None,
             initialized: false,
         }
     }
@@ -100,6 +239,52 @@ impl PublicDoenetMLCore {
         self.initialized = false;
     }
 
+    /// Set the state (e.g., a student's responses) that the document is restored to when it is created.
+    /// `state` is a blob returned by `export_state`.
+    pub fn set_saved_state(&mut self, state: Vec<u8>) {
+        self.saved_state = Some(state);
+        self.initialized = false;
+    }
+
+    /// Set callbacks that encrypt and decrypt saved state, e.g., to encrypt student work at rest.
+    /// Each callback is passed a `Uint8Array` and must synchronously return a `Uint8Array`.
+    pub fn set_state_encryption(&mut self, encrypt: js_sys::Function, decrypt: js_sys::Function) {
+        self.core
+            .set_state_encryption(Some(Rc::new(JsStateEncryption { encrypt, decrypt })));
+    }
+
+    /// Set callbacks that receive sampled timing events, e.g., to monitor performance in production.
+    /// `record` is passed each `TelemetryEvent` and `now` must return the current time in milliseconds,
+    /// e.g., `() => performance.now()`. One of every `sample_interval` events of each kind is recorded.
+    pub fn set_telemetry(
+        &mut self,
+        record: js_sys::Function,
+        now: js_sys::Function,
+        sample_interval: usize,
+    ) {
+        self.core
+            .set_telemetry(Some(Rc::new(JsTelemetry { record, now })), sample_interval);
+    }
+
+    /// Remove the telemetry set by `set_telemetry`.
+    pub fn clear_telemetry(&mut self) {
+        self.core.set_telemetry(None, 1);
+    }
+
+    /// Save the state of the document (e.g., a student's responses) as a blob
+    /// that can be passed to `set_saved_state` to restore it.
+    pub fn export_state(&self) -> Result<Vec<u8>, String> {
+        self.core.export_state().map_err(|err| err.to_string())
+    }
+
+    /// List the values that differ between two states returned by `export_state`,
+    /// e.g., so an instructor can review what a student changed between submissions.
+    pub fn diff_states(&self, before: Vec<u8>, after: Vec<u8>) -> Result<StateDiff, String> {
+        self.core
+            .diff_states(&before, &after)
+            .map_err(|err| err.to_string())
+    }
+
     pub fn return_normalized_dast_root(&mut self) -> Result<NormalizedRoot, String> {
         let dast_root = match &self.dast_root {
             Some(d) => d,
@@ -152,24 +337,17 @@ impl PublicDoenetMLCore {
     }
 
     pub fn return_dast(&mut self) -> Result<FlatDastRoot, String> {
-        if !self.initialized {
-            let _flags = match &self.flags_json {
-                Some(f) => f,
-                None => return Err("Cannot create core before flags are set.".to_string()),
-            };
-            let dast_root = match &self.dast_root {
-                Some(d) => d,
-                None => return Err("Cannot create core before source is set.".to_string()),
-            };
-
-            // Create components from JSON tree and create all dependencies.
-            self.core.init_from_dast_root(dast_root);
-            self.initialized = true;
-        }
-
+        self.initialize()?;
         Ok(self.core.to_flat_dast())
     }
 
+    /// Like `return_dast`, but returns the FlatDast encoded as `format`, e.g., MessagePack,
+    /// which is faster to pass to Javascript for large documents.
+    pub fn return_dast_encoded(&mut self, format: RenderOutputFormat) -> Result<Vec<u8>, String> {
+        self.initialize()?;
+        encode_render_output(&self.core.to_flat_dast(), format).map_err(|err| err.to_string())
+    }
+
     /// Send an action to DoenetMLCore. This is often in response to a user
     /// interaction with a component (and requesting a change to that component, like
     /// changing the value of a slider).
@@ -181,6 +359,313 @@ impl PublicDoenetMLCore {
         })
     }
 
+    /// Like `dispatch_action`, but returns the updates to the FlatDast encoded as `format`, e.g., MessagePack.
+    pub fn dispatch_action_encoded(
+        &mut self,
+        action: Action,
+        format: RenderOutputFormat,
+    ) -> Result<Vec<u8>, String> {
+        let response = ActionResponse {
+            payload: self.core.dispatch_action(action)?,
+        };
+        encode_render_output(&response, format).map_err(|err| err.to_string())
+    }
+
+    /// Run a batch of actions (e.g., the `movePoint` actions from dragging a point)
+    /// so that the document is updated only once.
+    ///
+    /// Returns updates to the FlatDast.
+    pub fn dispatch_actions(&mut self, batch: ActionBatch) -> Result<ActionResponse, String> {
+        Ok(ActionResponse {
+            payload: self.core.dispatch_actions(batch.actions)?,
+        })
+    }
+
+    /// Revert the changes made by the most recent action that has not been undone.
+    ///
+    /// Returns updates to the FlatDast.
+    pub fn undo(&mut self) -> Result<ActionResponse, String> {
+        Ok(ActionResponse {
+            payload: self.core.undo()?,
+        })
+    }
+
+    /// Reapply the changes of the most recent action reverted by `undo`.
+    ///
+    /// Returns updates to the FlatDast.
+    pub fn redo(&mut self) -> Result<ActionResponse, String> {
+        Ok(ActionResponse {
+            payload: self.core.redo()?,
+        })
+    }
+
+    /// Whether there is an action that can be undone.
+    pub fn can_undo(&self) -> bool {
+        self.core.can_undo()
+    }
+
+    /// Whether there is an undone action that can be redone.
+    pub fn can_redo(&self) -> bool {
+        self.core.can_redo()
+    }
+
+    /// Return the status and last computed value of every prop in the document,
+    /// for use by devtools.
+    pub fn debug_state_summary(&self) -> DebugStateSummary {
+        self.core.debug_state_summary()
+    }
+
+    /// Start recording how props are calculated, keeping the `capacity` most recent calculations.
+    pub fn enable_resolution_trace(&self, capacity: usize) {
+        self.core.enable_resolution_trace(capacity);
+    }
+
+    pub fn disable_resolution_trace(&self) {
+        self.core.disable_resolution_trace();
+    }
+
+    /// Return the most recent prop calculations, oldest first.
+    pub fn get_resolution_trace(&self) -> ResolutionTrace {
+        ResolutionTrace {
+            entries: self.core.get_resolution_trace(),
+        }
+    }
+
+    /// Set a breakpoint on the prop named `prop_name` of the component with index `component_idx`.
+    pub fn set_prop_breakpoint(&self, component_idx: usize, prop_name: &str) -> Result<(), String> {
+        self.core
+            .set_prop_breakpoint(ComponentIdx::new(component_idx), prop_name)
+    }
+
+    pub fn clear_prop_breakpoint(
+        &self,
+        component_idx: usize,
+        prop_name: &str,
+    ) -> Result<(), String> {
+        self.core
+            .clear_prop_breakpoint(ComponentIdx::new(component_idx), prop_name)
+    }
+
+    /// Return the breakpoint events recorded since the last call, oldest first.
+    pub fn take_breakpoint_events(&self) -> BreakpointEvents {
+        BreakpointEvents {
+            events: self.core.take_breakpoint_events(),
+        }
+    }
+
+    /// Return the essential data of the component with index `component_idx`,
+    /// i.e., the state stored by its props, such as the value typed into a `<textInput>`.
+    pub fn list_essential_data(&self, component_idx: usize) -> Result<EssentialDataList, String> {
+        Ok(EssentialDataList {
+            data: self
+                .core
+                .list_essential_data(ComponentIdx::new(component_idx))?,
+        })
+    }
+
+    /// Set the essential data stored by the prop named `origin` of the component with index `component_idx`,
+    /// or only its entry at `index` (starting from 1) if the data is a vector.
+    ///
+    /// Returns updates to the FlatDast.
+    pub fn set_essential_data(
+        &mut self,
+        component_idx: usize,
+        origin: &str,
+        index: Option<usize>,
+        value: JsValue,
+    ) -> Result<ActionResponse, String> {
+        let value: serde_json::Value =
+            serde_wasm_bindgen::from_value(value).map_err(|err| err.to_string())?;
+        Ok(ActionResponse {
+            payload: self.core.set_essential_data(
+                ComponentIdx::new(component_idx),
+                origin,
+                index,
+                value,
+            )?,
+        })
+    }
+
+    /// Return the changes made to essential data since the last call, oldest first.
+    pub fn take_essential_data_events(&self) -> EssentialDataEvents {
+        EssentialDataEvents {
+            events: self.core.take_essential_data_events(),
+        }
+    }
+
+    /// Override the value of the prop named `prop_name` of the component with index `component_idx`,
+    /// e.g., so that an instructor can adjust credit. `reason` is recorded with the override for auditing.
+    ///
+    /// Returns updates to the FlatDast.
+    pub fn override_prop(
+        &mut self,
+        component_idx: usize,
+        prop_name: &str,
+        value: JsValue,
+        reason: &str,
+    ) -> Result<ActionResponse, String> {
+        let value: serde_json::Value =
+            serde_wasm_bindgen::from_value(value).map_err(|err| err.to_string())?;
+        Ok(ActionResponse {
+            payload: self.core.override_prop(
+                ComponentIdx::new(component_idx),
+                prop_name,
+                value,
+                reason,
+            )?,
+        })
+    }
+
+    /// Return the value of the prop named `prop_name` of the component named `component_name`,
+    /// calculating it if necessary, e.g., so that a headless host can read an answer's credit.
+    /// The value is `null` if there is no such component or prop.
+    pub fn get_prop_value(&mut self, component_name: &str, prop_name: &str) -> PropValueResponse {
+        PropValueResponse {
+            value: self.core.get_prop_value(component_name, prop_name),
+        }
+    }
+
+    /// Return the entry at `index` (starting from 1) of the vector-valued prop named `prop_name`
+    /// of the component named `component_name`.
+    pub fn get_prop_value_at(
+        &mut self,
+        component_name: &str,
+        prop_name: &str,
+        index: usize,
+    ) -> PropValueResponse {
+        PropValueResponse {
+            value: self
+                .core
+                .get_prop_value_at(component_name, prop_name, index),
+        }
+    }
+
+    /// Move keyboard focus to the component with index `component_idx`.
+    ///
+    /// Returns updates to the FlatDast, in which the component is marked to receive focus.
+    pub fn set_focus(&mut self, component_idx: usize) -> Result<ActionResponse, String> {
+        Ok(ActionResponse {
+            payload: self.core.set_focus(ComponentIdx::new(component_idx))?,
+        })
+    }
+
+    /// Return every override applied to the document, in the order they were applied.
+    pub fn get_prop_overrides(&self) -> PropOverrides {
+        PropOverrides {
+            overrides: self.core.get_prop_overrides(),
+        }
+    }
+
+    /// Add a student's annotation, e.g., a highlight or a note, to the document.
+    ///
+    /// Returns the annotation with the id that identifies it.
+    pub fn add_annotation(&mut self, new_annotation: NewAnnotation) -> Result<Annotation, String> {
+        self.core.add_annotation(new_annotation)
+    }
+
+    /// Remove the annotation with id `id`.
+    pub fn remove_annotation(&mut self, id: usize) -> Result<(), String> {
+        self.core.remove_annotation(id)
+    }
+
+    /// Return every annotation of the document, in the order they were added.
+    pub fn get_annotations(&self) -> Annotations {
+        Annotations {
+            annotations: self.core.get_annotations(),
+        }
+    }
+
+    /// Return the ephemeral state of the document, e.g., the position of the user's cursor,
+    /// to be broadcast to collaborators.
+    pub fn get_ephemeral_state(&self) -> EphemeralState {
+        EphemeralState {
+            values: self.core.get_ephemeral_state(),
+        }
+    }
+
+    /// Apply ephemeral state broadcast by a collaborator.
+    ///
+    /// Returns updates to the FlatDast.
+    pub fn apply_ephemeral_state(
+        &mut self,
+        state: EphemeralState,
+    ) -> Result<ActionResponse, String> {
+        Ok(ActionResponse {
+            payload: self.core.apply_ephemeral_state(state.values)?,
+        })
+    }
+
+    /// Make `user_id` the active user, swapping in their state for components with `scope="user"`.
+    ///
+    /// Returns updates to the FlatDast.
+    pub fn set_active_user(&mut self, user_id: &str) -> ActionResponse {
+        ActionResponse {
+            payload: self.core.set_active_user(user_id),
+        }
+    }
+
+    /// Set the environment in which the document is displayed, e.g., when the window is resized.
+    ///
+    /// Returns updates to the FlatDast.
+    pub fn set_environment(&mut self, environment: DocumentEnvironment) -> ActionResponse {
+        ActionResponse {
+            payload: self.core.set_environment(environment),
+        }
+    }
+
+    /// Return the credit earned on each learning objective, e.g., for standards-based grading.
+    pub fn get_objective_report(&self) -> ObjectiveReport {
+        self.core.get_objective_report()
+    }
+
+    /// Return the option selected by each `<select>`, to be saved and passed back in the
+    /// `seenSelections` flag on later attempts so that they avoid repeating it.
+    pub fn get_selections(&self) -> Selections {
+        Selections {
+            selections: self.core.get_selections(),
+        }
+    }
+
+    /// Return the arm assigned by each `<experiment>`, for analytics.
+    pub fn get_experiment_assignments(&self) -> ExperimentAssignments {
+        ExperimentAssignments {
+            assignments: self.core.get_experiment_assignments(),
+        }
+    }
+
+    /// Return the authorship metadata of the component with index `component_idx`
+    /// and the chain of components it was copied from.
+    pub fn get_component_provenance(
+        &self,
+        component_idx: usize,
+    ) -> Result<ComponentProvenance, String> {
+        self.core
+            .get_component_provenance(ComponentIdx::new(component_idx))
+    }
+
+    /// Return informational warnings for the components that are never rendered,
+    /// so that authors can clean up their documents.
+    pub fn find_dead_content(&mut self) -> DeadContent {
+        DeadContent {
+            warnings: self.core.find_dead_content(),
+        }
+    }
+
+    /// Return summary statistics of the document, e.g., for displaying a summary of an assignment.
+    pub fn document_stats(&mut self) -> DocumentStats {
+        self.core.document_stats()
+    }
+
+    /// Return the capabilities of each component type, so that hosts can choose fallbacks
+    /// for component types their renderers cannot support before rendering a document.
+    pub fn component_manifest() -> ComponentManifest {
+        ComponentManifest {
+            capabilities: ComponentEnum::authored_component_capabilities()
+                .into_iter()
+                .collect(),
+        }
+    }
+
     pub fn _run_test(&mut self, test_name: &str) {
         self.core._run_test(test_name);
     }
@@ -189,3 +674,33 @@ impl PublicDoenetMLCore {
         self.core._get_tests()
     }
 }
+
+impl PublicDoenetMLCore {
+    /// Create the document from the source, flags, and saved state, if it has not already been created.
+    fn initialize(&mut self) -> Result<(), String> {
+        if !self.initialized {
+            let flags = match &self.flags_json {
+                Some(f) => f,
+                None => return Err("Cannot create core before flags are set.".to_string()),
+            };
+            let flags: DocumentFlags =
+                serde_json::from_str(flags).map_err(|err| format!("Invalid flags: {err}"))?;
+            self.core.set_flags(flags);
+            let dast_root = match &self.dast_root {
+                Some(d) => d,
+                None => return Err("Cannot create core before source is set.".to_string()),
+            };
+
+            // Create components from JSON tree and create all dependencies.
+            match &self.saved_state {
+                Some(state_blob) => self
+                    .core
+                    .init_from_dast_root_and_state(dast_root, state_blob)
+                    .map_err(|err| format!("Could not restore saved state: {err}"))?,
+                None => self.core.init_from_dast_root(dast_root),
+            }
+            self.initialized = true;
+        }
+        Ok(())
+    }
+}
@@ -57,6 +57,18 @@ impl ComponentModule {
 
         let extend_via_default_prop = self.extend_via_default_prop;
 
+        let string_children_become = self
+            .string_children_become
+            .as_ref()
+            .map(|name| name.to_case(Case::Camel))
+            .map_or(quote! {None}, |name| quote! {Some(#name)});
+
+        let needs_pointer_events = self.needs_pointer_events;
+        let needs_keyboard = self.needs_keyboard;
+        let supports_print = !self.print_unsupported;
+        let supports_ssr = !self.ssr_unsupported;
+        let experimental = self.experimental;
+
         let action_names = self.actions.get_action_names();
         let attribute_names = self.attributes.get_attribute_names();
         let preserve_ref_attribute_indices = self.attributes.get_preserve_ref_attribute_indices();
@@ -81,8 +93,13 @@ impl ComponentModule {
             .map(|x| {
                 let in_graph = x.in_graph;
                 let in_text = x.in_text;
+                let only_in_mode = match (x.print_only, x.interactive_only) {
+                    (true, false) => quote! {Some(crate::props::RenderMode::Print)},
+                    (false, true) => quote! {Some(crate::props::RenderMode::Interactive)},
+                    _ => quote! {None},
+                };
                 quote! {
-                    crate::props::ForRenderOutputs { in_graph: #in_graph, in_text: #in_text}
+                    crate::props::ForRenderOutputs { in_graph: #in_graph, in_text: #in_text, only_in_mode: #only_in_mode}
                 }
             })
             .collect::<Vec<_>>();
@@ -110,6 +127,17 @@ impl ComponentModule {
 
                 const REF_TRANSMUTES_TO: Option<&'static str> = #ref_transmutes_to;
 
+                const STRING_CHILDREN_BECOME: Option<&'static str> = #string_children_become;
+
+                const CAPABILITIES: ComponentCapabilities = ComponentCapabilities {
+                    needs_pointer_events: #needs_pointer_events,
+                    needs_keyboard: #needs_keyboard,
+                    supports_print: #supports_print,
+                    supports_ssr: #supports_ssr,
+                };
+
+                const EXPERIMENTAL: bool = #experimental;
+
                 pub const ATTRIBUTE_NAMES: &'static [&'static str] = &[#(#attribute_names),*];
 
                 const PRESERVE_REF_ATTRIBUTE_INDICES: &'static [usize] = &[#(#preserve_ref_attribute_indices),*];
@@ -156,6 +184,18 @@ impl ComponentModule {
                 fn extend_via_default_prop(&self) -> bool {
                     Component::EXTEND_VIA_DEFAULT_PROP
                 }
+
+                fn string_children_become(&self) -> Option<&'static str> {
+                    Component::STRING_CHILDREN_BECOME
+                }
+
+                fn capabilities(&self) -> ComponentCapabilities {
+                    Component::CAPABILITIES
+                }
+
+                fn is_experimental(&self) -> bool {
+                    Component::EXPERIMENTAL
+                }
             }
         };
 
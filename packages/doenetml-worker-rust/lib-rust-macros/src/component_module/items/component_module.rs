@@ -1,5 +1,6 @@
 //! Parse the `mod component {...}` module.
 
+use convert_case::{Case, Casing};
 use darling::FromAttributes;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
@@ -26,6 +27,18 @@ pub struct ComponentModule {
     pub ref_transmutes_to: Option<String>,
     /// The value of the `extend_via_default_prop` field.
     pub extend_via_default_prop: bool,
+    /// The value of the `string_children_become` field.
+    pub string_children_become: Option<String>,
+    /// Whether the `needs_pointer_events` flag was given.
+    pub needs_pointer_events: bool,
+    /// Whether the `needs_keyboard` flag was given.
+    pub needs_keyboard: bool,
+    /// Whether the `print_unsupported` flag was given.
+    pub print_unsupported: bool,
+    /// Whether the `ssr_unsupported` flag was given.
+    pub ssr_unsupported: bool,
+    /// Whether the `experimental` flag was given.
+    pub experimental: bool,
 
     //
     // The content defined _inside_ the module
@@ -47,6 +60,17 @@ pub struct ComponentMacroVariant {
     ref_transmutes_to: Option<Ident>,
     #[darling(default)]
     extend_via_default_prop: bool,
+    string_children_become: Option<Ident>,
+    #[darling(default)]
+    needs_pointer_events: bool,
+    #[darling(default)]
+    needs_keyboard: bool,
+    #[darling(default)]
+    print_unsupported: bool,
+    #[darling(default)]
+    ssr_unsupported: bool,
+    #[darling(default)]
+    experimental: bool,
 }
 
 impl ComponentModule {
@@ -68,6 +92,14 @@ impl ComponentModule {
             name,
             ref_transmutes_to: component_macro.ref_transmutes_to.map(|x| x.to_string()),
             extend_via_default_prop: component_macro.extend_via_default_prop,
+            string_children_become: component_macro
+                .string_children_become
+                .map(|x| x.to_string()),
+            needs_pointer_events: component_macro.needs_pointer_events,
+            needs_keyboard: component_macro.needs_keyboard,
+            print_unsupported: component_macro.print_unsupported,
+            ssr_unsupported: component_macro.ssr_unsupported,
+            experimental: component_macro.experimental,
             props,
             actions,
             attributes,
@@ -163,6 +195,13 @@ impl ComponentModule {
                 // No explanation needed
             }
         }
+
+        if let Some(string_children_become) = self.string_children_become.as_ref() {
+            doc_comments.push_str(&format!(
+                "\n\nText children are split into items separated by commas or whitespace, and each item becomes a <{}> component.",
+                string_children_become.to_case(Case::Camel)
+            ));
+        }
         doc_comments
     }
 }
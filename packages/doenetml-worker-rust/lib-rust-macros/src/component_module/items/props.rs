@@ -38,18 +38,30 @@ impl PropsVariant {
     /// The `for_render()` method returns a cleaned-up `for_render` value from the result of darling's `Override` processing.
     /// In the cleaned-up result,
     /// - an absence of `for_render` leads to no render outputs,
-    /// - a simple `for_render` leads to all render outputs, and
-    /// - an explicit specification of the outputs, as in `for_render(in_graph)`, contains only the specified outputs.
+    /// - a simple `for_render` leads to all render outputs,
+    /// - an explicit specification of the outputs, as in `for_render(in_graph)`, contains only the specified outputs, and
+    /// - a specification of only a render mode, as in `for_render(print_only)`, leads to all render outputs in that mode.
     fn for_render(&self) -> ForRenderOutputsPrelim {
         match &self.for_render {
-            Some(Override::Explicit(value)) => value.clone(),
+            Some(Override::Explicit(value)) => {
+                let mut value = value.clone();
+                if !value.in_text && !value.in_graph {
+                    value.in_text = true;
+                    value.in_graph = true;
+                }
+                value
+            }
             Some(Override::Inherit) => ForRenderOutputsPrelim {
                 in_text: true,
                 in_graph: true,
+                print_only: false,
+                interactive_only: false,
             },
             None => ForRenderOutputsPrelim {
                 in_text: false,
                 in_graph: false,
+                print_only: false,
+                interactive_only: false,
             },
         }
     }
@@ -62,6 +74,28 @@ pub struct ForRenderOutputsPrelim {
     pub in_text: bool,
     #[darling(default)]
     pub in_graph: bool,
+    /// The prop is rendered only when the document is rendered for print.
+    #[darling(default)]
+    pub print_only: bool,
+    /// The prop is rendered only when the document is rendered interactively.
+    #[darling(default)]
+    pub interactive_only: bool,
+}
+
+impl ForRenderOutputsPrelim {
+    /// Whether the prop is rendered only in some render modes.
+    fn is_mode_specific(&self) -> bool {
+        self.print_only || self.interactive_only
+    }
+
+    /// The description of the render mode restriction, if any, for use in doc comments.
+    fn mode_description(&self) -> &'static str {
+        match (self.print_only, self.interactive_only) {
+            (true, false) => " It is rendered only when the document is rendered for print.",
+            (false, true) => " It is rendered only when the document is rendered interactively.",
+            _ => "",
+        }
+    }
 }
 
 /// The `enum Props {...}` in a component module.
@@ -200,24 +234,23 @@ impl PropsEnum {
             }
         }
 
-        match (variant.for_render().in_graph, variant.for_render().in_text) {
+        let for_render = variant.for_render();
+        let mode_description = for_render.mode_description();
+        match (for_render.in_graph, for_render.in_text) {
             (true, true) => {
-                descriptions.push(
-                    "- ForRender: this prop is always rendered and available to the UI."
-                        .to_string(),
-                );
+                descriptions.push(format!(
+                    "- ForRender: this prop is always rendered and available to the UI.{mode_description}"
+                ));
             }
             (true, false) => {
-                descriptions.push(
-                    "- ForRender: this prop is rendered and available to the UI only in a graph."
-                        .to_string(),
-                );
+                descriptions.push(format!(
+                    "- ForRender: this prop is rendered and available to the UI only in a graph.{mode_description}"
+                ));
             }
             (false, true) => {
-                descriptions.push(
-                    "- ForRender: this prop is rendered and available to the UI only in text."
-                        .to_string(),
-                );
+                descriptions.push(format!(
+                    "- ForRender: this prop is rendered and available to the UI only in text.{mode_description}"
+                ));
             }
             (false, false) => {
                 descriptions.push("- NotForRender: this prop is not rendered.".to_string());
@@ -370,7 +403,7 @@ impl PropsEnum {
                 // `Foo` as a literal type name. (The corresponding type definition of `Foo` should be exported elsewhere.)
                 let value_type_name = value_type.segments.last().unwrap().ident.to_string();
 
-                Some((prop_name, value_type_name))
+                Some((prop_name, value_type_name, for_render.is_mode_specific()))
             })
             .collect::<Vec<_>>();
 
@@ -384,18 +417,26 @@ impl PropsEnum {
                 // `Foo` as a literal type name. (The corresponding type definition of `Foo` should be exported elsewhere.)
                 let value_type_name = value_type.segments.last().unwrap().ident.to_string();
 
-                Some((prop_name, value_type_name))
+                Some((prop_name, value_type_name, for_render.is_mode_specific()))
             })
             .collect::<Vec<_>>();
 
         let for_render_props_in_graph_ts = for_render_props_in_graph
             .iter()
-            .map(|(prop_name, value_type_name)| format!("{prop_name}: {value_type_name}"))
+            .map(|(prop_name, value_type_name, is_mode_specific)| {
+                // Props that are rendered only in some render modes may be absent.
+                let optional = if *is_mode_specific { "?" } else { "" };
+                format!("{prop_name}{optional}: {value_type_name}")
+            })
             .collect::<Vec<_>>()
             .join(", ");
         let for_render_props_in_text_ts = for_render_props_in_text
             .iter()
-            .map(|(prop_name, value_type_name)| format!("{prop_name}: {value_type_name}"))
+            .map(|(prop_name, value_type_name, is_mode_specific)| {
+                // Props that are rendered only in some render modes may be absent.
+                let optional = if *is_mode_specific { "?" } else { "" };
+                format!("{prop_name}{optional}: {value_type_name}")
+            })
             .collect::<Vec<_>>()
             .join(", ");
 
@@ -72,6 +72,16 @@ mod try_from_ref;
 ///   syntax), then instead of creating a component `<self>`, create the component specified by `ref_transmutes_to`.
 ///   This is used, for example, in the `textInput` component where the code `<textInput name="a"/>$a` should render as
 ///   `<textInput name="a"/><text extend="$a"/>` rather than `<textInput name="a"/><textInput extend="$a"/>`.
+/// - `string_children_become = ...` - Optional; supplied as an unquoted string. Text children of this component are split
+///   into items separated by commas or whitespace, and each item becomes a child component of the type specified by `string_children_become`.
+///   This is used, for example, in the `numberList` component where the code `<numberList>1 2</numberList>` is treated as
+///   `<numberList><number>1</number><number>2</number></numberList>`.
+/// - `needs_pointer_events`, `needs_keyboard`, `print_unsupported`, `ssr_unsupported` - Optional flags describing what a renderer
+///   must be able to do to render this component. They are sent to the renderers so that a host lacking a capability can
+///   substitute a fallback. E.g., `#[component(name = Slider, needs_pointer_events, needs_keyboard)]`.
+/// - `experimental` - Optional flag; the component may only be used in a document if its name is listed in the
+///   `experimentalComponents` flag given to core. Otherwise, it is replaced by an error. This allows a new component
+///   to be rolled out gradually.
 ///
 /// ### `#[attribute(...)]`
 ///
@@ -120,6 +130,8 @@ mod try_from_ref;
 /// - `default` - Optional; if set, this prop will be the default prop for the component. Only **one** prop can be the default prop.
 /// - `for_render` or `for_render(...)` - Optional. If specify `for_render` without arguments, this prop will always be sent to the renderer, whether in a graph or in text.
 ///   If specify `for_render(in_graph)` or `for_render(in_text)`, the prop will be sent to the renderer only if the component is in a graph or in text.
+///   If additionally specify `print_only` or `interactive_only`, as in `for_render(print_only)` or `for_render(in_text, interactive_only)`,
+///   the prop will be sent to the renderer only if the document is rendered for print or interactively.
 ///   If `for_render` is not given, this prop will be not included in data sent to the UI.
 ///
 #[proc_macro_attribute]
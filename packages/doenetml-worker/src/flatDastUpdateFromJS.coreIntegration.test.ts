@@ -291,7 +291,7 @@ describe.skipIf(!wasmAvailable)(
             expect(updates[nIdx].changedState?.text).toBe("7");
         });
 
-        it("translates the prototype `changeBoundingBox` action and propagates back to a bound number", async () => {
+        it("passes the prototype `changeAxisLimits` action through and propagates back to a bound number", async () => {
             const {
                 core,
                 resolvePathToNodeIdx,
@@ -306,22 +306,21 @@ describe.skipIf(!wasmAvailable)(
             const xmaxIdx = await resolvePathToNodeIdx("xmax");
 
             // Mirror the bridge's lookup: resolve the component type from the
-            // seeded map, then translate. The prototype's graph renderer
-            // dispatches the rust action name `changeBoundingBox`; the JS core
-            // registers it as `changeAxisLimits`.
+            // seeded map, then translate. The rust and JS cores both name the
+            // graph's action `changeAxisLimits`, so the name passes through.
             expect(componentIdxToName[gIdx]).toBe("graph");
-            const changeBoundingBoxAction = translateJsCoreActionName(
+            const changeAxisLimitsAction = translateJsCoreActionName(
                 componentIdxToName[gIdx],
-                "changeBoundingBox",
+                "changeAxisLimits",
             );
-            expect(changeBoundingBoxAction).toBe("changeAxisLimits");
+            expect(changeAxisLimitsAction).toBe("changeAxisLimits");
 
             // Panning/zooming the graph in the renderer changes its bounding box.
             // Because `xmax` is the source of the graph's `xMax`, the new value
             // must flow back to the `number` so its `$xmax` display updates.
             await core.requestAction({
                 componentIdx: gIdx,
-                actionName: changeBoundingBoxAction,
+                actionName: changeAxisLimitsAction,
                 args: { xMin: -5, xMax: 25, yMin: -5, yMax: 5 },
             });
 
@@ -2,13 +2,10 @@ import { describe, expect, it } from "vitest";
 import { translateJsCoreActionName } from "./jsCoreActionNames";
 
 describe("translateJsCoreActionName", () => {
-    it("translates the graph components' mismatched action names", () => {
+    it("translates the point's mismatched action name", () => {
         // The prototype dispatches the rust action names; the JS core registers
-        // different names for the graph components.
+        // a different name for moving a point.
         expect(translateJsCoreActionName("point", "move")).toBe("movePoint");
-        expect(translateJsCoreActionName("graph", "changeBoundingBox")).toBe(
-            "changeAxisLimits",
-        );
     });
 
     it("passes through action names that already match the JS core", () => {
@@ -18,6 +15,9 @@ describe("translateJsCoreActionName", () => {
         expect(
             translateJsCoreActionName("textInput", "updateImmediateValue"),
         ).toBe("updateImmediateValue");
+        expect(translateJsCoreActionName("graph", "changeAxisLimits")).toBe(
+            "changeAxisLimits",
+        );
     });
 
     it("passes through unknown component types and unmapped actions", () => {
@@ -4,12 +4,10 @@
  *
  * The prototype renderers were written against the rust core, so most action
  * names already match (e.g. `textInput`'s `updateValue` /
- * `updateImmediateValue`). The graph components are the exceptions: the rust
- * core (and thus the prototype) uses `move` / `changeBoundingBox`, while the JS
- * core registers `movePoint` / `changeAxisLimits`. The args are compatible in
- * both cases (JS `movePoint` accepts `{x, y, z}`, a superset of `{x, y}`; both
- * graph actions take `{xMin, xMax, yMin, yMax}`), so only the name needs
- * translating.
+ * `updateImmediateValue`, and `graph`'s `changeAxisLimits`). `point` is the
+ * exception: the rust core (and thus the prototype) uses `move`, while the JS
+ * core registers `movePoint`. The args are compatible (JS `movePoint` accepts
+ * `{x, y, z}`, a superset of `{x, y}`), so only the name needs translating.
  *
  * This name translation is temporary scaffolding. The differing names are an
  * artifact of the JS and Rust cores being developed separately; once the action
@@ -28,7 +26,6 @@ export const JS_CORE_ACTION_NAME_OVERRIDES: Record<
     Record<string, string>
 > = {
     point: { move: "movePoint" },
-    graph: { changeBoundingBox: "changeAxisLimits" },
 };
 
 /**